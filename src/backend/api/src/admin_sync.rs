@@ -0,0 +1,21 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AdminSyncConfig {
+    pub promote_controllers: bool,
+    pub demote_removed_controllers: bool,
+}
+
+pub type GetAdminSyncConfigResponse = AdminSyncConfig;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UpdateAdminSyncConfigRequest {
+    pub promote_controllers: Option<bool>,
+    pub demote_removed_controllers: Option<bool>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SyncAdminsResponse {
+    pub promoted_user_ids: Vec<String>,
+    pub demoted_user_ids: Vec<String>,
+}
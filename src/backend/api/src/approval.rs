@@ -0,0 +1,80 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SensitiveAction {
+    #[serde(rename = "set_user_roles")]
+    SetUserRoles,
+    #[serde(rename = "update_user_profile")]
+    UpdateUserProfile,
+    #[serde(rename = "execute_reward_payouts")]
+    ExecuteRewardPayouts,
+    #[serde(rename = "update_reviewer_assignment_config")]
+    UpdateReviewerAssignmentConfig,
+    #[serde(rename = "update_admin_sync_config")]
+    UpdateAdminSyncConfig,
+    #[serde(rename = "update_approval_config")]
+    UpdateApprovalConfig,
+    #[serde(rename = "suspend_user")]
+    SuspendUser,
+    #[serde(rename = "restore_user")]
+    RestoreUser,
+    #[serde(rename = "approve_reviewer_application")]
+    ApproveReviewerApplication,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApprovalConfig {
+    pub required_approvals: u8,
+    pub timeout_seconds: u64,
+    pub sensitive_actions: Vec<SensitiveAction>,
+}
+
+pub type GetApprovalConfigResponse = ApprovalConfig;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UpdateApprovalConfigRequest {
+    pub required_approvals: Option<u8>,
+    pub timeout_seconds: Option<u64>,
+    pub sensitive_actions: Option<Vec<SensitiveAction>>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ApprovalRequestStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "executed")]
+    Executed {
+        executed_by: String,
+        executed_at: String,
+    },
+    #[serde(rename = "expired")]
+    Expired,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApprovalRequest {
+    pub id: String,
+    pub action: SensitiveAction,
+    /// The candid encoded arguments of the endpoint call, decoded with the endpoint's argument type.
+    pub arguments: Vec<u8>,
+    pub requested_by: String,
+    pub requested_at: String,
+    pub expires_at: String,
+    pub required_approvals: u8,
+    pub approved_by: Vec<String>,
+    pub status: ApprovalRequestStatus,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListApprovalRequestsResponse {
+    pub approval_requests: Vec<ApprovalRequest>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApproveApprovalRequestRequest {
+    pub id: String,
+}
+
+pub type ApproveApprovalRequestResponse = ApprovalRequest;
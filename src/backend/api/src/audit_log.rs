@@ -0,0 +1,94 @@
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    #[serde(rename = "update_user_profile")]
+    UpdateUserProfile,
+    #[serde(rename = "suspend_user")]
+    SuspendUser,
+    #[serde(rename = "restore_user")]
+    RestoreUser,
+    #[serde(rename = "set_user_roles")]
+    SetUserRoles,
+    #[serde(rename = "sync_admins")]
+    SyncAdmins,
+    #[serde(rename = "update_admin_sync_config")]
+    UpdateAdminSyncConfig,
+    #[serde(rename = "approve_reviewer_application")]
+    ApproveReviewerApplication,
+    #[serde(rename = "reject_reviewer_application")]
+    RejectReviewerApplication,
+    #[serde(rename = "create_reward_period")]
+    CreateRewardPeriod,
+    #[serde(rename = "update_reward_period")]
+    UpdateRewardPeriod,
+    #[serde(rename = "close_reward_period")]
+    CloseRewardPeriod,
+    #[serde(rename = "execute_reward_payouts")]
+    ExecuteRewardPayouts,
+    #[serde(rename = "verify_reviewer_neuron")]
+    VerifyReviewerNeuron,
+    #[serde(rename = "assign_reviewer")]
+    AssignReviewer,
+    #[serde(rename = "update_reviewer_assignment_config")]
+    UpdateReviewerAssignmentConfig,
+    #[serde(rename = "approve_approval_request")]
+    ApproveApprovalRequest,
+    #[serde(rename = "update_approval_config")]
+    UpdateApprovalConfig,
+    #[serde(rename = "resolve_review_conflict")]
+    ResolveReviewConflict,
+    #[serde(rename = "score_proposal_review")]
+    ScoreProposalReview,
+    #[serde(rename = "sync_proposals")]
+    SyncProposals,
+    #[serde(rename = "moderate_comment")]
+    ModerateComment,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AuditLogEntry {
+    pub id: u64,
+    pub actor: Principal,
+    pub action: AuditAction,
+    pub target_ids: Vec<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    pub timestamp: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListAuditLogEntriesRequest {
+    pub action: Option<AuditAction>,
+    pub actor: Option<Principal>,
+    /// Only returns entries that affected the record with this id.
+    pub target_id: Option<String>,
+    /// Number of entries to skip, defaults to 0.
+    pub offset: Option<u64>,
+    /// Maximum number of entries to return, defaults to 50 and cannot exceed 100.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListAuditLogEntriesResponse {
+    /// Ordered from the most recent to the oldest entry.
+    pub entries: Vec<AuditLogEntry>,
+    /// Number of entries matching the filters.
+    pub total: u64,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExportAuditLogRequest {
+    pub before_timestamp_ms: Option<u64>,
+    pub after_timestamp_ms: Option<u64>,
+    /// Number of entries to skip, defaults to 0.
+    pub offset: Option<u64>,
+    /// Maximum number of entries to return, defaults to 500 and cannot exceed 1000.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExportAuditLogResponse {
+    /// Ordered from the oldest to the most recent entry.
+    pub entries: Vec<AuditLogEntry>,
+}
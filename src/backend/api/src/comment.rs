@@ -0,0 +1,83 @@
+use candid::{CandidType, Deserialize};
+
+use crate::HistoryEntry;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum CommentTarget {
+    #[serde(rename = "proposal")]
+    Proposal { proposal_id: String },
+    #[serde(rename = "proposal_review")]
+    ProposalReview { proposal_review_id: String },
+    #[serde(rename = "proposal_review_commit")]
+    ProposalReviewCommit { proposal_review_commit_id: String },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub target: CommentTarget,
+    pub parent_id: Option<String>,
+    pub user_id: String,
+    /// Empty if the comment was deleted.
+    pub content: String,
+    pub created_at: String,
+    pub last_updated_at: Option<String>,
+    pub deleted_at: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CommentWithId {
+    pub id: String,
+    pub comment: Comment,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CreateCommentRequest {
+    pub target: CommentTarget,
+    /// Set to reply to an existing comment on the same target.
+    pub parent_id: Option<String>,
+    pub content: String,
+}
+
+pub type CreateCommentResponse = CommentWithId;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UpdateCommentRequest {
+    pub id: String,
+    pub content: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct DeleteCommentRequest {
+    pub id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListCommentsRequest {
+    pub target: CommentTarget,
+    /// Number of comments to skip, defaults to 0.
+    pub offset: Option<u64>,
+    /// Maximum number of comments to return, defaults to 50 and cannot exceed 100.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListCommentsResponse {
+    /// Ordered by creation date, oldest first.
+    pub comments: Vec<CommentWithId>,
+    pub total: u64,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetCommentHistoryRequest {
+    pub id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CommentHistoryEntry {
+    pub content: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetCommentHistoryResponse {
+    pub history: Vec<HistoryEntry<CommentHistoryEntry>>,
+}
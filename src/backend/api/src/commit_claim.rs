@@ -0,0 +1,46 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CommitClaim {
+    pub proposal_id: String,
+    pub commit_sha: String,
+    pub user_id: String,
+    pub claimed_at: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ClaimCommitRequest {
+    pub proposal_id: String,
+    pub commit_sha: String,
+}
+
+pub type ClaimCommitResponse = CommitClaim;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReleaseCommitClaimRequest {
+    pub proposal_id: String,
+    pub commit_sha: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListCommitClaimsRequest {
+    pub proposal_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListCommitClaimsResponse {
+    pub commit_claims: Vec<CommitClaim>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListUnclaimedCommitsRequest {
+    pub proposal_id: String,
+    /// The commits of the proposal, as listed in its summary.
+    pub commit_shas: Vec<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListUnclaimedCommitsResponse {
+    pub commit_shas: Vec<String>,
+}
@@ -0,0 +1,31 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Endorsement {
+    pub proposal_review_id: String,
+    pub proposal_review_commit_id: Option<String>,
+    pub user_id: String,
+    pub created_at: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct EndorsementWithId {
+    pub id: String,
+    pub endorsement: Endorsement,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CreateEndorsementRequest {
+    pub proposal_review_id: String,
+    /// Set to endorse a single commit assessment instead of the whole proposal review.
+    pub proposal_review_commit_id: Option<String>,
+    pub note: Option<String>,
+}
+
+pub type CreateEndorsementResponse = EndorsementWithId;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct DeleteEndorsementRequest {
+    pub id: String,
+}
@@ -8,6 +8,8 @@ pub enum HistoryAction {
     Update,
     #[serde(rename = "delete")]
     Delete,
+    #[serde(rename = "suspend")]
+    Suspend,
     #[serde(rename = "restore")]
     Restore,
 }
@@ -0,0 +1,35 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum NeuronVerificationStatus {
+    #[serde(rename = "verified")]
+    Verified,
+
+    #[serde(rename = "unverified")]
+    Unverified { reason: String },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct NeuronVerification {
+    pub user_id: String,
+    pub neuron_id: u64,
+    pub status: NeuronVerificationStatus,
+    pub checked_at: String,
+    pub verified_at: Option<String>,
+}
+
+pub type VerifyMyNeuronResponse = NeuronVerification;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VerifyReviewerNeuronRequest {
+    pub user_id: String,
+}
+
+pub type VerifyReviewerNeuronResponse = NeuronVerification;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetNeuronVerificationRequest {
+    pub user_id: String,
+}
+
+pub type GetNeuronVerificationResponse = NeuronVerification;
@@ -0,0 +1,34 @@
+use candid::{CandidType, Deserialize, Principal};
+
+use crate::HistoryEntry;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CreatePrincipalLinkCodeResponse {
+    pub code: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RedeemPrincipalLinkCodeRequest {
+    pub code: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListMyPrincipalsResponse {
+    pub principals: Vec<Principal>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UnlinkMyPrincipalRequest {
+    pub principal: Principal,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct PrincipalLinkHistoryEntry {
+    pub principal: Principal,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetMyPrincipalLinkHistoryResponse {
+    pub history: Vec<HistoryEntry<PrincipalLinkHistoryEntry>>,
+}
@@ -1,6 +1,6 @@
 use candid::{CandidType, Deserialize};
 
-use crate::ProposalReviewCommitWithId;
+use crate::{EndorsementWithId, ProposalReviewCommitWithId};
 
 #[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
 pub enum ProposalReviewStatus {
@@ -32,6 +32,8 @@ pub struct ProposalReview {
     pub images_paths: Vec<String>,
     pub proposal_review_commits: Vec<ProposalReviewCommitWithId>,
     pub vote: ProposalVote,
+    pub attachments_paths: Vec<String>,
+    pub endorsements: Vec<EndorsementWithId>,
 }
 
 #[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
@@ -77,6 +79,25 @@ pub struct DeleteProposalReviewImageRequest {
     pub image_path: String,
 }
 
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct CreateProposalReviewAttachmentRequest {
+    pub proposal_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub content_bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct CreateProposalReviewAttachmentResponse {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct DeleteProposalReviewAttachmentRequest {
+    pub proposal_id: String,
+    pub attachment_path: String,
+}
+
 #[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
 pub struct ListProposalReviewsRequest {
     pub proposal_id: Option<String>,
@@ -0,0 +1,55 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewConflictKind {
+    #[serde(rename = "vote")]
+    Vote,
+    #[serde(rename = "build_reproduced")]
+    BuildReproduced,
+    #[serde(rename = "commit_matches_description")]
+    CommitMatchesDescription { commit_sha: String },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewConflictStatus {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "resolved")]
+    Resolved {
+        resolved_by: String,
+        resolved_at: String,
+        note: String,
+    },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewConflict {
+    pub proposal_id: String,
+    pub kind: ReviewConflictKind,
+    pub proposal_review_ids: Vec<String>,
+    pub detected_at: String,
+    pub last_updated_at: Option<String>,
+    pub status: ReviewConflictStatus,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewConflictWithId {
+    pub id: String,
+    pub review_conflict: ReviewConflict,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListOpenReviewConflictsRequest {
+    pub proposal_id: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListOpenReviewConflictsResponse {
+    pub review_conflicts: Vec<ReviewConflictWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ResolveReviewConflictRequest {
+    pub id: String,
+    pub note: String,
+}
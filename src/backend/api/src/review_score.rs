@@ -0,0 +1,47 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewScore {
+    pub proposal_review_id: String,
+    pub proposal_id: String,
+    pub reviewer_user_id: String,
+    pub scored_by: String,
+    pub depth: u8,
+    pub accuracy: u8,
+    pub timeliness: u8,
+    pub comment: String,
+    pub created_at: String,
+    pub last_updated_at: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerReputation {
+    /// Average score of the reviewer's most recently scored reviews, from 0 to 100.
+    pub score: u8,
+    pub scored_reviews_count: u32,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ScoreProposalReviewRequest {
+    pub proposal_review_id: String,
+    /// From 1 to 5.
+    pub depth: u8,
+    /// From 1 to 5.
+    pub accuracy: u8,
+    /// From 1 to 5.
+    pub timeliness: u8,
+    pub comment: String,
+}
+
+pub type ScoreProposalReviewResponse = ReviewScore;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListReviewScoresRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListReviewScoresResponse {
+    pub review_scores: Vec<ReviewScore>,
+    pub reputation: Option<ReviewerReputation>,
+}
@@ -0,0 +1,68 @@
+use candid::{CandidType, Deserialize};
+
+use crate::SocialLink;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerApplicationStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "approved")]
+    Approved {
+        reviewed_by: String,
+        reviewed_at: String,
+    },
+    #[serde(rename = "rejected")]
+    Rejected {
+        reviewed_by: String,
+        reviewed_at: String,
+        reason: String,
+    },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerApplication {
+    pub user_id: String,
+    pub bio: String,
+    pub neuron_id: u64,
+    pub wallet_address: String,
+    pub social_links: Vec<SocialLink>,
+    pub created_at: String,
+    pub status: ReviewerApplicationStatus,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerApplicationWithId {
+    pub id: String,
+    pub reviewer_application: ReviewerApplication,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SubmitReviewerApplicationRequest {
+    pub bio: String,
+    pub neuron_id: u64,
+    pub wallet_address: String,
+    pub social_links: Vec<SocialLink>,
+}
+
+pub type SubmitReviewerApplicationResponse = ReviewerApplicationWithId;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListReviewerApplicationsRequest {
+    pub pending_only: bool,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListReviewerApplicationsResponse {
+    pub reviewer_applications: Vec<ReviewerApplicationWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApproveReviewerApplicationRequest {
+    pub id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RejectReviewerApplicationRequest {
+    pub id: String,
+    pub reason: String,
+}
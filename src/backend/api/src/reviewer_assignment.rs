@@ -0,0 +1,118 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerAssignmentStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "accepted")]
+    Accepted,
+    #[serde(rename = "declined")]
+    Declined { reason: Option<String> },
+    #[serde(rename = "fulfilled")]
+    Fulfilled,
+    #[serde(rename = "unfulfilled")]
+    Unfulfilled,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerAssignmentStatusKey {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "accepted")]
+    Accepted,
+    #[serde(rename = "declined")]
+    Declined,
+    #[serde(rename = "fulfilled")]
+    Fulfilled,
+    #[serde(rename = "unfulfilled")]
+    Unfulfilled,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerAssignment {
+    pub proposal_id: String,
+    pub user_id: String,
+    pub assigned_by: Option<String>,
+    pub created_at: String,
+    pub last_updated_at: Option<String>,
+    pub status: ReviewerAssignmentStatus,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerAssignmentWithId {
+    pub id: String,
+    pub reviewer_assignment: ReviewerAssignment,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AssignReviewerRequest {
+    pub proposal_id: String,
+    pub user_id: String,
+}
+
+pub type AssignReviewerResponse = ReviewerAssignmentWithId;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AcceptReviewerAssignmentRequest {
+    pub id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct DeclineReviewerAssignmentRequest {
+    pub id: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListMyReviewerAssignmentsRequest {
+    pub status: Option<ReviewerAssignmentStatusKey>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListMyReviewerAssignmentsResponse {
+    pub reviewer_assignments: Vec<ReviewerAssignmentWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListProposalReviewerAssignmentsRequest {
+    pub proposal_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListProposalReviewerAssignmentsResponse {
+    pub reviewer_assignments: Vec<ReviewerAssignmentWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListUnfulfilledReviewerAssignmentsRequest {
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListUnfulfilledReviewerAssignmentsResponse {
+    pub reviewer_assignments: Vec<ReviewerAssignmentWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerAssignmentPolicy {
+    #[serde(rename = "manual")]
+    Manual,
+    #[serde(rename = "round_robin")]
+    RoundRobin,
+    #[serde(rename = "least_loaded")]
+    LeastLoaded,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerAssignmentConfig {
+    pub policy: ReviewerAssignmentPolicy,
+    pub reviewers_per_proposal: u8,
+}
+
+pub type GetReviewerAssignmentConfigResponse = ReviewerAssignmentConfig;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UpdateReviewerAssignmentConfigRequest {
+    pub policy: Option<ReviewerAssignmentPolicy>,
+    pub reviewers_per_proposal: Option<u8>,
+}
@@ -0,0 +1,45 @@
+use candid::{CandidType, Deserialize};
+
+use crate::{ProposalVote, UserProfile};
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetReviewerProfileRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct ReviewerTopicStatistics {
+    /// The NNS topic of the proposals, as in `ProposalInfo`.
+    pub topic: i32,
+    pub proposals_count: u64,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct ReviewerProfileStatistics {
+    pub published_reviews_count: u64,
+    pub proposals_reviewed_by_topic: Vec<ReviewerTopicStatistics>,
+    pub reviewed_commits_count: u64,
+    /// Share of the published reviews that reproduced the build, from 0 to 100.
+    /// Only set when the reviewer has at least one published review.
+    pub build_reproduced_rate: Option<u8>,
+    /// Only set when the reviewer has at least one published review.
+    pub average_publication_delay_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct ReviewerRecentReview {
+    pub proposal_review_id: String,
+    pub proposal_id: String,
+    pub nervous_system_proposal_id: u64,
+    pub published_at: String,
+    pub vote: ProposalVote,
+    pub build_reproduced: Option<bool>,
+}
+
+#[derive(Debug, Clone, CandidType, PartialEq, Eq)]
+pub struct GetReviewerProfileResponse {
+    pub profile: UserProfile,
+    pub statistics: ReviewerProfileStatistics,
+    /// The most recently published reviews, newest first.
+    pub recent_reviews: Vec<ReviewerRecentReview>,
+}
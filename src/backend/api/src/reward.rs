@@ -0,0 +1,139 @@
+use candid::{CandidType, Deserialize};
+
+/// Amounts are expressed in e8s.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardRules {
+    pub reward_per_review_e8s: u64,
+    pub verified_build_bonus_e8s: u64,
+    pub late_review_penalty_e8s: u64,
+    /// A review is late if it is published more than this many hours after the proposal was proposed.
+    pub late_review_after_hours: u32,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum RewardPeriodStatus {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "closed")]
+    Closed { closed_at: String },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardPeriod {
+    pub name: String,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub rules: RewardRules,
+    pub status: RewardPeriodStatus,
+    pub created_by: String,
+    pub created_at: String,
+    pub last_updated_at: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardPeriodWithId {
+    pub id: String,
+    pub reward_period: RewardPeriod,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CreateRewardPeriodRequest {
+    pub name: String,
+    pub start_timestamp_ms: u64,
+    pub end_timestamp_ms: u64,
+    pub rules: RewardRules,
+}
+
+pub type CreateRewardPeriodResponse = RewardPeriodWithId;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UpdateRewardPeriodRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub start_timestamp_ms: Option<u64>,
+    pub end_timestamp_ms: Option<u64>,
+    pub rules: Option<RewardRules>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CloseRewardPeriodRequest {
+    pub id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListRewardPeriodsResponse {
+    pub reward_periods: Vec<RewardPeriodWithId>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardStatementItem {
+    pub proposal_id: String,
+    pub proposal_review_id: String,
+    pub verified_build: bool,
+    pub late: bool,
+    pub amount_e8s: u64,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardStatement {
+    pub user_id: String,
+    pub wallet_address: String,
+    pub items: Vec<RewardStatementItem>,
+    pub total_e8s: u64,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExportRewardStatementsRequest {
+    pub reward_period_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExportRewardStatementsResponse {
+    pub reward_period: RewardPeriodWithId,
+    pub statements: Vec<RewardStatement>,
+    pub total_e8s: u64,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum RewardPayoutStatus {
+    #[serde(rename = "pending")]
+    Pending {
+        attempts: u32,
+        last_error: Option<String>,
+    },
+    #[serde(rename = "completed")]
+    Completed {
+        block_index: u64,
+        completed_at: String,
+    },
+    #[serde(rename = "failed")]
+    Failed { error: String, failed_at: String },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardPayout {
+    pub user_id: String,
+    pub wallet_address: String,
+    pub amount_e8s: u64,
+    pub status: RewardPayoutStatus,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExecuteRewardPayoutsRequest {
+    pub reward_period_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ExecuteRewardPayoutsResponse {
+    pub payouts: Vec<RewardPayout>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListRewardPayoutsRequest {
+    pub reward_period_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListRewardPayoutsResponse {
+    pub payouts: Vec<RewardPayout>,
+}
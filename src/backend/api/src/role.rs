@@ -0,0 +1,61 @@
+use candid::{CandidType, Deserialize};
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    #[serde(rename = "manage_roles")]
+    ManageRoles,
+    #[serde(rename = "manage_users")]
+    ManageUsers,
+    #[serde(rename = "read_logs")]
+    ReadLogs,
+    #[serde(rename = "read_audit_log")]
+    ReadAuditLog,
+    #[serde(rename = "sync_proposals")]
+    SyncProposals,
+    #[serde(rename = "manage_reviewer_assignments")]
+    ManageReviewerAssignments,
+    #[serde(rename = "moderate_reviews")]
+    ModerateReviews,
+    #[serde(rename = "manage_rewards")]
+    ManageRewards,
+    #[serde(rename = "review_proposals")]
+    ReviewProposals,
+    #[serde(rename = "comment")]
+    Comment,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    #[serde(rename = "moderator")]
+    Moderator,
+    #[serde(rename = "rewards_manager")]
+    RewardsManager,
+    #[serde(rename = "sync_operator")]
+    SyncOperator,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct SetUserRolesRequest {
+    pub user_id: String,
+    pub roles: Vec<Role>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetUserRolesRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetUserRolesResponse {
+    pub user_id: String,
+    pub roles: Vec<Role>,
+    pub permissions: Vec<Permission>,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct GetMyPermissionsResponse {
+    pub roles: Vec<Role>,
+    pub permissions: Vec<Permission>,
+}
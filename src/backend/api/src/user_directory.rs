@@ -0,0 +1,105 @@
+use crate::{Role, UserConfig};
+use candid::{CandidType, Deserialize, Principal};
+
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, PartialEq, Eq)]
+pub enum UserConfigType {
+    #[serde(rename = "admin")]
+    Admin,
+
+    #[serde(rename = "reviewer")]
+    Reviewer,
+
+    #[serde(rename = "anonymous")]
+    Anonymous,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub enum UserStatus {
+    #[serde(rename = "active")]
+    Active,
+
+    #[serde(rename = "suspended")]
+    Suspended {
+        reason: String,
+        suspended_by: String,
+        suspended_at: String,
+    },
+
+    #[serde(rename = "deleted")]
+    Deleted { deleted_at: String },
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct ListUsersRequest {
+    pub config_type: Option<UserConfigType>,
+    /// Only returns users that have been assigned this role.
+    pub role: Option<Role>,
+    /// Case-insensitive substring of the username.
+    pub username: Option<String>,
+    pub principal: Option<Principal>,
+    /// Number of users to skip, defaults to 0.
+    pub offset: Option<u64>,
+    /// Maximum number of users to return, defaults to 50 and cannot exceed 100.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct UserDirectoryEntry {
+    pub id: String,
+    pub username: String,
+    pub config: UserConfig,
+    pub roles: Vec<Role>,
+    pub principals: Vec<Principal>,
+    pub status: UserStatus,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct ListUsersResponse {
+    /// Ordered by user id.
+    pub users: Vec<UserDirectoryEntry>,
+    /// Number of users matching the filters.
+    pub total: u64,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetUserIdByPrincipalRequest {
+    pub principal: Principal,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetUserIdByPrincipalResponse {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetPrincipalsByUserIdRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetPrincipalsByUserIdResponse {
+    pub principals: Vec<Principal>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct UsernameCollision {
+    /// The lowercase username shared by the users.
+    pub username: String,
+    pub user_ids: Vec<String>,
+    /// The user that holds the username in the uniqueness index, the others have to rename.
+    pub owner_id: Option<String>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct InvalidUsername {
+    pub user_id: String,
+    pub username: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct GetUsernameReportResponse {
+    pub collisions: Vec<UsernameCollision>,
+    /// Usernames that were set before the username rules existed and break them.
+    pub invalid_usernames: Vec<InvalidUsername>,
+}
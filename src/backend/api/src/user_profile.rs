@@ -1,4 +1,4 @@
-use crate::HistoryEntry;
+use crate::{HistoryEntry, ReviewerReputation};
 use candid::{CandidType, Deserialize};
 
 #[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
@@ -48,7 +48,10 @@ pub enum SocialLinkPlatform {
 #[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
 pub struct SocialLink {
     pub platform: SocialLinkPlatform,
+    /// The handle on the platform, or the URL for websites.
     pub username: String,
+    /// The canonical profile URL, computed by the canister and ignored in requests.
+    pub url: Option<String>,
 }
 
 #[derive(Debug, Clone, CandidType, PartialEq, Eq)]
@@ -56,6 +59,10 @@ pub struct UserProfile {
     pub id: String,
     pub username: String,
     pub config: UserConfig,
+    /// Only set for reviewers that have at least one scored review.
+    pub reputation: Option<ReviewerReputation>,
+    /// Path of the avatar image, served over HTTP by the canister.
+    pub avatar_path: Option<String>,
 }
 
 #[derive(Debug, Clone, CandidType, PartialEq, Eq)]
@@ -128,3 +135,25 @@ pub struct UpdateUserProfileRequest {
     pub username: Option<String>,
     pub config: Option<UserConfigUpdate>,
 }
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct SuspendUserRequest {
+    pub user_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct RestoreUserRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct CreateMyUserAvatarRequest {
+    pub content_type: String,
+    pub content_bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
+pub struct CreateMyUserAvatarResponse {
+    pub path: String,
+}
@@ -0,0 +1,39 @@
+use candid::{CandidType, Deserialize};
+
+use crate::ProposalVote;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum VoteAlignmentStatus {
+    #[serde(rename = "matched")]
+    Matched,
+
+    #[serde(rename = "mismatched")]
+    Mismatched,
+
+    #[serde(rename = "not_voted")]
+    NotVoted,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VoteAlignment {
+    pub proposal_id: String,
+    pub user_id: String,
+    pub proposal_review_id: String,
+    pub neuron_id: u64,
+    pub review_vote: ProposalVote,
+    pub neuron_vote: Option<ProposalVote>,
+    pub status: VoteAlignmentStatus,
+    pub checked_at: String,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListVoteAlignmentsRequest {
+    pub proposal_id: Option<String>,
+    pub user_id: Option<String>,
+    pub status: Option<VoteAlignmentStatus>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ListVoteAlignmentsResponse {
+    pub vote_alignments: Vec<VoteAlignment>,
+}
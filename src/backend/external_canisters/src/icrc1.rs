@@ -0,0 +1,162 @@
+use candid::{CandidType, Deserialize, Nat, Principal};
+use ic_cdk::{api::call::RejectionCode, call};
+use serde_bytes::ByteBuf;
+
+type CanisterResult<T> = Result<T, (RejectionCode, String)>;
+
+pub type Subaccount = [u8; 32];
+
+pub type BlockIndex = Nat;
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Account {
+    pub owner: Principal,
+    pub subaccount: Option<Subaccount>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct TransferArg {
+    pub from_subaccount: Option<Subaccount>,
+    pub to: Account,
+    pub amount: Nat,
+    pub fee: Option<Nat>,
+    pub memo: Option<ByteBuf>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum TransferError {
+    BadFee { expected_fee: Nat },
+    BadBurn { min_burn_amount: Nat },
+    InsufficientFunds { balance: Nat },
+    TooOld,
+    CreatedInFuture { ledger_time: u64 },
+    Duplicate { duplicate_of: BlockIndex },
+    TemporarilyUnavailable,
+    GenericError { error_code: Nat, message: String },
+}
+
+/// The subset of the [ICRC-1](https://github.com/dfinity/ICRC-1/tree/main/standards/ICRC-1)
+/// ledger interface used by the backend.
+pub trait Icrc1Ledger {
+    async fn icrc1_transfer(
+        &self,
+        arg: TransferArg,
+    ) -> CanisterResult<Result<BlockIndex, TransferError>>;
+}
+
+pub struct Icrc1LedgerCanisterService(pub Principal);
+
+impl Icrc1Ledger for Icrc1LedgerCanisterService {
+    async fn icrc1_transfer(
+        &self,
+        arg: TransferArg,
+    ) -> CanisterResult<Result<BlockIndex, TransferError>> {
+        let (res,) = call(self.0, "icrc1_transfer", (arg,)).await?;
+        Ok(res)
+    }
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+pub mod testing {
+    use std::{
+        cell::RefCell,
+        collections::{BTreeMap, VecDeque},
+    };
+
+    use super::*;
+
+    /// An in-memory stand-in for an ICRC-1 ledger.
+    ///
+    /// Like the real ledger, it deduplicates transfers that have the same
+    /// arguments and set `created_at_time`, returning [TransferError::Duplicate].
+    /// Failures can be queued with [InMemoryIcrc1Ledger::fail_next_call] and
+    /// [InMemoryIcrc1Ledger::reject_next_call] to simulate an unreliable ledger.
+    #[derive(Default)]
+    pub struct InMemoryIcrc1Ledger {
+        state: RefCell<InMemoryIcrc1LedgerState>,
+    }
+
+    #[derive(Default)]
+    struct InMemoryIcrc1LedgerState {
+        balance: u64,
+        blocks: Vec<TransferArg>,
+        received: BTreeMap<Account, u64>,
+        queued_failures: VecDeque<CanisterResult<TransferError>>,
+    }
+
+    impl InMemoryIcrc1Ledger {
+        pub fn with_balance(balance: u64) -> Self {
+            let ledger = Self::default();
+            ledger.state.borrow_mut().balance = balance;
+
+            ledger
+        }
+
+        /// Makes the next transfer fail with the given error, without executing it.
+        pub fn fail_next_call(&self, error: TransferError) {
+            self.state.borrow_mut().queued_failures.push_back(Ok(error));
+        }
+
+        /// Makes the next call be rejected by the system, without executing it.
+        pub fn reject_next_call(&self, rejection_code: RejectionCode) {
+            self.state
+                .borrow_mut()
+                .queued_failures
+                .push_back(Err((rejection_code, "Call rejected".to_string())));
+        }
+
+        pub fn balance(&self) -> u64 {
+            self.state.borrow().balance
+        }
+
+        pub fn received(&self, account: &Account) -> u64 {
+            self.state
+                .borrow()
+                .received
+                .get(account)
+                .copied()
+                .unwrap_or_default()
+        }
+
+        pub fn blocks_count(&self) -> usize {
+            self.state.borrow().blocks.len()
+        }
+    }
+
+    impl Icrc1Ledger for InMemoryIcrc1Ledger {
+        async fn icrc1_transfer(
+            &self,
+            arg: TransferArg,
+        ) -> CanisterResult<Result<BlockIndex, TransferError>> {
+            let mut state = self.state.borrow_mut();
+
+            match state.queued_failures.pop_front() {
+                Some(Ok(error)) => return Ok(Err(error)),
+                Some(Err(rejection)) => return Err(rejection),
+                None => {}
+            }
+
+            if arg.created_at_time.is_some() {
+                if let Some(duplicate_of) = state.blocks.iter().position(|block| *block == arg) {
+                    return Ok(Err(TransferError::Duplicate {
+                        duplicate_of: duplicate_of.into(),
+                    }));
+                }
+            }
+
+            let amount = u64::try_from(&arg.amount.0).unwrap();
+            if amount > state.balance {
+                return Ok(Err(TransferError::InsufficientFunds {
+                    balance: state.balance.into(),
+                }));
+            }
+
+            state.balance -= amount;
+            *state.received.entry(arg.to).or_default() += amount;
+            state.blocks.push(arg);
+
+            Ok(Ok((state.blocks.len() - 1).into()))
+        }
+    }
+}
@@ -1 +1,4 @@
+pub mod icrc1;
+pub mod management;
 pub mod nns;
+pub mod payout;
@@ -0,0 +1,43 @@
+use candid::Principal;
+use ic_cdk::api::{
+    call::RejectionCode,
+    management_canister::main::{canister_status, raw_rand, CanisterIdRecord},
+};
+
+use crate::nns::CanisterResult;
+
+pub struct ManagementCanisterService;
+
+/// The subset of the management canister interface used to read canister settings.
+pub trait ControllerReader {
+    /// Only succeeds if the calling canister is a controller of `canister_id`,
+    /// so a canister must be its own controller to read its controllers.
+    async fn get_controllers(&self, canister_id: Principal) -> CanisterResult<Vec<Principal>>;
+}
+
+impl ControllerReader for ManagementCanisterService {
+    async fn get_controllers(&self, canister_id: Principal) -> CanisterResult<Vec<Principal>> {
+        let (res,) = canister_status(CanisterIdRecord { canister_id }).await?;
+        Ok(res.settings.controllers)
+    }
+}
+
+/// Cryptographically secure randomness from the management canister.
+pub trait RandomnessSource {
+    /// Returns 32 bytes from the subnet's random beacon. Unlike the canister's
+    /// seeded pseudo-random generator, the result cannot be predicted by other
+    /// callers, so it is suitable for generating secrets.
+    async fn get_random_bytes(&self) -> CanisterResult<[u8; 32]>;
+}
+
+impl RandomnessSource for ManagementCanisterService {
+    async fn get_random_bytes(&self) -> CanisterResult<[u8; 32]> {
+        let (bytes,) = raw_rand().await?;
+        bytes.try_into().map_err(|bytes: Vec<u8>| {
+            (
+                RejectionCode::CanisterError,
+                format!("Expected 32 random bytes, got {}", bytes.len()),
+            )
+        })
+    }
+}
@@ -1,8 +1,9 @@
 use candid::Principal;
 use ic_cdk::{api::call::RejectionCode, call};
 use ic_nns_governance::pb::v1::*;
+use std::collections::BTreeMap;
 
-type CanisterResult<T> = Result<T, (RejectionCode, String)>;
+pub type CanisterResult<T> = Result<T, (RejectionCode, String)>;
 
 pub struct GovernanceCanisterService(pub Principal);
 
@@ -19,4 +20,232 @@ impl GovernanceCanisterService {
         let (res,) = call(self.0, "get_proposal_info", (arg0,)).await?;
         Ok(res)
     }
+
+    pub async fn list_neurons(&self, arg0: ListNeurons) -> CanisterResult<ListNeuronsResponse> {
+        let (res,) = call(self.0, "list_neurons", (arg0,)).await?;
+        Ok(res)
+    }
+}
+
+/// The principals that are allowed to act on behalf of a neuron.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeuronAccess {
+    pub controller: Option<Principal>,
+    pub hot_keys: Vec<Principal>,
+}
+
+impl NeuronAccess {
+    pub fn is_controlled_by(&self, principal: &Principal) -> bool {
+        self.controller.as_ref() == Some(principal) || self.hot_keys.contains(principal)
+    }
+}
+
+/// The subset of the NNS governance interface used to read neurons.
+pub trait NeuronReader {
+    /// Returns `None` if the neuron does not exist, or if it is
+    /// neither public nor readable by the calling canister.
+    async fn get_neuron_access(&self, neuron_id: u64) -> CanisterResult<Option<NeuronAccess>>;
+}
+
+impl NeuronReader for GovernanceCanisterService {
+    async fn get_neuron_access(&self, neuron_id: u64) -> CanisterResult<Option<NeuronAccess>> {
+        let res = self
+            .list_neurons(ListNeurons {
+                neuron_ids: vec![neuron_id],
+                include_neurons_readable_by_caller: false,
+                include_public_neurons_in_full_neurons: Some(true),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(res
+            .full_neurons
+            .into_iter()
+            .find(|neuron| neuron.id.as_ref().is_some_and(|id| id.id == neuron_id))
+            .map(|neuron| NeuronAccess {
+                controller: neuron.controller.map(|controller| controller.0),
+                hot_keys: neuron
+                    .hot_keys
+                    .into_iter()
+                    .map(|hot_key| hot_key.0)
+                    .collect(),
+            }))
+    }
+}
+
+/// Same as `ic_nns_governance::pb::v1::Vote`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BallotVote {
+    Unspecified,
+    Yes,
+    No,
+}
+
+impl From<i32> for BallotVote {
+    fn from(vote: i32) -> Self {
+        match vote {
+            1 => Self::Yes,
+            2 => Self::No,
+            _ => Self::Unspecified,
+        }
+    }
+}
+
+/// The governance canister only keeps this many of the latest ballots of a neuron.
+pub const MAX_NEURON_RECENT_BALLOTS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalVotingPeriod {
+    pub deadline_timestamp_seconds: Option<u64>,
+    pub decided_timestamp_seconds: u64,
+}
+
+impl ProposalVotingPeriod {
+    /// Neurons can keep voting after the proposal is decided, until its deadline.
+    pub fn is_voting_closed(&self, now_timestamp_seconds: u64) -> bool {
+        match self.deadline_timestamp_seconds {
+            Some(deadline) => now_timestamp_seconds >= deadline,
+            None => self.decided_timestamp_seconds > 0,
+        }
+    }
+}
+
+/// The vote of a neuron on a proposal, as far as the recent ballots of the neuron tell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NeuronBallot {
+    Voted(BallotVote),
+    NotVoted,
+    /// The proposal is older than the ballots the governance canister still keeps for the neuron.
+    Unknown,
+}
+
+/// The latest ballots of a neuron, by proposal id.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NeuronRecentBallots {
+    pub votes: BTreeMap<u64, BallotVote>,
+}
+
+impl NeuronRecentBallots {
+    pub fn ballot(&self, proposal_id: u64) -> NeuronBallot {
+        if let Some(vote) = self.votes.get(&proposal_id) {
+            return NeuronBallot::Voted(*vote);
+        }
+
+        // neurons mostly vote on proposals in the order they are made, so once the kept ballots
+        // reach back past the proposal, a missing ballot means that the neuron did not vote
+        let covers_proposal = self.votes.len() < MAX_NEURON_RECENT_BALLOTS
+            || self
+                .votes
+                .keys()
+                .next()
+                .is_some_and(|oldest_proposal_id| *oldest_proposal_id < proposal_id);
+
+        if covers_proposal {
+            NeuronBallot::NotVoted
+        } else {
+            NeuronBallot::Unknown
+        }
+    }
+}
+
+/// The subset of the NNS governance interface used to read the votes of neurons.
+pub trait BallotReader {
+    /// Returns `None` if the proposal does not exist.
+    async fn get_proposal_voting_period(
+        &self,
+        proposal_id: u64,
+    ) -> CanisterResult<Option<ProposalVotingPeriod>>;
+
+    /// Returns the recent ballots of the given neurons, by neuron id.
+    /// The ballots of a proposal only list the neurons controlled by the caller,
+    /// so the votes are read from the neurons instead. Neurons that don't exist,
+    /// or that are neither public nor readable by the calling canister, are left out.
+    async fn get_neuron_recent_ballots(
+        &self,
+        neuron_ids: Vec<u64>,
+    ) -> CanisterResult<BTreeMap<u64, NeuronRecentBallots>>;
+}
+
+impl BallotReader for GovernanceCanisterService {
+    async fn get_proposal_voting_period(
+        &self,
+        proposal_id: u64,
+    ) -> CanisterResult<Option<ProposalVotingPeriod>> {
+        let res = self.get_proposal_info(proposal_id).await?;
+
+        Ok(res.map(|proposal_info| ProposalVotingPeriod {
+            deadline_timestamp_seconds: proposal_info.deadline_timestamp_seconds,
+            decided_timestamp_seconds: proposal_info.decided_timestamp_seconds,
+        }))
+    }
+
+    async fn get_neuron_recent_ballots(
+        &self,
+        neuron_ids: Vec<u64>,
+    ) -> CanisterResult<BTreeMap<u64, NeuronRecentBallots>> {
+        let res = self
+            .list_neurons(ListNeurons {
+                neuron_ids,
+                include_neurons_readable_by_caller: false,
+                include_public_neurons_in_full_neurons: Some(true),
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(res
+            .full_neurons
+            .into_iter()
+            .filter_map(|neuron| {
+                let neuron_id = neuron.id?.id;
+                let votes = neuron
+                    .recent_ballots
+                    .into_iter()
+                    .filter_map(|ballot| {
+                        Some((ballot.proposal_id?.id, BallotVote::from(ballot.vote)))
+                    })
+                    .collect();
+
+                Some((neuron_id, NeuronRecentBallots { votes }))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recent_ballots(proposal_ids: impl Iterator<Item = u64>) -> NeuronRecentBallots {
+        NeuronRecentBallots {
+            votes: proposal_ids.map(|id| (id, BallotVote::Yes)).collect(),
+        }
+    }
+
+    #[test]
+    fn ballot_voted() {
+        let ballots = NeuronRecentBallots {
+            votes: BTreeMap::from([(10, BallotVote::No)]),
+        };
+
+        assert_eq!(ballots.ballot(10), NeuronBallot::Voted(BallotVote::No));
+    }
+
+    #[test]
+    fn ballot_not_voted() {
+        assert_eq!(
+            NeuronRecentBallots::default().ballot(10),
+            NeuronBallot::NotVoted
+        );
+        assert_eq!(
+            recent_ballots(0..MAX_NEURON_RECENT_BALLOTS as u64).ballot(50_000),
+            NeuronBallot::NotVoted
+        );
+    }
+
+    #[test]
+    fn ballot_unknown() {
+        let ballots = recent_ballots(1_000..1_000 + MAX_NEURON_RECENT_BALLOTS as u64);
+
+        assert_eq!(ballots.ballot(10), NeuronBallot::Unknown);
+    }
 }
@@ -0,0 +1,213 @@
+use candid::Nat;
+use ic_cdk::api::call::RejectionCode;
+use serde_bytes::ByteBuf;
+
+use crate::icrc1::{Account, Icrc1Ledger, TransferArg, TransferError};
+
+/// Number of times a transfer is attempted when the ledger is temporarily unavailable.
+const MAX_TRANSFER_ATTEMPTS: usize = 3;
+
+/// A single transfer to be executed on an ICRC-1 ledger.
+///
+/// `memo` and `created_at_time` must stay the same for every attempt of the same payout:
+/// the ledger uses them to deduplicate transfers, which is what makes retries safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payout {
+    pub to: Account,
+    pub amount_e8s: u64,
+    pub memo: Vec<u8>,
+    /// Nanoseconds since the UNIX epoch.
+    pub created_at_time: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutError {
+    /// The ledger rejected the transfer, so it was not executed.
+    Rejected(TransferError),
+    /// It is not known whether the transfer was executed.
+    /// Executing the same payout again is safe.
+    Unknown(String),
+}
+
+pub struct PayoutExecutor<L: Icrc1Ledger> {
+    ledger: L,
+}
+
+impl<L: Icrc1Ledger> PayoutExecutor<L> {
+    pub fn new(ledger: L) -> Self {
+        Self { ledger }
+    }
+
+    pub fn ledger(&self) -> &L {
+        &self.ledger
+    }
+
+    /// Transfers the payout amount and returns the index of the block that contains the transfer.
+    ///
+    /// If the same payout was already executed, the index of the original block is returned
+    /// and no tokens are transferred.
+    pub async fn execute(&self, payout: &Payout) -> Result<u64, PayoutError> {
+        let arg = TransferArg {
+            from_subaccount: None,
+            to: payout.to,
+            amount: Nat::from(payout.amount_e8s),
+            fee: None,
+            memo: Some(ByteBuf::from(payout.memo.clone())),
+            created_at_time: Some(payout.created_at_time),
+        };
+
+        let mut last_error = PayoutError::Unknown("Transfer was not attempted".to_string());
+        for _ in 0..MAX_TRANSFER_ATTEMPTS {
+            match self.ledger.icrc1_transfer(arg.clone()).await {
+                Ok(Ok(block_index)) => return block_index_to_u64(block_index),
+                Ok(Err(TransferError::Duplicate { duplicate_of })) => {
+                    return block_index_to_u64(duplicate_of)
+                }
+                Ok(Err(TransferError::TemporarilyUnavailable)) => {
+                    last_error = PayoutError::Rejected(TransferError::TemporarilyUnavailable);
+                }
+                Ok(Err(err)) => return Err(PayoutError::Rejected(err)),
+                // the call did not reach the ledger, so it can be retried right away
+                Err((RejectionCode::SysTransient, message)) => {
+                    last_error = PayoutError::Unknown(message);
+                }
+                Err((rejection_code, message)) => {
+                    return Err(PayoutError::Unknown(format!(
+                        "Ledger call rejected with code {:?}: {}",
+                        rejection_code, message
+                    )))
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+fn block_index_to_u64(block_index: Nat) -> Result<u64, PayoutError> {
+    u64::try_from(&block_index.0).map_err(|_| {
+        PayoutError::Unknown(format!("Block index {} does not fit into u64", block_index))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::icrc1::testing::InMemoryIcrc1Ledger;
+    use candid::Principal;
+
+    fn payout() -> Payout {
+        Payout {
+            to: Account {
+                owner: Principal::from_slice(&[1]),
+                subaccount: None,
+            },
+            amount_e8s: 150,
+            memo: vec![1; 32],
+            created_at_time: 1_700_000_000_000_000_000,
+        }
+    }
+
+    #[async_std::test]
+    async fn execute() {
+        let executor = PayoutExecutor::new(InMemoryIcrc1Ledger::with_balance(1_000));
+
+        let result = executor.execute(&payout()).await;
+
+        assert_eq!(result, Ok(0));
+        assert_eq!(executor.ledger().received(&payout().to), 150);
+        assert_eq!(executor.ledger().balance(), 850);
+    }
+
+    #[async_std::test]
+    async fn execute_twice_pays_once() {
+        let executor = PayoutExecutor::new(InMemoryIcrc1Ledger::with_balance(1_000));
+
+        let first_result = executor.execute(&payout()).await;
+        let second_result = executor.execute(&payout()).await;
+
+        assert_eq!(first_result, Ok(0));
+        assert_eq!(second_result, Ok(0));
+        assert_eq!(executor.ledger().received(&payout().to), 150);
+        assert_eq!(executor.ledger().blocks_count(), 1);
+    }
+
+    #[async_std::test]
+    async fn execute_different_payouts() {
+        let executor = PayoutExecutor::new(InMemoryIcrc1Ledger::with_balance(1_000));
+        let other_payout = Payout {
+            memo: vec![2; 32],
+            ..payout()
+        };
+
+        let first_result = executor.execute(&payout()).await;
+        let second_result = executor.execute(&other_payout).await;
+
+        assert_eq!(first_result, Ok(0));
+        assert_eq!(second_result, Ok(1));
+        assert_eq!(executor.ledger().received(&payout().to), 300);
+    }
+
+    #[async_std::test]
+    async fn execute_retries_temporarily_unavailable() {
+        let ledger = InMemoryIcrc1Ledger::with_balance(1_000);
+        ledger.fail_next_call(TransferError::TemporarilyUnavailable);
+        ledger.reject_next_call(RejectionCode::SysTransient);
+        let executor = PayoutExecutor::new(ledger);
+
+        let result = executor.execute(&payout()).await;
+
+        assert_eq!(result, Ok(0));
+        assert_eq!(executor.ledger().received(&payout().to), 150);
+    }
+
+    #[async_std::test]
+    async fn execute_gives_up_after_max_attempts() {
+        let ledger = InMemoryIcrc1Ledger::with_balance(1_000);
+        for _ in 0..MAX_TRANSFER_ATTEMPTS {
+            ledger.fail_next_call(TransferError::TemporarilyUnavailable);
+        }
+        let executor = PayoutExecutor::new(ledger);
+
+        let result = executor.execute(&payout()).await;
+
+        assert_eq!(
+            result,
+            Err(PayoutError::Rejected(TransferError::TemporarilyUnavailable))
+        );
+        assert_eq!(executor.ledger().blocks_count(), 0);
+    }
+
+    #[async_std::test]
+    async fn execute_insufficient_funds() {
+        let executor = PayoutExecutor::new(InMemoryIcrc1Ledger::with_balance(100));
+
+        let result = executor.execute(&payout()).await;
+
+        assert_eq!(
+            result,
+            Err(PayoutError::Rejected(TransferError::InsufficientFunds {
+                balance: Nat::from(100u64),
+            }))
+        );
+        assert_eq!(executor.ledger().balance(), 100);
+    }
+
+    #[async_std::test]
+    async fn execute_unknown_outcome() {
+        let ledger = InMemoryIcrc1Ledger::with_balance(1_000);
+        ledger.reject_next_call(RejectionCode::CanisterError);
+        let executor = PayoutExecutor::new(ledger);
+
+        let first_result = executor.execute(&payout()).await;
+        let second_result = executor.execute(&payout()).await;
+
+        assert_eq!(
+            first_result,
+            Err(PayoutError::Unknown(
+                "Ledger call rejected with code CanisterError: Call rejected".to_string()
+            ))
+        );
+        assert_eq!(second_result, Ok(0));
+    }
+}
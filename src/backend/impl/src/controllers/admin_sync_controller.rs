@@ -0,0 +1,328 @@
+use backend_api::{
+    ApiError, ApiResult, GetAdminSyncConfigResponse, SyncAdminsResponse,
+    UpdateAdminSyncConfigRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::{Encode, Principal};
+use external_canisters::management::ManagementCanisterService;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AdminSyncRepositoryImpl, ApprovalRepositoryImpl, AuditLogRepositoryImpl, LogRepositoryImpl,
+        Permission, RoleRepositoryImpl, SensitiveAction, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AdminSyncService, AdminSyncServiceImpl,
+        ApprovalService, ApprovalServiceImpl, AuditLogServiceImpl, LogService, LogServiceImpl,
+    },
+    system_api::get_canister_id,
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+async fn sync_admins() -> ApiResult<SyncAdminsResponse> {
+    let calling_principal = caller();
+
+    AdminSyncController::default()
+        .sync_admins(calling_principal)
+        .await
+        .into()
+}
+
+#[query]
+fn get_admin_sync_config() -> ApiResult<GetAdminSyncConfigResponse> {
+    let calling_principal = caller();
+
+    AdminSyncController::default()
+        .get_admin_sync_config(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn update_admin_sync_config(request: UpdateAdminSyncConfigRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    AdminSyncController::default()
+        .update_admin_sync_config(calling_principal, request)
+        .into()
+}
+
+pub(super) struct AdminSyncController<
+    A: AccessControlService,
+    P: ApprovalService,
+    L: LogService,
+    S: AdminSyncService,
+> {
+    access_control_service: A,
+    approval_service: P,
+    log_service: L,
+    admin_sync_service: S,
+}
+
+impl Default
+    for AdminSyncController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        LogServiceImpl<LogRepositoryImpl>,
+        AdminSyncServiceImpl<
+            AdminSyncRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ManagementCanisterService,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+            LogServiceImpl::default(),
+            AdminSyncServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: ApprovalService, L: LogService, S: AdminSyncService>
+    AdminSyncController<A, P, L, S>
+{
+    fn new(
+        access_control_service: A,
+        approval_service: P,
+        log_service: L,
+        admin_sync_service: S,
+    ) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+            log_service,
+            admin_sync_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    async fn sync_admins(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<SyncAdminsResponse, ApiError> {
+        self.admin_sync_service.sync_admins(calling_principal).await
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn get_admin_sync_config(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetAdminSyncConfigResponse, ApiError> {
+        Ok(self.admin_sync_service.get_admin_sync_config())
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn update_admin_sync_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateAdminSyncConfigRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::UpdateAdminSyncConfig,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.admin_sync_service
+            .update_admin_sync_config(calling_principal, request)
+    }
+
+    pub async fn sync_admins_job(&self) {
+        let _ = self.log_service.log_info(
+            "Syncing admins with canister controllers".to_string(),
+            Some("sync_admins".to_string()),
+        );
+
+        match self.admin_sync_service.sync_admins(get_canister_id()).await {
+            Ok(response) => {
+                let _ = self.log_service.log_info(
+                    format!(
+                        "Successfully promoted {} and demoted {} admins",
+                        response.promoted_user_ids.len(),
+                        response.demoted_user_ids.len()
+                    ),
+                    Some("sync_admins".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error syncing admins ({})", e),
+                    Some("sync_admins".to_string()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{
+            MockAccessControlService, MockAdminSyncService, MockApprovalService, MockLogService,
+        },
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    async fn sync_admins() {
+        let calling_principal = fixtures::principal_a();
+        let response = SyncAdminsResponse {
+            promoted_user_ids: vec![fixtures::uuid_a().to_string()],
+            demoted_user_ids: vec![],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockAdminSyncService::new();
+        service_mock
+            .expect_sync_admins()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(response.clone()));
+
+        let controller = AdminSyncController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            MockLogService::new(),
+            service_mock,
+        );
+
+        let result = controller.sync_admins(calling_principal).await.unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    async fn sync_admins_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the {} permission to call this endpoint",
+            calling_principal.to_text(),
+            Permission::ManageRoles
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockAdminSyncService::new();
+        service_mock.expect_sync_admins().never();
+
+        let controller = AdminSyncController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            MockLogService::new(),
+            service_mock,
+        );
+
+        let result = controller.sync_admins(calling_principal).await.unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn update_admin_sync_config() {
+        let calling_principal = fixtures::principal_a();
+        let request = UpdateAdminSyncConfigRequest {
+            promote_controllers: Some(false),
+            demote_removed_controllers: None,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::UpdateAdminSyncConfig),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockAdminSyncService::new();
+        service_mock
+            .expect_update_admin_sync_config()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = AdminSyncController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            MockLogService::new(),
+            service_mock,
+        );
+
+        controller
+            .update_admin_sync_config(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    async fn sync_admins_job() {
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Syncing admins with canister controllers".to_string()),
+                eq(Some("sync_admins".to_string())),
+            )
+            .return_const(Ok(()));
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Successfully promoted 1 and demoted 0 admins".to_string()),
+                eq(Some("sync_admins".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockAdminSyncService::new();
+        service_mock
+            .expect_sync_admins()
+            .once()
+            .with(eq(get_canister_id()))
+            .return_const(Ok(SyncAdminsResponse {
+                promoted_user_ids: vec![fixtures::uuid_a().to_string()],
+                demoted_user_ids: vec![],
+            }));
+
+        let controller = AdminSyncController::new(
+            MockAccessControlService::new(),
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller.sync_admins_job().await;
+    }
+}
@@ -0,0 +1,285 @@
+use backend_api::{
+    ApiError, ApiResult, ApproveApprovalRequestRequest, ApproveApprovalRequestResponse,
+    GetApprovalConfigResponse, ListApprovalRequestsResponse, UpdateApprovalConfigRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::{Encode, Principal};
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, Permission, RoleRepositoryImpl,
+        SensitiveAction, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl,
+    },
+};
+
+#[query]
+fn list_approval_requests() -> ApiResult<ListApprovalRequestsResponse> {
+    let calling_principal = caller();
+
+    ApprovalController::default()
+        .list_approval_requests(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn approve_approval_request(
+    request: ApproveApprovalRequestRequest,
+) -> ApiResult<ApproveApprovalRequestResponse> {
+    let calling_principal = caller();
+
+    ApprovalController::default()
+        .approve_approval_request(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_approval_config() -> ApiResult<GetApprovalConfigResponse> {
+    let calling_principal = caller();
+
+    ApprovalController::default()
+        .get_approval_config(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn update_approval_config(request: UpdateApprovalConfigRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ApprovalController::default()
+        .update_approval_config(calling_principal, request)
+        .into()
+}
+
+struct ApprovalController<A: AccessControlService, S: ApprovalService> {
+    access_control_service: A,
+    approval_service: S,
+}
+
+impl Default
+    for ApprovalController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, S: ApprovalService> ApprovalController<A, S> {
+    fn new(access_control_service: A, approval_service: S) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn list_approval_requests(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<ListApprovalRequestsResponse, ApiError> {
+        self.approval_service.list_approval_requests()
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn approve_approval_request(
+        &self,
+        calling_principal: Principal,
+        request: ApproveApprovalRequestRequest,
+    ) -> Result<ApproveApprovalRequestResponse, ApiError> {
+        self.approval_service
+            .approve_approval_request(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn get_approval_config(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetApprovalConfigResponse, ApiError> {
+        Ok(self.approval_service.get_approval_config())
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn update_approval_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateApprovalConfigRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::UpdateApprovalConfig,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.approval_service
+            .update_approval_config(calling_principal, request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_approval_request,
+        services::{MockAccessControlService, MockApprovalService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn approve_approval_request() {
+        let calling_principal = fixtures::principal_b();
+        let request = ApproveApprovalRequestRequest {
+            id: fixtures::uuid_c().to_string(),
+        };
+        let response = map_approval_request(
+            fixtures::uuid_c(),
+            fixtures::approval_request_approved(),
+            &fixtures::date_time_a(),
+        );
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockApprovalService::new();
+        service_mock
+            .expect_approve_approval_request()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ApprovalController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .approve_approval_request(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn approve_approval_request_unauthorized() {
+        let calling_principal = fixtures::principal_b();
+        let request = ApproveApprovalRequestRequest {
+            id: fixtures::uuid_c().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the {} permission to call this endpoint",
+            calling_principal.to_text(),
+            Permission::ManageRoles
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockApprovalService::new();
+        service_mock.expect_approve_approval_request().never();
+
+        let controller = ApprovalController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .approve_approval_request(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn update_approval_config() {
+        let calling_principal = fixtures::principal_a();
+        let request = UpdateApprovalConfigRequest {
+            required_approvals: Some(2),
+            timeout_seconds: None,
+            sensitive_actions: None,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockApprovalService::new();
+        service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::UpdateApprovalConfig),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+        service_mock
+            .expect_update_approval_config()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = ApprovalController::new(access_control_service_mock, service_mock);
+
+        controller
+            .update_approval_config(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn update_approval_config_not_approved() {
+        let calling_principal = fixtures::principal_a();
+        let request = UpdateApprovalConfigRequest {
+            required_approvals: Some(1),
+            timeout_seconds: None,
+            sensitive_actions: None,
+        };
+        let error = ApiError::conflict(&format!(
+            "Action update_approval_config requires 2 approvals, created approval request with id {}",
+            fixtures::uuid_c()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockApprovalService::new();
+        service_mock
+            .expect_assert_approved()
+            .once()
+            .return_const(Err(error.clone()));
+        service_mock.expect_update_approval_config().never();
+
+        let controller = ApprovalController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .update_approval_config(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
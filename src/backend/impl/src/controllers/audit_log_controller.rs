@@ -0,0 +1,201 @@
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, Permission, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogService, AuditLogServiceImpl,
+    },
+};
+use backend_api::{
+    ApiError, ApiResult, ExportAuditLogRequest, ExportAuditLogResponse, ListAuditLogEntriesRequest,
+    ListAuditLogEntriesResponse,
+};
+use backend_macros::require_permission;
+use candid::Principal;
+use ic_cdk::*;
+
+#[query]
+fn list_audit_log_entries(
+    request: ListAuditLogEntriesRequest,
+) -> ApiResult<ListAuditLogEntriesResponse> {
+    let calling_principal = caller();
+
+    AuditLogController::default()
+        .list_audit_log_entries(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn export_audit_log(request: ExportAuditLogRequest) -> ApiResult<ExportAuditLogResponse> {
+    let calling_principal = caller();
+
+    AuditLogController::default()
+        .export_audit_log(calling_principal, request)
+        .into()
+}
+
+struct AuditLogController<A: AccessControlService, L: AuditLogService> {
+    access_control_service: A,
+    audit_log_service: L,
+}
+
+impl Default
+    for AuditLogController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, L: AuditLogService> AuditLogController<A, L> {
+    fn new(access_control_service: A, audit_log_service: L) -> Self {
+        Self {
+            access_control_service,
+            audit_log_service,
+        }
+    }
+
+    #[require_permission(Permission::ReadAuditLog)]
+    fn list_audit_log_entries(
+        &self,
+        calling_principal: Principal,
+        request: ListAuditLogEntriesRequest,
+    ) -> Result<ListAuditLogEntriesResponse, ApiError> {
+        self.audit_log_service.list_audit_log_entries(request)
+    }
+
+    #[require_permission(Permission::ReadAuditLog)]
+    fn export_audit_log(
+        &self,
+        calling_principal: Principal,
+        request: ExportAuditLogRequest,
+    ) -> Result<ExportAuditLogResponse, ApiError> {
+        self.audit_log_service.export_audit_log(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_audit_log_entry,
+        services::{MockAccessControlService, MockAuditLogService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn list_audit_log_entries() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListAuditLogEntriesRequest {
+            action: None,
+            actor: None,
+            target_id: None,
+            offset: None,
+            limit: None,
+        };
+        let response = ListAuditLogEntriesResponse {
+            entries: vec![map_audit_log_entry(0, fixtures::audit_log_entry())],
+            total: 1,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReadAuditLog))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockAuditLogService::new();
+        service_mock
+            .expect_list_audit_log_entries()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = AuditLogController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .list_audit_log_entries(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn export_audit_log() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExportAuditLogRequest {
+            before_timestamp_ms: None,
+            after_timestamp_ms: None,
+            offset: None,
+            limit: None,
+        };
+        let response = ExportAuditLogResponse {
+            entries: vec![map_audit_log_entry(0, fixtures::audit_log_entry())],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReadAuditLog))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockAuditLogService::new();
+        service_mock
+            .expect_export_audit_log()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = AuditLogController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .export_audit_log(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn export_audit_log_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExportAuditLogRequest {
+            before_timestamp_ms: None,
+            after_timestamp_ms: None,
+            offset: None,
+            limit: None,
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the {} permission to call this endpoint",
+            calling_principal.to_text(),
+            Permission::ReadAuditLog
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReadAuditLog))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockAuditLogService::new();
+        service_mock.expect_export_audit_log().never();
+
+        let controller = AuditLogController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .export_audit_log(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
@@ -0,0 +1,316 @@
+use backend_api::{
+    ApiError, ApiResult, CreateCommentRequest, CreateCommentResponse, DeleteCommentRequest,
+    GetCommentHistoryRequest, GetCommentHistoryResponse, ListCommentsRequest, ListCommentsResponse,
+    UpdateCommentRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, CommentRepositoryImpl, Permission, ProposalRepositoryImpl,
+        ProposalReviewCommitRepositoryImpl, ProposalReviewRepositoryImpl, RoleRepositoryImpl,
+        UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, CommentService,
+        CommentServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn create_comment(request: CreateCommentRequest) -> ApiResult<CreateCommentResponse> {
+    let calling_principal = caller();
+
+    CommentController::default()
+        .create_comment(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn update_comment(request: UpdateCommentRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    CommentController::default()
+        .update_comment(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn delete_comment(request: DeleteCommentRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    CommentController::default()
+        .delete_comment(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_comments(request: ListCommentsRequest) -> ApiResult<ListCommentsResponse> {
+    CommentController::default().list_comments(request).into()
+}
+
+#[query]
+fn get_comment_history(request: GetCommentHistoryRequest) -> ApiResult<GetCommentHistoryResponse> {
+    let calling_principal = caller();
+
+    CommentController::default()
+        .get_comment_history(calling_principal, request)
+        .into()
+}
+
+struct CommentController<A: AccessControlService, C: CommentService> {
+    access_control_service: A,
+    comment_service: C,
+}
+
+impl Default
+    for CommentController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        CommentServiceImpl<
+            CommentRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ProposalRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalReviewCommitRepositoryImpl,
+            AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            CommentServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, C: CommentService> CommentController<A, C> {
+    fn new(access_control_service: A, comment_service: C) -> Self {
+        Self {
+            access_control_service,
+            comment_service,
+        }
+    }
+
+    #[require_permission(Permission::Comment)]
+    fn create_comment(
+        &self,
+        calling_principal: Principal,
+        request: CreateCommentRequest,
+    ) -> Result<CreateCommentResponse, ApiError> {
+        self.comment_service
+            .create_comment(calling_principal, request)
+    }
+
+    #[require_permission(Permission::Comment)]
+    fn update_comment(
+        &self,
+        calling_principal: Principal,
+        request: UpdateCommentRequest,
+    ) -> Result<(), ApiError> {
+        self.comment_service
+            .update_comment(calling_principal, request)
+    }
+
+    #[require_permission(Permission::Comment)]
+    fn delete_comment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteCommentRequest,
+    ) -> Result<(), ApiError> {
+        self.comment_service
+            .delete_comment(calling_principal, request)
+    }
+
+    fn list_comments(
+        &self,
+        request: ListCommentsRequest,
+    ) -> Result<ListCommentsResponse, ApiError> {
+        self.comment_service.list_comments(request)
+    }
+
+    fn get_comment_history(
+        &self,
+        calling_principal: Principal,
+        request: GetCommentHistoryRequest,
+    ) -> Result<GetCommentHistoryResponse, ApiError> {
+        self.comment_service
+            .get_comment_history(calling_principal, request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_comment,
+        services::{MockAccessControlService, MockCommentService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_comment() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateCommentRequest {
+            target: backend_api::CommentTarget::Proposal {
+                proposal_id: fixtures::proposal_id().to_string(),
+            },
+            parent_id: None,
+            content: "Has anyone managed to **reproduce** the build?".to_string(),
+        };
+        let response = map_comment(fixtures::uuid(), fixtures::comment_proposal());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::Comment))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockCommentService::new();
+        service_mock
+            .expect_create_comment()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = CommentController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_comment(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn create_comment_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateCommentRequest {
+            target: backend_api::CommentTarget::Proposal {
+                proposal_id: fixtures::proposal_id().to_string(),
+            },
+            parent_id: None,
+            content: "Hello".to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin or a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::Comment))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockCommentService::new();
+        service_mock.expect_create_comment().never();
+
+        let controller = CommentController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_comment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn update_comment() {
+        let calling_principal = fixtures::principal_a();
+        let request = UpdateCommentRequest {
+            id: fixtures::uuid().to_string(),
+            content: "Edited".to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::Comment))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockCommentService::new();
+        service_mock
+            .expect_update_comment()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = CommentController::new(access_control_service_mock, service_mock);
+
+        controller
+            .update_comment(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_comment_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeleteCommentRequest {
+            id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin or a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::Comment))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockCommentService::new();
+        service_mock.expect_delete_comment().never();
+
+        let controller = CommentController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .delete_comment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn list_comments() {
+        let request = ListCommentsRequest {
+            target: backend_api::CommentTarget::Proposal {
+                proposal_id: fixtures::proposal_id().to_string(),
+            },
+            offset: None,
+            limit: None,
+        };
+        let response = ListCommentsResponse {
+            comments: vec![map_comment(fixtures::uuid(), fixtures::comment_proposal())],
+            total: 1,
+        };
+
+        let access_control_service_mock = MockAccessControlService::new();
+
+        let mut service_mock = MockCommentService::new();
+        service_mock
+            .expect_list_comments()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = CommentController::new(access_control_service_mock, service_mock);
+
+        let result = controller.list_comments(request).unwrap();
+
+        assert_eq!(result, response);
+    }
+}
@@ -0,0 +1,313 @@
+use backend_api::{
+    ApiError, ApiResult, ClaimCommitRequest, ClaimCommitResponse, ListCommitClaimsRequest,
+    ListCommitClaimsResponse, ListUnclaimedCommitsRequest, ListUnclaimedCommitsResponse,
+    ReleaseCommitClaimRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        CommitClaimRepositoryImpl, LogRepositoryImpl, Permission, ProposalRepositoryImpl,
+        RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, CommitClaimService, CommitClaimServiceImpl,
+        LogService, LogServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn claim_commit(request: ClaimCommitRequest) -> ApiResult<ClaimCommitResponse> {
+    let calling_principal = caller();
+
+    CommitClaimController::default()
+        .claim_commit(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn release_commit_claim(request: ReleaseCommitClaimRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    CommitClaimController::default()
+        .release_commit_claim(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_commit_claims(request: ListCommitClaimsRequest) -> ApiResult<ListCommitClaimsResponse> {
+    CommitClaimController::default()
+        .list_commit_claims(request)
+        .into()
+}
+
+#[query]
+fn list_unclaimed_commits(
+    request: ListUnclaimedCommitsRequest,
+) -> ApiResult<ListUnclaimedCommitsResponse> {
+    CommitClaimController::default()
+        .list_unclaimed_commits(request)
+        .into()
+}
+
+pub(super) struct CommitClaimController<
+    A: AccessControlService,
+    L: LogService,
+    C: CommitClaimService,
+> {
+    access_control_service: A,
+    log_service: L,
+    commit_claim_service: C,
+}
+
+impl Default
+    for CommitClaimController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        LogServiceImpl<LogRepositoryImpl>,
+        CommitClaimServiceImpl<
+            CommitClaimRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ProposalRepositoryImpl,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            LogServiceImpl::default(),
+            CommitClaimServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, L: LogService, C: CommitClaimService> CommitClaimController<A, L, C> {
+    fn new(access_control_service: A, log_service: L, commit_claim_service: C) -> Self {
+        Self {
+            access_control_service,
+            log_service,
+            commit_claim_service,
+        }
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn claim_commit(
+        &self,
+        calling_principal: Principal,
+        request: ClaimCommitRequest,
+    ) -> Result<ClaimCommitResponse, ApiError> {
+        self.commit_claim_service
+            .claim_commit(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn release_commit_claim(
+        &self,
+        calling_principal: Principal,
+        request: ReleaseCommitClaimRequest,
+    ) -> Result<(), ApiError> {
+        self.commit_claim_service
+            .release_commit_claim(calling_principal, request)
+    }
+
+    fn list_commit_claims(
+        &self,
+        request: ListCommitClaimsRequest,
+    ) -> Result<ListCommitClaimsResponse, ApiError> {
+        self.commit_claim_service.list_commit_claims(request)
+    }
+
+    fn list_unclaimed_commits(
+        &self,
+        request: ListUnclaimedCommitsRequest,
+    ) -> Result<ListUnclaimedCommitsResponse, ApiError> {
+        self.commit_claim_service.list_unclaimed_commits(request)
+    }
+
+    pub fn release_commit_claims_job(&self) {
+        match self.commit_claim_service.release_commit_claims() {
+            Ok(count) => {
+                let _ = self.log_service.log_info(
+                    format!("Successfully released {count} commit claims"),
+                    Some("release_commit_claims".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error releasing commit claims ({})", e),
+                    Some("release_commit_claims".to_string()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockCommitClaimService, MockLogService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn claim_commit() {
+        let calling_principal = fixtures::principal_a();
+        let request = ClaimCommitRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            commit_sha: fixtures::commit_sha_a().to_string(),
+        };
+        let response: ClaimCommitResponse = fixtures::commit_claim().into();
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockCommitClaimService::new();
+        service_mock
+            .expect_claim_commit()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller =
+            CommitClaimController::new(access_control_service_mock, log_service_mock, service_mock);
+
+        let result = controller.claim_commit(calling_principal, request).unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn claim_commit_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ClaimCommitRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            commit_sha: fixtures::commit_sha_a().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockCommitClaimService::new();
+        service_mock.expect_claim_commit().never();
+
+        let controller =
+            CommitClaimController::new(access_control_service_mock, log_service_mock, service_mock);
+
+        let result = controller
+            .claim_commit(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn release_commit_claim() {
+        let calling_principal = fixtures::principal_a();
+        let request = ReleaseCommitClaimRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            commit_sha: fixtures::commit_sha_a().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockCommitClaimService::new();
+        service_mock
+            .expect_release_commit_claim()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller =
+            CommitClaimController::new(access_control_service_mock, log_service_mock, service_mock);
+
+        controller
+            .release_commit_claim(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn list_unclaimed_commits() {
+        let request = ListUnclaimedCommitsRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            commit_shas: vec![
+                fixtures::commit_sha_a().to_string(),
+                fixtures::commit_sha_b().to_string(),
+            ],
+        };
+        let response = ListUnclaimedCommitsResponse {
+            commit_shas: vec![fixtures::commit_sha_b().to_string()],
+        };
+
+        let access_control_service_mock = MockAccessControlService::new();
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockCommitClaimService::new();
+        service_mock
+            .expect_list_unclaimed_commits()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller =
+            CommitClaimController::new(access_control_service_mock, log_service_mock, service_mock);
+
+        let result = controller.list_unclaimed_commits(request).unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn release_commit_claims_job_success() {
+        let access_control_service_mock = MockAccessControlService::new();
+
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Successfully released 2 commit claims".to_string()),
+                eq(Some("release_commit_claims".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockCommitClaimService::new();
+        service_mock
+            .expect_release_commit_claims()
+            .once()
+            .return_const(Ok(2));
+
+        let controller =
+            CommitClaimController::new(access_control_service_mock, log_service_mock, service_mock);
+
+        controller.release_commit_claims_job();
+    }
+}
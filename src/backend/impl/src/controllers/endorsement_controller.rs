@@ -0,0 +1,225 @@
+use backend_api::{
+    ApiError, ApiResult, CreateEndorsementRequest, CreateEndorsementResponse,
+    DeleteEndorsementRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        EndorsementRepositoryImpl, Permission, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, EndorsementService, EndorsementServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn create_endorsement(request: CreateEndorsementRequest) -> ApiResult<CreateEndorsementResponse> {
+    let calling_principal = caller();
+
+    EndorsementController::default()
+        .create_endorsement(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn delete_endorsement(request: DeleteEndorsementRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    EndorsementController::default()
+        .delete_endorsement(calling_principal, request)
+        .into()
+}
+
+struct EndorsementController<A: AccessControlService, E: EndorsementService> {
+    access_control_service: A,
+    endorsement_service: E,
+}
+
+impl Default
+    for EndorsementController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        EndorsementServiceImpl<
+            EndorsementRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalReviewCommitRepositoryImpl,
+            UserProfileRepositoryImpl,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            EndorsementServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, E: EndorsementService> EndorsementController<A, E> {
+    fn new(access_control_service: A, endorsement_service: E) -> Self {
+        Self {
+            access_control_service,
+            endorsement_service,
+        }
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn create_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: CreateEndorsementRequest,
+    ) -> Result<CreateEndorsementResponse, ApiError> {
+        self.endorsement_service
+            .create_endorsement(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn delete_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: DeleteEndorsementRequest,
+    ) -> Result<(), ApiError> {
+        self.endorsement_service
+            .delete_endorsement(calling_principal, request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_endorsement,
+        services::{MockAccessControlService, MockEndorsementService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_endorsement() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateEndorsementRequest {
+            proposal_review_id: fixtures::proposal_review_id().to_string(),
+            proposal_review_commit_id: None,
+            note: Some("I independently confirm this".to_string()),
+        };
+        let response = map_endorsement(fixtures::uuid(), fixtures::endorsement_proposal_review());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockEndorsementService::new();
+        service_mock
+            .expect_create_endorsement()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = EndorsementController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_endorsement(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn create_endorsement_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateEndorsementRequest {
+            proposal_review_id: fixtures::proposal_review_id().to_string(),
+            proposal_review_commit_id: None,
+            note: None,
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockEndorsementService::new();
+        service_mock.expect_create_endorsement().never();
+
+        let controller = EndorsementController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_endorsement(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn delete_endorsement() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeleteEndorsementRequest {
+            id: fixtures::uuid().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockEndorsementService::new();
+        service_mock
+            .expect_delete_endorsement()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = EndorsementController::new(access_control_service_mock, service_mock);
+
+        controller
+            .delete_endorsement(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_endorsement_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeleteEndorsementRequest {
+            id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockEndorsementService::new();
+        service_mock.expect_delete_endorsement().never();
+
+        let controller = EndorsementController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .delete_endorsement(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
-    repositories::{CertificationRepositoryImpl, ImageRepositoryImpl, UserProfileRepositoryImpl},
+    repositories::{
+        AdminSyncRepositoryImpl, AuditLogRepositoryImpl, CertificationRepositoryImpl,
+        ImageRepositoryImpl, UserProfileRepositoryImpl,
+    },
     services::{
-        HttpService, HttpServiceImpl, ImageService, ImageServiceImpl, InitService, InitServiceImpl,
+        AdminSyncService, AdminSyncServiceImpl, AuditLogServiceImpl, HttpService, HttpServiceImpl,
+        ImageService, ImageServiceImpl, InitService, InitServiceImpl,
     },
 };
 use backend_api::ApiError;
 use candid::Principal;
+use external_canisters::management::ManagementCanisterService;
 use ic_cdk::*;
 use ic_cdk_timers::set_timer;
 use std::time::Duration;
@@ -42,10 +47,11 @@ fn init_admin(calling_principal: Principal) {
     }
 }
 
-struct InitController<T: InitService, I: ImageService, H: HttpService> {
+struct InitController<T: InitService, I: ImageService, H: HttpService, S: AdminSyncService> {
     init_service: T,
     image_service: I,
     http_service: H,
+    admin_sync_service: S,
 }
 
 impl Default
@@ -53,6 +59,12 @@ impl Default
         InitServiceImpl<UserProfileRepositoryImpl>,
         ImageServiceImpl<ImageRepositoryImpl, CertificationRepositoryImpl>,
         HttpServiceImpl<CertificationRepositoryImpl>,
+        AdminSyncServiceImpl<
+            AdminSyncRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ManagementCanisterService,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
     >
 {
     fn default() -> Self {
@@ -60,21 +72,27 @@ impl Default
             InitServiceImpl::default(),
             ImageServiceImpl::default(),
             HttpServiceImpl::default(),
+            AdminSyncServiceImpl::default(),
         )
     }
 }
 
-impl<T: InitService, I: ImageService, H: HttpService> InitController<T, I, H> {
-    fn new(init_service: T, image_service: I, http_service: H) -> Self {
+impl<T: InitService, I: ImageService, H: HttpService, S: AdminSyncService>
+    InitController<T, I, H, S>
+{
+    fn new(init_service: T, image_service: I, http_service: H, admin_sync_service: S) -> Self {
         Self {
             init_service,
             image_service,
             http_service,
+            admin_sync_service,
         }
     }
 
     fn init(&self, calling_principal: Principal) -> Result<(), ApiError> {
-        self.init_service.init(calling_principal)
+        self.init_service.init(calling_principal)?;
+        self.admin_sync_service
+            .seed_controller_admins(calling_principal)
     }
 
     fn init_http_certification(&self) {
@@ -92,6 +110,8 @@ mod jobs {
     /// Starts all cron jobs.
     pub fn start_jobs() {
         nns_proposals::start();
+        neurons::start();
+        admins::start();
 
         LogServiceImpl::default()
             .log_info("Jobs started.".to_string(), Some("start_jobs".to_string()))
@@ -100,7 +120,11 @@ mod jobs {
 
     mod nns_proposals {
         use super::*;
-        use crate::controllers::proposal_controller::ProposalController;
+        use crate::controllers::{
+            commit_claim_controller::CommitClaimController,
+            proposal_controller::ProposalController,
+            reviewer_assignment_controller::ReviewerAssignmentController,
+        };
 
         // spawn individiual jobs so that if one fails, the others can still run
         pub fn start() {
@@ -115,10 +139,58 @@ mod jobs {
 
         async fn sync_proposals() {
             ProposalController::default().sync_proposals_job().await;
+            ReviewerAssignmentController::default().assign_reviewers_job();
         }
 
         async fn complete_pending_proposals() {
             ProposalController::default().complete_pending_proposals_job();
+            ReviewerAssignmentController::default().close_reviewer_assignments_job();
+            CommitClaimController::default().release_commit_claims_job();
+        }
+    }
+
+    mod neurons {
+        use super::*;
+        use crate::controllers::{
+            neuron_verification_controller::NeuronVerificationController,
+            vote_alignment_controller::VoteAlignmentController,
+        };
+
+        pub fn start() {
+            set_timer_interval(Duration::from_millis(3_600_000), || {
+                spawn(reverify_neurons());
+            });
+
+            set_timer_interval(Duration::from_millis(3_600_000), || {
+                spawn(check_vote_alignments());
+            });
+        }
+
+        async fn reverify_neurons() {
+            NeuronVerificationController::default()
+                .reverify_neurons_job()
+                .await;
+        }
+
+        async fn check_vote_alignments() {
+            VoteAlignmentController::default()
+                .check_vote_alignments_job()
+                .await;
+        }
+    }
+
+    mod admins {
+        use super::*;
+        use crate::controllers::admin_sync_controller::AdminSyncController;
+
+        pub fn start() {
+            set_timer_interval(Duration::from_millis(3_600_000), || {
+                spawn(sync_admins());
+            });
+        }
+
+        async fn sync_admins() {
+            AdminSyncController::default().sync_admins_job().await;
         }
     }
 }
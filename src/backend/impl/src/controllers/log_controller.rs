@@ -1,8 +1,9 @@
 use crate::{
-    repositories::{LogRepositoryImpl, UserProfileRepositoryImpl},
+    repositories::{LogRepositoryImpl, Permission, RoleRepositoryImpl, UserProfileRepositoryImpl},
     services::{AccessControlService, AccessControlServiceImpl, LogService, LogServiceImpl},
 };
 use backend_api::{ApiError, ApiResult, ListLogsResponse, LogsFilterRequest};
+use backend_macros::require_permission;
 use candid::Principal;
 use ic_cdk::*;
 
@@ -22,7 +23,7 @@ struct LogController<A: AccessControlService, L: LogService> {
 
 impl Default
     for LogController<
-        AccessControlServiceImpl<UserProfileRepositoryImpl>,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
         LogServiceImpl<LogRepositoryImpl>,
     >
 {
@@ -42,14 +43,12 @@ impl<A: AccessControlService, L: LogService> LogController<A, L> {
         }
     }
 
+    #[require_permission(Permission::ReadLogs)]
     fn list_logs(
         &self,
         calling_principal: Principal,
         request: LogsFilterRequest,
     ) -> Result<ListLogsResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_is_admin(&calling_principal)?;
-
         self.log_service.list_logs(request)
     }
 }
@@ -84,9 +83,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_admin()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReadLogs))
             .return_const(Err(error.clone()));
 
         let mut service_mock = MockLogService::new();
@@ -116,9 +115,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_admin()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReadLogs))
             .return_const(Ok(()));
 
         let mut service_mock = MockLogService::new();
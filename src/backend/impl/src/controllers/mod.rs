@@ -1,9 +1,212 @@
+mod admin_sync_controller;
+mod approval_controller;
+mod audit_log_controller;
+mod comment_controller;
+mod commit_claim_controller;
 #[cfg(feature = "dev")]
 mod dev_controller;
+mod endorsement_controller;
 mod http_controller;
 mod init_controller;
 mod log_controller;
+mod neuron_verification_controller;
+mod principal_link_controller;
 mod proposal_controller;
 mod proposal_review_commit_controller;
 mod proposal_review_controller;
+mod review_conflict_controller;
+mod review_score_controller;
+mod reviewer_application_controller;
+mod reviewer_assignment_controller;
+mod reviewer_profile_controller;
+mod reward_controller;
+mod reward_payout_controller;
+mod role_controller;
+mod user_avatar_controller;
+mod user_directory_controller;
 mod user_profile_controller;
+mod vote_alignment_controller;
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    // New controllers have to be added here to be covered by the access policy check.
+    const CONTROLLER_SOURCES: &[(&str, &str)] = &[
+        (
+            "admin_sync_controller",
+            include_str!("admin_sync_controller.rs"),
+        ),
+        (
+            "approval_controller",
+            include_str!("approval_controller.rs"),
+        ),
+        (
+            "audit_log_controller",
+            include_str!("audit_log_controller.rs"),
+        ),
+        ("comment_controller", include_str!("comment_controller.rs")),
+        (
+            "commit_claim_controller",
+            include_str!("commit_claim_controller.rs"),
+        ),
+        (
+            "endorsement_controller",
+            include_str!("endorsement_controller.rs"),
+        ),
+        ("http_controller", include_str!("http_controller.rs")),
+        ("init_controller", include_str!("init_controller.rs")),
+        ("log_controller", include_str!("log_controller.rs")),
+        (
+            "neuron_verification_controller",
+            include_str!("neuron_verification_controller.rs"),
+        ),
+        (
+            "principal_link_controller",
+            include_str!("principal_link_controller.rs"),
+        ),
+        (
+            "proposal_controller",
+            include_str!("proposal_controller.rs"),
+        ),
+        (
+            "proposal_review_commit_controller",
+            include_str!("proposal_review_commit_controller.rs"),
+        ),
+        (
+            "proposal_review_controller",
+            include_str!("proposal_review_controller.rs"),
+        ),
+        (
+            "review_conflict_controller",
+            include_str!("review_conflict_controller.rs"),
+        ),
+        (
+            "review_score_controller",
+            include_str!("review_score_controller.rs"),
+        ),
+        (
+            "reviewer_application_controller",
+            include_str!("reviewer_application_controller.rs"),
+        ),
+        (
+            "reviewer_assignment_controller",
+            include_str!("reviewer_assignment_controller.rs"),
+        ),
+        (
+            "reviewer_profile_controller",
+            include_str!("reviewer_profile_controller.rs"),
+        ),
+        ("reward_controller", include_str!("reward_controller.rs")),
+        (
+            "reward_payout_controller",
+            include_str!("reward_payout_controller.rs"),
+        ),
+        ("role_controller", include_str!("role_controller.rs")),
+        (
+            "user_avatar_controller",
+            include_str!("user_avatar_controller.rs"),
+        ),
+        (
+            "user_directory_controller",
+            include_str!("user_directory_controller.rs"),
+        ),
+        (
+            "user_profile_controller",
+            include_str!("user_profile_controller.rs"),
+        ),
+        (
+            "vote_alignment_controller",
+            include_str!("vote_alignment_controller.rs"),
+        ),
+    ];
+
+    // Controllers that are deliberately not covered by the access policy check.
+    // `dev_controller` is only compiled with the `dev` feature, for local testing,
+    // and its endpoints are open on purpose so that tests can drive the canister.
+    const EXEMPT_CONTROLLERS: &[&str] = &["dev_controller"];
+
+    const ACCESS_POLICY_ATTRIBUTES: &[&str] =
+        &["#[require_permission(", "#[require_authenticated]"];
+
+    fn fn_name(line: &str) -> Option<&str> {
+        let line = line.strip_prefix("async ").unwrap_or(line);
+        let name = line.strip_prefix("fn ")?;
+
+        name.split('(').next()
+    }
+
+    fn update_endpoints(source: &str) -> Vec<&str> {
+        let mut lines = source.lines();
+        let mut endpoints = vec![];
+        while let Some(line) = lines.next() {
+            if line != "#[update]" {
+                continue;
+            }
+            if let Some(name) = lines.by_ref().find_map(fn_name) {
+                endpoints.push(name);
+            }
+        }
+
+        endpoints
+    }
+
+    /// Checks the attributes of the controller method with the same name as the endpoint.
+    fn declares_access_policy(source: &str, endpoint: &str) -> bool {
+        let lines: Vec<&str> = source.lines().collect();
+
+        lines.iter().enumerate().any(|(i, line)| {
+            line.starts_with("    ")
+                && fn_name(line.trim_start()) == Some(endpoint)
+                && lines[..i]
+                    .iter()
+                    .rev()
+                    .map(|line| line.trim())
+                    .take_while(|line| line.starts_with("#[") || line.starts_with("///"))
+                    .any(|line| {
+                        ACCESS_POLICY_ATTRIBUTES
+                            .iter()
+                            .any(|attribute| line.starts_with(attribute))
+                    })
+        })
+    }
+
+    #[rstest]
+    fn every_controller_is_checked_or_exempt() {
+        let unchecked: Vec<&str> = include_str!("mod.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("mod ")?.strip_suffix(';'))
+            .filter(|controller| {
+                !EXEMPT_CONTROLLERS.contains(controller)
+                    && !CONTROLLER_SOURCES
+                        .iter()
+                        .any(|(name, _)| name == controller)
+            })
+            .collect();
+
+        assert!(
+            unchecked.is_empty(),
+            "Controllers missing from the access policy check: {:?}",
+            unchecked
+        );
+    }
+
+    #[rstest]
+    fn every_update_endpoint_declares_access_policy() {
+        let missing: Vec<String> = CONTROLLER_SOURCES
+            .iter()
+            .flat_map(|(controller, source)| {
+                update_endpoints(source)
+                    .into_iter()
+                    .filter(|endpoint| !declares_access_policy(source, endpoint))
+                    .map(move |endpoint| format!("{}::{}", controller, endpoint))
+            })
+            .collect();
+
+        assert!(
+            missing.is_empty(),
+            "Update endpoints without an access policy: {:?}",
+            missing
+        );
+    }
+}
@@ -0,0 +1,365 @@
+use backend_api::{
+    ApiError, ApiResult, GetNeuronVerificationRequest, GetNeuronVerificationResponse,
+    VerifyMyNeuronResponse, VerifyReviewerNeuronRequest, VerifyReviewerNeuronResponse,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use external_canisters::nns::GovernanceCanisterService;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, LogRepositoryImpl, NeuronVerificationRepositoryImpl, Permission,
+        RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, LogService,
+        LogServiceImpl, NeuronVerificationService, NeuronVerificationServiceImpl,
+        ReverifyNeuronsResult,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+async fn verify_my_neuron() -> ApiResult<VerifyMyNeuronResponse> {
+    let calling_principal = caller();
+
+    NeuronVerificationController::default()
+        .verify_my_neuron(calling_principal)
+        .await
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+async fn verify_reviewer_neuron(
+    request: VerifyReviewerNeuronRequest,
+) -> ApiResult<VerifyReviewerNeuronResponse> {
+    let calling_principal = caller();
+
+    NeuronVerificationController::default()
+        .verify_reviewer_neuron(calling_principal, request)
+        .await
+        .into()
+}
+
+#[query]
+fn get_neuron_verification(
+    request: GetNeuronVerificationRequest,
+) -> ApiResult<GetNeuronVerificationResponse> {
+    NeuronVerificationController::default()
+        .get_neuron_verification(request)
+        .into()
+}
+
+pub(super) struct NeuronVerificationController<
+    A: AccessControlService,
+    L: LogService,
+    N: NeuronVerificationService,
+> {
+    access_control_service: A,
+    log_service: L,
+    neuron_verification_service: N,
+}
+
+impl Default
+    for NeuronVerificationController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        LogServiceImpl<LogRepositoryImpl>,
+        NeuronVerificationServiceImpl<
+            NeuronVerificationRepositoryImpl,
+            UserProfileRepositoryImpl,
+            GovernanceCanisterService,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            LogServiceImpl::default(),
+            NeuronVerificationServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, L: LogService, N: NeuronVerificationService>
+    NeuronVerificationController<A, L, N>
+{
+    fn new(access_control_service: A, log_service: L, neuron_verification_service: N) -> Self {
+        Self {
+            access_control_service,
+            log_service,
+            neuron_verification_service,
+        }
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    async fn verify_my_neuron(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<VerifyMyNeuronResponse, ApiError> {
+        self.neuron_verification_service
+            .verify_my_neuron(calling_principal)
+            .await
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    async fn verify_reviewer_neuron(
+        &self,
+        calling_principal: Principal,
+        request: VerifyReviewerNeuronRequest,
+    ) -> Result<VerifyReviewerNeuronResponse, ApiError> {
+        self.neuron_verification_service
+            .verify_reviewer_neuron(calling_principal, request)
+            .await
+    }
+
+    fn get_neuron_verification(
+        &self,
+        request: GetNeuronVerificationRequest,
+    ) -> Result<GetNeuronVerificationResponse, ApiError> {
+        self.neuron_verification_service
+            .get_neuron_verification(request)
+    }
+
+    pub async fn reverify_neurons_job(&self) {
+        let _ = self.log_service.log_info(
+            "Verifying reviewer neurons".to_string(),
+            Some("reverify_neurons".to_string()),
+        );
+
+        match self.neuron_verification_service.reverify_neurons().await {
+            Ok(ReverifyNeuronsResult {
+                verified_neurons_count,
+                failures,
+            }) => {
+                for (user_id, e) in failures {
+                    let _ = self.log_service.log_error(
+                        format!(
+                            "Error verifying the neuron of user with id {} ({})",
+                            user_id, e
+                        ),
+                        Some("reverify_neurons".to_string()),
+                    );
+                }
+
+                let _ = self.log_service.log_info(
+                    format!("Successfully verified {verified_neurons_count} reviewer neurons"),
+                    Some("reverify_neurons".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error verifying reviewer neurons ({})", e),
+                    Some("reverify_neurons".to_string()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_neuron_verification,
+        services::{MockAccessControlService, MockLogService, MockNeuronVerificationService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    async fn verify_my_neuron() {
+        let calling_principal = fixtures::principal_a();
+        let response = map_neuron_verification(
+            fixtures::user_id(),
+            fixtures::neuron_verification_verified(),
+        );
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockNeuronVerificationService::new();
+        service_mock
+            .expect_verify_my_neuron()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(response.clone()));
+
+        let controller = NeuronVerificationController::new(
+            access_control_service_mock,
+            MockLogService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .verify_my_neuron(calling_principal)
+            .await
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    async fn verify_my_neuron_not_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockNeuronVerificationService::new();
+        service_mock.expect_verify_my_neuron().never();
+
+        let controller = NeuronVerificationController::new(
+            access_control_service_mock,
+            MockLogService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .verify_my_neuron(calling_principal)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    async fn verify_reviewer_neuron_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = VerifyReviewerNeuronRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockNeuronVerificationService::new();
+        service_mock.expect_verify_reviewer_neuron().never();
+
+        let controller = NeuronVerificationController::new(
+            access_control_service_mock,
+            MockLogService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .verify_reviewer_neuron(calling_principal, request)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    async fn reverify_neurons_job() {
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Verifying reviewer neurons".to_string()),
+                eq(Some("reverify_neurons".to_string())),
+            )
+            .return_const(Ok(()));
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Successfully verified 2 reviewer neurons".to_string()),
+                eq(Some("reverify_neurons".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockNeuronVerificationService::new();
+        service_mock
+            .expect_reverify_neurons()
+            .once()
+            .return_const(Ok(ReverifyNeuronsResult {
+                verified_neurons_count: 2,
+                failures: vec![],
+            }));
+
+        let controller = NeuronVerificationController::new(
+            MockAccessControlService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller.reverify_neurons_job().await;
+    }
+
+    #[rstest]
+    async fn reverify_neurons_job_with_failures() {
+        let error = ApiError::internal("Failed to fetch neuron 1");
+
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Verifying reviewer neurons".to_string()),
+                eq(Some("reverify_neurons".to_string())),
+            )
+            .return_const(Ok(()));
+        log_service_mock
+            .expect_log_error()
+            .once()
+            .with(
+                eq(format!(
+                    "Error verifying the neuron of user with id {} ({})",
+                    fixtures::uuid_a(),
+                    error
+                )),
+                eq(Some("reverify_neurons".to_string())),
+            )
+            .return_const(Ok(()));
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Successfully verified 1 reviewer neurons".to_string()),
+                eq(Some("reverify_neurons".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockNeuronVerificationService::new();
+        service_mock
+            .expect_reverify_neurons()
+            .once()
+            .return_const(Ok(ReverifyNeuronsResult {
+                verified_neurons_count: 1,
+                failures: vec![(fixtures::uuid_a(), error.clone())],
+            }));
+
+        let controller = NeuronVerificationController::new(
+            MockAccessControlService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller.reverify_neurons_job().await;
+    }
+}
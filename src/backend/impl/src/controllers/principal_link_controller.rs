@@ -0,0 +1,240 @@
+use backend_api::{
+    ApiError, ApiResult, CreatePrincipalLinkCodeResponse, GetMyPrincipalLinkHistoryResponse,
+    ListMyPrincipalsResponse, RedeemPrincipalLinkCodeRequest, UnlinkMyPrincipalRequest,
+};
+use backend_macros::{log_errors, require_authenticated};
+use candid::Principal;
+use external_canisters::management::ManagementCanisterService;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        PrincipalLinkCodeRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, PrincipalLinkService,
+        PrincipalLinkServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+async fn create_principal_link_code() -> ApiResult<CreatePrincipalLinkCodeResponse> {
+    let calling_principal = caller();
+
+    PrincipalLinkController::default()
+        .create_principal_link_code(calling_principal)
+        .await
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn redeem_principal_link_code(request: RedeemPrincipalLinkCodeRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    PrincipalLinkController::default()
+        .redeem_principal_link_code(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_my_principals() -> ApiResult<ListMyPrincipalsResponse> {
+    let calling_principal = caller();
+
+    PrincipalLinkController::default()
+        .list_my_principals(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn unlink_my_principal(request: UnlinkMyPrincipalRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    PrincipalLinkController::default()
+        .unlink_my_principal(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_my_principal_link_history() -> ApiResult<GetMyPrincipalLinkHistoryResponse> {
+    let calling_principal = caller();
+
+    PrincipalLinkController::default()
+        .get_my_principal_link_history(calling_principal)
+        .into()
+}
+
+struct PrincipalLinkController<A: AccessControlService, P: PrincipalLinkService> {
+    access_control_service: A,
+    principal_link_service: P,
+}
+
+impl Default
+    for PrincipalLinkController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        PrincipalLinkServiceImpl<
+            PrincipalLinkCodeRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ManagementCanisterService,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            PrincipalLinkServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: PrincipalLinkService> PrincipalLinkController<A, P> {
+    fn new(access_control_service: A, principal_link_service: P) -> Self {
+        Self {
+            access_control_service,
+            principal_link_service,
+        }
+    }
+
+    #[require_authenticated]
+    async fn create_principal_link_code(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<CreatePrincipalLinkCodeResponse, ApiError> {
+        self.principal_link_service
+            .create_principal_link_code(calling_principal)
+            .await
+    }
+
+    #[require_authenticated]
+    fn redeem_principal_link_code(
+        &self,
+        calling_principal: Principal,
+        request: RedeemPrincipalLinkCodeRequest,
+    ) -> Result<(), ApiError> {
+        self.principal_link_service
+            .redeem_principal_link_code(calling_principal, request)
+    }
+
+    #[require_authenticated]
+    fn list_my_principals(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<ListMyPrincipalsResponse, ApiError> {
+        self.principal_link_service
+            .list_my_principals(calling_principal)
+    }
+
+    #[require_authenticated]
+    fn unlink_my_principal(
+        &self,
+        calling_principal: Principal,
+        request: UnlinkMyPrincipalRequest,
+    ) -> Result<(), ApiError> {
+        self.principal_link_service
+            .unlink_my_principal(calling_principal, request)
+    }
+
+    #[require_authenticated]
+    fn get_my_principal_link_history(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPrincipalLinkHistoryResponse, ApiError> {
+        self.principal_link_service
+            .get_my_principal_link_history(calling_principal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockPrincipalLinkService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn redeem_principal_link_code() {
+        let calling_principal = fixtures::principal_b();
+        let request = RedeemPrincipalLinkCodeRequest {
+            code: fixtures::uuid().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockPrincipalLinkService::new();
+        service_mock
+            .expect_redeem_principal_link_code()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = PrincipalLinkController::new(access_control_service_mock, service_mock);
+
+        controller
+            .redeem_principal_link_code(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn redeem_principal_link_code_anonymous_principal() {
+        let calling_principal = Principal::anonymous();
+        let request = RedeemPrincipalLinkCodeRequest {
+            code: fixtures::uuid().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Err(ApiError::unauthenticated()));
+
+        let mut service_mock = MockPrincipalLinkService::new();
+        service_mock.expect_redeem_principal_link_code().never();
+
+        let controller = PrincipalLinkController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .redeem_principal_link_code(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::unauthenticated());
+    }
+
+    #[rstest]
+    fn list_my_principals() {
+        let calling_principal = fixtures::principal_a();
+        let response = ListMyPrincipalsResponse {
+            principals: vec![fixtures::principal_a(), fixtures::principal_b()],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockPrincipalLinkService::new();
+        service_mock
+            .expect_list_my_principals()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(response.clone()));
+
+        let controller = PrincipalLinkController::new(access_control_service_mock, service_mock);
+
+        let result = controller.list_my_principals(calling_principal).unwrap();
+
+        assert_eq!(result, response);
+    }
+}
@@ -1,14 +1,17 @@
 use crate::{
-    repositories::{LogRepositoryImpl, ProposalRepositoryImpl, UserProfileRepositoryImpl},
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, LogRepositoryImpl, Permission,
+        ProposalRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
     services::{
-        AccessControlService, AccessControlServiceImpl, LogService, LogServiceImpl,
-        ProposalService, ProposalServiceImpl,
+        AccessControlService, AccessControlServiceImpl, AuditLogService, AuditLogServiceImpl,
+        LogService, LogServiceImpl, ProposalService, ProposalServiceImpl,
     },
 };
 use backend_api::{
     ApiError, ApiResult, ListProposalsRequest, ListProposalsResponse, SyncProposalsResponse,
 };
-use backend_macros::log_errors;
+use backend_macros::{log_errors, require_permission};
 use candid::Principal;
 use ic_cdk::*;
 
@@ -28,17 +31,24 @@ fn list_proposals(request: ListProposalsRequest) -> ApiResult<ListProposalsRespo
     ProposalController::default().list_proposals(request).into()
 }
 
-pub(super) struct ProposalController<A: AccessControlService, L: LogService, P: ProposalService> {
+pub(super) struct ProposalController<
+    A: AccessControlService,
+    L: LogService,
+    P: ProposalService,
+    AL: AuditLogService,
+> {
     access_control_service: A,
     log_service: L,
     proposal_service: P,
+    audit_log_service: AL,
 }
 
 impl Default
     for ProposalController<
-        AccessControlServiceImpl<UserProfileRepositoryImpl>,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
         LogServiceImpl<LogRepositoryImpl>,
         ProposalServiceImpl<ProposalRepositoryImpl, LogServiceImpl<LogRepositoryImpl>>,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
     >
 {
     fn default() -> Self {
@@ -46,27 +56,45 @@ impl Default
             AccessControlServiceImpl::default(),
             LogServiceImpl::default(),
             ProposalServiceImpl::default(),
+            AuditLogServiceImpl::default(),
         )
     }
 }
 
-impl<A: AccessControlService, L: LogService, P: ProposalService> ProposalController<A, L, P> {
-    fn new(access_control_service: A, log_service: L, proposal_service: P) -> Self {
+impl<A: AccessControlService, L: LogService, P: ProposalService, AL: AuditLogService>
+    ProposalController<A, L, P, AL>
+{
+    fn new(
+        access_control_service: A,
+        log_service: L,
+        proposal_service: P,
+        audit_log_service: AL,
+    ) -> Self {
         Self {
             access_control_service,
             log_service,
             proposal_service,
+            audit_log_service,
         }
     }
 
+    #[require_permission(Permission::SyncProposals)]
     async fn sync_proposals(
         &self,
         calling_principal: Principal,
     ) -> Result<SyncProposalsResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_is_admin(&calling_principal)?;
-
-        self.proposal_service.fetch_and_save_nns_proposals().await
+        let response = self.proposal_service.fetch_and_save_nns_proposals().await?;
+
+        // recorded here rather than in the service, so that the scheduled sync isn't audited
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::SyncProposals,
+            vec![],
+            None,
+            Some(audit_snapshot(&response)),
+        )?;
+
+        Ok(response)
     }
 
     pub async fn sync_proposals_job(&self) {
@@ -130,7 +158,9 @@ mod tests {
     use crate::{
         fixtures,
         mappings::map_get_proposal_response,
-        services::{MockAccessControlService, MockLogService, MockProposalService},
+        services::{
+            MockAccessControlService, MockAuditLogService, MockLogService, MockProposalService,
+        },
     };
     use mockall::predicate::*;
     use rstest::*;
@@ -146,9 +176,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_admin()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::SyncProposals))
             .return_const(Err(error.clone()));
 
         let log_service_mock = MockLogService::new();
@@ -161,6 +191,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         let result = controller
@@ -184,9 +215,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_admin()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::SyncProposals))
             .return_const(Ok(()));
 
         let log_service_mock = MockLogService::new();
@@ -196,10 +227,24 @@ mod tests {
             .once()
             .return_const(Ok(expected_result.clone()));
 
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SyncProposals),
+                eq(Vec::<String>::new()),
+                eq(None::<String>),
+                eq(Some(audit_snapshot(&expected_result))),
+            )
+            .return_const(Ok(()));
+
         let controller = ProposalController::new(
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            audit_log_service_mock,
         );
 
         let result = controller.sync_proposals(calling_principal).await.unwrap();
@@ -228,6 +273,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         let result = controller
@@ -276,6 +322,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         controller.sync_proposals_job().await;
@@ -313,6 +360,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         controller.sync_proposals_job().await;
@@ -353,6 +401,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         controller.complete_pending_proposals_job();
@@ -390,6 +439,7 @@ mod tests {
             access_control_service_mock,
             log_service_mock,
             proposal_service_mock,
+            MockAuditLogService::new(),
         );
 
         controller.complete_pending_proposals_job();
@@ -2,14 +2,14 @@ use backend_api::{
     ApiError, ApiResult, CreateProposalReviewCommitRequest, CreateProposalReviewCommitResponse,
     DeleteProposalReviewCommitRequest, UpdateProposalReviewCommitRequest,
 };
-use backend_macros::log_errors;
+use backend_macros::{log_errors, require_permission};
 use candid::Principal;
 use ic_cdk::*;
 
 use crate::{
     repositories::{
-        ProposalRepositoryImpl, ProposalReviewCommitRepositoryImpl, ProposalReviewRepositoryImpl,
-        UserProfileRepositoryImpl,
+        Permission, ProposalRepositoryImpl, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
     },
     services::{
         AccessControlService, AccessControlServiceImpl, ProposalReviewCommitService,
@@ -56,7 +56,7 @@ struct ProposalReviewCommitController<A: AccessControlService, P: ProposalReview
 
 impl Default
     for ProposalReviewCommitController<
-        AccessControlServiceImpl<UserProfileRepositoryImpl>,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
         ProposalReviewCommitServiceImpl<
             ProposalReviewCommitRepositoryImpl,
             UserProfileRepositoryImpl,
@@ -84,38 +84,32 @@ impl<A: AccessControlService, P: ProposalReviewCommitService> ProposalReviewComm
         }
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn create_proposal_review_commit(
         &self,
         calling_principal: Principal,
         request: CreateProposalReviewCommitRequest,
     ) -> Result<CreateProposalReviewCommitResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_commit_service
             .create_proposal_review_commit(calling_principal, request)
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn update_proposal_review_commit(
         &self,
         calling_principal: Principal,
         request: UpdateProposalReviewCommitRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_commit_service
             .update_proposal_review_commit(calling_principal, request)
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn delete_proposal_review_commit(
         &self,
         calling_principal: Principal,
         request: DeleteProposalReviewCommitRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_commit_service
             .delete_proposal_review_commit(calling_principal, request)
     }
@@ -149,9 +143,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
@@ -189,9 +183,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
@@ -220,9 +214,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
@@ -257,9 +251,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
@@ -284,9 +278,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
@@ -317,9 +311,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut service_mock = MockProposalReviewCommitService::new();
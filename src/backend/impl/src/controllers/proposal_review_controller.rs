@@ -1,22 +1,25 @@
 use crate::{
     repositories::{
-        CertificationRepositoryImpl, ImageRepositoryImpl, ProposalRepositoryImpl,
+        AuditLogRepositoryImpl, CertificationRepositoryImpl, EndorsementRepositoryImpl,
+        ImageRepositoryImpl, Permission, ProposalId, ProposalRepositoryImpl,
         ProposalReviewCommitRepositoryImpl, ProposalReviewRepositoryImpl,
-        UserProfileRepositoryImpl,
+        ReviewConflictRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
     },
     services::{
-        AccessControlService, AccessControlServiceImpl, ProposalReviewService,
-        ProposalReviewServiceImpl,
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, ProposalReviewService,
+        ProposalReviewServiceImpl, ReviewConflictService, ReviewConflictServiceImpl,
     },
 };
 use backend_api::{
-    ApiError, ApiResult, CreateProposalReviewImageRequest, CreateProposalReviewImageResponse,
-    CreateProposalReviewRequest, CreateProposalReviewResponse, DeleteProposalReviewImageRequest,
+    ApiError, ApiResult, CreateProposalReviewAttachmentRequest,
+    CreateProposalReviewAttachmentResponse, CreateProposalReviewImageRequest,
+    CreateProposalReviewImageResponse, CreateProposalReviewRequest, CreateProposalReviewResponse,
+    DeleteProposalReviewAttachmentRequest, DeleteProposalReviewImageRequest,
     GetMyProposalReviewRequest, GetMyProposalReviewResponse, GetMyProposalReviewSummaryRequest,
     GetMyProposalReviewSummaryResponse, GetProposalReviewRequest, GetProposalReviewResponse,
     ListProposalReviewsRequest, ListProposalReviewsResponse, UpdateProposalReviewRequest,
 };
-use backend_macros::log_errors;
+use backend_macros::{log_errors, require_authenticated, require_permission};
 use candid::Principal;
 use ic_cdk::*;
 
@@ -84,6 +87,30 @@ fn delete_proposal_review_image(request: DeleteProposalReviewImageRequest) -> Ap
         .into()
 }
 
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn create_proposal_review_attachment(
+    request: CreateProposalReviewAttachmentRequest,
+) -> ApiResult<CreateProposalReviewAttachmentResponse> {
+    let calling_principal = caller();
+
+    ProposalReviewController::default()
+        .create_proposal_review_attachment(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn delete_proposal_review_attachment(
+    request: DeleteProposalReviewAttachmentRequest,
+) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ProposalReviewController::default()
+        .delete_proposal_review_attachment(calling_principal, request)
+        .into()
+}
+
 #[query]
 fn get_my_proposal_review(
     request: GetMyProposalReviewRequest,
@@ -106,14 +133,19 @@ fn get_my_proposal_review_summary(
         .into()
 }
 
-struct ProposalReviewController<A: AccessControlService, P: ProposalReviewService> {
+struct ProposalReviewController<
+    A: AccessControlService,
+    P: ProposalReviewService,
+    R: ReviewConflictService,
+> {
     access_control_service: A,
     proposal_review_service: P,
+    review_conflict_service: R,
 }
 
 impl Default
     for ProposalReviewController<
-        AccessControlServiceImpl<UserProfileRepositoryImpl>,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
         ProposalReviewServiceImpl<
             ProposalReviewRepositoryImpl,
             UserProfileRepositoryImpl,
@@ -121,6 +153,15 @@ impl Default
             ProposalReviewCommitRepositoryImpl,
             ImageRepositoryImpl,
             CertificationRepositoryImpl,
+            EndorsementRepositoryImpl,
+            AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        >,
+        ReviewConflictServiceImpl<
+            ReviewConflictRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalReviewCommitRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
         >,
     >
 {
@@ -128,26 +169,32 @@ impl Default
         Self::new(
             AccessControlServiceImpl::default(),
             ProposalReviewServiceImpl::default(),
+            ReviewConflictServiceImpl::default(),
         )
     }
 }
 
-impl<A: AccessControlService, P: ProposalReviewService> ProposalReviewController<A, P> {
-    fn new(access_control_service: A, proposal_review_service: P) -> Self {
+impl<A: AccessControlService, P: ProposalReviewService, R: ReviewConflictService>
+    ProposalReviewController<A, P, R>
+{
+    fn new(
+        access_control_service: A,
+        proposal_review_service: P,
+        review_conflict_service: R,
+    ) -> Self {
         Self {
             access_control_service,
             proposal_review_service,
+            review_conflict_service,
         }
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn create_proposal_review(
         &self,
         calling_principal: Principal,
         request: CreateProposalReviewRequest,
     ) -> Result<CreateProposalReviewResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         let proposal_review = self
             .proposal_review_service
             .create_proposal_review(calling_principal, request)?;
@@ -155,16 +202,24 @@ impl<A: AccessControlService, P: ProposalReviewService> ProposalReviewController
         Ok(proposal_review)
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn update_proposal_review(
         &self,
         calling_principal: Principal,
         request: UpdateProposalReviewRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        let is_publishing = request.status == Some(backend_api::ProposalReviewStatus::Published);
 
         self.proposal_review_service
-            .update_proposal_review(calling_principal, request)
+            .update_proposal_review(calling_principal, request)?;
+
+        if is_publishing {
+            self.review_conflict_service
+                .detect_review_conflicts(proposal_id)?;
+        }
+
+        Ok(())
     }
 
     fn list_proposal_reviews(
@@ -185,57 +240,67 @@ impl<A: AccessControlService, P: ProposalReviewService> ProposalReviewController
             .get_proposal_review(calling_principal, request)
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn create_proposal_review_image(
         &self,
         calling_principal: Principal,
         request: CreateProposalReviewImageRequest,
     ) -> Result<CreateProposalReviewImageResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_service
             .create_proposal_review_image(calling_principal, request)
     }
 
+    #[require_authenticated]
+    #[require_permission(Permission::ReviewProposals)]
     fn get_my_proposal_review(
         &self,
         calling_principal: Principal,
         request: GetMyProposalReviewRequest,
     ) -> Result<GetMyProposalReviewResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_service
             .get_my_proposal_review(calling_principal, request)
     }
 
+    #[require_authenticated]
+    #[require_permission(Permission::ReviewProposals)]
     fn get_my_proposal_review_summary(
         &self,
         calling_principal: Principal,
         request: GetMyProposalReviewSummaryRequest,
     ) -> Result<GetMyProposalReviewSummaryResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_service
             .get_my_proposal_review_summary(calling_principal, request)
     }
 
+    #[require_permission(Permission::ReviewProposals)]
     fn delete_proposal_review_image(
         &self,
         calling_principal: Principal,
         request: DeleteProposalReviewImageRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_is_reviewer(&calling_principal)?;
-
         self.proposal_review_service
             .delete_proposal_review_image(calling_principal, request)
     }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn create_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: CreateProposalReviewAttachmentRequest,
+    ) -> Result<CreateProposalReviewAttachmentResponse, ApiError> {
+        self.proposal_review_service
+            .create_proposal_review_attachment(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn delete_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteProposalReviewAttachmentRequest,
+    ) -> Result<(), ApiError> {
+        self.proposal_review_service
+            .delete_proposal_review_attachment(calling_principal, request)
+    }
 }
 
 #[cfg(test)]
@@ -243,9 +308,11 @@ mod tests {
     use super::*;
     use crate::{
         fixtures,
-        services::{MockAccessControlService, MockProposalReviewService},
+        services::{
+            MockAccessControlService, MockProposalReviewService, MockReviewConflictService,
+        },
     };
-    use backend_api::ProposalVote;
+    use backend_api::{ProposalReviewStatus, ProposalVote};
     use mockall::predicate::*;
     use rstest::*;
 
@@ -265,9 +332,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -281,6 +348,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -306,9 +374,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -319,6 +387,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -341,9 +410,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -356,6 +425,51 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
+        );
+
+        controller
+            .update_proposal_review(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn update_proposal_review_publish() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let request = UpdateProposalReviewRequest {
+            proposal_id: proposal_id.to_string(),
+            status: Some(ProposalReviewStatus::Published),
+            summary: None,
+            build_reproduced: None,
+            vote: None,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut proposal_review_service_mock = MockProposalReviewService::new();
+        proposal_review_service_mock
+            .expect_update_proposal_review()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let mut review_conflict_service_mock = MockReviewConflictService::new();
+        review_conflict_service_mock
+            .expect_detect_review_conflicts()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(()));
+
+        let controller = ProposalReviewController::new(
+            access_control_service_mock,
+            proposal_review_service_mock,
+            review_conflict_service_mock,
         );
 
         controller
@@ -380,9 +494,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -393,6 +507,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -416,9 +531,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -431,6 +546,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -455,9 +571,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -468,6 +584,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -487,9 +604,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Ok(()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -502,6 +619,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         controller
@@ -523,9 +641,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_is_reviewer()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
             .return_const(Err(error.clone()));
 
         let mut proposal_review_service_mock = MockProposalReviewService::new();
@@ -536,6 +654,7 @@ mod tests {
         let controller = ProposalReviewController::new(
             access_control_service_mock,
             proposal_review_service_mock,
+            MockReviewConflictService::new(),
         );
 
         let result = controller
@@ -544,4 +663,153 @@ mod tests {
 
         assert_eq!(result, error);
     }
+
+    #[rstest]
+    fn create_proposal_review_attachment() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateProposalReviewAttachmentRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            file_name: "build.log".to_string(),
+            content_type: "text/plain".to_string(),
+            content_bytes: vec![1, 2, 3],
+        };
+        let response = CreateProposalReviewAttachmentResponse {
+            path: "/images/reviews/attachments/dummy-attachment-id".to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut proposal_review_service_mock = MockProposalReviewService::new();
+        proposal_review_service_mock
+            .expect_create_proposal_review_attachment()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ProposalReviewController::new(
+            access_control_service_mock,
+            proposal_review_service_mock,
+            MockReviewConflictService::new(),
+        );
+
+        let result = controller
+            .create_proposal_review_attachment(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn create_proposal_review_attachment_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateProposalReviewAttachmentRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            file_name: "build.log".to_string(),
+            content_type: "text/plain".to_string(),
+            content_bytes: vec![1, 2, 3],
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let mut proposal_review_service_mock = MockProposalReviewService::new();
+        proposal_review_service_mock
+            .expect_create_proposal_review_attachment()
+            .never();
+
+        let controller = ProposalReviewController::new(
+            access_control_service_mock,
+            proposal_review_service_mock,
+            MockReviewConflictService::new(),
+        );
+
+        let result = controller
+            .create_proposal_review_attachment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn delete_proposal_review_attachment() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeleteProposalReviewAttachmentRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            attachment_path: "/images/reviews/attachments/dummy-attachment-id".to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let mut proposal_review_service_mock = MockProposalReviewService::new();
+        proposal_review_service_mock
+            .expect_delete_proposal_review_attachment()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = ProposalReviewController::new(
+            access_control_service_mock,
+            proposal_review_service_mock,
+            MockReviewConflictService::new(),
+        );
+
+        controller
+            .delete_proposal_review_attachment(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_proposal_review_attachment_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeleteProposalReviewAttachmentRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            attachment_path: "/images/reviews/attachments/dummy-attachment-id".to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let mut proposal_review_service_mock = MockProposalReviewService::new();
+        proposal_review_service_mock
+            .expect_delete_proposal_review_attachment()
+            .never();
+
+        let controller = ProposalReviewController::new(
+            access_control_service_mock,
+            proposal_review_service_mock,
+            MockReviewConflictService::new(),
+        );
+
+        let result = controller
+            .delete_proposal_review_attachment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
 }
@@ -0,0 +1,200 @@
+use backend_api::{
+    ApiError, ApiResult, ListOpenReviewConflictsRequest, ListOpenReviewConflictsResponse,
+    ResolveReviewConflictRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, Permission, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewRepositoryImpl, ReviewConflictRepositoryImpl, RoleRepositoryImpl,
+        UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, ReviewConflictService,
+        ReviewConflictServiceImpl,
+    },
+};
+
+#[query]
+fn list_open_review_conflicts(
+    request: ListOpenReviewConflictsRequest,
+) -> ApiResult<ListOpenReviewConflictsResponse> {
+    let calling_principal = caller();
+
+    ReviewConflictController::default()
+        .list_open_review_conflicts(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn resolve_review_conflict(request: ResolveReviewConflictRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewConflictController::default()
+        .resolve_review_conflict(calling_principal, request)
+        .into()
+}
+
+struct ReviewConflictController<A: AccessControlService, R: ReviewConflictService> {
+    access_control_service: A,
+    review_conflict_service: R,
+}
+
+impl Default
+    for ReviewConflictController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ReviewConflictServiceImpl<
+            ReviewConflictRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalReviewCommitRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ReviewConflictServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, R: ReviewConflictService> ReviewConflictController<A, R> {
+    fn new(access_control_service: A, review_conflict_service: R) -> Self {
+        Self {
+            access_control_service,
+            review_conflict_service,
+        }
+    }
+
+    #[require_permission(Permission::ModerateReviews)]
+    fn list_open_review_conflicts(
+        &self,
+        calling_principal: Principal,
+        request: ListOpenReviewConflictsRequest,
+    ) -> Result<ListOpenReviewConflictsResponse, ApiError> {
+        self.review_conflict_service
+            .list_open_review_conflicts(request)
+    }
+
+    #[require_permission(Permission::ModerateReviews)]
+    fn resolve_review_conflict(
+        &self,
+        calling_principal: Principal,
+        request: ResolveReviewConflictRequest,
+    ) -> Result<(), ApiError> {
+        self.review_conflict_service
+            .resolve_review_conflict(calling_principal, request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_review_conflicts,
+        services::{MockAccessControlService, MockReviewConflictService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn list_open_review_conflicts() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListOpenReviewConflictsRequest { proposal_id: None };
+        let response = ListOpenReviewConflictsResponse {
+            review_conflicts: map_review_conflicts(vec![(
+                fixtures::uuid(),
+                fixtures::review_conflict_open(),
+            )]),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewConflictService::new();
+        service_mock
+            .expect_list_open_review_conflicts()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewConflictController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .list_open_review_conflicts(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn resolve_review_conflict() {
+        let calling_principal = fixtures::principal_a();
+        let request = ResolveReviewConflictRequest {
+            id: fixtures::uuid().to_string(),
+            note: "Discussed with both reviewers".to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewConflictService::new();
+        service_mock
+            .expect_resolve_review_conflict()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = ReviewConflictController::new(access_control_service_mock, service_mock);
+
+        controller
+            .resolve_review_conflict(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn resolve_review_conflict_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ResolveReviewConflictRequest {
+            id: fixtures::uuid().to_string(),
+            note: "Discussed with both reviewers".to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockReviewConflictService::new();
+        service_mock.expect_resolve_review_conflict().never();
+
+        let controller = ReviewConflictController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .resolve_review_conflict(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
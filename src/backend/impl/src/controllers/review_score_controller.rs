@@ -0,0 +1,237 @@
+use backend_api::{
+    ApiError, ApiResult, ListReviewScoresRequest, ListReviewScoresResponse,
+    ScoreProposalReviewRequest, ScoreProposalReviewResponse,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, Permission, ProposalRepositoryImpl, ProposalReviewRepositoryImpl,
+        ReviewScoreRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, ReviewScoreService,
+        ReviewScoreServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn score_proposal_review(
+    request: ScoreProposalReviewRequest,
+) -> ApiResult<ScoreProposalReviewResponse> {
+    let calling_principal = caller();
+
+    ReviewScoreController::default()
+        .score_proposal_review(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_review_scores(request: ListReviewScoresRequest) -> ApiResult<ListReviewScoresResponse> {
+    let calling_principal = caller();
+
+    ReviewScoreController::default()
+        .list_review_scores(calling_principal, request)
+        .into()
+}
+
+struct ReviewScoreController<A: AccessControlService, R: ReviewScoreService> {
+    access_control_service: A,
+    review_score_service: R,
+}
+
+impl Default
+    for ReviewScoreController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ReviewScoreServiceImpl<
+            ReviewScoreRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ReviewScoreServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, R: ReviewScoreService> ReviewScoreController<A, R> {
+    fn new(access_control_service: A, review_score_service: R) -> Self {
+        Self {
+            access_control_service,
+            review_score_service,
+        }
+    }
+
+    #[require_permission(Permission::ModerateReviews)]
+    fn score_proposal_review(
+        &self,
+        calling_principal: Principal,
+        request: ScoreProposalReviewRequest,
+    ) -> Result<ScoreProposalReviewResponse, ApiError> {
+        self.review_score_service
+            .score_proposal_review(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ModerateReviews)]
+    fn list_review_scores(
+        &self,
+        calling_principal: Principal,
+        request: ListReviewScoresRequest,
+    ) -> Result<ListReviewScoresResponse, ApiError> {
+        self.review_score_service.list_review_scores(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_review_score,
+        services::{MockAccessControlService, MockReviewScoreService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn score_proposal_review_request() -> ScoreProposalReviewRequest {
+        ScoreProposalReviewRequest {
+            proposal_review_id: fixtures::proposal_review_id().to_string(),
+            depth: 4,
+            accuracy: 5,
+            timeliness: 3,
+            comment: "Thorough review".to_string(),
+        }
+    }
+
+    #[rstest]
+    fn score_proposal_review() {
+        let calling_principal = fixtures::principal_a();
+        let request = score_proposal_review_request();
+        let response = map_review_score(fixtures::proposal_review_id(), fixtures::review_score());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewScoreService::new();
+        service_mock
+            .expect_score_proposal_review()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewScoreController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .score_proposal_review(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn score_proposal_review_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockReviewScoreService::new();
+        service_mock.expect_score_proposal_review().never();
+
+        let controller = ReviewScoreController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .score_proposal_review(calling_principal, score_proposal_review_request())
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn list_review_scores() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListReviewScoresRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let response = ListReviewScoresResponse {
+            review_scores: vec![map_review_score(
+                fixtures::proposal_review_id(),
+                fixtures::review_score(),
+            )],
+            reputation: None,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewScoreService::new();
+        service_mock
+            .expect_list_review_scores()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewScoreController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .list_review_scores(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn list_review_scores_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListReviewScoresRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockReviewScoreService::new();
+        service_mock.expect_list_review_scores().never();
+
+        let controller = ReviewScoreController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .list_review_scores(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
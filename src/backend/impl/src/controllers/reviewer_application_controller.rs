@@ -0,0 +1,328 @@
+use backend_api::{
+    ApiError, ApiResult, ApproveReviewerApplicationRequest, ListReviewerApplicationsRequest,
+    ListReviewerApplicationsResponse, RejectReviewerApplicationRequest,
+    SubmitReviewerApplicationRequest, SubmitReviewerApplicationResponse,
+};
+use backend_macros::{log_errors, require_authenticated, require_permission};
+use candid::{Encode, Principal};
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, Permission,
+        ReviewerApplicationRepositoryImpl, RoleRepositoryImpl, SensitiveAction,
+        UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl, ReviewerApplicationService, ReviewerApplicationServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn submit_reviewer_application(
+    request: SubmitReviewerApplicationRequest,
+) -> ApiResult<SubmitReviewerApplicationResponse> {
+    let calling_principal = caller();
+
+    ReviewerApplicationController::default()
+        .submit_reviewer_application(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_reviewer_applications(
+    request: ListReviewerApplicationsRequest,
+) -> ApiResult<ListReviewerApplicationsResponse> {
+    let calling_principal = caller();
+
+    ReviewerApplicationController::default()
+        .list_reviewer_applications(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn approve_reviewer_application(request: ApproveReviewerApplicationRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewerApplicationController::default()
+        .approve_reviewer_application(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn reject_reviewer_application(request: RejectReviewerApplicationRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewerApplicationController::default()
+        .reject_reviewer_application(calling_principal, request)
+        .into()
+}
+
+struct ReviewerApplicationController<
+    A: AccessControlService,
+    P: ApprovalService,
+    R: ReviewerApplicationService,
+> {
+    access_control_service: A,
+    approval_service: P,
+    reviewer_application_service: R,
+}
+
+impl Default
+    for ReviewerApplicationController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        ReviewerApplicationServiceImpl<
+            ReviewerApplicationRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+            ReviewerApplicationServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: ApprovalService, R: ReviewerApplicationService>
+    ReviewerApplicationController<A, P, R>
+{
+    fn new(
+        access_control_service: A,
+        approval_service: P,
+        reviewer_application_service: R,
+    ) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+            reviewer_application_service,
+        }
+    }
+
+    #[require_authenticated]
+    fn submit_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: SubmitReviewerApplicationRequest,
+    ) -> Result<SubmitReviewerApplicationResponse, ApiError> {
+        self.reviewer_application_service
+            .submit_reviewer_application(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn list_reviewer_applications(
+        &self,
+        calling_principal: Principal,
+        request: ListReviewerApplicationsRequest,
+    ) -> Result<ListReviewerApplicationsResponse, ApiError> {
+        self.reviewer_application_service
+            .list_reviewer_applications(request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn approve_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: ApproveReviewerApplicationRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::ApproveReviewerApplication,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.reviewer_application_service
+            .approve_reviewer_application(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn reject_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: RejectReviewerApplicationRequest,
+    ) -> Result<(), ApiError> {
+        self.reviewer_application_service
+            .reject_reviewer_application(calling_principal, request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_reviewer_application,
+        services::{MockAccessControlService, MockApprovalService, MockReviewerApplicationService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn submit_reviewer_application() {
+        let calling_principal = fixtures::principal_a();
+        let request = SubmitReviewerApplicationRequest {
+            bio: "Active in the ICP community and on the developer forum.".to_string(),
+            neuron_id: fixtures::neuron_id(),
+            wallet_address: fixtures::wallet_address().to_string(),
+            social_links: vec![],
+        };
+        let response =
+            map_reviewer_application(fixtures::uuid(), fixtures::reviewer_application_pending());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewerApplicationService::new();
+        service_mock
+            .expect_submit_reviewer_application()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewerApplicationController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .submit_reviewer_application(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn approve_reviewer_application() {
+        let calling_principal = fixtures::principal_a();
+        let request = ApproveReviewerApplicationRequest {
+            id: fixtures::uuid().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewerApplicationService::new();
+        service_mock
+            .expect_approve_reviewer_application()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::ApproveReviewerApplication),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let controller = ReviewerApplicationController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        controller
+            .approve_reviewer_application(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn approve_reviewer_application_not_approved() {
+        let calling_principal = fixtures::principal_a();
+        let request = ApproveReviewerApplicationRequest {
+            id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::conflict(&format!(
+            "Action approve_reviewer_application requires 2 approvals, created approval request with id {}",
+            fixtures::uuid_c()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockReviewerApplicationService::new();
+        service_mock.expect_approve_reviewer_application().never();
+
+        let controller = ReviewerApplicationController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .approve_reviewer_application(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn reject_reviewer_application_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = RejectReviewerApplicationRequest {
+            id: fixtures::uuid().to_string(),
+            reason: "The neuron has no voting history yet.".to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockReviewerApplicationService::new();
+        service_mock.expect_reject_reviewer_application().never();
+
+        let controller = ReviewerApplicationController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .reject_reviewer_application(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
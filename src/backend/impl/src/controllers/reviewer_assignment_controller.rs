@@ -0,0 +1,605 @@
+use backend_api::{
+    AcceptReviewerAssignmentRequest, ApiError, ApiResult, AssignReviewerRequest,
+    AssignReviewerResponse, DeclineReviewerAssignmentRequest, GetReviewerAssignmentConfigResponse,
+    ListMyReviewerAssignmentsRequest, ListMyReviewerAssignmentsResponse,
+    ListProposalReviewerAssignmentsRequest, ListProposalReviewerAssignmentsResponse,
+    ListUnfulfilledReviewerAssignmentsRequest, ListUnfulfilledReviewerAssignmentsResponse,
+    UpdateReviewerAssignmentConfigRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::{Encode, Principal};
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, LogRepositoryImpl, Permission,
+        ProposalRepositoryImpl, ProposalReviewRepositoryImpl, ReviewerAssignmentRepositoryImpl,
+        RoleRepositoryImpl, SensitiveAction, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl, LogService, LogServiceImpl, ReviewerAssignmentService,
+        ReviewerAssignmentServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn assign_reviewer(request: AssignReviewerRequest) -> ApiResult<AssignReviewerResponse> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .assign_reviewer(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn accept_reviewer_assignment(request: AcceptReviewerAssignmentRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .accept_reviewer_assignment(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn decline_reviewer_assignment(request: DeclineReviewerAssignmentRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .decline_reviewer_assignment(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_my_reviewer_assignments(
+    request: ListMyReviewerAssignmentsRequest,
+) -> ApiResult<ListMyReviewerAssignmentsResponse> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .list_my_reviewer_assignments(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_proposal_reviewer_assignments(
+    request: ListProposalReviewerAssignmentsRequest,
+) -> ApiResult<ListProposalReviewerAssignmentsResponse> {
+    ReviewerAssignmentController::default()
+        .list_proposal_reviewer_assignments(request)
+        .into()
+}
+
+#[query]
+fn list_unfulfilled_reviewer_assignments(
+    request: ListUnfulfilledReviewerAssignmentsRequest,
+) -> ApiResult<ListUnfulfilledReviewerAssignmentsResponse> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .list_unfulfilled_reviewer_assignments(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_reviewer_assignment_config() -> ApiResult<GetReviewerAssignmentConfigResponse> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .get_reviewer_assignment_config(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn update_reviewer_assignment_config(
+    request: UpdateReviewerAssignmentConfigRequest,
+) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    ReviewerAssignmentController::default()
+        .update_reviewer_assignment_config(calling_principal, request)
+        .into()
+}
+
+pub(super) struct ReviewerAssignmentController<
+    A: AccessControlService,
+    P: ApprovalService,
+    L: LogService,
+    R: ReviewerAssignmentService,
+> {
+    access_control_service: A,
+    approval_service: P,
+    log_service: L,
+    reviewer_assignment_service: R,
+}
+
+impl Default
+    for ReviewerAssignmentController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        LogServiceImpl<LogRepositoryImpl>,
+        ReviewerAssignmentServiceImpl<
+            ReviewerAssignmentRepositoryImpl,
+            UserProfileRepositoryImpl,
+            ProposalRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+            LogServiceImpl::default(),
+            ReviewerAssignmentServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: ApprovalService, L: LogService, R: ReviewerAssignmentService>
+    ReviewerAssignmentController<A, P, L, R>
+{
+    fn new(
+        access_control_service: A,
+        approval_service: P,
+        log_service: L,
+        reviewer_assignment_service: R,
+    ) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+            log_service,
+            reviewer_assignment_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageReviewerAssignments)]
+    fn assign_reviewer(
+        &self,
+        calling_principal: Principal,
+        request: AssignReviewerRequest,
+    ) -> Result<AssignReviewerResponse, ApiError> {
+        self.reviewer_assignment_service
+            .assign_reviewer(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn accept_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: AcceptReviewerAssignmentRequest,
+    ) -> Result<(), ApiError> {
+        self.reviewer_assignment_service
+            .accept_reviewer_assignment(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn decline_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: DeclineReviewerAssignmentRequest,
+    ) -> Result<(), ApiError> {
+        self.reviewer_assignment_service
+            .decline_reviewer_assignment(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ReviewProposals)]
+    fn list_my_reviewer_assignments(
+        &self,
+        calling_principal: Principal,
+        request: ListMyReviewerAssignmentsRequest,
+    ) -> Result<ListMyReviewerAssignmentsResponse, ApiError> {
+        self.reviewer_assignment_service
+            .list_my_reviewer_assignments(calling_principal, request)
+    }
+
+    fn list_proposal_reviewer_assignments(
+        &self,
+        request: ListProposalReviewerAssignmentsRequest,
+    ) -> Result<ListProposalReviewerAssignmentsResponse, ApiError> {
+        self.reviewer_assignment_service
+            .list_proposal_reviewer_assignments(request)
+    }
+
+    #[require_permission(Permission::ManageReviewerAssignments)]
+    fn list_unfulfilled_reviewer_assignments(
+        &self,
+        calling_principal: Principal,
+        request: ListUnfulfilledReviewerAssignmentsRequest,
+    ) -> Result<ListUnfulfilledReviewerAssignmentsResponse, ApiError> {
+        self.reviewer_assignment_service
+            .list_unfulfilled_reviewer_assignments(request)
+    }
+
+    #[require_permission(Permission::ManageReviewerAssignments)]
+    fn get_reviewer_assignment_config(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetReviewerAssignmentConfigResponse, ApiError> {
+        Ok(self
+            .reviewer_assignment_service
+            .get_reviewer_assignment_config())
+    }
+
+    #[require_permission(Permission::ManageReviewerAssignments)]
+    fn update_reviewer_assignment_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateReviewerAssignmentConfigRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::UpdateReviewerAssignmentConfig,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.reviewer_assignment_service
+            .update_reviewer_assignment_config(calling_principal, request)
+    }
+
+    pub fn assign_reviewers_job(&self) {
+        match self.reviewer_assignment_service.assign_reviewers() {
+            Ok(count) => {
+                let _ = self.log_service.log_info(
+                    format!("Successfully created {count} reviewer assignments"),
+                    Some("assign_reviewers".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error assigning reviewers ({})", e),
+                    Some("assign_reviewers".to_string()),
+                );
+            }
+        }
+    }
+
+    pub fn close_reviewer_assignments_job(&self) {
+        match self
+            .reviewer_assignment_service
+            .close_reviewer_assignments()
+        {
+            Ok(count) => {
+                let _ = self.log_service.log_info(
+                    format!("Successfully closed {count} reviewer assignments"),
+                    Some("close_reviewer_assignments".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error closing reviewer assignments ({})", e),
+                    Some("close_reviewer_assignments".to_string()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_reviewer_assignment,
+        services::{
+            MockAccessControlService, MockApprovalService, MockLogService,
+            MockReviewerAssignmentService,
+        },
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn assign_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let request = AssignReviewerRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            user_id: fixtures::user_id().to_string(),
+        };
+        let response =
+            map_reviewer_assignment(fixtures::uuid(), fixtures::reviewer_assignment_pending());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(Permission::ManageReviewerAssignments),
+            )
+            .return_const(Ok(()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_assign_reviewer()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .assign_reviewer(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn assign_reviewer_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = AssignReviewerRequest {
+            proposal_id: fixtures::proposal_id().to_string(),
+            user_id: fixtures::user_id().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(Permission::ManageReviewerAssignments),
+            )
+            .return_const(Err(error.clone()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock.expect_assign_reviewer().never();
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .assign_reviewer(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn accept_reviewer_assignment() {
+        let calling_principal = fixtures::principal_a();
+        let request = AcceptReviewerAssignmentRequest {
+            id: fixtures::uuid().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Ok(()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_accept_reviewer_assignment()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller
+            .accept_reviewer_assignment(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn decline_reviewer_assignment_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = DeclineReviewerAssignmentRequest {
+            id: fixtures::uuid().to_string(),
+            reason: None,
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be a reviewer to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ReviewProposals))
+            .return_const(Err(error.clone()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock.expect_decline_reviewer_assignment().never();
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .decline_reviewer_assignment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn list_unfulfilled_reviewer_assignments_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListUnfulfilledReviewerAssignmentsRequest { user_id: None };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(Permission::ManageReviewerAssignments),
+            )
+            .return_const(Err(error.clone()));
+
+        let log_service_mock = MockLogService::new();
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_list_unfulfilled_reviewer_assignments()
+            .never();
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .list_unfulfilled_reviewer_assignments(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn update_reviewer_assignment_config() {
+        let calling_principal = fixtures::principal_a();
+        let request = UpdateReviewerAssignmentConfigRequest {
+            policy: Some(backend_api::ReviewerAssignmentPolicy::RoundRobin),
+            reviewers_per_proposal: None,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(Permission::ManageReviewerAssignments),
+            )
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::UpdateReviewerAssignmentConfig),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_update_reviewer_assignment_config()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            MockLogService::new(),
+            service_mock,
+        );
+
+        controller
+            .update_reviewer_assignment_config(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn assign_reviewers_job_success() {
+        let access_control_service_mock = MockAccessControlService::new();
+
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Successfully created 3 reviewer assignments".to_string()),
+                eq(Some("assign_reviewers".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_assign_reviewers()
+            .once()
+            .return_const(Ok(3));
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller.assign_reviewers_job();
+    }
+
+    #[rstest]
+    fn close_reviewer_assignments_job_failure() {
+        let access_control_service_mock = MockAccessControlService::new();
+
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_error()
+            .once()
+            .with(
+                eq("Error closing reviewer assignments (500: Failed to do something)".to_string()),
+                eq(Some("close_reviewer_assignments".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockReviewerAssignmentService::new();
+        service_mock
+            .expect_close_reviewer_assignments()
+            .once()
+            .return_const(Err(ApiError::internal("Failed to do something")));
+
+        let controller = ReviewerAssignmentController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            log_service_mock,
+            service_mock,
+        );
+
+        controller.close_reviewer_assignments_job();
+    }
+}
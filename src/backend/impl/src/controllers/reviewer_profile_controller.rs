@@ -0,0 +1,94 @@
+use backend_api::{ApiError, ApiResult, GetReviewerProfileRequest, GetReviewerProfileResponse};
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ProposalRepositoryImpl, ProposalReviewCommitRepositoryImpl, ProposalReviewRepositoryImpl,
+        ReviewScoreRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{ReviewerProfileService, ReviewerProfileServiceImpl},
+};
+
+#[query]
+fn get_reviewer_profile(
+    request: GetReviewerProfileRequest,
+) -> ApiResult<GetReviewerProfileResponse> {
+    ReviewerProfileController::default()
+        .get_reviewer_profile(request)
+        .into()
+}
+
+struct ReviewerProfileController<R: ReviewerProfileService> {
+    reviewer_profile_service: R,
+}
+
+impl Default
+    for ReviewerProfileController<
+        ReviewerProfileServiceImpl<
+            UserProfileRepositoryImpl,
+            ReviewScoreRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            ProposalRepositoryImpl,
+            ProposalReviewCommitRepositoryImpl,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(ReviewerProfileServiceImpl::default())
+    }
+}
+
+impl<R: ReviewerProfileService> ReviewerProfileController<R> {
+    fn new(reviewer_profile_service: R) -> Self {
+        Self {
+            reviewer_profile_service,
+        }
+    }
+
+    fn get_reviewer_profile(
+        &self,
+        request: GetReviewerProfileRequest,
+    ) -> Result<GetReviewerProfileResponse, ApiError> {
+        self.reviewer_profile_service.get_reviewer_profile(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, mappings::map_user_profile, services::MockReviewerProfileService};
+    use backend_api::ReviewerProfileStatistics;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn get_reviewer_profile() {
+        let request = GetReviewerProfileRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let response = GetReviewerProfileResponse {
+            profile: map_user_profile(fixtures::user_id(), fixtures::reviewer_user_profile(), None),
+            statistics: ReviewerProfileStatistics {
+                published_reviews_count: 0,
+                proposals_reviewed_by_topic: vec![],
+                reviewed_commits_count: 0,
+                build_reproduced_rate: None,
+                average_publication_delay_seconds: None,
+            },
+            recent_reviews: vec![],
+        };
+
+        let mut service_mock = MockReviewerProfileService::new();
+        service_mock
+            .expect_get_reviewer_profile()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = ReviewerProfileController::new(service_mock);
+
+        let result = controller.get_reviewer_profile(request).unwrap();
+
+        assert_eq!(result, response);
+    }
+}
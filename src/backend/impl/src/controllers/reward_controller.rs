@@ -0,0 +1,293 @@
+use backend_api::{
+    ApiError, ApiResult, CloseRewardPeriodRequest, CreateRewardPeriodRequest,
+    CreateRewardPeriodResponse, ExportRewardStatementsRequest, ExportRewardStatementsResponse,
+    ListRewardPeriodsResponse, UpdateRewardPeriodRequest,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        AuditLogRepositoryImpl, Permission, ProposalRepositoryImpl, ProposalReviewRepositoryImpl,
+        RewardRepositoryImpl, RoleRepositoryImpl, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogServiceImpl, RewardService,
+        RewardServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn create_reward_period(
+    request: CreateRewardPeriodRequest,
+) -> ApiResult<CreateRewardPeriodResponse> {
+    let calling_principal = caller();
+
+    RewardController::default()
+        .create_reward_period(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn update_reward_period(request: UpdateRewardPeriodRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    RewardController::default()
+        .update_reward_period(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn close_reward_period(request: CloseRewardPeriodRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    RewardController::default()
+        .close_reward_period(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn list_reward_periods() -> ApiResult<ListRewardPeriodsResponse> {
+    let calling_principal = caller();
+
+    RewardController::default()
+        .list_reward_periods(calling_principal)
+        .into()
+}
+
+#[query]
+fn export_reward_statements(
+    request: ExportRewardStatementsRequest,
+) -> ApiResult<ExportRewardStatementsResponse> {
+    let calling_principal = caller();
+
+    RewardController::default()
+        .export_reward_statements(calling_principal, request)
+        .into()
+}
+
+struct RewardController<A: AccessControlService, R: RewardService> {
+    access_control_service: A,
+    reward_service: R,
+}
+
+impl Default
+    for RewardController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        RewardServiceImpl<
+            RewardRepositoryImpl,
+            ProposalRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            RewardServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, R: RewardService> RewardController<A, R> {
+    fn new(access_control_service: A, reward_service: R) -> Self {
+        Self {
+            access_control_service,
+            reward_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn create_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CreateRewardPeriodRequest,
+    ) -> Result<CreateRewardPeriodResponse, ApiError> {
+        self.reward_service
+            .create_reward_period(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn update_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: UpdateRewardPeriodRequest,
+    ) -> Result<(), ApiError> {
+        self.reward_service
+            .update_reward_period(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn close_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CloseRewardPeriodRequest,
+    ) -> Result<(), ApiError> {
+        self.reward_service
+            .close_reward_period(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn list_reward_periods(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<ListRewardPeriodsResponse, ApiError> {
+        self.reward_service.list_reward_periods()
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn export_reward_statements(
+        &self,
+        calling_principal: Principal,
+        request: ExportRewardStatementsRequest,
+    ) -> Result<ExportRewardStatementsResponse, ApiError> {
+        self.reward_service.export_reward_statements(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::{map_export_reward_statements_response, map_reward_period},
+        services::{MockAccessControlService, MockRewardService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_reward_period() {
+        let calling_principal = fixtures::principal_a();
+        let request = CreateRewardPeriodRequest {
+            name: "Reward period 1".to_string(),
+            start_timestamp_ms: 0,
+            end_timestamp_ms: 1_000,
+            rules: fixtures::reward_rules().into(),
+        };
+        let response = map_reward_period(fixtures::uuid(), fixtures::reward_period_open());
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockRewardService::new();
+        service_mock
+            .expect_create_reward_period()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = RewardController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_reward_period(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn close_reward_period_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = CloseRewardPeriodRequest {
+            id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockRewardService::new();
+        service_mock.expect_close_reward_period().never();
+
+        let controller = RewardController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .close_reward_period(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn export_reward_statements() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExportRewardStatementsRequest {
+            reward_period_id: fixtures::uuid().to_string(),
+        };
+        let response = map_export_reward_statements_response(
+            fixtures::uuid(),
+            fixtures::reward_period_closed(),
+            vec![fixtures::reward_statement()],
+        );
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockRewardService::new();
+        service_mock
+            .expect_export_reward_statements()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = RewardController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .export_reward_statements(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn export_reward_statements_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExportRewardStatementsRequest {
+            reward_period_id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockRewardService::new();
+        service_mock.expect_export_reward_statements().never();
+
+        let controller = RewardController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .export_reward_statements(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
@@ -0,0 +1,244 @@
+use backend_api::{
+    ApiError, ApiResult, ExecuteRewardPayoutsRequest, ExecuteRewardPayoutsResponse,
+    ListRewardPayoutsRequest, ListRewardPayoutsResponse,
+};
+use backend_macros::{log_errors, require_permission};
+use candid::{Encode, Principal};
+use external_canisters::icrc1::Icrc1LedgerCanisterService;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, Permission, RewardRepositoryImpl,
+        RoleRepositoryImpl, SensitiveAction, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl, RewardPayoutService, RewardPayoutServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+async fn execute_reward_payouts(
+    request: ExecuteRewardPayoutsRequest,
+) -> ApiResult<ExecuteRewardPayoutsResponse> {
+    let calling_principal = caller();
+
+    RewardPayoutController::default()
+        .execute_reward_payouts(calling_principal, request)
+        .await
+        .into()
+}
+
+#[query]
+fn list_reward_payouts(request: ListRewardPayoutsRequest) -> ApiResult<ListRewardPayoutsResponse> {
+    let calling_principal = caller();
+
+    RewardPayoutController::default()
+        .list_reward_payouts(calling_principal, request)
+        .into()
+}
+
+struct RewardPayoutController<A: AccessControlService, P: ApprovalService, R: RewardPayoutService> {
+    access_control_service: A,
+    approval_service: P,
+    reward_payout_service: R,
+}
+
+impl Default
+    for RewardPayoutController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        RewardPayoutServiceImpl<
+            RewardRepositoryImpl,
+            Icrc1LedgerCanisterService,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+            RewardPayoutServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: ApprovalService, R: RewardPayoutService>
+    RewardPayoutController<A, P, R>
+{
+    fn new(access_control_service: A, approval_service: P, reward_payout_service: R) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+            reward_payout_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    async fn execute_reward_payouts(
+        &self,
+        calling_principal: Principal,
+        request: ExecuteRewardPayoutsRequest,
+    ) -> Result<ExecuteRewardPayoutsResponse, ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::ExecuteRewardPayouts,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.reward_payout_service
+            .execute_reward_payouts(calling_principal, request)
+            .await
+    }
+
+    #[require_permission(Permission::ManageRewards)]
+    fn list_reward_payouts(
+        &self,
+        calling_principal: Principal,
+        request: ListRewardPayoutsRequest,
+    ) -> Result<ListRewardPayoutsResponse, ApiError> {
+        self.reward_payout_service.list_reward_payouts(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockApprovalService, MockRewardPayoutService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    async fn execute_reward_payouts() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExecuteRewardPayoutsRequest {
+            reward_period_id: fixtures::uuid().to_string(),
+        };
+        let response = ExecuteRewardPayoutsResponse {
+            payouts: vec![fixtures::reward_payout_completed().into()],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::ExecuteRewardPayouts),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockRewardPayoutService::new();
+        service_mock
+            .expect_execute_reward_payouts()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = RewardPayoutController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        let result = controller
+            .execute_reward_payouts(calling_principal, request)
+            .await
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = ExecuteRewardPayoutsRequest {
+            reward_period_id: fixtures::uuid().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must be an admin to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockRewardPayoutService::new();
+        service_mock.expect_execute_reward_payouts().never();
+
+        let controller = RewardPayoutController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .execute_reward_payouts(calling_principal, request)
+            .await
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn list_reward_payouts() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListRewardPayoutsRequest {
+            reward_period_id: fixtures::uuid().to_string(),
+        };
+        let response = ListRewardPayoutsResponse {
+            payouts: vec![
+                fixtures::reward_payout_pending().into(),
+                fixtures::reward_payout_completed().into(),
+            ],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRewards))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockRewardPayoutService::new();
+        service_mock
+            .expect_list_reward_payouts()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = RewardPayoutController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .list_reward_payouts(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+}
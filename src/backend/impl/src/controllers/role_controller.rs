@@ -0,0 +1,235 @@
+use backend_api::{
+    ApiError, ApiResult, GetMyPermissionsResponse, GetUserRolesRequest, GetUserRolesResponse,
+    SetUserRolesRequest,
+};
+use backend_macros::{log_errors, require_authenticated, require_permission};
+use candid::{Encode, Principal};
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, Permission, RoleRepositoryImpl,
+        SensitiveAction, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl, RoleService, RoleServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn set_user_roles(request: SetUserRolesRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    RoleController::default()
+        .set_user_roles(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_user_roles(request: GetUserRolesRequest) -> ApiResult<GetUserRolesResponse> {
+    let calling_principal = caller();
+
+    RoleController::default()
+        .get_user_roles(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_my_permissions() -> ApiResult<GetMyPermissionsResponse> {
+    let calling_principal = caller();
+
+    RoleController::default()
+        .get_my_permissions(calling_principal)
+        .into()
+}
+
+struct RoleController<A: AccessControlService, P: ApprovalService, R: RoleService> {
+    access_control_service: A,
+    approval_service: P,
+    role_service: R,
+}
+
+impl Default
+    for RoleController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        RoleServiceImpl<
+            RoleRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
+            RoleServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, P: ApprovalService, R: RoleService> RoleController<A, P, R> {
+    fn new(access_control_service: A, approval_service: P, role_service: R) -> Self {
+        Self {
+            access_control_service,
+            approval_service,
+            role_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn set_user_roles(
+        &self,
+        calling_principal: Principal,
+        request: SetUserRolesRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::SetUserRoles,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.role_service.set_user_roles(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageRoles)]
+    fn get_user_roles(
+        &self,
+        calling_principal: Principal,
+        request: GetUserRolesRequest,
+    ) -> Result<GetUserRolesResponse, ApiError> {
+        self.role_service.get_user_roles(request)
+    }
+
+    #[require_authenticated]
+    fn get_my_permissions(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPermissionsResponse, ApiError> {
+        self.role_service.get_my_permissions(calling_principal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockApprovalService, MockRoleService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn set_user_roles() {
+        let calling_principal = fixtures::principal_a();
+        let request = SetUserRolesRequest {
+            user_id: fixtures::uuid_b().to_string(),
+            roles: vec![backend_api::Role::Moderator],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::SetUserRoles),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockRoleService::new();
+        service_mock
+            .expect_set_user_roles()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = RoleController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        controller
+            .set_user_roles(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn set_user_roles_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = SetUserRolesRequest {
+            user_id: fixtures::uuid_b().to_string(),
+            roles: vec![backend_api::Role::Moderator],
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the manage_roles permission to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageRoles))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockRoleService::new();
+        service_mock.expect_set_user_roles().never();
+
+        let controller = RoleController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .set_user_roles(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn get_my_permissions_anonymous_principal() {
+        let calling_principal = Principal::anonymous();
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Err(ApiError::unauthenticated()));
+
+        let mut service_mock = MockRoleService::new();
+        service_mock.expect_get_my_permissions().never();
+
+        let controller = RoleController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .get_my_permissions(calling_principal)
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::unauthenticated());
+    }
+}
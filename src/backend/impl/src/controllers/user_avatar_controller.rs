@@ -0,0 +1,181 @@
+use backend_api::{ApiError, ApiResult, CreateMyUserAvatarRequest, CreateMyUserAvatarResponse};
+use backend_macros::{log_errors, require_authenticated};
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        CertificationRepositoryImpl, ImageRepositoryImpl, RoleRepositoryImpl,
+        UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, UserAvatarService, UserAvatarServiceImpl,
+    },
+};
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn create_my_user_avatar(
+    request: CreateMyUserAvatarRequest,
+) -> ApiResult<CreateMyUserAvatarResponse> {
+    let calling_principal = caller();
+
+    UserAvatarController::default()
+        .create_my_user_avatar(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn delete_my_user_avatar() -> ApiResult<()> {
+    let calling_principal = caller();
+
+    UserAvatarController::default()
+        .delete_my_user_avatar(calling_principal)
+        .into()
+}
+
+struct UserAvatarController<A: AccessControlService, U: UserAvatarService> {
+    access_control_service: A,
+    user_avatar_service: U,
+}
+
+impl Default
+    for UserAvatarController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        UserAvatarServiceImpl<
+            UserProfileRepositoryImpl,
+            ImageRepositoryImpl,
+            CertificationRepositoryImpl,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            UserAvatarServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, U: UserAvatarService> UserAvatarController<A, U> {
+    fn new(access_control_service: A, user_avatar_service: U) -> Self {
+        Self {
+            access_control_service,
+            user_avatar_service,
+        }
+    }
+
+    #[require_authenticated]
+    fn create_my_user_avatar(
+        &self,
+        calling_principal: Principal,
+        request: CreateMyUserAvatarRequest,
+    ) -> Result<CreateMyUserAvatarResponse, ApiError> {
+        self.user_avatar_service
+            .create_my_user_avatar(calling_principal, request)
+    }
+
+    #[require_authenticated]
+    fn delete_my_user_avatar(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        self.user_avatar_service
+            .delete_my_user_avatar(calling_principal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockUserAvatarService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_my_user_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let image = fixtures::image_without_subpath();
+        let request = CreateMyUserAvatarRequest {
+            content_type: image.content_type,
+            content_bytes: image.content_bytes,
+        };
+        let response = CreateMyUserAvatarResponse {
+            path: format!("/images/avatars/{}", fixtures::uuid_a()),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserAvatarService::new();
+        service_mock
+            .expect_create_my_user_avatar()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = UserAvatarController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn create_my_user_avatar_anonymous_principal() {
+        let calling_principal = Principal::anonymous();
+        let request = CreateMyUserAvatarRequest {
+            content_type: "image/png".to_string(),
+            content_bytes: vec![],
+        };
+        let error = ApiError::unauthenticated();
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockUserAvatarService::new();
+        service_mock.expect_create_my_user_avatar().never();
+
+        let controller = UserAvatarController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn delete_my_user_avatar() {
+        let calling_principal = fixtures::principal_a();
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_principal_not_anonymous()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserAvatarService::new();
+        service_mock
+            .expect_delete_my_user_avatar()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let controller = UserAvatarController::new(access_control_service_mock, service_mock);
+
+        controller.delete_my_user_avatar(calling_principal).unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+use backend_api::{
+    ApiError, ApiResult, GetPrincipalsByUserIdRequest, GetPrincipalsByUserIdResponse,
+    GetUserIdByPrincipalRequest, GetUserIdByPrincipalResponse, GetUsernameReportResponse,
+    ListUsersRequest, ListUsersResponse,
+};
+use backend_macros::require_permission;
+use candid::Principal;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{Permission, RoleRepositoryImpl, UserProfileRepositoryImpl},
+    services::{
+        AccessControlService, AccessControlServiceImpl, UserDirectoryService,
+        UserDirectoryServiceImpl,
+    },
+};
+
+#[query]
+fn list_users(request: ListUsersRequest) -> ApiResult<ListUsersResponse> {
+    let calling_principal = caller();
+
+    UserDirectoryController::default()
+        .list_users(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_user_id_by_principal(
+    request: GetUserIdByPrincipalRequest,
+) -> ApiResult<GetUserIdByPrincipalResponse> {
+    let calling_principal = caller();
+
+    UserDirectoryController::default()
+        .get_user_id_by_principal(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_principals_by_user_id(
+    request: GetPrincipalsByUserIdRequest,
+) -> ApiResult<GetPrincipalsByUserIdResponse> {
+    let calling_principal = caller();
+
+    UserDirectoryController::default()
+        .get_principals_by_user_id(calling_principal, request)
+        .into()
+}
+
+#[query]
+fn get_username_report() -> ApiResult<GetUsernameReportResponse> {
+    let calling_principal = caller();
+
+    UserDirectoryController::default()
+        .get_username_report(calling_principal)
+        .into()
+}
+
+struct UserDirectoryController<A: AccessControlService, U: UserDirectoryService> {
+    access_control_service: A,
+    user_directory_service: U,
+}
+
+impl Default
+    for UserDirectoryController<
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        UserDirectoryServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AccessControlServiceImpl::default(),
+            UserDirectoryServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: AccessControlService, U: UserDirectoryService> UserDirectoryController<A, U> {
+    fn new(access_control_service: A, user_directory_service: U) -> Self {
+        Self {
+            access_control_service,
+            user_directory_service,
+        }
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn list_users(
+        &self,
+        calling_principal: Principal,
+        request: ListUsersRequest,
+    ) -> Result<ListUsersResponse, ApiError> {
+        self.user_directory_service.list_users(request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn get_user_id_by_principal(
+        &self,
+        calling_principal: Principal,
+        request: GetUserIdByPrincipalRequest,
+    ) -> Result<GetUserIdByPrincipalResponse, ApiError> {
+        self.user_directory_service
+            .get_user_id_by_principal(request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn get_principals_by_user_id(
+        &self,
+        calling_principal: Principal,
+        request: GetPrincipalsByUserIdRequest,
+    ) -> Result<GetPrincipalsByUserIdResponse, ApiError> {
+        self.user_directory_service
+            .get_principals_by_user_id(request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn get_username_report(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetUsernameReportResponse, ApiError> {
+        self.user_directory_service.get_username_report()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        services::{MockAccessControlService, MockUserDirectoryService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn list_users() {
+        let calling_principal = fixtures::principal_a();
+        let request = ListUsersRequest {
+            config_type: None,
+            role: None,
+            username: Some("alpine".to_string()),
+            principal: None,
+            offset: None,
+            limit: None,
+        };
+        let response = ListUsersResponse {
+            users: vec![],
+            total: 0,
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserDirectoryService::new();
+        service_mock
+            .expect_list_users()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = UserDirectoryController::new(access_control_service_mock, service_mock);
+
+        let result = controller.list_users(calling_principal, request).unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn get_user_id_by_principal_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = GetUserIdByPrincipalRequest {
+            principal: fixtures::principal_b(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the manage_users permission to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockUserDirectoryService::new();
+        service_mock.expect_get_user_id_by_principal().never();
+
+        let controller = UserDirectoryController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .get_user_id_by_principal(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn get_principals_by_user_id() {
+        let calling_principal = fixtures::principal_a();
+        let request = GetPrincipalsByUserIdRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let response = GetPrincipalsByUserIdResponse {
+            principals: vec![fixtures::principal_b()],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserDirectoryService::new();
+        service_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = UserDirectoryController::new(access_control_service_mock, service_mock);
+
+        let result = controller
+            .get_principals_by_user_id(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    fn get_username_report() {
+        let calling_principal = fixtures::principal_a();
+        let response = GetUsernameReportResponse {
+            collisions: vec![],
+            invalid_usernames: vec![],
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserDirectoryService::new();
+        service_mock
+            .expect_get_username_report()
+            .once()
+            .return_const(Ok(response.clone()));
+
+        let controller = UserDirectoryController::new(access_control_service_mock, service_mock);
+
+        let result = controller.get_username_report(calling_principal).unwrap();
+
+        assert_eq!(result, response);
+    }
+}
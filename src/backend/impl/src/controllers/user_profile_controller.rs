@@ -1,16 +1,21 @@
 use crate::{
-    repositories::UserProfileRepositoryImpl,
+    repositories::{
+        ApprovalRepositoryImpl, AuditLogRepositoryImpl, CertificationRepositoryImpl,
+        ImageRepositoryImpl, Permission, ReviewScoreRepositoryImpl, RoleRepositoryImpl,
+        SensitiveAction, UserProfileRepositoryImpl,
+    },
     services::{
-        AccessControlService, AccessControlServiceImpl, UserProfileService, UserProfileServiceImpl,
+        AccessControlService, AccessControlServiceImpl, ApprovalService, ApprovalServiceImpl,
+        AuditLogServiceImpl, UserAvatarServiceImpl, UserProfileService, UserProfileServiceImpl,
     },
 };
 use backend_api::{
     ApiError, ApiResult, CreateMyUserProfileResponse, GetMyUserProfileHistoryResponse,
-    GetMyUserProfileResponse, ListReviewerProfilesResponse, UpdateMyUserProfileRequest,
-    UpdateUserProfileRequest,
+    GetMyUserProfileResponse, ListReviewerProfilesResponse, RestoreUserRequest, SuspendUserRequest,
+    UpdateMyUserProfileRequest, UpdateUserProfileRequest,
 };
-use backend_macros::log_errors;
-use candid::Principal;
+use backend_macros::{log_errors, require_authenticated, require_permission};
+use candid::{Encode, Principal};
 use ic_cdk::*;
 
 #[query]
@@ -68,29 +73,78 @@ fn update_user_profile(request: UpdateUserProfileRequest) -> ApiResult<()> {
         .into()
 }
 
-struct UserProfileController<A: AccessControlService, U: UserProfileService> {
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn delete_my_user_profile() -> ApiResult<()> {
+    let calling_principal = caller();
+
+    UserProfileController::default()
+        .delete_my_user_profile(calling_principal)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn suspend_user(request: SuspendUserRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    UserProfileController::default()
+        .suspend_user(calling_principal, request)
+        .into()
+}
+
+#[update]
+#[log_errors(crate::services::log_update_call_error)]
+fn restore_user(request: RestoreUserRequest) -> ApiResult<()> {
+    let calling_principal = caller();
+
+    UserProfileController::default()
+        .restore_user(calling_principal, request)
+        .into()
+}
+
+struct UserProfileController<A: AccessControlService, P: ApprovalService, U: UserProfileService> {
     access_control_service: A,
+    approval_service: P,
     user_profile_service: U,
 }
 
 impl Default
     for UserProfileController<
-        AccessControlServiceImpl<UserProfileRepositoryImpl>,
-        UserProfileServiceImpl<UserProfileRepositoryImpl>,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        ApprovalServiceImpl<
+            ApprovalRepositoryImpl,
+            UserProfileRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        >,
+        UserProfileServiceImpl<
+            UserProfileRepositoryImpl,
+            ReviewScoreRepositoryImpl,
+            AuditLogServiceImpl<AuditLogRepositoryImpl>,
+            UserAvatarServiceImpl<
+                UserProfileRepositoryImpl,
+                ImageRepositoryImpl,
+                CertificationRepositoryImpl,
+            >,
+        >,
     >
 {
     fn default() -> Self {
         Self::new(
             AccessControlServiceImpl::default(),
+            ApprovalServiceImpl::default(),
             UserProfileServiceImpl::default(),
         )
     }
 }
 
-impl<A: AccessControlService, U: UserProfileService> UserProfileController<A, U> {
-    fn new(access_control_service: A, user_profile_service: U) -> Self {
+impl<A: AccessControlService, P: ApprovalService, U: UserProfileService>
+    UserProfileController<A, P, U>
+{
+    fn new(access_control_service: A, approval_service: P, user_profile_service: U) -> Self {
         Self {
             access_control_service,
+            approval_service,
             user_profile_service,
         }
     }
@@ -101,13 +155,11 @@ impl<A: AccessControlService, U: UserProfileService> UserProfileController<A, U>
         Ok(profiles)
     }
 
+    #[require_authenticated]
     fn get_my_user_profile(
         &self,
         calling_principal: Principal,
     ) -> Result<GetMyUserProfileResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-
         let profile = self
             .user_profile_service
             .get_my_user_profile(calling_principal)?;
@@ -115,13 +167,11 @@ impl<A: AccessControlService, U: UserProfileService> UserProfileController<A, U>
         Ok(profile)
     }
 
+    #[require_authenticated]
     fn get_my_user_profile_history(
         &self,
         calling_principal: Principal,
     ) -> Result<GetMyUserProfileHistoryResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-
         let profile_history = self
             .user_profile_service
             .get_my_user_profile_history(calling_principal)?;
@@ -129,13 +179,11 @@ impl<A: AccessControlService, U: UserProfileService> UserProfileController<A, U>
         Ok(profile_history)
     }
 
+    #[require_authenticated]
     fn create_my_user_profile(
         &self,
         calling_principal: Principal,
     ) -> Result<CreateMyUserProfileResponse, ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-
         let profile = self
             .user_profile_service
             .create_my_user_profile(calling_principal)?;
@@ -143,35 +191,73 @@ impl<A: AccessControlService, U: UserProfileService> UserProfileController<A, U>
         Ok(profile)
     }
 
+    #[require_authenticated]
     fn update_my_user_profile(
         &self,
         calling_principal: Principal,
         request: UpdateMyUserProfileRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-
         self.user_profile_service
             .update_my_user_profile(calling_principal, request)?;
 
         Ok(())
     }
 
+    #[require_permission(Permission::ManageUsers)]
     fn update_user_profile(
         &self,
         calling_principal: Principal,
         request: UpdateUserProfileRequest,
     ) -> Result<(), ApiError> {
-        self.access_control_service
-            .assert_principal_not_anonymous(&calling_principal)?;
-        self.access_control_service
-            .assert_principal_is_admin(&calling_principal)?;
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::UpdateUserProfile,
+            Encode!(&request).unwrap(),
+        )?;
 
         self.user_profile_service
             .update_user_profile(calling_principal, request)?;
 
         Ok(())
     }
+
+    #[require_authenticated]
+    fn delete_my_user_profile(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        self.user_profile_service
+            .delete_my_user_profile(calling_principal)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn suspend_user(
+        &self,
+        calling_principal: Principal,
+        request: SuspendUserRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::SuspendUser,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.user_profile_service
+            .suspend_user(calling_principal, request)
+    }
+
+    #[require_permission(Permission::ManageUsers)]
+    fn restore_user(
+        &self,
+        calling_principal: Principal,
+        request: RestoreUserRequest,
+    ) -> Result<(), ApiError> {
+        self.approval_service.assert_approved(
+            calling_principal,
+            SensitiveAction::RestoreUser,
+            Encode!(&request).unwrap(),
+        )?;
+
+        self.user_profile_service
+            .restore_user(calling_principal, request)
+    }
 }
 
 #[cfg(test)]
@@ -183,7 +269,7 @@ mod tests {
             map_get_my_user_profile_history_response, map_get_my_user_profile_response,
             map_list_reviewer_profiles_response,
         },
-        services::{MockAccessControlService, MockUserProfileService},
+        services::{MockAccessControlService, MockApprovalService, MockUserProfileService},
     };
     use backend_api::UserConfig;
     use mockall::predicate::*;
@@ -194,6 +280,7 @@ mod tests {
         let profiles = map_list_reviewer_profiles_response(vec![(
             fixtures::user_id(),
             fixtures::reviewer_user_profile(),
+            None,
         )]);
 
         let mut service_mock = MockUserProfileService::new();
@@ -202,7 +289,11 @@ mod tests {
             .once()
             .return_const(Ok(profiles.clone()));
 
-        let controller = UserProfileController::new(MockAccessControlService::new(), service_mock);
+        let controller = UserProfileController::new(
+            MockAccessControlService::new(),
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller.list_reviewer_profiles().unwrap();
 
@@ -212,8 +303,11 @@ mod tests {
     #[rstest]
     fn get_my_user_profile() {
         let calling_principal = fixtures::principal_a();
-        let profile =
-            map_get_my_user_profile_response(fixtures::user_id(), fixtures::admin_user_profile());
+        let profile = map_get_my_user_profile_response(
+            fixtures::user_id(),
+            fixtures::admin_user_profile(),
+            None,
+        );
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
@@ -230,8 +324,11 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Ok(returned_profile));
 
-        let controller =
-            UserProfileController::new(access_control_service_mock, user_profile_service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            user_profile_service_mock,
+        );
 
         let result = controller.get_my_user_profile(calling_principal).unwrap();
 
@@ -252,7 +349,11 @@ mod tests {
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_get_my_user_profile().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .get_my_user_profile(calling_principal)
@@ -281,7 +382,11 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Err(returned_error));
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .get_my_user_profile(calling_principal)
@@ -311,7 +416,11 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Ok(returned_profile_history));
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .get_my_user_profile_history(calling_principal)
@@ -334,7 +443,11 @@ mod tests {
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_get_my_user_profile_history().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .get_my_user_profile_history(calling_principal)
@@ -357,7 +470,11 @@ mod tests {
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_get_my_user_profile_history().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .get_my_user_profile_history(calling_principal)
@@ -390,7 +507,11 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Ok(returned_profile));
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .create_my_user_profile(calling_principal)
@@ -413,7 +534,11 @@ mod tests {
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_create_my_user_profile().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .create_my_user_profile(calling_principal)
@@ -434,14 +559,9 @@ mod tests {
 
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_not_anonymous()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
-            .return_const(Ok(()));
-        access_control_service_mock
-            .expect_assert_principal_is_admin()
-            .once()
-            .with(eq(calling_principal))
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
             .return_const(Ok(()));
 
         let mut service_mock = MockUserProfileService::new();
@@ -451,7 +571,22 @@ mod tests {
             .with(eq(calling_principal), eq(request.clone()))
             .return_const(Ok(()));
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::UpdateUserProfile),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
 
         controller
             .update_user_profile(calling_principal, request)
@@ -480,7 +615,11 @@ mod tests {
             .with(eq(calling_principal), eq(request.clone()))
             .return_const(Ok(()));
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         controller
             .update_my_user_profile(calling_principal, request)
@@ -505,7 +644,11 @@ mod tests {
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_update_my_user_profile().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .update_my_user_profile(calling_principal, request)
@@ -524,26 +667,32 @@ mod tests {
             config: None,
         };
 
+        let error = ApiError::not_found(&format!(
+            "Principal {} must have a profile to call this endpoint",
+            &calling_principal.to_text()
+        ));
+
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
-            .expect_assert_principal_not_anonymous()
+            .expect_assert_permission()
             .once()
-            .with(eq(calling_principal))
-            .return_const(Err(ApiError::unauthenticated()));
-        access_control_service_mock
-            .expect_assert_principal_is_admin()
-            .never();
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
 
         let mut service_mock = MockUserProfileService::new();
         service_mock.expect_update_user_profile().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
 
         let result = controller
             .update_user_profile(calling_principal, request)
             .unwrap_err();
 
-        assert_eq!(result, ApiError::unauthenticated());
+        assert_eq!(result, error);
     }
 
     #[rstest]
@@ -561,25 +710,211 @@ mod tests {
             &calling_principal.to_text()
         ));
 
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockUserProfileService::new();
+        service_mock.expect_update_user_profile().never();
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .update_user_profile(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn delete_my_user_profile() {
+        let calling_principal = fixtures::principal_a();
+
         let mut access_control_service_mock = MockAccessControlService::new();
         access_control_service_mock
             .expect_assert_principal_not_anonymous()
             .once()
             .with(eq(calling_principal))
             .return_const(Ok(()));
-        access_control_service_mock
-            .expect_assert_principal_is_admin()
+
+        let mut service_mock = MockUserProfileService::new();
+        service_mock
+            .expect_delete_my_user_profile()
             .once()
             .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        controller
+            .delete_my_user_profile(calling_principal)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn suspend_user() {
+        let calling_principal = fixtures::principal_a();
+        let request = SuspendUserRequest {
+            user_id: fixtures::user_id().to_string(),
+            reason: "Posted spam in review comments".to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserProfileService::new();
+        service_mock
+            .expect_suspend_user()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::SuspendUser),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        controller.suspend_user(calling_principal, request).unwrap();
+    }
+
+    #[rstest]
+    fn suspend_user_not_approved() {
+        let calling_principal = fixtures::principal_a();
+        let request = SuspendUserRequest {
+            user_id: fixtures::user_id().to_string(),
+            reason: "Posted spam in review comments".to_string(),
+        };
+        let error = ApiError::conflict(&format!(
+            "Action suspend_user requires 2 approvals, created approval request with id {}",
+            fixtures::uuid_c()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
             .return_const(Err(error.clone()));
 
         let mut service_mock = MockUserProfileService::new();
-        service_mock.expect_update_user_profile().never();
+        service_mock.expect_suspend_user().never();
 
-        let controller = UserProfileController::new(access_control_service_mock, service_mock);
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
 
         let result = controller
-            .update_user_profile(calling_principal, request)
+            .suspend_user(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn restore_user() {
+        let calling_principal = fixtures::principal_a();
+        let request = RestoreUserRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Ok(()));
+
+        let mut approval_service_mock = MockApprovalService::new();
+        approval_service_mock
+            .expect_assert_approved()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(SensitiveAction::RestoreUser),
+                eq(Encode!(&request).unwrap()),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockUserProfileService::new();
+        service_mock
+            .expect_restore_user()
+            .once()
+            .with(eq(calling_principal), eq(request.clone()))
+            .return_const(Ok(()));
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            approval_service_mock,
+            service_mock,
+        );
+
+        controller.restore_user(calling_principal, request).unwrap();
+    }
+
+    #[rstest]
+    fn restore_user_unauthorized() {
+        let calling_principal = fixtures::principal_a();
+        let request = RestoreUserRequest {
+            user_id: fixtures::user_id().to_string(),
+        };
+        let error = ApiError::permission_denied(&format!(
+            "Principal {} must have the manage_users permission to call this endpoint",
+            calling_principal.to_text()
+        ));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ManageUsers))
+            .return_const(Err(error.clone()));
+
+        let mut service_mock = MockUserProfileService::new();
+        service_mock.expect_restore_user().never();
+
+        let controller = UserProfileController::new(
+            access_control_service_mock,
+            MockApprovalService::new(),
+            service_mock,
+        );
+
+        let result = controller
+            .restore_user(calling_principal, request)
             .unwrap_err();
 
         assert_eq!(result, error);
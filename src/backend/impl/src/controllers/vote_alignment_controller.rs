@@ -0,0 +1,156 @@
+use backend_api::{ApiError, ApiResult, ListVoteAlignmentsRequest, ListVoteAlignmentsResponse};
+use external_canisters::nns::GovernanceCanisterService;
+use ic_cdk::*;
+
+use crate::{
+    repositories::{
+        LogRepositoryImpl, ProposalRepositoryImpl, ProposalReviewRepositoryImpl,
+        UserProfileRepositoryImpl, VoteAlignmentRepositoryImpl,
+    },
+    services::{LogService, LogServiceImpl, VoteAlignmentService, VoteAlignmentServiceImpl},
+};
+
+#[query]
+fn list_vote_alignments(
+    request: ListVoteAlignmentsRequest,
+) -> ApiResult<ListVoteAlignmentsResponse> {
+    VoteAlignmentController::default()
+        .list_vote_alignments(request)
+        .into()
+}
+
+pub(super) struct VoteAlignmentController<L: LogService, V: VoteAlignmentService> {
+    log_service: L,
+    vote_alignment_service: V,
+}
+
+impl Default
+    for VoteAlignmentController<
+        LogServiceImpl<LogRepositoryImpl>,
+        VoteAlignmentServiceImpl<
+            VoteAlignmentRepositoryImpl,
+            ProposalRepositoryImpl,
+            ProposalReviewRepositoryImpl,
+            UserProfileRepositoryImpl,
+            GovernanceCanisterService,
+        >,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            LogServiceImpl::default(),
+            VoteAlignmentServiceImpl::default(),
+        )
+    }
+}
+
+impl<L: LogService, V: VoteAlignmentService> VoteAlignmentController<L, V> {
+    fn new(log_service: L, vote_alignment_service: V) -> Self {
+        Self {
+            log_service,
+            vote_alignment_service,
+        }
+    }
+
+    fn list_vote_alignments(
+        &self,
+        request: ListVoteAlignmentsRequest,
+    ) -> Result<ListVoteAlignmentsResponse, ApiError> {
+        self.vote_alignment_service.list_vote_alignments(request)
+    }
+
+    pub async fn check_vote_alignments_job(&self) {
+        let _ = self.log_service.log_info(
+            "Checking reviewer vote alignments".to_string(),
+            Some("check_vote_alignments".to_string()),
+        );
+
+        match self.vote_alignment_service.check_vote_alignments().await {
+            Ok(count) => {
+                let _ = self.log_service.log_info(
+                    format!("Successfully checked reviewer vote alignments of {count} proposals"),
+                    Some("check_vote_alignments".to_string()),
+                );
+            }
+            Err(e) => {
+                let _ = self.log_service.log_error(
+                    format!("Error checking reviewer vote alignments ({})", e),
+                    Some("check_vote_alignments".to_string()),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        mappings::map_vote_alignment,
+        services::{MockLogService, MockVoteAlignmentService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn list_vote_alignments() {
+        let request = ListVoteAlignmentsRequest {
+            proposal_id: Some(fixtures::proposal_id().to_string()),
+            user_id: None,
+            status: None,
+        };
+        let response = ListVoteAlignmentsResponse {
+            vote_alignments: vec![map_vote_alignment(fixtures::vote_alignment_matched())],
+        };
+
+        let mut service_mock = MockVoteAlignmentService::new();
+        service_mock
+            .expect_list_vote_alignments()
+            .once()
+            .with(eq(request.clone()))
+            .return_const(Ok(response.clone()));
+
+        let controller = VoteAlignmentController::new(MockLogService::new(), service_mock);
+
+        let result = controller.list_vote_alignments(request).unwrap();
+
+        assert_eq!(result, response);
+    }
+
+    #[rstest]
+    async fn check_vote_alignments_job_failure() {
+        let error = ApiError::internal("Failed to fetch ballots of proposal 127093");
+
+        let mut log_service_mock = MockLogService::new();
+        log_service_mock
+            .expect_log_info()
+            .once()
+            .with(
+                eq("Checking reviewer vote alignments".to_string()),
+                eq(Some("check_vote_alignments".to_string())),
+            )
+            .return_const(Ok(()));
+        log_service_mock
+            .expect_log_error()
+            .once()
+            .with(
+                eq(format!(
+                    "Error checking reviewer vote alignments ({})",
+                    error
+                )),
+                eq(Some("check_vote_alignments".to_string())),
+            )
+            .return_const(Ok(()));
+
+        let mut service_mock = MockVoteAlignmentService::new();
+        service_mock
+            .expect_check_vote_alignments()
+            .once()
+            .return_const(Err(error));
+
+        let controller = VoteAlignmentController::new(log_service_mock, service_mock);
+
+        controller.check_vote_alignments_job().await;
+    }
+}
@@ -0,0 +1,49 @@
+use candid::Encode;
+use rstest::*;
+
+use crate::repositories::{ApprovalRequest, ApprovalRequestStatus, SensitiveAction};
+
+use super::{date_time_a, uuid_a, uuid_b, uuid_c};
+
+#[fixture]
+pub fn approval_request_arguments() -> Vec<u8> {
+    Encode!(&backend_api::SetUserRolesRequest {
+        user_id: uuid_c().to_string(),
+        roles: vec![backend_api::Role::Moderator],
+    })
+    .unwrap()
+}
+
+#[fixture]
+pub fn approval_request_pending() -> ApprovalRequest {
+    ApprovalRequest {
+        action: SensitiveAction::SetUserRoles,
+        arguments: approval_request_arguments(),
+        requested_by: uuid_a(),
+        requested_at: date_time_a(),
+        expires_at: date_time_a().add(chrono::Duration::days(1)),
+        required_approvals: 2,
+        approved_by: vec![uuid_a()],
+        status: ApprovalRequestStatus::Pending,
+    }
+}
+
+#[fixture]
+pub fn approval_request_approved() -> ApprovalRequest {
+    ApprovalRequest {
+        approved_by: vec![uuid_a(), uuid_b()],
+        status: ApprovalRequestStatus::Approved,
+        ..approval_request_pending()
+    }
+}
+
+#[fixture]
+pub fn approval_request_executed() -> ApprovalRequest {
+    ApprovalRequest {
+        status: ApprovalRequestStatus::Executed {
+            executed_by: uuid_a(),
+            executed_at: date_time_a().add(chrono::Duration::hours(2)),
+        },
+        ..approval_request_approved()
+    }
+}
@@ -0,0 +1,39 @@
+use std::path::PathBuf;
+
+use rstest::*;
+
+use crate::repositories::{AttachmentEncoding, Image, ImageAttachment};
+
+use super::{date_time_a, uuid_a};
+
+pub const SAMPLE_BUILD_LOG: &str = "Building IC-OS...\nBuild reproduced successfully\n";
+
+#[fixture]
+pub fn attachment_with_subpath() -> Image {
+    Image {
+        created_at: date_time_a(),
+        user_id: uuid_a(),
+        content_type: "text/plain".to_string(),
+        sub_path: Some(PathBuf::from("reviews/attachments")),
+        content_bytes: AttachmentEncoding::Gzip.encode(SAMPLE_BUILD_LOG.as_bytes()),
+        attachment: Some(ImageAttachment {
+            file_name: "build.log".to_string(),
+            content_encoding: AttachmentEncoding::Gzip,
+        }),
+    }
+}
+
+#[fixture]
+pub fn attachment_without_subpath() -> Image {
+    Image {
+        created_at: date_time_a(),
+        user_id: uuid_a(),
+        content_type: "application/gzip".to_string(),
+        sub_path: None,
+        content_bytes: vec![0x1f, 0x8b, 0x08, 0x00],
+        attachment: Some(ImageAttachment {
+            file_name: "build-logs.tar.gz".to_string(),
+            content_encoding: AttachmentEncoding::Identity,
+        }),
+    }
+}
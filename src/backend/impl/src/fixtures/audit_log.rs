@@ -0,0 +1,32 @@
+use rstest::*;
+
+use crate::repositories::{audit_snapshot, AuditAction, AuditLogEntry};
+
+use super::{date_time_a, date_time_b, principal_a, principal_b, user_roles, uuid_b, uuid_c};
+
+#[fixture]
+pub fn audit_log_entry() -> AuditLogEntry {
+    AuditLogEntry {
+        actor: principal_a(),
+        action: AuditAction::SetUserRoles,
+        target_ids: vec![uuid_c().to_string()],
+        before: None,
+        after: Some(audit_snapshot(&user_roles())),
+        timestamp: date_time_a(),
+    }
+}
+
+#[fixture]
+pub fn audit_log_entries() -> Vec<AuditLogEntry> {
+    vec![
+        AuditLogEntry {
+            actor: principal_b(),
+            action: AuditAction::SuspendUser,
+            target_ids: vec![uuid_b().to_string()],
+            before: None,
+            after: Some("Suspended".to_string()),
+            timestamp: date_time_b(),
+        },
+        audit_log_entry(),
+    ]
+}
@@ -0,0 +1,40 @@
+use rstest::*;
+
+use crate::repositories::{Comment, CommentTarget};
+
+use super::{date_time_a, date_time_b, proposal_id, proposal_review_id, user_id, uuid_a};
+
+#[fixture]
+pub fn comment_proposal() -> Comment {
+    Comment {
+        target: CommentTarget::Proposal(proposal_id()),
+        parent_id: None,
+        user_id: user_id(),
+        content: "Has anyone managed to **reproduce** the build?".to_string(),
+        created_at: date_time_a(),
+        last_updated_at: None,
+        deleted_at: None,
+    }
+}
+
+#[fixture]
+pub fn comment_reply() -> Comment {
+    Comment {
+        target: CommentTarget::ProposalReview(proposal_review_id()),
+        parent_id: Some(uuid_a()),
+        user_id: user_id(),
+        content: "Yes, the hashes match.".to_string(),
+        created_at: date_time_a(),
+        last_updated_at: Some(date_time_b()),
+        deleted_at: None,
+    }
+}
+
+#[fixture]
+pub fn comment_deleted() -> Comment {
+    Comment {
+        content: "".to_string(),
+        deleted_at: Some(date_time_b()),
+        ..comment_proposal()
+    }
+}
@@ -0,0 +1,18 @@
+use rstest::*;
+
+use crate::repositories::CommitClaim;
+
+use super::{commit_sha_a, date_time_a, proposal_id, user_id};
+
+#[fixture]
+pub fn commit_claim() -> CommitClaim {
+    let claimed_at = date_time_a();
+
+    CommitClaim {
+        proposal_id: proposal_id(),
+        commit_sha: commit_sha_a(),
+        user_id: user_id(),
+        claimed_at,
+        expires_at: claimed_at.add(chrono::Duration::hours(8)),
+    }
+}
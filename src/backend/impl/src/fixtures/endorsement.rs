@@ -0,0 +1,27 @@
+use rstest::*;
+
+use crate::repositories::Endorsement;
+
+use super::{date_time_a, proposal_review_commit_id, proposal_review_id, uuid_b};
+
+#[fixture]
+pub fn endorsement_proposal_review() -> Endorsement {
+    Endorsement {
+        proposal_review_id: proposal_review_id(),
+        proposal_review_commit_id: None,
+        user_id: uuid_b(),
+        created_at: date_time_a(),
+        note: Some("I independently confirm this".to_string()),
+    }
+}
+
+#[fixture]
+pub fn endorsement_proposal_review_commit() -> Endorsement {
+    Endorsement {
+        proposal_review_id: proposal_review_id(),
+        proposal_review_commit_id: Some(proposal_review_commit_id()),
+        user_id: uuid_b(),
+        created_at: date_time_a(),
+        note: None,
+    }
+}
@@ -32,6 +32,11 @@ pub fn uuid_b() -> Uuid {
     Uuid::try_from("fd83c44f-0892-4d3c-a619-10674346d338").unwrap()
 }
 
+#[fixture]
+pub fn uuid_c() -> Uuid {
+    Uuid::try_from("6c2a9a0e-5b0c-4b7e-8d7a-3f4e2b1c9d05").unwrap()
+}
+
 #[fixture]
 pub fn user_id() -> UserId {
     uuid()
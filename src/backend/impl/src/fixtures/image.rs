@@ -16,6 +16,7 @@ pub fn image_with_subpath() -> Image {
         content_type: "image/png".to_string(),
         sub_path: Some(PathBuf::from("logos")),
         content_bytes: SAMPLE_IMAGE.to_vec(),
+        attachment: None,
     }
 }
 
@@ -27,5 +28,6 @@ pub fn image_without_subpath() -> Image {
         content_type: "image/png".to_string(),
         sub_path: None,
         content_bytes: SAMPLE_IMAGE.to_vec(),
+        attachment: None,
     }
 }
@@ -0,0 +1,27 @@
+use rstest::*;
+
+use crate::repositories::{NeuronVerification, NeuronVerificationStatus};
+
+use super::{date_time_a, date_time_b, neuron_id};
+
+#[fixture]
+pub fn neuron_verification_verified() -> NeuronVerification {
+    NeuronVerification {
+        neuron_id: neuron_id(),
+        status: NeuronVerificationStatus::Verified,
+        checked_at: date_time_a(),
+        verified_at: Some(date_time_a()),
+    }
+}
+
+#[fixture]
+pub fn neuron_verification_unverified() -> NeuronVerification {
+    NeuronVerification {
+        neuron_id: neuron_id(),
+        status: NeuronVerificationStatus::Unverified {
+            reason: format!("Neuron {} is not controlled by the reviewer", neuron_id()),
+        },
+        checked_at: date_time_a(),
+        verified_at: Some(date_time_b()),
+    }
+}
@@ -0,0 +1,15 @@
+use rstest::*;
+
+use crate::repositories::PrincipalLinkCode;
+
+use super::{date_time_a, principal_a, user_id};
+
+#[fixture]
+pub fn principal_link_code() -> PrincipalLinkCode {
+    PrincipalLinkCode {
+        user_id: user_id(),
+        created_by: principal_a(),
+        created_at: date_time_a(),
+        expires_at: date_time_a().add(chrono::Duration::minutes(10)),
+    }
+}
@@ -16,6 +16,8 @@ pub fn proposal_review_draft() -> ProposalReview {
         build_reproduced: Some(true),
         images_ids: vec![],
         vote: ProposalVote::Unspecified,
+        attachments_ids: None,
+        published_at: None,
     }
 }
 
@@ -31,6 +33,8 @@ pub fn proposal_review_published() -> ProposalReview {
         build_reproduced: Some(true),
         images_ids: vec![uuid()],
         vote: ProposalVote::Yes,
+        attachments_ids: Some(vec![]),
+        published_at: Some(date_time_a()),
     }
 }
 
@@ -0,0 +1,33 @@
+use rstest::*;
+
+use crate::repositories::{ReviewConflict, ReviewConflictKind, ReviewConflictStatus};
+
+use super::{date_time_a, date_time_b, proposal_id, uuid_a, uuid_b};
+
+#[fixture]
+pub fn review_conflict_open() -> ReviewConflict {
+    ReviewConflict {
+        proposal_id: proposal_id(),
+        kind: ReviewConflictKind::Vote,
+        proposal_review_ids: vec![uuid_a(), uuid_b()],
+        detected_at: date_time_a(),
+        last_updated_at: None,
+        status: ReviewConflictStatus::Open,
+    }
+}
+
+#[fixture]
+pub fn review_conflict_resolved() -> ReviewConflict {
+    ReviewConflict {
+        proposal_id: proposal_id(),
+        kind: ReviewConflictKind::Vote,
+        proposal_review_ids: vec![uuid_a(), uuid_b()],
+        detected_at: date_time_a(),
+        last_updated_at: Some(date_time_b()),
+        status: ReviewConflictStatus::Resolved {
+            resolved_by: uuid_a(),
+            resolved_at: date_time_b(),
+            note: "The release notes were ambiguous".to_string(),
+        },
+    }
+}
@@ -0,0 +1,20 @@
+use rstest::*;
+
+use crate::repositories::ReviewScore;
+
+use super::{date_time_a, proposal_id, user_id, uuid_b};
+
+#[fixture]
+pub fn review_score() -> ReviewScore {
+    ReviewScore {
+        proposal_id: proposal_id(),
+        reviewer_user_id: user_id(),
+        scored_by: uuid_b(),
+        depth: 4,
+        accuracy: 5,
+        timeliness: 3,
+        comment: "Thorough review, published close to the deadline".to_string(),
+        created_at: date_time_a(),
+        last_updated_at: None,
+    }
+}
@@ -0,0 +1,44 @@
+use rstest::*;
+
+use crate::repositories::{ReviewerApplication, ReviewerApplicationStatus};
+
+use super::{
+    date_time_a, date_time_b, dscvr_social_link, neuron_id, open_chat_social_link, user_id, uuid_a,
+    wallet_address,
+};
+
+#[fixture]
+pub fn reviewer_application_pending() -> ReviewerApplication {
+    ReviewerApplication {
+        user_id: user_id(),
+        bio: "Active in the ICP community and on the developer forum.".to_string(),
+        neuron_id: neuron_id(),
+        wallet_address: wallet_address(),
+        social_links: vec![dscvr_social_link(), open_chat_social_link()],
+        created_at: date_time_b(),
+        status: ReviewerApplicationStatus::Pending,
+    }
+}
+
+#[fixture]
+pub fn reviewer_application_approved() -> ReviewerApplication {
+    ReviewerApplication {
+        status: ReviewerApplicationStatus::Approved {
+            reviewed_by: uuid_a(),
+            reviewed_at: date_time_a(),
+        },
+        ..reviewer_application_pending()
+    }
+}
+
+#[fixture]
+pub fn reviewer_application_rejected() -> ReviewerApplication {
+    ReviewerApplication {
+        status: ReviewerApplicationStatus::Rejected {
+            reviewed_by: uuid_a(),
+            reviewed_at: date_time_a(),
+            reason: "The neuron has no voting history yet.".to_string(),
+        },
+        ..reviewer_application_pending()
+    }
+}
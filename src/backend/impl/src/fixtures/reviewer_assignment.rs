@@ -0,0 +1,43 @@
+use rstest::*;
+
+use crate::repositories::{ReviewerAssignment, ReviewerAssignmentStatus};
+
+use super::{date_time_a, date_time_b, proposal_id, user_id, uuid_b};
+
+#[fixture]
+pub fn reviewer_assignment_pending() -> ReviewerAssignment {
+    ReviewerAssignment {
+        proposal_id: proposal_id(),
+        user_id: user_id(),
+        assigned_by: Some(uuid_b()),
+        created_at: date_time_a(),
+        last_updated_at: None,
+        status: ReviewerAssignmentStatus::Pending,
+    }
+}
+
+#[fixture]
+pub fn reviewer_assignment_accepted() -> ReviewerAssignment {
+    ReviewerAssignment {
+        proposal_id: proposal_id(),
+        user_id: user_id(),
+        assigned_by: None,
+        created_at: date_time_a(),
+        last_updated_at: Some(date_time_b()),
+        status: ReviewerAssignmentStatus::Accepted,
+    }
+}
+
+#[fixture]
+pub fn reviewer_assignment_declined() -> ReviewerAssignment {
+    ReviewerAssignment {
+        proposal_id: proposal_id(),
+        user_id: user_id(),
+        assigned_by: None,
+        created_at: date_time_a(),
+        last_updated_at: Some(date_time_b()),
+        status: ReviewerAssignmentStatus::Declined {
+            reason: Some("Not familiar with this topic".to_string()),
+        },
+    }
+}
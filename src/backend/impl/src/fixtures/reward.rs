@@ -0,0 +1,87 @@
+use rstest::*;
+
+use crate::repositories::{
+    reward_payout_memo, RewardPayout, RewardPayoutStatus, RewardPeriod, RewardPeriodStatus,
+    RewardRules, RewardStatement, RewardStatementItem,
+};
+
+use super::{
+    date_time_a, date_time_b, proposal_id, proposal_review_id, user_id, uuid, uuid_b,
+    wallet_address,
+};
+
+#[fixture]
+pub fn reward_rules() -> RewardRules {
+    RewardRules {
+        reward_per_review_e8s: 100,
+        verified_build_bonus_e8s: 50,
+        late_review_penalty_e8s: 30,
+        late_review_after_hours: 48,
+    }
+}
+
+#[fixture]
+pub fn reward_period_open() -> RewardPeriod {
+    RewardPeriod {
+        name: "Reward period 1".to_string(),
+        starts_at: date_time_b(),
+        ends_at: date_time_a(),
+        rules: reward_rules(),
+        status: RewardPeriodStatus::Open,
+        created_by: uuid_b(),
+        created_at: date_time_b(),
+        last_updated_at: None,
+    }
+}
+
+#[fixture]
+pub fn reward_period_closed() -> RewardPeriod {
+    RewardPeriod {
+        status: RewardPeriodStatus::Closed {
+            closed_at: date_time_a(),
+        },
+        ..reward_period_open()
+    }
+}
+
+#[fixture]
+pub fn reward_statement() -> RewardStatement {
+    RewardStatement {
+        user_id: user_id(),
+        wallet_address: wallet_address().to_string(),
+        items: vec![RewardStatementItem {
+            proposal_id: proposal_id(),
+            proposal_review_id: proposal_review_id(),
+            verified_build: true,
+            late: false,
+            amount_e8s: 150,
+        }],
+        total_e8s: 150,
+    }
+}
+
+#[fixture]
+pub fn reward_payout_pending() -> RewardPayout {
+    RewardPayout {
+        user_id: user_id(),
+        wallet_address: wallet_address().to_string(),
+        amount_e8s: 150,
+        memo: reward_payout_memo(uuid(), user_id()),
+        created_at_time: date_time_a().timestamp_micros() * 1_000,
+        status: RewardPayoutStatus::Pending {
+            attempts: 0,
+            last_error: None,
+        },
+    }
+}
+
+#[fixture]
+pub fn reward_payout_completed() -> RewardPayout {
+    RewardPayout {
+        status: RewardPayoutStatus::Completed {
+            block_index: 42,
+            completed_at: date_time_a(),
+        },
+        ..reward_payout_pending()
+    }
+}
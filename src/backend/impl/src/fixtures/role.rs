@@ -0,0 +1,14 @@
+use rstest::*;
+
+use crate::repositories::{Role, UserRoles};
+
+use super::{date_time_a, uuid_a};
+
+#[fixture]
+pub fn user_roles() -> UserRoles {
+    UserRoles {
+        roles: vec![Role::RewardsManager, Role::SyncOperator],
+        updated_by: uuid_a(),
+        updated_at: date_time_a(),
+    }
+}
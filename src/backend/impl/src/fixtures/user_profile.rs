@@ -2,7 +2,7 @@ use crate::{
     fixtures::{date_time_a, principal_a},
     repositories::{
         HistoryAction, NeuronId, SocialLink, SocialLinkPlatform, UserConfig, UserProfile,
-        UserProfileHistoryEntry,
+        UserProfileHistoryEntry, WalletAddress,
     },
 };
 use rstest::*;
@@ -13,8 +13,13 @@ pub fn neuron_id() -> NeuronId {
 }
 
 #[fixture]
-pub fn wallet_address() -> String {
-    "da01eead5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb".to_string()
+pub fn wallet_address() -> WalletAddress {
+    WalletAddress::AccountIdentifier {
+        hash: hex::decode("5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb")
+            .unwrap()
+            .try_into()
+            .unwrap(),
+    }
 }
 
 #[fixture]
@@ -22,6 +27,8 @@ pub fn anonymous_user_profile() -> UserProfile {
     UserProfile {
         username: "AlpineAdventurer2023".to_string(),
         config: UserConfig::Anonymous,
+        avatar_id: None,
+        legacy_wallet_address: None,
     }
 }
 
@@ -37,7 +44,7 @@ pub fn dscvr_social_link() -> SocialLink {
 pub fn open_chat_social_link() -> SocialLink {
     SocialLink {
         platform: SocialLinkPlatform::OpenChat,
-        username: "k3b7z-9jklm-abcde-fghij-yz12".to_string(),
+        username: "rrkah-fqaaa-aaaaa-aaaaq-cai".to_string(),
     }
 }
 
@@ -56,9 +63,11 @@ pub fn reviewer_user_profile() -> UserProfile {
         config: UserConfig::Reviewer {
             bio: "Active in the ICP community and on the developer forum.".to_string(),
             neuron_id: neuron_id(),
-            wallet_address: wallet_address(),
+            wallet_address: Some(wallet_address()),
             social_links: vec![dscvr_social_link(), open_chat_social_link()],
         },
+        avatar_id: None,
+        legacy_wallet_address: None,
     }
 }
 
@@ -69,6 +78,8 @@ pub fn admin_user_profile() -> UserProfile {
         config: UserConfig::Admin {
             bio: "Active in the ICP community and on the developer forum.".to_string(),
         },
+        avatar_id: None,
+        legacy_wallet_address: None,
     }
 }
 
@@ -0,0 +1,21 @@
+use rstest::*;
+
+use crate::repositories::UserStatus;
+
+use super::{date_time_a, uuid_a};
+
+#[fixture]
+pub fn suspended_user_status() -> UserStatus {
+    UserStatus::Suspended {
+        reason: "Posted spam in review comments".to_string(),
+        suspended_by: uuid_a(),
+        suspended_at: date_time_a(),
+    }
+}
+
+#[fixture]
+pub fn deleted_user_status() -> UserStatus {
+    UserStatus::Deleted {
+        deleted_at: date_time_a(),
+    }
+}
@@ -0,0 +1,33 @@
+use rstest::*;
+
+use crate::repositories::{ProposalVote, VoteAlignment, VoteAlignmentStatus};
+
+use super::{date_time_a, neuron_id, proposal_id, user_id, uuid};
+
+#[fixture]
+pub fn vote_alignment_matched() -> VoteAlignment {
+    VoteAlignment {
+        proposal_id: proposal_id(),
+        user_id: user_id(),
+        proposal_review_id: uuid(),
+        neuron_id: neuron_id(),
+        review_vote: ProposalVote::Yes,
+        neuron_vote: Some(ProposalVote::Yes),
+        status: VoteAlignmentStatus::Matched,
+        checked_at: date_time_a(),
+    }
+}
+
+#[fixture]
+pub fn vote_alignment_not_voted() -> VoteAlignment {
+    VoteAlignment {
+        proposal_id: proposal_id(),
+        user_id: user_id(),
+        proposal_review_id: uuid(),
+        neuron_id: neuron_id(),
+        review_vote: ProposalVote::No,
+        neuron_vote: None,
+        status: VoteAlignmentStatus::NotVoted,
+        checked_at: date_time_a(),
+    }
+}
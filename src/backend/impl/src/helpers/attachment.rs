@@ -0,0 +1,61 @@
+use backend_api::ApiError;
+use ic_http_certification::HttpResponse;
+
+use crate::repositories::Image;
+
+use super::{IC_CERTIFICATE_EXPRESSION_HEADER, RESPONSE_ONLY_CEL_EXPR};
+
+/// Attachments are stored encoded, but are always served with their original content,
+/// so that the certified body matches what the client receives.
+///
+/// Use [super::create_image_http_response] for images that are not attachments.
+pub fn create_attachment_http_response<'a>(image: Image) -> Result<HttpResponse<'a>, ApiError> {
+    let attachment = image
+        .attachment
+        .as_ref()
+        .ok_or_else(|| ApiError::internal("Image is not an attachment"))?;
+    let body = image.decoded_content_bytes()?;
+    let content_type = if image.content_type == "text/plain" {
+        "text/plain; charset=utf-8".to_string()
+    } else {
+        image.content_type.clone()
+    };
+    let headers = vec![
+        (
+            "strict-transport-security".to_string(),
+            "max-age=31536000; includeSubDomains".to_string(),
+        ),
+        ("x-frame-options".to_string(), "DENY".to_string()),
+        ("x-content-type-options".to_string(), "nosniff".to_string()),
+        (
+            "content-security-policy".to_string(),
+            "default-src 'none'; sandbox".to_string(),
+        ),
+        ("referrer-policy".to_string(), "no-referrer".to_string()),
+        (
+            "cross-origin-embedder-policy".to_string(),
+            "require-corp".to_string(),
+        ),
+        (
+            "cross-origin-opener-policy".to_string(),
+            "same-origin".to_string(),
+        ),
+        (
+            "cache-control".to_string(),
+            "public, max-age=31536000, immutable".to_string(),
+        ),
+        (
+            IC_CERTIFICATE_EXPRESSION_HEADER.to_string(),
+            RESPONSE_ONLY_CEL_EXPR.to_string(),
+        ),
+        // additional attachment-specific headers
+        ("content-length".to_string(), body.len().to_string()),
+        ("content-type".to_string(), content_type),
+        (
+            "content-disposition".to_string(),
+            format!("inline; filename=\"{}\"", attachment.file_name),
+        ),
+    ];
+
+    Ok(HttpResponse::ok(body, headers).build())
+}
@@ -0,0 +1,48 @@
+use backend_api::ApiError;
+use miniz_oxide::{deflate::compress_to_vec, inflate::decompress_to_vec};
+
+const GZIP_COMPRESSION_LEVEL: u8 = 6;
+
+/// Minimal gzip member header: magic bytes, deflate method, no flags,
+/// no modification time, no extra flags, unknown OS.
+const GZIP_HEADER: [u8; 10] = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+const GZIP_TRAILER_SIZE: usize = 8;
+
+pub fn gzip_compress(bytes: &[u8]) -> Vec<u8> {
+    let deflated = compress_to_vec(bytes, GZIP_COMPRESSION_LEVEL);
+
+    let mut compressed = Vec::with_capacity(GZIP_HEADER.len() + deflated.len() + GZIP_TRAILER_SIZE);
+    compressed.extend_from_slice(&GZIP_HEADER);
+    compressed.extend_from_slice(&deflated);
+    compressed.extend_from_slice(&crc32fast::hash(bytes).to_le_bytes());
+    // ISIZE is defined as the input size modulo 2^32
+    compressed.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+
+    compressed
+}
+
+/// Decompresses bytes produced by [gzip_compress].
+///
+/// Only the minimal header written by [gzip_compress] is supported,
+/// optional header fields (file name, comment, etc.) are rejected.
+pub fn gzip_decompress(bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+    if bytes.len() < GZIP_HEADER.len() + GZIP_TRAILER_SIZE || bytes[..4] != GZIP_HEADER[..4] {
+        return Err(ApiError::internal("Invalid gzip header"));
+    }
+
+    let (deflated, trailer) =
+        bytes[GZIP_HEADER.len()..].split_at(bytes.len() - GZIP_HEADER.len() - GZIP_TRAILER_SIZE);
+
+    let decompressed = decompress_to_vec(deflated)
+        .map_err(|err| ApiError::internal(&format!("Failed to decompress gzip data: {:?}", err)))?;
+
+    let expected_crc = u32::from_le_bytes(trailer[..4].try_into().unwrap());
+    let expected_size = u32::from_le_bytes(trailer[4..].try_into().unwrap());
+
+    if crc32fast::hash(&decompressed) != expected_crc || decompressed.len() as u32 != expected_size
+    {
+        return Err(ApiError::internal("Gzip data is corrupted"));
+    }
+
+    Ok(decompressed)
+}
@@ -1,9 +1,13 @@
+mod attachment;
 mod cbor;
 mod certification;
+mod gzip;
 mod http;
 mod image;
 
+pub use attachment::*;
 pub use cbor::*;
 pub use certification::*;
+pub use gzip::*;
 pub use http::*;
 pub use image::*;
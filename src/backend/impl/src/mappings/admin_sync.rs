@@ -0,0 +1,10 @@
+use crate::repositories::AdminSyncConfig;
+
+impl From<AdminSyncConfig> for backend_api::AdminSyncConfig {
+    fn from(value: AdminSyncConfig) -> Self {
+        backend_api::AdminSyncConfig {
+            promote_controllers: value.promote_controllers,
+            demote_removed_controllers: value.demote_removed_controllers,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::repositories::{
+    ApprovalConfig, ApprovalRequest, ApprovalRequestId, ApprovalRequestStatus, DateTime,
+    SensitiveAction,
+};
+
+impl From<SensitiveAction> for backend_api::SensitiveAction {
+    fn from(value: SensitiveAction) -> Self {
+        match value {
+            SensitiveAction::SetUserRoles => backend_api::SensitiveAction::SetUserRoles,
+            SensitiveAction::UpdateUserProfile => backend_api::SensitiveAction::UpdateUserProfile,
+            SensitiveAction::ExecuteRewardPayouts => {
+                backend_api::SensitiveAction::ExecuteRewardPayouts
+            }
+            SensitiveAction::UpdateReviewerAssignmentConfig => {
+                backend_api::SensitiveAction::UpdateReviewerAssignmentConfig
+            }
+            SensitiveAction::UpdateAdminSyncConfig => {
+                backend_api::SensitiveAction::UpdateAdminSyncConfig
+            }
+            SensitiveAction::UpdateApprovalConfig => {
+                backend_api::SensitiveAction::UpdateApprovalConfig
+            }
+            SensitiveAction::SuspendUser => backend_api::SensitiveAction::SuspendUser,
+            SensitiveAction::RestoreUser => backend_api::SensitiveAction::RestoreUser,
+            SensitiveAction::ApproveReviewerApplication => {
+                backend_api::SensitiveAction::ApproveReviewerApplication
+            }
+        }
+    }
+}
+
+impl From<backend_api::SensitiveAction> for SensitiveAction {
+    fn from(value: backend_api::SensitiveAction) -> Self {
+        match value {
+            backend_api::SensitiveAction::SetUserRoles => SensitiveAction::SetUserRoles,
+            backend_api::SensitiveAction::UpdateUserProfile => SensitiveAction::UpdateUserProfile,
+            backend_api::SensitiveAction::ExecuteRewardPayouts => {
+                SensitiveAction::ExecuteRewardPayouts
+            }
+            backend_api::SensitiveAction::UpdateReviewerAssignmentConfig => {
+                SensitiveAction::UpdateReviewerAssignmentConfig
+            }
+            backend_api::SensitiveAction::UpdateAdminSyncConfig => {
+                SensitiveAction::UpdateAdminSyncConfig
+            }
+            backend_api::SensitiveAction::UpdateApprovalConfig => {
+                SensitiveAction::UpdateApprovalConfig
+            }
+            backend_api::SensitiveAction::SuspendUser => SensitiveAction::SuspendUser,
+            backend_api::SensitiveAction::RestoreUser => SensitiveAction::RestoreUser,
+            backend_api::SensitiveAction::ApproveReviewerApplication => {
+                SensitiveAction::ApproveReviewerApplication
+            }
+        }
+    }
+}
+
+impl From<ApprovalConfig> for backend_api::ApprovalConfig {
+    fn from(value: ApprovalConfig) -> Self {
+        backend_api::ApprovalConfig {
+            required_approvals: value.required_approvals,
+            timeout_seconds: value.timeout_seconds,
+            sensitive_actions: value
+                .sensitive_actions
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// Requests that were not executed before they expired are mapped as expired.
+pub fn map_approval_request(
+    id: ApprovalRequestId,
+    approval_request: ApprovalRequest,
+    current_time: &DateTime,
+) -> backend_api::ApprovalRequest {
+    let status = match approval_request.status {
+        ApprovalRequestStatus::Executed {
+            executed_by,
+            executed_at,
+        } => backend_api::ApprovalRequestStatus::Executed {
+            executed_by: executed_by.to_string(),
+            executed_at: executed_at.to_string(),
+        },
+        _ if &approval_request.expires_at <= current_time => {
+            backend_api::ApprovalRequestStatus::Expired
+        }
+        ApprovalRequestStatus::Pending => backend_api::ApprovalRequestStatus::Pending,
+        ApprovalRequestStatus::Approved => backend_api::ApprovalRequestStatus::Approved,
+    };
+
+    backend_api::ApprovalRequest {
+        id: id.to_string(),
+        action: approval_request.action.into(),
+        arguments: approval_request.arguments,
+        requested_by: approval_request.requested_by.to_string(),
+        requested_at: approval_request.requested_at.to_string(),
+        expires_at: approval_request.expires_at.to_string(),
+        required_approvals: approval_request.required_approvals,
+        approved_by: approval_request
+            .approved_by
+            .into_iter()
+            .map(|user_id| user_id.to_string())
+            .collect(),
+        status,
+    }
+}
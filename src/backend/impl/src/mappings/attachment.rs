@@ -0,0 +1,15 @@
+use crate::repositories::CreateAttachmentRequest;
+
+impl CreateAttachmentRequest for backend_api::CreateProposalReviewAttachmentRequest {
+    fn file_name(&self) -> String {
+        self.file_name.clone()
+    }
+
+    fn content_type(&self) -> String {
+        self.content_type.clone()
+    }
+
+    fn content_bytes(&self) -> Vec<u8> {
+        self.content_bytes.clone()
+    }
+}
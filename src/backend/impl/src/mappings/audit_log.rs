@@ -0,0 +1,84 @@
+use crate::repositories::{AuditAction, AuditLogEntry, AuditLogEntryId};
+
+impl From<AuditAction> for backend_api::AuditAction {
+    fn from(value: AuditAction) -> Self {
+        match value {
+            AuditAction::UpdateUserProfile => backend_api::AuditAction::UpdateUserProfile,
+            AuditAction::SuspendUser => backend_api::AuditAction::SuspendUser,
+            AuditAction::RestoreUser => backend_api::AuditAction::RestoreUser,
+            AuditAction::SetUserRoles => backend_api::AuditAction::SetUserRoles,
+            AuditAction::SyncAdmins => backend_api::AuditAction::SyncAdmins,
+            AuditAction::UpdateAdminSyncConfig => backend_api::AuditAction::UpdateAdminSyncConfig,
+            AuditAction::ApproveReviewerApplication => {
+                backend_api::AuditAction::ApproveReviewerApplication
+            }
+            AuditAction::RejectReviewerApplication => {
+                backend_api::AuditAction::RejectReviewerApplication
+            }
+            AuditAction::CreateRewardPeriod => backend_api::AuditAction::CreateRewardPeriod,
+            AuditAction::UpdateRewardPeriod => backend_api::AuditAction::UpdateRewardPeriod,
+            AuditAction::CloseRewardPeriod => backend_api::AuditAction::CloseRewardPeriod,
+            AuditAction::ExecuteRewardPayouts => backend_api::AuditAction::ExecuteRewardPayouts,
+            AuditAction::VerifyReviewerNeuron => backend_api::AuditAction::VerifyReviewerNeuron,
+            AuditAction::AssignReviewer => backend_api::AuditAction::AssignReviewer,
+            AuditAction::UpdateReviewerAssignmentConfig => {
+                backend_api::AuditAction::UpdateReviewerAssignmentConfig
+            }
+            AuditAction::ApproveApprovalRequest => backend_api::AuditAction::ApproveApprovalRequest,
+            AuditAction::UpdateApprovalConfig => backend_api::AuditAction::UpdateApprovalConfig,
+            AuditAction::ResolveReviewConflict => backend_api::AuditAction::ResolveReviewConflict,
+            AuditAction::ScoreProposalReview => backend_api::AuditAction::ScoreProposalReview,
+            AuditAction::SyncProposals => backend_api::AuditAction::SyncProposals,
+            AuditAction::ModerateComment => backend_api::AuditAction::ModerateComment,
+        }
+    }
+}
+
+impl From<backend_api::AuditAction> for AuditAction {
+    fn from(value: backend_api::AuditAction) -> Self {
+        match value {
+            backend_api::AuditAction::UpdateUserProfile => AuditAction::UpdateUserProfile,
+            backend_api::AuditAction::SuspendUser => AuditAction::SuspendUser,
+            backend_api::AuditAction::RestoreUser => AuditAction::RestoreUser,
+            backend_api::AuditAction::SetUserRoles => AuditAction::SetUserRoles,
+            backend_api::AuditAction::SyncAdmins => AuditAction::SyncAdmins,
+            backend_api::AuditAction::UpdateAdminSyncConfig => AuditAction::UpdateAdminSyncConfig,
+            backend_api::AuditAction::ApproveReviewerApplication => {
+                AuditAction::ApproveReviewerApplication
+            }
+            backend_api::AuditAction::RejectReviewerApplication => {
+                AuditAction::RejectReviewerApplication
+            }
+            backend_api::AuditAction::CreateRewardPeriod => AuditAction::CreateRewardPeriod,
+            backend_api::AuditAction::UpdateRewardPeriod => AuditAction::UpdateRewardPeriod,
+            backend_api::AuditAction::CloseRewardPeriod => AuditAction::CloseRewardPeriod,
+            backend_api::AuditAction::ExecuteRewardPayouts => AuditAction::ExecuteRewardPayouts,
+            backend_api::AuditAction::VerifyReviewerNeuron => AuditAction::VerifyReviewerNeuron,
+            backend_api::AuditAction::AssignReviewer => AuditAction::AssignReviewer,
+            backend_api::AuditAction::UpdateReviewerAssignmentConfig => {
+                AuditAction::UpdateReviewerAssignmentConfig
+            }
+            backend_api::AuditAction::ApproveApprovalRequest => AuditAction::ApproveApprovalRequest,
+            backend_api::AuditAction::UpdateApprovalConfig => AuditAction::UpdateApprovalConfig,
+            backend_api::AuditAction::ResolveReviewConflict => AuditAction::ResolveReviewConflict,
+            backend_api::AuditAction::ScoreProposalReview => AuditAction::ScoreProposalReview,
+            backend_api::AuditAction::SyncProposals => AuditAction::SyncProposals,
+            backend_api::AuditAction::ModerateComment => AuditAction::ModerateComment,
+        }
+    }
+}
+
+pub fn map_audit_log_entry(
+    id: AuditLogEntryId,
+    audit_log_entry: AuditLogEntry,
+) -> backend_api::AuditLogEntry {
+    backend_api::AuditLogEntry {
+        id,
+        actor: audit_log_entry.actor,
+        action: audit_log_entry.action.into(),
+        target_ids: audit_log_entry.target_ids,
+        before: audit_log_entry.before,
+        after: audit_log_entry.after,
+        timestamp: audit_log_entry.timestamp.to_string(),
+    }
+}
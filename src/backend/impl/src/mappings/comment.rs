@@ -0,0 +1,89 @@
+use backend_api::{ApiError, GetCommentHistoryResponse, HistoryEntry};
+
+use crate::repositories::{Comment, CommentHistoryEntry, CommentId, CommentTarget, Uuid};
+
+impl From<CommentTarget> for backend_api::CommentTarget {
+    fn from(value: CommentTarget) -> Self {
+        match value {
+            CommentTarget::Proposal(id) => backend_api::CommentTarget::Proposal {
+                proposal_id: id.to_string(),
+            },
+            CommentTarget::ProposalReview(id) => backend_api::CommentTarget::ProposalReview {
+                proposal_review_id: id.to_string(),
+            },
+            CommentTarget::ProposalReviewCommit(id) => {
+                backend_api::CommentTarget::ProposalReviewCommit {
+                    proposal_review_commit_id: id.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<backend_api::CommentTarget> for CommentTarget {
+    type Error = ApiError;
+
+    fn try_from(value: backend_api::CommentTarget) -> Result<Self, Self::Error> {
+        let target = match value {
+            backend_api::CommentTarget::Proposal { proposal_id } => {
+                CommentTarget::Proposal(Uuid::try_from(proposal_id.as_str())?)
+            }
+            backend_api::CommentTarget::ProposalReview { proposal_review_id } => {
+                CommentTarget::ProposalReview(Uuid::try_from(proposal_review_id.as_str())?)
+            }
+            backend_api::CommentTarget::ProposalReviewCommit {
+                proposal_review_commit_id,
+            } => CommentTarget::ProposalReviewCommit(Uuid::try_from(
+                proposal_review_commit_id.as_str(),
+            )?),
+        };
+
+        Ok(target)
+    }
+}
+
+impl From<Comment> for backend_api::Comment {
+    fn from(value: Comment) -> Self {
+        backend_api::Comment {
+            target: value.target.into(),
+            parent_id: value.parent_id.map(|id| id.to_string()),
+            user_id: value.user_id.to_string(),
+            content: value.content,
+            created_at: value.created_at.to_string(),
+            last_updated_at: value.last_updated_at.map(|dt| dt.to_string()),
+            deleted_at: value.deleted_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+pub fn map_comment(id: CommentId, comment: Comment) -> backend_api::CommentWithId {
+    backend_api::CommentWithId {
+        id: id.to_string(),
+        comment: comment.into(),
+    }
+}
+
+pub fn map_comments(comments: Vec<(CommentId, Comment)>) -> Vec<backend_api::CommentWithId> {
+    comments
+        .into_iter()
+        .map(|(id, comment)| map_comment(id, comment))
+        .collect()
+}
+
+pub fn map_get_comment_history_response(
+    history: Vec<CommentHistoryEntry>,
+) -> GetCommentHistoryResponse {
+    GetCommentHistoryResponse {
+        history: history
+            .into_iter()
+            .map(|entry| HistoryEntry {
+                action: entry.action.into(),
+                date_time: entry.date_time.to_string(),
+                user: entry.principal,
+                data: backend_api::CommentHistoryEntry {
+                    content: entry.data.content,
+                },
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,13 @@
+use crate::repositories::CommitClaim;
+
+impl From<CommitClaim> for backend_api::CommitClaim {
+    fn from(value: CommitClaim) -> Self {
+        backend_api::CommitClaim {
+            proposal_id: value.proposal_id.to_string(),
+            commit_sha: value.commit_sha.to_string(),
+            user_id: value.user_id.to_string(),
+            claimed_at: value.claimed_at.to_string(),
+            expires_at: value.expires_at.to_string(),
+        }
+    }
+}
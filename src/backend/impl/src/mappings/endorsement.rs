@@ -0,0 +1,32 @@
+use crate::repositories::{Endorsement, EndorsementId};
+
+impl From<Endorsement> for backend_api::Endorsement {
+    fn from(value: Endorsement) -> Self {
+        backend_api::Endorsement {
+            proposal_review_id: value.proposal_review_id.to_string(),
+            proposal_review_commit_id: value.proposal_review_commit_id.map(|id| id.to_string()),
+            user_id: value.user_id.to_string(),
+            created_at: value.created_at.to_string(),
+            note: value.note,
+        }
+    }
+}
+
+pub fn map_endorsement(
+    id: EndorsementId,
+    endorsement: Endorsement,
+) -> backend_api::EndorsementWithId {
+    backend_api::EndorsementWithId {
+        id: id.to_string(),
+        endorsement: endorsement.into(),
+    }
+}
+
+pub fn map_endorsements(
+    endorsements: Vec<(EndorsementId, Endorsement)>,
+) -> Vec<backend_api::EndorsementWithId> {
+    endorsements
+        .into_iter()
+        .map(|(id, endorsement)| map_endorsement(id, endorsement))
+        .collect()
+}
@@ -9,3 +9,13 @@ impl CreateImageRequest for backend_api::CreateProposalReviewImageRequest {
         self.content_bytes.clone()
     }
 }
+
+impl CreateImageRequest for backend_api::CreateMyUserAvatarRequest {
+    fn content_type(&self) -> String {
+        self.content_type.clone()
+    }
+
+    fn content_bytes(&self) -> Vec<u8> {
+        self.content_bytes.clone()
+    }
+}
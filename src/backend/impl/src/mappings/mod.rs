@@ -1,12 +1,44 @@
+mod admin_sync;
+mod approval;
+mod attachment;
+mod audit_log;
+mod comment;
+mod commit_claim;
+mod endorsement;
 mod image;
 mod log;
+mod neuron_verification;
+mod principal_link;
 mod proposal;
 mod proposal_review;
 mod proposal_review_commit;
+mod review_conflict;
+mod review_score;
+mod reviewer_application;
+mod reviewer_assignment;
+mod reward;
+mod role;
+mod user_directory;
 mod user_profile;
+mod vote_alignment;
 
+pub use admin_sync::*;
+pub use approval::*;
+pub use audit_log::*;
+pub use comment::*;
+pub use endorsement::*;
 pub use log::*;
+pub use neuron_verification::*;
+pub use principal_link::*;
 pub use proposal::*;
 pub use proposal_review::*;
 pub use proposal_review_commit::*;
+pub use review_conflict::*;
+pub use review_score::*;
+pub use reviewer_application::*;
+pub use reviewer_assignment::*;
+pub use reward::*;
+pub use role::*;
+pub use user_directory::*;
 pub use user_profile::*;
+pub use vote_alignment::*;
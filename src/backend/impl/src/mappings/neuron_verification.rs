@@ -0,0 +1,25 @@
+use crate::repositories::{NeuronVerification, NeuronVerificationStatus, UserId};
+
+impl From<NeuronVerificationStatus> for backend_api::NeuronVerificationStatus {
+    fn from(value: NeuronVerificationStatus) -> Self {
+        match value {
+            NeuronVerificationStatus::Verified => backend_api::NeuronVerificationStatus::Verified,
+            NeuronVerificationStatus::Unverified { reason } => {
+                backend_api::NeuronVerificationStatus::Unverified { reason }
+            }
+        }
+    }
+}
+
+pub fn map_neuron_verification(
+    user_id: UserId,
+    neuron_verification: NeuronVerification,
+) -> backend_api::NeuronVerification {
+    backend_api::NeuronVerification {
+        user_id: user_id.to_string(),
+        neuron_id: neuron_verification.neuron_id,
+        status: neuron_verification.status.into(),
+        checked_at: neuron_verification.checked_at.to_string(),
+        verified_at: neuron_verification.verified_at.map(|dt| dt.to_string()),
+    }
+}
@@ -0,0 +1,21 @@
+use backend_api::{GetMyPrincipalLinkHistoryResponse, HistoryEntry};
+
+use crate::repositories::PrincipalLinkHistoryEntry;
+
+pub fn map_get_my_principal_link_history_response(
+    history: Vec<PrincipalLinkHistoryEntry>,
+) -> GetMyPrincipalLinkHistoryResponse {
+    GetMyPrincipalLinkHistoryResponse {
+        history: history
+            .into_iter()
+            .map(|entry| HistoryEntry {
+                action: entry.action.into(),
+                date_time: entry.date_time.to_string(),
+                user: entry.principal,
+                data: backend_api::PrincipalLinkHistoryEntry {
+                    principal: entry.data.principal,
+                },
+            })
+            .collect(),
+    }
+}
@@ -1,9 +1,9 @@
 use crate::repositories::{
-    ProposalReview, ProposalReviewCommit, ProposalReviewCommitId, ProposalReviewId,
-    ProposalReviewStatus, ProposalVote,
+    Endorsement, EndorsementId, ProposalReview, ProposalReviewCommit, ProposalReviewCommitId,
+    ProposalReviewId, ProposalReviewStatus, ProposalVote,
 };
 
-use super::map_proposal_review_commits;
+use super::{map_endorsements, map_proposal_review_commits};
 
 impl From<ProposalReviewStatus> for backend_api::ProposalReviewStatus {
     fn from(proposal_review_status: ProposalReviewStatus) -> Self {
@@ -56,6 +56,8 @@ impl From<ProposalReview> for backend_api::ProposalReview {
             images_paths: vec![],
             proposal_review_commits: vec![],
             vote: proposal_review.vote.into(),
+            attachments_paths: vec![],
+            endorsements: vec![],
         }
     }
 }
@@ -65,12 +67,16 @@ pub fn map_proposal_review(
     proposal_review: ProposalReview,
     proposal_review_commits: Vec<(ProposalReviewCommitId, ProposalReviewCommit)>,
     images_paths: Vec<String>,
+    attachments_paths: Vec<String>,
+    endorsements: Vec<(EndorsementId, Endorsement)>,
 ) -> backend_api::ProposalReviewWithId {
     backend_api::ProposalReviewWithId {
         id: id.to_string(),
         proposal_review: backend_api::ProposalReview {
             proposal_review_commits: map_proposal_review_commits(proposal_review_commits),
             images_paths,
+            attachments_paths,
+            endorsements: map_endorsements(endorsements),
             ..proposal_review.into()
         },
     }
@@ -0,0 +1,70 @@
+use crate::repositories::{
+    ReviewConflict, ReviewConflictId, ReviewConflictKind, ReviewConflictStatus,
+};
+
+impl From<ReviewConflictKind> for backend_api::ReviewConflictKind {
+    fn from(value: ReviewConflictKind) -> Self {
+        match value {
+            ReviewConflictKind::Vote => backend_api::ReviewConflictKind::Vote,
+            ReviewConflictKind::BuildReproduced => backend_api::ReviewConflictKind::BuildReproduced,
+            ReviewConflictKind::CommitMatchesDescription { commit_sha } => {
+                backend_api::ReviewConflictKind::CommitMatchesDescription {
+                    commit_sha: commit_sha.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl From<ReviewConflictStatus> for backend_api::ReviewConflictStatus {
+    fn from(value: ReviewConflictStatus) -> Self {
+        match value {
+            ReviewConflictStatus::Open => backend_api::ReviewConflictStatus::Open,
+            ReviewConflictStatus::Resolved {
+                resolved_by,
+                resolved_at,
+                note,
+            } => backend_api::ReviewConflictStatus::Resolved {
+                resolved_by: resolved_by.to_string(),
+                resolved_at: resolved_at.to_string(),
+                note,
+            },
+        }
+    }
+}
+
+impl From<ReviewConflict> for backend_api::ReviewConflict {
+    fn from(value: ReviewConflict) -> Self {
+        backend_api::ReviewConflict {
+            proposal_id: value.proposal_id.to_string(),
+            kind: value.kind.into(),
+            proposal_review_ids: value
+                .proposal_review_ids
+                .iter()
+                .map(|proposal_review_id| proposal_review_id.to_string())
+                .collect(),
+            detected_at: value.detected_at.to_string(),
+            last_updated_at: value.last_updated_at.map(|dt| dt.to_string()),
+            status: value.status.into(),
+        }
+    }
+}
+
+pub fn map_review_conflict(
+    id: ReviewConflictId,
+    review_conflict: ReviewConflict,
+) -> backend_api::ReviewConflictWithId {
+    backend_api::ReviewConflictWithId {
+        id: id.to_string(),
+        review_conflict: review_conflict.into(),
+    }
+}
+
+pub fn map_review_conflicts(
+    review_conflicts: Vec<(ReviewConflictId, ReviewConflict)>,
+) -> Vec<backend_api::ReviewConflictWithId> {
+    review_conflicts
+        .into_iter()
+        .map(|(id, review_conflict)| map_review_conflict(id, review_conflict))
+        .collect()
+}
@@ -0,0 +1,39 @@
+use crate::repositories::{ProposalReviewId, ReviewScore, ReviewerReputation};
+
+impl From<ReviewerReputation> for backend_api::ReviewerReputation {
+    fn from(value: ReviewerReputation) -> Self {
+        backend_api::ReviewerReputation {
+            score: value.score,
+            scored_reviews_count: value.scored_reviews_count,
+        }
+    }
+}
+
+pub fn map_review_score(
+    proposal_review_id: ProposalReviewId,
+    review_score: ReviewScore,
+) -> backend_api::ReviewScore {
+    backend_api::ReviewScore {
+        proposal_review_id: proposal_review_id.to_string(),
+        proposal_id: review_score.proposal_id.to_string(),
+        reviewer_user_id: review_score.reviewer_user_id.to_string(),
+        scored_by: review_score.scored_by.to_string(),
+        depth: review_score.depth,
+        accuracy: review_score.accuracy,
+        timeliness: review_score.timeliness,
+        comment: review_score.comment,
+        created_at: review_score.created_at.to_string(),
+        last_updated_at: review_score.last_updated_at.map(|dt| dt.to_string()),
+    }
+}
+
+pub fn map_review_scores(
+    review_scores: Vec<(ProposalReviewId, ReviewScore)>,
+) -> Vec<backend_api::ReviewScore> {
+    review_scores
+        .into_iter()
+        .map(|(proposal_review_id, review_score)| {
+            map_review_score(proposal_review_id, review_score)
+        })
+        .collect()
+}
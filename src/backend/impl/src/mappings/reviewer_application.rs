@@ -0,0 +1,53 @@
+use crate::repositories::{ReviewerApplication, ReviewerApplicationId, ReviewerApplicationStatus};
+
+impl From<ReviewerApplicationStatus> for backend_api::ReviewerApplicationStatus {
+    fn from(value: ReviewerApplicationStatus) -> Self {
+        match value {
+            ReviewerApplicationStatus::Pending => backend_api::ReviewerApplicationStatus::Pending,
+            ReviewerApplicationStatus::Approved {
+                reviewed_by,
+                reviewed_at,
+            } => backend_api::ReviewerApplicationStatus::Approved {
+                reviewed_by: reviewed_by.to_string(),
+                reviewed_at: reviewed_at.to_string(),
+            },
+            ReviewerApplicationStatus::Rejected {
+                reviewed_by,
+                reviewed_at,
+                reason,
+            } => backend_api::ReviewerApplicationStatus::Rejected {
+                reviewed_by: reviewed_by.to_string(),
+                reviewed_at: reviewed_at.to_string(),
+                reason,
+            },
+        }
+    }
+}
+
+impl From<ReviewerApplication> for backend_api::ReviewerApplication {
+    fn from(value: ReviewerApplication) -> Self {
+        backend_api::ReviewerApplication {
+            user_id: value.user_id.to_string(),
+            bio: value.bio,
+            neuron_id: value.neuron_id,
+            wallet_address: value.wallet_address.to_string(),
+            social_links: value
+                .social_links
+                .into_iter()
+                .map(|link| link.into())
+                .collect(),
+            created_at: value.created_at.to_string(),
+            status: value.status.into(),
+        }
+    }
+}
+
+pub fn map_reviewer_application(
+    id: ReviewerApplicationId,
+    reviewer_application: ReviewerApplication,
+) -> backend_api::ReviewerApplicationWithId {
+    backend_api::ReviewerApplicationWithId {
+        id: id.to_string(),
+        reviewer_application: reviewer_application.into(),
+    }
+}
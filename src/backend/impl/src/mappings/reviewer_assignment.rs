@@ -0,0 +1,114 @@
+use crate::repositories::{
+    ReviewerAssignment, ReviewerAssignmentConfig, ReviewerAssignmentId, ReviewerAssignmentPolicy,
+    ReviewerAssignmentStatus,
+};
+
+impl From<ReviewerAssignmentStatus> for backend_api::ReviewerAssignmentStatus {
+    fn from(value: ReviewerAssignmentStatus) -> Self {
+        match value {
+            ReviewerAssignmentStatus::Pending => backend_api::ReviewerAssignmentStatus::Pending,
+            ReviewerAssignmentStatus::Accepted => backend_api::ReviewerAssignmentStatus::Accepted,
+            ReviewerAssignmentStatus::Declined { reason } => {
+                backend_api::ReviewerAssignmentStatus::Declined { reason }
+            }
+            ReviewerAssignmentStatus::Fulfilled => backend_api::ReviewerAssignmentStatus::Fulfilled,
+            ReviewerAssignmentStatus::Unfulfilled => {
+                backend_api::ReviewerAssignmentStatus::Unfulfilled
+            }
+        }
+    }
+}
+
+pub fn reviewer_assignment_status_matches_key(
+    status: &ReviewerAssignmentStatus,
+    key: &backend_api::ReviewerAssignmentStatusKey,
+) -> bool {
+    matches!(
+        (status, key),
+        (
+            ReviewerAssignmentStatus::Pending,
+            backend_api::ReviewerAssignmentStatusKey::Pending
+        ) | (
+            ReviewerAssignmentStatus::Accepted,
+            backend_api::ReviewerAssignmentStatusKey::Accepted
+        ) | (
+            ReviewerAssignmentStatus::Declined { .. },
+            backend_api::ReviewerAssignmentStatusKey::Declined
+        ) | (
+            ReviewerAssignmentStatus::Fulfilled,
+            backend_api::ReviewerAssignmentStatusKey::Fulfilled
+        ) | (
+            ReviewerAssignmentStatus::Unfulfilled,
+            backend_api::ReviewerAssignmentStatusKey::Unfulfilled
+        )
+    )
+}
+
+impl From<ReviewerAssignment> for backend_api::ReviewerAssignment {
+    fn from(value: ReviewerAssignment) -> Self {
+        backend_api::ReviewerAssignment {
+            proposal_id: value.proposal_id.to_string(),
+            user_id: value.user_id.to_string(),
+            assigned_by: value.assigned_by.map(|user_id| user_id.to_string()),
+            created_at: value.created_at.to_string(),
+            last_updated_at: value.last_updated_at.map(|dt| dt.to_string()),
+            status: value.status.into(),
+        }
+    }
+}
+
+pub fn map_reviewer_assignment(
+    id: ReviewerAssignmentId,
+    reviewer_assignment: ReviewerAssignment,
+) -> backend_api::ReviewerAssignmentWithId {
+    backend_api::ReviewerAssignmentWithId {
+        id: id.to_string(),
+        reviewer_assignment: reviewer_assignment.into(),
+    }
+}
+
+pub fn map_reviewer_assignments(
+    reviewer_assignments: Vec<(ReviewerAssignmentId, ReviewerAssignment)>,
+) -> Vec<backend_api::ReviewerAssignmentWithId> {
+    reviewer_assignments
+        .into_iter()
+        .map(|(id, reviewer_assignment)| map_reviewer_assignment(id, reviewer_assignment))
+        .collect()
+}
+
+impl From<ReviewerAssignmentPolicy> for backend_api::ReviewerAssignmentPolicy {
+    fn from(value: ReviewerAssignmentPolicy) -> Self {
+        match value {
+            ReviewerAssignmentPolicy::Manual => backend_api::ReviewerAssignmentPolicy::Manual,
+            ReviewerAssignmentPolicy::RoundRobin => {
+                backend_api::ReviewerAssignmentPolicy::RoundRobin
+            }
+            ReviewerAssignmentPolicy::LeastLoaded => {
+                backend_api::ReviewerAssignmentPolicy::LeastLoaded
+            }
+        }
+    }
+}
+
+impl From<backend_api::ReviewerAssignmentPolicy> for ReviewerAssignmentPolicy {
+    fn from(value: backend_api::ReviewerAssignmentPolicy) -> Self {
+        match value {
+            backend_api::ReviewerAssignmentPolicy::Manual => ReviewerAssignmentPolicy::Manual,
+            backend_api::ReviewerAssignmentPolicy::RoundRobin => {
+                ReviewerAssignmentPolicy::RoundRobin
+            }
+            backend_api::ReviewerAssignmentPolicy::LeastLoaded => {
+                ReviewerAssignmentPolicy::LeastLoaded
+            }
+        }
+    }
+}
+
+impl From<ReviewerAssignmentConfig> for backend_api::ReviewerAssignmentConfig {
+    fn from(value: ReviewerAssignmentConfig) -> Self {
+        backend_api::ReviewerAssignmentConfig {
+            policy: value.policy.into(),
+            reviewers_per_proposal: value.reviewers_per_proposal,
+        }
+    }
+}
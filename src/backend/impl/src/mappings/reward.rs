@@ -0,0 +1,153 @@
+use crate::repositories::{
+    RewardPayout, RewardPayoutStatus, RewardPeriod, RewardPeriodId, RewardPeriodStatus,
+    RewardRules, RewardStatement, RewardStatementItem,
+};
+
+impl From<RewardRules> for backend_api::RewardRules {
+    fn from(value: RewardRules) -> Self {
+        backend_api::RewardRules {
+            reward_per_review_e8s: value.reward_per_review_e8s,
+            verified_build_bonus_e8s: value.verified_build_bonus_e8s,
+            late_review_penalty_e8s: value.late_review_penalty_e8s,
+            late_review_after_hours: value.late_review_after_hours,
+        }
+    }
+}
+
+impl From<backend_api::RewardRules> for RewardRules {
+    fn from(value: backend_api::RewardRules) -> Self {
+        RewardRules {
+            reward_per_review_e8s: value.reward_per_review_e8s,
+            verified_build_bonus_e8s: value.verified_build_bonus_e8s,
+            late_review_penalty_e8s: value.late_review_penalty_e8s,
+            late_review_after_hours: value.late_review_after_hours,
+        }
+    }
+}
+
+impl From<RewardPeriodStatus> for backend_api::RewardPeriodStatus {
+    fn from(value: RewardPeriodStatus) -> Self {
+        match value {
+            RewardPeriodStatus::Open => backend_api::RewardPeriodStatus::Open,
+            RewardPeriodStatus::Closed { closed_at } => backend_api::RewardPeriodStatus::Closed {
+                closed_at: closed_at.to_string(),
+            },
+        }
+    }
+}
+
+impl From<RewardPeriod> for backend_api::RewardPeriod {
+    fn from(value: RewardPeriod) -> Self {
+        backend_api::RewardPeriod {
+            name: value.name,
+            starts_at: value.starts_at.to_string(),
+            ends_at: value.ends_at.to_string(),
+            rules: value.rules.into(),
+            status: value.status.into(),
+            created_by: value.created_by.to_string(),
+            created_at: value.created_at.to_string(),
+            last_updated_at: value.last_updated_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+impl From<RewardStatementItem> for backend_api::RewardStatementItem {
+    fn from(value: RewardStatementItem) -> Self {
+        backend_api::RewardStatementItem {
+            proposal_id: value.proposal_id.to_string(),
+            proposal_review_id: value.proposal_review_id.to_string(),
+            verified_build: value.verified_build,
+            late: value.late,
+            amount_e8s: value.amount_e8s,
+        }
+    }
+}
+
+impl From<RewardStatement> for backend_api::RewardStatement {
+    fn from(value: RewardStatement) -> Self {
+        backend_api::RewardStatement {
+            user_id: value.user_id.to_string(),
+            wallet_address: value.wallet_address,
+            items: value.items.into_iter().map(Into::into).collect(),
+            total_e8s: value.total_e8s,
+        }
+    }
+}
+
+impl From<RewardPayoutStatus> for backend_api::RewardPayoutStatus {
+    fn from(value: RewardPayoutStatus) -> Self {
+        match value {
+            RewardPayoutStatus::Pending {
+                attempts,
+                last_error,
+            } => backend_api::RewardPayoutStatus::Pending {
+                attempts,
+                last_error,
+            },
+            RewardPayoutStatus::Completed {
+                block_index,
+                completed_at,
+            } => backend_api::RewardPayoutStatus::Completed {
+                block_index,
+                completed_at: completed_at.to_string(),
+            },
+            RewardPayoutStatus::Failed { error, failed_at } => {
+                backend_api::RewardPayoutStatus::Failed {
+                    error,
+                    failed_at: failed_at.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl From<RewardPayout> for backend_api::RewardPayout {
+    fn from(value: RewardPayout) -> Self {
+        backend_api::RewardPayout {
+            user_id: value.user_id.to_string(),
+            wallet_address: value.wallet_address,
+            amount_e8s: value.amount_e8s,
+            status: value.status.into(),
+        }
+    }
+}
+
+pub fn map_reward_period(
+    reward_period_id: RewardPeriodId,
+    reward_period: RewardPeriod,
+) -> backend_api::RewardPeriodWithId {
+    backend_api::RewardPeriodWithId {
+        id: reward_period_id.to_string(),
+        reward_period: reward_period.into(),
+    }
+}
+
+pub fn map_list_reward_periods_response(
+    reward_periods: Vec<(RewardPeriodId, RewardPeriod)>,
+) -> backend_api::ListRewardPeriodsResponse {
+    backend_api::ListRewardPeriodsResponse {
+        reward_periods: reward_periods
+            .into_iter()
+            .map(|(reward_period_id, reward_period)| {
+                map_reward_period(reward_period_id, reward_period)
+            })
+            .collect(),
+    }
+}
+
+pub fn map_export_reward_statements_response(
+    reward_period_id: RewardPeriodId,
+    reward_period: RewardPeriod,
+    reward_statements: Vec<RewardStatement>,
+) -> backend_api::ExportRewardStatementsResponse {
+    let total_e8s = reward_statements
+        .iter()
+        .map(|reward_statement| reward_statement.total_e8s)
+        .sum();
+
+    backend_api::ExportRewardStatementsResponse {
+        reward_period: map_reward_period(reward_period_id, reward_period),
+        statements: reward_statements.into_iter().map(Into::into).collect(),
+        total_e8s,
+    }
+}
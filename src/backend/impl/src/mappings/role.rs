@@ -0,0 +1,40 @@
+use crate::repositories::{Permission, Role};
+
+impl From<Permission> for backend_api::Permission {
+    fn from(value: Permission) -> Self {
+        match value {
+            Permission::ManageRoles => backend_api::Permission::ManageRoles,
+            Permission::ManageUsers => backend_api::Permission::ManageUsers,
+            Permission::ReadLogs => backend_api::Permission::ReadLogs,
+            Permission::ReadAuditLog => backend_api::Permission::ReadAuditLog,
+            Permission::SyncProposals => backend_api::Permission::SyncProposals,
+            Permission::ManageReviewerAssignments => {
+                backend_api::Permission::ManageReviewerAssignments
+            }
+            Permission::ModerateReviews => backend_api::Permission::ModerateReviews,
+            Permission::ManageRewards => backend_api::Permission::ManageRewards,
+            Permission::ReviewProposals => backend_api::Permission::ReviewProposals,
+            Permission::Comment => backend_api::Permission::Comment,
+        }
+    }
+}
+
+impl From<Role> for backend_api::Role {
+    fn from(value: Role) -> Self {
+        match value {
+            Role::Moderator => backend_api::Role::Moderator,
+            Role::RewardsManager => backend_api::Role::RewardsManager,
+            Role::SyncOperator => backend_api::Role::SyncOperator,
+        }
+    }
+}
+
+impl From<backend_api::Role> for Role {
+    fn from(value: backend_api::Role) -> Self {
+        match value {
+            backend_api::Role::Moderator => Role::Moderator,
+            backend_api::Role::RewardsManager => Role::RewardsManager,
+            backend_api::Role::SyncOperator => Role::SyncOperator,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::repositories::{UserConfig, UserStatus};
+
+pub fn map_user_status(user_status: Option<UserStatus>) -> backend_api::UserStatus {
+    match user_status {
+        None => backend_api::UserStatus::Active,
+        Some(UserStatus::Suspended {
+            reason,
+            suspended_by,
+            suspended_at,
+        }) => backend_api::UserStatus::Suspended {
+            reason,
+            suspended_by: suspended_by.to_string(),
+            suspended_at: suspended_at.to_string(),
+        },
+        Some(UserStatus::Deleted { deleted_at }) => backend_api::UserStatus::Deleted {
+            deleted_at: deleted_at.to_string(),
+        },
+    }
+}
+
+impl From<&UserConfig> for backend_api::UserConfigType {
+    fn from(value: &UserConfig) -> Self {
+        match value {
+            UserConfig::Admin { .. } => backend_api::UserConfigType::Admin,
+            UserConfig::Reviewer { .. } => backend_api::UserConfigType::Reviewer,
+            UserConfig::Anonymous => backend_api::UserConfigType::Anonymous,
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::repositories::{
-    HistoryAction, SocialLink, SocialLinkPlatform, UserConfig, UserId, UserProfile,
-    UserProfileHistoryEntry,
+    HistoryAction, ReviewerReputation, SocialLink, SocialLinkPlatform, UserConfig, UserId,
+    UserProfile, UserProfileHistoryEntry,
 };
 use backend_api::{
-    CreateMyUserProfileResponse, GetMyUserProfileHistoryResponse, GetMyUserProfileResponse,
-    HistoryEntry,
+    ApiError, CreateMyUserProfileResponse, GetMyUserProfileHistoryResponse,
+    GetMyUserProfileResponse, HistoryEntry,
 };
 
 impl From<UserConfig> for backend_api::UserConfig {
@@ -19,7 +19,9 @@ impl From<UserConfig> for backend_api::UserConfig {
             } => backend_api::UserConfig::Reviewer {
                 bio,
                 neuron_id,
-                wallet_address,
+                wallet_address: wallet_address
+                    .map(|wallet_address| wallet_address.to_string())
+                    .unwrap_or_default(),
                 social_links: social_links.into_iter().map(|link| link.into()).collect(),
             },
             UserConfig::Anonymous => backend_api::UserConfig::Anonymous,
@@ -36,7 +38,9 @@ impl From<SocialLinkPlatform> for backend_api::SocialLinkPlatform {
             SocialLinkPlatform::X => backend_api::SocialLinkPlatform::X,
             SocialLinkPlatform::GitHub => backend_api::SocialLinkPlatform::GitHub,
             SocialLinkPlatform::DfinityForum => backend_api::SocialLinkPlatform::DfinityForum,
-            SocialLinkPlatform::Discord => backend_api::SocialLinkPlatform::Discord,
+            SocialLinkPlatform::Discord | SocialLinkPlatform::LegacyDiscord => {
+                backend_api::SocialLinkPlatform::Discord
+            }
             SocialLinkPlatform::Website => backend_api::SocialLinkPlatform::Website,
         }
     }
@@ -60,18 +64,18 @@ impl From<backend_api::SocialLinkPlatform> for SocialLinkPlatform {
 impl From<SocialLink> for backend_api::SocialLink {
     fn from(value: SocialLink) -> Self {
         backend_api::SocialLink {
+            url: value.url(),
             platform: value.platform.into(),
             username: value.username,
         }
     }
 }
 
-impl From<backend_api::SocialLink> for SocialLink {
-    fn from(value: backend_api::SocialLink) -> Self {
-        SocialLink {
-            platform: value.platform.into(),
-            username: value.username,
-        }
+impl TryFrom<backend_api::SocialLink> for SocialLink {
+    type Error = ApiError;
+
+    fn try_from(value: backend_api::SocialLink) -> Result<Self, Self::Error> {
+        SocialLink::new(value.platform.into(), &value.username)
     }
 }
 
@@ -81,22 +85,49 @@ impl From<HistoryAction> for backend_api::HistoryAction {
             HistoryAction::Create => backend_api::HistoryAction::Create,
             HistoryAction::Update => backend_api::HistoryAction::Update,
             HistoryAction::Delete => backend_api::HistoryAction::Delete,
+            HistoryAction::Suspend => backend_api::HistoryAction::Suspend,
             HistoryAction::Restore => backend_api::HistoryAction::Restore,
         }
     }
 }
 
+/// Maps the config of a profile, showing an unparseable legacy wallet address
+/// in place of a missing one so that the reviewer can correct it.
+pub fn map_user_config(user_profile: &UserProfile) -> backend_api::UserConfig {
+    let mut config = backend_api::UserConfig::from(user_profile.config.clone());
+
+    if let (backend_api::UserConfig::Reviewer { wallet_address, .. }, Some(legacy_wallet_address)) =
+        (&mut config, &user_profile.legacy_wallet_address)
+    {
+        if wallet_address.is_empty() {
+            *wallet_address = legacy_wallet_address.clone();
+        }
+    }
+
+    config
+}
+
+pub fn map_user_profile(
+    user_id: UserId,
+    user_profile: UserProfile,
+    reputation: Option<ReviewerReputation>,
+) -> backend_api::UserProfile {
+    backend_api::UserProfile {
+        id: user_id.to_string(),
+        avatar_path: user_profile.avatar_path(),
+        config: map_user_config(&user_profile),
+        username: user_profile.username,
+        reputation: reputation.map(Into::into),
+    }
+}
+
 pub fn map_list_reviewer_profiles_response(
-    profiles: Vec<(UserId, UserProfile)>,
+    profiles: Vec<(UserId, UserProfile, Option<ReviewerReputation>)>,
 ) -> backend_api::ListReviewerProfilesResponse {
     backend_api::ListReviewerProfilesResponse {
         profiles: profiles
             .into_iter()
-            .map(|(user_id, profile)| backend_api::UserProfile {
-                id: user_id.to_string(),
-                username: profile.username,
-                config: profile.config.into(),
-            })
+            .map(|(user_id, profile, reputation)| map_user_profile(user_id, profile, reputation))
             .collect(),
     }
 }
@@ -104,12 +135,9 @@ pub fn map_list_reviewer_profiles_response(
 pub fn map_get_my_user_profile_response(
     user_id: UserId,
     user_profile: UserProfile,
+    reputation: Option<ReviewerReputation>,
 ) -> GetMyUserProfileResponse {
-    GetMyUserProfileResponse {
-        id: user_id.to_string(),
-        username: user_profile.username,
-        config: user_profile.config.into(),
-    }
+    map_user_profile(user_id, user_profile, reputation)
 }
 
 pub fn map_create_my_user_profile_response(
@@ -118,8 +146,8 @@ pub fn map_create_my_user_profile_response(
 ) -> CreateMyUserProfileResponse {
     CreateMyUserProfileResponse {
         id: user_id.to_string(),
+        config: map_user_config(&user_profile),
         username: user_profile.username,
-        config: user_profile.config.into(),
     }
 }
 
@@ -134,8 +162,8 @@ pub fn map_get_my_user_profile_history_response(
                 date_time: entry.date_time.to_string(),
                 user: entry.principal,
                 data: backend_api::UserProfileHistoryEntry {
+                    config: map_user_config(&entry.data),
                     username: entry.data.username,
-                    config: entry.data.config.into(),
                 },
             })
             .collect(),
@@ -0,0 +1,34 @@
+use crate::repositories::{VoteAlignment, VoteAlignmentStatus};
+
+impl From<VoteAlignmentStatus> for backend_api::VoteAlignmentStatus {
+    fn from(value: VoteAlignmentStatus) -> Self {
+        match value {
+            VoteAlignmentStatus::Matched => backend_api::VoteAlignmentStatus::Matched,
+            VoteAlignmentStatus::Mismatched => backend_api::VoteAlignmentStatus::Mismatched,
+            VoteAlignmentStatus::NotVoted => backend_api::VoteAlignmentStatus::NotVoted,
+        }
+    }
+}
+
+impl From<backend_api::VoteAlignmentStatus> for VoteAlignmentStatus {
+    fn from(value: backend_api::VoteAlignmentStatus) -> Self {
+        match value {
+            backend_api::VoteAlignmentStatus::Matched => VoteAlignmentStatus::Matched,
+            backend_api::VoteAlignmentStatus::Mismatched => VoteAlignmentStatus::Mismatched,
+            backend_api::VoteAlignmentStatus::NotVoted => VoteAlignmentStatus::NotVoted,
+        }
+    }
+}
+
+pub fn map_vote_alignment(vote_alignment: VoteAlignment) -> backend_api::VoteAlignment {
+    backend_api::VoteAlignment {
+        proposal_id: vote_alignment.proposal_id.to_string(),
+        user_id: vote_alignment.user_id.to_string(),
+        proposal_review_id: vote_alignment.proposal_review_id.to_string(),
+        neuron_id: vote_alignment.neuron_id,
+        review_vote: vote_alignment.review_vote.into(),
+        neuron_vote: vote_alignment.neuron_vote.map(|vote| vote.into()),
+        status: vote_alignment.status.into(),
+        checked_at: vote_alignment.checked_at.to_string(),
+    }
+}
@@ -0,0 +1,137 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_admin_sync_config, init_controller_admins, AdminSyncConfig, AdminSyncConfigMemory,
+    ControllerAdminMemory, DateTime, UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AdminSyncRepository {
+    fn get_admin_sync_config(&self) -> AdminSyncConfig;
+
+    fn set_admin_sync_config(&self, config: AdminSyncConfig) -> Result<(), ApiError>;
+
+    /// Returns the admins that were promoted because they are canister controllers,
+    /// along with the date they were promoted.
+    fn get_controller_admins(&self) -> Vec<(UserId, DateTime)>;
+
+    fn add_controller_admin(&self, user_id: UserId, promoted_at: DateTime);
+
+    fn remove_controller_admin(&self, user_id: &UserId);
+}
+
+pub struct AdminSyncRepositoryImpl {}
+
+impl Default for AdminSyncRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdminSyncRepository for AdminSyncRepositoryImpl {
+    fn get_admin_sync_config(&self) -> AdminSyncConfig {
+        STATE.with_borrow(|s| s.config.get().clone())
+    }
+
+    fn set_admin_sync_config(&self, config: AdminSyncConfig) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.config
+                .set(config)
+                .map(|_| ())
+                .map_err(|_| ApiError::internal("Failed to set admin sync config"))
+        })
+    }
+
+    fn get_controller_admins(&self) -> Vec<(UserId, DateTime)> {
+        STATE.with_borrow(|s| s.controller_admins.iter().collect())
+    }
+
+    fn add_controller_admin(&self, user_id: UserId, promoted_at: DateTime) {
+        STATE.with_borrow_mut(|s| {
+            s.controller_admins.insert(user_id, promoted_at);
+        });
+    }
+
+    fn remove_controller_admin(&self, user_id: &UserId) {
+        STATE.with_borrow_mut(|s| {
+            s.controller_admins.remove(user_id);
+        });
+    }
+}
+
+impl AdminSyncRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct AdminSyncState {
+    config: AdminSyncConfigMemory,
+    controller_admins: ControllerAdminMemory,
+}
+
+impl Default for AdminSyncState {
+    fn default() -> Self {
+        Self {
+            config: init_admin_sync_config(),
+            controller_admins: init_controller_admins(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<AdminSyncState> = RefCell::new(AdminSyncState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn get_and_set_admin_sync_config() {
+        STATE.set(AdminSyncState::default());
+
+        let repository = AdminSyncRepositoryImpl::default();
+
+        assert_eq!(
+            repository.get_admin_sync_config(),
+            AdminSyncConfig::default()
+        );
+
+        let config = AdminSyncConfig {
+            promote_controllers: false,
+            demote_removed_controllers: true,
+        };
+        repository.set_admin_sync_config(config.clone()).unwrap();
+
+        assert_eq!(repository.get_admin_sync_config(), config);
+    }
+
+    #[rstest]
+    fn add_and_remove_controller_admins() {
+        STATE.set(AdminSyncState::default());
+
+        let repository = AdminSyncRepositoryImpl::default();
+        repository.add_controller_admin(fixtures::uuid_a(), fixtures::date_time_a());
+        repository.add_controller_admin(fixtures::uuid_b(), fixtures::date_time_b());
+
+        assert_eq!(
+            repository.get_controller_admins(),
+            vec![
+                (fixtures::uuid_a(), fixtures::date_time_a()),
+                (fixtures::uuid_b(), fixtures::date_time_b()),
+            ]
+        );
+
+        repository.remove_controller_admin(&fixtures::uuid_a());
+
+        assert_eq!(
+            repository.get_controller_admins(),
+            vec![(fixtures::uuid_b(), fixtures::date_time_b())]
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_approval_config, init_approval_requests, ApprovalConfig, ApprovalConfigMemory,
+    ApprovalRequest, ApprovalRequestId, ApprovalRequestMemory,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ApprovalRepository {
+    fn get_approval_request_by_id(
+        &self,
+        approval_request_id: &ApprovalRequestId,
+    ) -> Option<ApprovalRequest>;
+
+    fn get_approval_requests(&self) -> Vec<(ApprovalRequestId, ApprovalRequest)>;
+
+    fn create_approval_request(
+        &self,
+        approval_request: ApprovalRequest,
+    ) -> Result<ApprovalRequestId, ApiError>;
+
+    fn update_approval_request(
+        &self,
+        approval_request_id: ApprovalRequestId,
+        approval_request: ApprovalRequest,
+    ) -> Result<(), ApiError>;
+
+    fn get_approval_config(&self) -> ApprovalConfig;
+
+    fn set_approval_config(&self, config: ApprovalConfig) -> Result<(), ApiError>;
+}
+
+pub struct ApprovalRepositoryImpl {}
+
+impl Default for ApprovalRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApprovalRepository for ApprovalRepositoryImpl {
+    fn get_approval_request_by_id(
+        &self,
+        approval_request_id: &ApprovalRequestId,
+    ) -> Option<ApprovalRequest> {
+        STATE.with_borrow(|s| s.approval_requests.get(approval_request_id))
+    }
+
+    fn get_approval_requests(&self) -> Vec<(ApprovalRequestId, ApprovalRequest)> {
+        STATE.with_borrow(|s| s.approval_requests.iter().collect())
+    }
+
+    fn create_approval_request(
+        &self,
+        approval_request: ApprovalRequest,
+    ) -> Result<ApprovalRequestId, ApiError> {
+        let approval_request_id = ApprovalRequestId::new();
+
+        STATE.with_borrow_mut(|s| {
+            s.approval_requests
+                .insert(approval_request_id, approval_request);
+        });
+
+        Ok(approval_request_id)
+    }
+
+    fn update_approval_request(
+        &self,
+        approval_request_id: ApprovalRequestId,
+        approval_request: ApprovalRequest,
+    ) -> Result<(), ApiError> {
+        self.get_approval_request_by_id(&approval_request_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Approval request with id {} not found",
+                    approval_request_id
+                ))
+            })?;
+
+        STATE.with_borrow_mut(|s| {
+            s.approval_requests
+                .insert(approval_request_id, approval_request);
+
+            Ok(())
+        })
+    }
+
+    fn get_approval_config(&self) -> ApprovalConfig {
+        STATE.with_borrow(|s| s.config.get().clone())
+    }
+
+    fn set_approval_config(&self, config: ApprovalConfig) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.config
+                .set(config)
+                .map(|_| ())
+                .map_err(|_| ApiError::internal("Failed to set approval config"))
+        })
+    }
+}
+
+impl ApprovalRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ApprovalState {
+    approval_requests: ApprovalRequestMemory,
+    config: ApprovalConfigMemory,
+}
+
+impl Default for ApprovalState {
+    fn default() -> Self {
+        Self {
+            approval_requests: init_approval_requests(),
+            config: init_approval_config(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ApprovalState> = RefCell::new(ApprovalState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, repositories::SensitiveAction};
+    use rstest::*;
+
+    #[rstest]
+    fn create_and_get_approval_request() {
+        STATE.set(ApprovalState::default());
+
+        let repository = ApprovalRepositoryImpl::default();
+        let approval_request_id = repository
+            .create_approval_request(fixtures::approval_request_pending())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_approval_request_by_id(&approval_request_id),
+            Some(fixtures::approval_request_pending())
+        );
+        assert_eq!(
+            repository.get_approval_requests(),
+            vec![(approval_request_id, fixtures::approval_request_pending())]
+        );
+    }
+
+    #[rstest]
+    fn update_approval_request() {
+        STATE.set(ApprovalState::default());
+
+        let repository = ApprovalRepositoryImpl::default();
+        let approval_request_id = repository
+            .create_approval_request(fixtures::approval_request_pending())
+            .unwrap();
+
+        repository
+            .update_approval_request(approval_request_id, fixtures::approval_request_approved())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_approval_request_by_id(&approval_request_id),
+            Some(fixtures::approval_request_approved())
+        );
+    }
+
+    #[rstest]
+    fn update_approval_request_not_found() {
+        STATE.set(ApprovalState::default());
+
+        let repository = ApprovalRepositoryImpl::default();
+        let approval_request_id = fixtures::uuid_a();
+
+        let result = repository
+            .update_approval_request(approval_request_id, fixtures::approval_request_approved())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Approval request with id {} not found",
+                approval_request_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_and_set_approval_config() {
+        STATE.set(ApprovalState::default());
+
+        let repository = ApprovalRepositoryImpl::default();
+
+        assert_eq!(repository.get_approval_config(), ApprovalConfig::default());
+
+        let config = ApprovalConfig {
+            required_approvals: 3,
+            timeout_seconds: 3_600,
+            sensitive_actions: vec![SensitiveAction::SetUserRoles],
+        };
+        repository.set_approval_config(config.clone()).unwrap();
+
+        assert_eq!(repository.get_approval_config(), config);
+    }
+}
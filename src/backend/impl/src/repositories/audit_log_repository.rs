@@ -0,0 +1,264 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_audit_log, init_audit_log_timestamp_index, AuditLogEntry, AuditLogEntryId, AuditLogFilter,
+    AuditLogMemory, AuditLogTimestampIndexMemory, DateTime, LogTimestampKey, LogTimestampRange,
+};
+
+/// The audit log is append-only, entries can never be updated or removed.
+#[cfg_attr(test, mockall::automock)]
+pub trait AuditLogRepository {
+    /// Returns a page of the entries matching the filter,
+    /// from the most recent to the oldest one.
+    fn get_audit_log_entries(
+        &self,
+        filter: &AuditLogFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(AuditLogEntryId, AuditLogEntry)>;
+
+    fn count_audit_log_entries(&self, filter: &AuditLogFilter) -> u64;
+
+    /// Returns a page of the entries recorded in the given time range, both bounds included,
+    /// from the oldest to the most recent one.
+    fn get_audit_log_entries_by_timestamp(
+        &self,
+        after: Option<DateTime>,
+        before: Option<DateTime>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<(AuditLogEntryId, AuditLogEntry)>, ApiError>;
+
+    fn append_audit_log_entry(
+        &self,
+        audit_log_entry: AuditLogEntry,
+    ) -> Result<AuditLogEntryId, ApiError>;
+}
+
+pub struct AuditLogRepositoryImpl {}
+
+impl Default for AuditLogRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuditLogRepository for AuditLogRepositoryImpl {
+    fn get_audit_log_entries(
+        &self,
+        filter: &AuditLogFilter,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<(AuditLogEntryId, AuditLogEntry)> {
+        STATE.with_borrow(|s| {
+            (0..s.audit_log.len())
+                .rev()
+                .filter_map(|id| {
+                    // the None case should never happen
+                    s.audit_log
+                        .get(id)
+                        .map(|audit_log_entry| (id, audit_log_entry))
+                })
+                .filter(|(_, audit_log_entry)| filter.matches(audit_log_entry))
+                .skip(offset)
+                .take(limit)
+                .collect()
+        })
+    }
+
+    fn count_audit_log_entries(&self, filter: &AuditLogFilter) -> u64 {
+        STATE.with_borrow(|s| {
+            if filter.is_empty() {
+                return s.audit_log.len();
+            }
+
+            s.audit_log
+                .iter()
+                .filter(|audit_log_entry| filter.matches(audit_log_entry))
+                .count() as u64
+        })
+    }
+
+    fn get_audit_log_entries_by_timestamp(
+        &self,
+        after: Option<DateTime>,
+        before: Option<DateTime>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<(AuditLogEntryId, AuditLogEntry)>, ApiError> {
+        let range = LogTimestampRange::new(after, before)?;
+        let audit_log_entries = STATE.with_borrow(|s| {
+            s.audit_log_timestamp_index
+                .range(range)
+                .skip(offset)
+                .take(limit)
+                .filter_map(|(_, id)| {
+                    // the None case should never happen
+                    s.audit_log
+                        .get(id)
+                        .map(|audit_log_entry| (id, audit_log_entry))
+                })
+                .collect()
+        });
+        Ok(audit_log_entries)
+    }
+
+    fn append_audit_log_entry(
+        &self,
+        audit_log_entry: AuditLogEntry,
+    ) -> Result<AuditLogEntryId, ApiError> {
+        STATE.with_borrow_mut(|s| {
+            let id = s.audit_log.append(&audit_log_entry).map_err(|e| {
+                ApiError::internal(&format!("Cannot write audit log entry: {:?}", e))
+            })?;
+            let key = LogTimestampKey::new(audit_log_entry.timestamp, id)?;
+            s.audit_log_timestamp_index.insert(key, id);
+            Ok(id)
+        })
+    }
+}
+
+impl AuditLogRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct AuditLogState {
+    audit_log: AuditLogMemory,
+    audit_log_timestamp_index: AuditLogTimestampIndexMemory,
+}
+
+impl Default for AuditLogState {
+    fn default() -> Self {
+        Self {
+            audit_log: init_audit_log(),
+            audit_log_timestamp_index: init_audit_log_timestamp_index(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<AuditLogState> = RefCell::new(AuditLogState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, repositories::AuditAction};
+    use rstest::*;
+
+    #[rstest]
+    fn append_audit_log_entry() {
+        STATE.set(AuditLogState::default());
+
+        let repository = AuditLogRepositoryImpl::default();
+        let audit_log_entry_id = repository
+            .append_audit_log_entry(fixtures::audit_log_entry())
+            .unwrap();
+
+        let result = STATE.with_borrow(|s| s.audit_log.get(audit_log_entry_id));
+
+        assert_eq!(result, Some(fixtures::audit_log_entry()));
+    }
+
+    fn stored_audit_log_entries() -> Vec<(AuditLogEntryId, AuditLogEntry)> {
+        fixtures::audit_log_entries()
+            .into_iter()
+            .enumerate()
+            .map(|(id, audit_log_entry)| (id as AuditLogEntryId, audit_log_entry))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::no_filters(AuditLogFilter::default(), 0, 10, vec![1, 0], 2)]
+    #[case::action(
+        AuditLogFilter {
+            action: Some(AuditAction::SuspendUser),
+            ..Default::default()
+        },
+        0,
+        10,
+        vec![0],
+        1
+    )]
+    #[case::actor(
+        AuditLogFilter {
+            actor: Some(fixtures::principal_a()),
+            ..Default::default()
+        },
+        0,
+        10,
+        vec![1],
+        1
+    )]
+    #[case::pagination(AuditLogFilter::default(), 1, 1, vec![0], 2)]
+    fn get_audit_log_entries(
+        #[case] filter: AuditLogFilter,
+        #[case] offset: usize,
+        #[case] limit: usize,
+        #[case] expected_ids: Vec<usize>,
+        #[case] expected_total: u64,
+    ) {
+        STATE.set(AuditLogState::default());
+
+        let repository = AuditLogRepositoryImpl::default();
+        for audit_log_entry in fixtures::audit_log_entries() {
+            repository.append_audit_log_entry(audit_log_entry).unwrap();
+        }
+
+        let result = repository.get_audit_log_entries(&filter, offset, limit);
+
+        let stored_entries = stored_audit_log_entries();
+        assert_eq!(
+            result,
+            expected_ids
+                .into_iter()
+                .map(|id| stored_entries[id].clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(repository.count_audit_log_entries(&filter), expected_total);
+    }
+
+    #[rstest]
+    #[case::no_range(None, None, 0, 10, vec![0, 1])]
+    #[case::after(Some(fixtures::date_time_a()), None, 0, 10, vec![1])]
+    #[case::before(None, Some(fixtures::date_time_b()), 0, 10, vec![0])]
+    #[case::empty_range(
+        Some(fixtures::date_time_b().add(chrono::Duration::seconds(1))),
+        Some(fixtures::date_time_a().sub(chrono::Duration::seconds(1))),
+        0,
+        10,
+        vec![]
+    )]
+    #[case::pagination(None, None, 1, 1, vec![1])]
+    fn get_audit_log_entries_by_timestamp(
+        #[case] after: Option<DateTime>,
+        #[case] before: Option<DateTime>,
+        #[case] offset: usize,
+        #[case] limit: usize,
+        #[case] expected_ids: Vec<usize>,
+    ) {
+        STATE.set(AuditLogState::default());
+
+        let repository = AuditLogRepositoryImpl::default();
+        for audit_log_entry in fixtures::audit_log_entries() {
+            repository.append_audit_log_entry(audit_log_entry).unwrap();
+        }
+
+        let result = repository
+            .get_audit_log_entries_by_timestamp(after, before, offset, limit)
+            .unwrap();
+
+        let stored_entries = stored_audit_log_entries();
+        assert_eq!(
+            result,
+            expected_ids
+                .into_iter()
+                .map(|id| stored_entries[id].clone())
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+use candid::Principal;
+
+use super::{
+    init_comment_history_id, init_comment_target_index, init_comments, init_comments_history,
+    Comment, CommentHistoryEntry, CommentHistoryIdMemory, CommentHistoryKey, CommentHistoryMemory,
+    CommentHistoryRange, CommentId, CommentMemory, CommentTargetIndexMemory, CommentTargetKey,
+    CommentTargetRange, Uuid,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait CommentRepository {
+    fn get_comment_by_id(&self, comment_id: &CommentId) -> Option<Comment>;
+
+    /// Returns the comments on the given target, ordered by creation date.
+    fn get_comments_by_target_id(
+        &self,
+        target_id: Uuid,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<(CommentId, Comment)>, ApiError>;
+
+    fn count_comments_by_target_id(&self, target_id: Uuid) -> Result<u64, ApiError>;
+
+    fn get_comment_history_by_id(
+        &self,
+        comment_id: CommentId,
+    ) -> Result<Vec<CommentHistoryEntry>, ApiError>;
+
+    fn create_comment(
+        &self,
+        calling_principal: Principal,
+        comment: Comment,
+    ) -> Result<CommentId, ApiError>;
+
+    fn update_comment(
+        &self,
+        calling_principal: Principal,
+        comment_id: CommentId,
+        comment: Comment,
+    ) -> Result<(), ApiError>;
+
+    fn delete_comment(
+        &self,
+        calling_principal: Principal,
+        comment_id: CommentId,
+        comment: Comment,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct CommentRepositoryImpl {}
+
+impl Default for CommentRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommentRepository for CommentRepositoryImpl {
+    fn get_comment_by_id(&self, comment_id: &CommentId) -> Option<Comment> {
+        STATE.with_borrow(|s| s.comments.get(comment_id))
+    }
+
+    fn get_comments_by_target_id(
+        &self,
+        target_id: Uuid,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<(CommentId, Comment)>, ApiError> {
+        let range = CommentTargetRange::new(target_id)?;
+
+        let comments = STATE.with_borrow(|s| {
+            s.target_index
+                .range(range)
+                .skip(offset)
+                .take(limit)
+                .filter_map(|(_, comment_id)| {
+                    // the None case should never happen
+                    s.comments
+                        .get(&comment_id)
+                        .map(|comment| (comment_id, comment))
+                })
+                .collect()
+        });
+
+        Ok(comments)
+    }
+
+    fn count_comments_by_target_id(&self, target_id: Uuid) -> Result<u64, ApiError> {
+        let range = CommentTargetRange::new(target_id)?;
+
+        let count = STATE.with_borrow(|s| s.target_index.range(range).count());
+
+        Ok(count as u64)
+    }
+
+    fn get_comment_history_by_id(
+        &self,
+        comment_id: CommentId,
+    ) -> Result<Vec<CommentHistoryEntry>, ApiError> {
+        STATE.with_borrow(|s| {
+            Ok(s.comments_history
+                .range(CommentHistoryRange::new(comment_id)?)
+                .map(|(_, entry)| entry)
+                .collect())
+        })
+    }
+
+    fn create_comment(
+        &self,
+        calling_principal: Principal,
+        comment: Comment,
+    ) -> Result<CommentId, ApiError> {
+        let comment_id = CommentId::new();
+        let target_key =
+            CommentTargetKey::new(comment.target.id(), comment.created_at, comment_id)?;
+        let history_entry_id = Self::get_next_history_id()?;
+
+        STATE.with_borrow_mut(|s| {
+            s.comments.insert(comment_id, comment.clone());
+            s.target_index.insert(target_key, comment_id);
+
+            let history_entry = CommentHistoryEntry::create_action(calling_principal, comment)?;
+            s.comments_history.insert(
+                CommentHistoryKey::new(comment_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })?;
+
+        Ok(comment_id)
+    }
+
+    fn update_comment(
+        &self,
+        calling_principal: Principal,
+        comment_id: CommentId,
+        comment: Comment,
+    ) -> Result<(), ApiError> {
+        self.assert_comment_exists(&comment_id)?;
+        let history_entry = CommentHistoryEntry::update_action(calling_principal, comment.clone())?;
+
+        self.save_comment(comment_id, comment, history_entry)
+    }
+
+    fn delete_comment(
+        &self,
+        calling_principal: Principal,
+        comment_id: CommentId,
+        comment: Comment,
+    ) -> Result<(), ApiError> {
+        self.assert_comment_exists(&comment_id)?;
+        let history_entry = CommentHistoryEntry::delete_action(calling_principal, comment.clone())?;
+
+        self.save_comment(comment_id, comment, history_entry)
+    }
+}
+
+impl CommentRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn assert_comment_exists(&self, comment_id: &CommentId) -> Result<(), ApiError> {
+        self.get_comment_by_id(comment_id).ok_or_else(|| {
+            ApiError::not_found(&format!("Comment with Id {} not found", comment_id))
+        })?;
+
+        Ok(())
+    }
+
+    fn save_comment(
+        &self,
+        comment_id: CommentId,
+        comment: Comment,
+        history_entry: CommentHistoryEntry,
+    ) -> Result<(), ApiError> {
+        let history_entry_id = Self::get_next_history_id()?;
+
+        STATE.with_borrow_mut(|s| {
+            s.comments.insert(comment_id, comment);
+            s.comments_history.insert(
+                CommentHistoryKey::new(comment_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })
+    }
+
+    fn get_next_history_id() -> Result<u128, ApiError> {
+        STATE.with_borrow_mut(|s| {
+            // Same as for the user profile history, this id prevents entries written for the
+            // same comment within the same round from overwriting each other.
+            let next_id = s.comments_history_id.get().wrapping_add(1);
+
+            s.comments_history_id.set(next_id).map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to set comment history id. Next id: {}",
+                    next_id
+                ))
+            })?;
+
+            Ok(next_id)
+        })
+    }
+}
+
+struct CommentState {
+    comments: CommentMemory,
+    target_index: CommentTargetIndexMemory,
+    comments_history: CommentHistoryMemory,
+    comments_history_id: CommentHistoryIdMemory,
+}
+
+impl Default for CommentState {
+    fn default() -> Self {
+        Self {
+            comments: init_comments(),
+            target_index: init_comment_target_index(),
+            comments_history: init_comments_history(),
+            comments_history_id: init_comment_history_id(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<CommentState> = RefCell::new(CommentState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b},
+        repositories::{CommentTarget, HistoryAction},
+    };
+    use rstest::*;
+
+    #[rstest]
+    #[case::proposal(fixtures::comment_proposal())]
+    #[case::reply(fixtures::comment_reply())]
+    #[case::deleted(fixtures::comment_deleted())]
+    fn create_and_get_comment_by_id(#[case] comment: Comment) {
+        STATE.set(CommentState::default());
+
+        let repository = CommentRepositoryImpl::default();
+        let comment_id = repository
+            .create_comment(fixtures::principal_a(), comment.clone())
+            .unwrap();
+
+        let result = repository.get_comment_by_id(&comment_id);
+
+        assert_eq!(result, Some(comment));
+    }
+
+    #[rstest]
+    fn get_comments_by_target_id() {
+        STATE.set(CommentState::default());
+
+        let principal = fixtures::principal_a();
+        let repository = CommentRepositoryImpl::default();
+
+        let comments = [
+            Comment {
+                target: CommentTarget::Proposal(uuid_a()),
+                created_at: fixtures::date_time_a(),
+                ..fixtures::comment_proposal()
+            },
+            Comment {
+                target: CommentTarget::Proposal(uuid_a()),
+                created_at: fixtures::date_time_b(),
+                ..fixtures::comment_proposal()
+            },
+            Comment {
+                target: CommentTarget::Proposal(uuid_a()),
+                created_at: fixtures::date_time_c(),
+                ..fixtures::comment_proposal()
+            },
+        ];
+        let comment_ids: Vec<_> = comments
+            .iter()
+            .map(|comment| {
+                repository
+                    .create_comment(principal, comment.clone())
+                    .unwrap()
+            })
+            .collect();
+        repository
+            .create_comment(
+                principal,
+                Comment {
+                    target: CommentTarget::Proposal(uuid_b()),
+                    ..fixtures::comment_proposal()
+                },
+            )
+            .unwrap();
+
+        let first_page = repository
+            .get_comments_by_target_id(uuid_a(), 0, 2)
+            .unwrap();
+        let second_page = repository
+            .get_comments_by_target_id(uuid_a(), 2, 2)
+            .unwrap();
+        let count = repository.count_comments_by_target_id(uuid_a()).unwrap();
+
+        // ordered by creation date: c (1998), b (2014), a (2021)
+        assert_eq!(
+            first_page,
+            vec![
+                (comment_ids[2], comments[2].clone()),
+                (comment_ids[1], comments[1].clone()),
+            ]
+        );
+        assert_eq!(second_page, vec![(comment_ids[0], comments[0].clone())]);
+        assert_eq!(count, 3);
+    }
+
+    #[rstest]
+    fn update_and_delete_comment_history() {
+        STATE.set(CommentState::default());
+
+        let principal = fixtures::principal_a();
+        let admin_principal = fixtures::principal_b();
+        let comment = fixtures::comment_proposal();
+        let updated_comment = fixtures::comment_reply();
+        let deleted_comment = fixtures::comment_deleted();
+
+        let repository = CommentRepositoryImpl::default();
+        let comment_id = repository
+            .create_comment(principal, comment.clone())
+            .unwrap();
+
+        repository
+            .update_comment(principal, comment_id, updated_comment.clone())
+            .unwrap();
+        repository
+            .delete_comment(admin_principal, comment_id, deleted_comment.clone())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_comment_by_id(&comment_id),
+            Some(deleted_comment.clone())
+        );
+
+        let history = repository.get_comment_history_by_id(comment_id).unwrap();
+        let history: Vec<_> = history
+            .into_iter()
+            .map(|entry| (entry.action, entry.principal, entry.data))
+            .collect();
+
+        assert_eq!(
+            history,
+            vec![
+                (HistoryAction::Create, principal, comment),
+                (HistoryAction::Update, principal, updated_comment),
+                (HistoryAction::Delete, admin_principal, deleted_comment),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn update_comment_not_found() {
+        STATE.set(CommentState::default());
+
+        let repository = CommentRepositoryImpl::default();
+        let comment_id = fixtures::uuid();
+
+        let result = repository
+            .update_comment(
+                fixtures::principal_a(),
+                comment_id,
+                fixtures::comment_proposal(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!("Comment with Id {} not found", comment_id))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_commit_claims, CommitClaim, CommitClaimKey, CommitClaimMemory, CommitClaimProposalRange,
+    CommitSha, ProposalId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait CommitClaimRepository {
+    fn get_commit_claim(
+        &self,
+        proposal_id: ProposalId,
+        commit_sha: CommitSha,
+    ) -> Result<Option<CommitClaim>, ApiError>;
+
+    fn get_commit_claims_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<CommitClaim>, ApiError>;
+
+    fn get_all_commit_claims(&self) -> Vec<CommitClaim>;
+
+    /// Creates the claim or replaces the existing claim on the same commit.
+    fn upsert_commit_claim(&self, commit_claim: CommitClaim) -> Result<(), ApiError>;
+
+    fn delete_commit_claim(
+        &self,
+        proposal_id: ProposalId,
+        commit_sha: CommitSha,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct CommitClaimRepositoryImpl {}
+
+impl Default for CommitClaimRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommitClaimRepository for CommitClaimRepositoryImpl {
+    fn get_commit_claim(
+        &self,
+        proposal_id: ProposalId,
+        commit_sha: CommitSha,
+    ) -> Result<Option<CommitClaim>, ApiError> {
+        let key = CommitClaimKey::new(proposal_id, commit_sha)?;
+
+        Ok(STATE.with_borrow(|s| s.commit_claims.get(&key)))
+    }
+
+    fn get_commit_claims_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<CommitClaim>, ApiError> {
+        let range = CommitClaimProposalRange::new(proposal_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.commit_claims
+                .range(range)
+                .map(|(_, commit_claim)| commit_claim)
+                .collect()
+        }))
+    }
+
+    fn get_all_commit_claims(&self) -> Vec<CommitClaim> {
+        STATE.with_borrow(|s| {
+            s.commit_claims
+                .iter()
+                .map(|(_, commit_claim)| commit_claim)
+                .collect()
+        })
+    }
+
+    fn upsert_commit_claim(&self, commit_claim: CommitClaim) -> Result<(), ApiError> {
+        let key = CommitClaimKey::new(commit_claim.proposal_id, commit_claim.commit_sha)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.commit_claims.insert(key, commit_claim);
+        });
+
+        Ok(())
+    }
+
+    fn delete_commit_claim(
+        &self,
+        proposal_id: ProposalId,
+        commit_sha: CommitSha,
+    ) -> Result<(), ApiError> {
+        let key = CommitClaimKey::new(proposal_id, commit_sha)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.commit_claims.remove(&key).ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Commit claim for commit {} of proposal with Id {} not found",
+                    commit_sha, proposal_id
+                ))
+            })
+        })?;
+
+        Ok(())
+    }
+}
+
+impl CommitClaimRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct CommitClaimState {
+    commit_claims: CommitClaimMemory,
+}
+
+impl Default for CommitClaimState {
+    fn default() -> Self {
+        Self {
+            commit_claims: init_commit_claims(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<CommitClaimState> = RefCell::new(CommitClaimState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, commit_sha_a, commit_sha_b, uuid_a, uuid_b};
+    use rstest::*;
+
+    #[rstest]
+    fn upsert_and_get_commit_claim() {
+        STATE.set(CommitClaimState::default());
+
+        let commit_claim = fixtures::commit_claim();
+
+        let repository = CommitClaimRepositoryImpl::default();
+        repository
+            .upsert_commit_claim(commit_claim.clone())
+            .unwrap();
+
+        let result = repository
+            .get_commit_claim(commit_claim.proposal_id, commit_claim.commit_sha)
+            .unwrap();
+
+        assert_eq!(result, Some(commit_claim));
+    }
+
+    #[rstest]
+    fn upsert_commit_claim_replaces_existing() {
+        STATE.set(CommitClaimState::default());
+
+        let original_commit_claim = fixtures::commit_claim();
+        let updated_commit_claim = CommitClaim {
+            user_id: uuid_b(),
+            ..fixtures::commit_claim()
+        };
+
+        let repository = CommitClaimRepositoryImpl::default();
+        repository
+            .upsert_commit_claim(original_commit_claim.clone())
+            .unwrap();
+        repository
+            .upsert_commit_claim(updated_commit_claim.clone())
+            .unwrap();
+
+        let result = repository
+            .get_commit_claims_by_proposal_id(original_commit_claim.proposal_id)
+            .unwrap();
+
+        assert_eq!(result, vec![updated_commit_claim]);
+    }
+
+    #[rstest]
+    fn get_commit_claims_by_proposal_id() {
+        STATE.set(CommitClaimState::default());
+
+        let repository = CommitClaimRepositoryImpl::default();
+
+        for (proposal_id, commit_sha) in [
+            (uuid_a(), commit_sha_a()),
+            (uuid_a(), commit_sha_b()),
+            (uuid_b(), commit_sha_a()),
+        ] {
+            repository
+                .upsert_commit_claim(CommitClaim {
+                    proposal_id,
+                    commit_sha,
+                    ..fixtures::commit_claim()
+                })
+                .unwrap();
+        }
+
+        let result = repository
+            .get_commit_claims_by_proposal_id(uuid_a())
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                CommitClaim {
+                    proposal_id: uuid_a(),
+                    commit_sha: commit_sha_a(),
+                    ..fixtures::commit_claim()
+                },
+                CommitClaim {
+                    proposal_id: uuid_a(),
+                    commit_sha: commit_sha_b(),
+                    ..fixtures::commit_claim()
+                },
+            ]
+        );
+        assert_eq!(repository.get_all_commit_claims().len(), 3);
+    }
+
+    #[rstest]
+    fn delete_commit_claim() {
+        STATE.set(CommitClaimState::default());
+
+        let commit_claim = fixtures::commit_claim();
+
+        let repository = CommitClaimRepositoryImpl::default();
+        repository
+            .upsert_commit_claim(commit_claim.clone())
+            .unwrap();
+
+        repository
+            .delete_commit_claim(commit_claim.proposal_id, commit_claim.commit_sha)
+            .unwrap();
+
+        let result = repository
+            .get_commit_claim(commit_claim.proposal_id, commit_claim.commit_sha)
+            .unwrap();
+
+        assert_eq!(result, None);
+    }
+
+    #[rstest]
+    fn delete_commit_claim_not_found() {
+        STATE.set(CommitClaimState::default());
+
+        let repository = CommitClaimRepositoryImpl::default();
+
+        let result = repository
+            .delete_commit_claim(uuid_a(), commit_sha_a())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Commit claim for commit {} of proposal with Id {} not found",
+                commit_sha_a(),
+                uuid_a()
+            ))
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_endorsement_proposal_review_id_index, init_endorsements, Endorsement, EndorsementId,
+    EndorsementMemory, EndorsementProposalReviewIdIndexMemory, EndorsementProposalReviewKey,
+    EndorsementProposalReviewRange, ProposalReviewId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait EndorsementRepository {
+    fn get_endorsement_by_id(&self, endorsement_id: &EndorsementId) -> Option<Endorsement>;
+
+    fn get_endorsements_by_proposal_review_id(
+        &self,
+        proposal_review_id: ProposalReviewId,
+    ) -> Result<Vec<(EndorsementId, Endorsement)>, ApiError>;
+
+    fn create_endorsement(&self, endorsement: Endorsement) -> Result<EndorsementId, ApiError>;
+
+    fn delete_endorsement(&self, endorsement_id: &EndorsementId) -> Result<(), ApiError>;
+}
+
+pub struct EndorsementRepositoryImpl {}
+
+impl Default for EndorsementRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EndorsementRepository for EndorsementRepositoryImpl {
+    fn get_endorsement_by_id(&self, endorsement_id: &EndorsementId) -> Option<Endorsement> {
+        STATE.with_borrow(|s| s.endorsements.get(endorsement_id))
+    }
+
+    fn get_endorsements_by_proposal_review_id(
+        &self,
+        proposal_review_id: ProposalReviewId,
+    ) -> Result<Vec<(EndorsementId, Endorsement)>, ApiError> {
+        let range = EndorsementProposalReviewRange::new(proposal_review_id)?;
+
+        let endorsements = STATE.with_borrow(|s| {
+            s.proposal_review_id_index
+                .range(range)
+                .filter_map(|(_, endorsement_id)| {
+                    // the None case should never happen
+                    s.endorsements
+                        .get(&endorsement_id)
+                        .map(|endorsement| (endorsement_id, endorsement))
+                })
+                .collect()
+        });
+
+        Ok(endorsements)
+    }
+
+    fn create_endorsement(&self, endorsement: Endorsement) -> Result<EndorsementId, ApiError> {
+        let endorsement_id = EndorsementId::new();
+        let proposal_review_key =
+            EndorsementProposalReviewKey::new(endorsement.proposal_review_id, endorsement_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.endorsements.insert(endorsement_id, endorsement);
+            s.proposal_review_id_index
+                .insert(proposal_review_key, endorsement_id);
+        });
+
+        Ok(endorsement_id)
+    }
+
+    fn delete_endorsement(&self, endorsement_id: &EndorsementId) -> Result<(), ApiError> {
+        let endorsement = self.get_endorsement_by_id(endorsement_id).ok_or_else(|| {
+            ApiError::not_found(&format!("Endorsement with Id {} not found", endorsement_id))
+        })?;
+        let proposal_review_key =
+            EndorsementProposalReviewKey::new(endorsement.proposal_review_id, *endorsement_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.endorsements.remove(endorsement_id);
+            s.proposal_review_id_index.remove(&proposal_review_key);
+        });
+
+        Ok(())
+    }
+}
+
+impl EndorsementRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct EndorsementState {
+    endorsements: EndorsementMemory,
+    proposal_review_id_index: EndorsementProposalReviewIdIndexMemory,
+}
+
+impl Default for EndorsementState {
+    fn default() -> Self {
+        Self {
+            endorsements: init_endorsements(),
+            proposal_review_id_index: init_endorsement_proposal_review_id_index(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<EndorsementState> = RefCell::new(EndorsementState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, uuid_a, uuid_b};
+    use rstest::*;
+
+    #[rstest]
+    #[case::proposal_review(fixtures::endorsement_proposal_review())]
+    #[case::proposal_review_commit(fixtures::endorsement_proposal_review_commit())]
+    fn create_and_get_endorsement_by_id(#[case] endorsement: Endorsement) {
+        STATE.set(EndorsementState::default());
+
+        let repository = EndorsementRepositoryImpl::default();
+        let endorsement_id = repository.create_endorsement(endorsement.clone()).unwrap();
+
+        let result = repository.get_endorsement_by_id(&endorsement_id);
+
+        assert_eq!(result, Some(endorsement));
+    }
+
+    #[rstest]
+    fn get_endorsements_by_proposal_review_id() {
+        STATE.set(EndorsementState::default());
+
+        let repository = EndorsementRepositoryImpl::default();
+
+        let endorsement_id = repository
+            .create_endorsement(Endorsement {
+                proposal_review_id: uuid_a(),
+                ..fixtures::endorsement_proposal_review()
+            })
+            .unwrap();
+        repository
+            .create_endorsement(Endorsement {
+                proposal_review_id: uuid_b(),
+                ..fixtures::endorsement_proposal_review()
+            })
+            .unwrap();
+
+        let result = repository
+            .get_endorsements_by_proposal_review_id(uuid_a())
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![(
+                endorsement_id,
+                Endorsement {
+                    proposal_review_id: uuid_a(),
+                    ..fixtures::endorsement_proposal_review()
+                }
+            )]
+        );
+    }
+
+    #[rstest]
+    fn delete_endorsement() {
+        STATE.set(EndorsementState::default());
+
+        let endorsement = fixtures::endorsement_proposal_review();
+
+        let repository = EndorsementRepositoryImpl::default();
+        let endorsement_id = repository.create_endorsement(endorsement.clone()).unwrap();
+
+        repository.delete_endorsement(&endorsement_id).unwrap();
+
+        assert_eq!(repository.get_endorsement_by_id(&endorsement_id), None);
+        assert_eq!(
+            repository
+                .get_endorsements_by_proposal_review_id(endorsement.proposal_review_id)
+                .unwrap(),
+            vec![]
+        );
+    }
+
+    #[rstest]
+    fn delete_endorsement_not_found() {
+        STATE.set(EndorsementState::default());
+
+        let repository = EndorsementRepositoryImpl::default();
+        let endorsement_id = fixtures::uuid();
+
+        let result = repository.delete_endorsement(&endorsement_id).unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!("Endorsement with Id {} not found", endorsement_id))
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use ic_stable_structures::{BTreeMap, Cell};
+
+use crate::repositories::{AdminSyncConfig, DateTime, UserId};
+
+use super::{Memory, ADMIN_SYNC_CONFIG_MEMORY_ID, CONTROLLER_ADMINS_MEMORY_ID, MEMORY_MANAGER};
+
+pub type AdminSyncConfigMemory = Cell<AdminSyncConfig, Memory>;
+pub type ControllerAdminMemory = BTreeMap<UserId, DateTime, Memory>;
+
+pub fn init_admin_sync_config() -> AdminSyncConfigMemory {
+    AdminSyncConfigMemory::init(get_admin_sync_config_memory(), AdminSyncConfig::default()).unwrap()
+}
+
+pub fn init_controller_admins() -> ControllerAdminMemory {
+    ControllerAdminMemory::init(get_controller_admins_memory())
+}
+
+fn get_admin_sync_config_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(ADMIN_SYNC_CONFIG_MEMORY_ID))
+}
+
+fn get_controller_admins_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(CONTROLLER_ADMINS_MEMORY_ID))
+}
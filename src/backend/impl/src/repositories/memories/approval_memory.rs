@@ -0,0 +1,24 @@
+use ic_stable_structures::{BTreeMap, Cell};
+
+use crate::repositories::{ApprovalConfig, ApprovalRequest, ApprovalRequestId};
+
+use super::{Memory, APPROVAL_CONFIG_MEMORY_ID, APPROVAL_REQUESTS_MEMORY_ID, MEMORY_MANAGER};
+
+pub type ApprovalRequestMemory = BTreeMap<ApprovalRequestId, ApprovalRequest, Memory>;
+pub type ApprovalConfigMemory = Cell<ApprovalConfig, Memory>;
+
+pub fn init_approval_requests() -> ApprovalRequestMemory {
+    ApprovalRequestMemory::init(get_approval_requests_memory())
+}
+
+pub fn init_approval_config() -> ApprovalConfigMemory {
+    ApprovalConfigMemory::init(get_approval_config_memory(), ApprovalConfig::default()).unwrap()
+}
+
+fn get_approval_requests_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(APPROVAL_REQUESTS_MEMORY_ID))
+}
+
+fn get_approval_config_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(APPROVAL_CONFIG_MEMORY_ID))
+}
@@ -0,0 +1,31 @@
+use ic_stable_structures::{BTreeMap, Log};
+
+use crate::repositories::{AuditLogEntry, AuditLogEntryId, LogTimestampKey};
+
+use super::{
+    Memory, AUDIT_LOG_INDEX_MEMORY_ID, AUDIT_LOG_MEMORY_ID, AUDIT_LOG_TIMESTAMP_INDEX_MEMORY_ID,
+    MEMORY_MANAGER,
+};
+
+pub type AuditLogMemory = Log<AuditLogEntry, Memory, Memory>;
+pub type AuditLogTimestampIndexMemory = BTreeMap<LogTimestampKey, AuditLogEntryId, Memory>;
+
+pub fn init_audit_log() -> AuditLogMemory {
+    AuditLogMemory::init(get_audit_log_index_memory(), get_audit_log_memory()).unwrap()
+}
+
+pub fn init_audit_log_timestamp_index() -> AuditLogTimestampIndexMemory {
+    AuditLogTimestampIndexMemory::init(get_audit_log_timestamp_index_memory())
+}
+
+fn get_audit_log_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(AUDIT_LOG_INDEX_MEMORY_ID))
+}
+
+fn get_audit_log_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(AUDIT_LOG_MEMORY_ID))
+}
+
+fn get_audit_log_timestamp_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(AUDIT_LOG_TIMESTAMP_INDEX_MEMORY_ID))
+}
@@ -0,0 +1,47 @@
+use ic_stable_structures::{BTreeMap, Cell};
+
+use crate::repositories::{
+    Comment, CommentHistoryEntry, CommentHistoryKey, CommentId, CommentTargetKey,
+};
+
+use super::{
+    Memory, COMMENTS_HISTORY_ID_MEMORY_ID, COMMENTS_HISTORY_MEMORY_ID, COMMENTS_MEMORY_ID,
+    COMMENTS_TARGET_INDEX_MEMORY_ID, MEMORY_MANAGER,
+};
+
+pub type CommentMemory = BTreeMap<CommentId, Comment, Memory>;
+pub type CommentTargetIndexMemory = BTreeMap<CommentTargetKey, CommentId, Memory>;
+pub type CommentHistoryMemory = BTreeMap<CommentHistoryKey, CommentHistoryEntry, Memory>;
+pub type CommentHistoryIdMemory = Cell<u128, Memory>;
+
+pub fn init_comments() -> CommentMemory {
+    CommentMemory::init(get_comments_memory())
+}
+
+pub fn init_comment_target_index() -> CommentTargetIndexMemory {
+    CommentTargetIndexMemory::init(get_comment_target_index_memory())
+}
+
+pub fn init_comments_history() -> CommentHistoryMemory {
+    CommentHistoryMemory::init(get_comments_history_memory())
+}
+
+pub fn init_comment_history_id() -> CommentHistoryIdMemory {
+    CommentHistoryIdMemory::init(get_comment_history_id_memory(), 0).unwrap()
+}
+
+fn get_comments_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(COMMENTS_MEMORY_ID))
+}
+
+fn get_comment_target_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(COMMENTS_TARGET_INDEX_MEMORY_ID))
+}
+
+fn get_comments_history_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(COMMENTS_HISTORY_MEMORY_ID))
+}
+
+fn get_comment_history_id_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(COMMENTS_HISTORY_ID_MEMORY_ID))
+}
@@ -0,0 +1,15 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{CommitClaim, CommitClaimKey};
+
+use super::{Memory, COMMIT_CLAIMS_MEMORY_ID, MEMORY_MANAGER};
+
+pub type CommitClaimMemory = BTreeMap<CommitClaimKey, CommitClaim, Memory>;
+
+pub fn init_commit_claims() -> CommitClaimMemory {
+    CommitClaimMemory::init(get_commit_claims_memory())
+}
+
+fn get_commit_claims_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(COMMIT_CLAIMS_MEMORY_ID))
+}
@@ -0,0 +1,30 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{Endorsement, EndorsementId, EndorsementProposalReviewKey};
+
+use super::{
+    Memory, ENDORSEMENTS_MEMORY_ID, ENDORSEMENTS_PROPOSAL_REVIEW_ID_INDEX_MEMORY_ID, MEMORY_MANAGER,
+};
+
+pub type EndorsementMemory = BTreeMap<EndorsementId, Endorsement, Memory>;
+pub type EndorsementProposalReviewIdIndexMemory =
+    BTreeMap<EndorsementProposalReviewKey, EndorsementId, Memory>;
+
+pub fn init_endorsements() -> EndorsementMemory {
+    EndorsementMemory::init(get_endorsements_memory())
+}
+
+pub fn init_endorsement_proposal_review_id_index() -> EndorsementProposalReviewIdIndexMemory {
+    EndorsementProposalReviewIdIndexMemory::init(get_endorsement_proposal_review_id_index_memory())
+}
+
+fn get_endorsements_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(ENDORSEMENTS_MEMORY_ID))
+}
+
+fn get_endorsement_proposal_review_id_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| {
+        m.borrow()
+            .get(ENDORSEMENTS_PROPOSAL_REVIEW_ID_INDEX_MEMORY_ID)
+    })
+}
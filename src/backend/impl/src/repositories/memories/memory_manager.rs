@@ -29,3 +29,39 @@ pub(super) const IMAGES_MEMORY_ID: MemoryId = MemoryId::new(13);
 pub(super) const PROPOSAL_NERVOUS_SYSTEM_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new(14);
 pub(super) const PROPOSAL_TIMESTAMP_INDEX_MEMORY_ID: MemoryId = MemoryId::new(15);
 pub(super) const LOGS_TIMESTAMP_LEVEL_INDEX_MEMORY_ID: MemoryId = MemoryId::new(16);
+// memory ID 17 is unused, attachments are stored in the images memory
+pub(super) const REVIEWER_ASSIGNMENTS_MEMORY_ID: MemoryId = MemoryId::new(18);
+pub(super) const REVIEWER_ASSIGNMENTS_PROPOSAL_ID_USER_ID_INDEX_MEMORY_ID: MemoryId =
+    MemoryId::new(19);
+pub(super) const REVIEWER_ASSIGNMENTS_USER_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new(20);
+pub(super) const REVIEWER_ASSIGNMENT_CONFIG_MEMORY_ID: MemoryId = MemoryId::new(21);
+pub(super) const COMMIT_CLAIMS_MEMORY_ID: MemoryId = MemoryId::new(22);
+pub(super) const REVIEW_CONFLICTS_MEMORY_ID: MemoryId = MemoryId::new(23);
+pub(super) const REVIEW_CONFLICTS_PROPOSAL_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new(24);
+pub(super) const ENDORSEMENTS_MEMORY_ID: MemoryId = MemoryId::new(25);
+pub(super) const ENDORSEMENTS_PROPOSAL_REVIEW_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new(26);
+pub(super) const COMMENTS_MEMORY_ID: MemoryId = MemoryId::new(27);
+pub(super) const COMMENTS_TARGET_INDEX_MEMORY_ID: MemoryId = MemoryId::new(28);
+pub(super) const COMMENTS_HISTORY_MEMORY_ID: MemoryId = MemoryId::new(29);
+pub(super) const COMMENTS_HISTORY_ID_MEMORY_ID: MemoryId = MemoryId::new(30);
+pub(super) const REVIEW_SCORES_MEMORY_ID: MemoryId = MemoryId::new(31);
+pub(super) const REVIEW_SCORES_USER_ID_INDEX_MEMORY_ID: MemoryId = MemoryId::new(32);
+pub(super) const REWARD_PERIODS_MEMORY_ID: MemoryId = MemoryId::new(33);
+pub(super) const REWARD_STATEMENTS_MEMORY_ID: MemoryId = MemoryId::new(34);
+pub(super) const REWARD_PAYOUTS_MEMORY_ID: MemoryId = MemoryId::new(35);
+pub(super) const NEURON_VERIFICATIONS_MEMORY_ID: MemoryId = MemoryId::new(36);
+pub(super) const VOTE_ALIGNMENTS_MEMORY_ID: MemoryId = MemoryId::new(37);
+pub(super) const REVIEWER_APPLICATIONS_MEMORY_ID: MemoryId = MemoryId::new(38);
+pub(super) const USER_ROLES_MEMORY_ID: MemoryId = MemoryId::new(39);
+pub(super) const PRINCIPAL_LINK_CODES_MEMORY_ID: MemoryId = MemoryId::new(40);
+pub(super) const PRINCIPAL_LINK_HISTORY_MEMORY_ID: MemoryId = MemoryId::new(41);
+pub(super) const USER_STATUSES_MEMORY_ID: MemoryId = MemoryId::new(42);
+pub(super) const USERNAME_INDEX_MEMORY_ID: MemoryId = MemoryId::new(43);
+pub(super) const ADMIN_SYNC_CONFIG_MEMORY_ID: MemoryId = MemoryId::new(44);
+pub(super) const CONTROLLER_ADMINS_MEMORY_ID: MemoryId = MemoryId::new(45);
+pub(super) const APPROVAL_REQUESTS_MEMORY_ID: MemoryId = MemoryId::new(46);
+pub(super) const APPROVAL_CONFIG_MEMORY_ID: MemoryId = MemoryId::new(47);
+pub(super) const AUDIT_LOG_INDEX_MEMORY_ID: MemoryId = MemoryId::new(48);
+pub(super) const AUDIT_LOG_MEMORY_ID: MemoryId = MemoryId::new(49);
+pub(super) const AUDIT_LOG_TIMESTAMP_INDEX_MEMORY_ID: MemoryId = MemoryId::new(50);
+pub(super) const USER_PRINCIPAL_INDEX_MEMORY_ID: MemoryId = MemoryId::new(51);
@@ -1,16 +1,46 @@
+mod admin_sync_memory;
+mod approval_memory;
+mod audit_log_memory;
+mod comment_memory;
+mod commit_claim_memory;
+mod endorsement_memory;
 mod image_memory;
 mod log_memory;
 mod memory_manager;
+mod neuron_verification_memory;
+mod principal_link_memory;
 mod proposal_memory;
 mod proposal_review_commit_memory;
 mod proposal_review_memory;
+mod review_conflict_memory;
+mod review_score_memory;
+mod reviewer_application_memory;
+mod reviewer_assignment_memory;
+mod reward_memory;
+mod role_memory;
 mod user_profile_memory;
+mod vote_alignment_memory;
 
 use memory_manager::*;
 
+pub(super) use admin_sync_memory::*;
+pub(super) use approval_memory::*;
+pub(super) use audit_log_memory::*;
+pub(super) use comment_memory::*;
+pub(super) use commit_claim_memory::*;
+pub(super) use endorsement_memory::*;
 pub(super) use image_memory::*;
 pub(super) use log_memory::*;
+pub(super) use neuron_verification_memory::*;
+pub(super) use principal_link_memory::*;
 pub(super) use proposal_memory::*;
 pub(super) use proposal_review_commit_memory::*;
 pub(super) use proposal_review_memory::*;
+pub(super) use review_conflict_memory::*;
+pub(super) use review_score_memory::*;
+pub(super) use reviewer_application_memory::*;
+pub(super) use reviewer_assignment_memory::*;
+pub(super) use reward_memory::*;
+pub(super) use role_memory::*;
 pub(super) use user_profile_memory::*;
+pub(super) use vote_alignment_memory::*;
@@ -0,0 +1,15 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{NeuronVerification, UserId};
+
+use super::{Memory, MEMORY_MANAGER, NEURON_VERIFICATIONS_MEMORY_ID};
+
+pub type NeuronVerificationMemory = BTreeMap<UserId, NeuronVerification, Memory>;
+
+pub fn init_neuron_verifications() -> NeuronVerificationMemory {
+    NeuronVerificationMemory::init(get_neuron_verifications_memory())
+}
+
+fn get_neuron_verifications_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(NEURON_VERIFICATIONS_MEMORY_ID))
+}
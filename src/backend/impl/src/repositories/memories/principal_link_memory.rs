@@ -0,0 +1,29 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{
+    PrincipalLinkCode, PrincipalLinkCodeId, PrincipalLinkHistoryEntry, UserProfileHistoryKey,
+};
+
+use super::{
+    Memory, MEMORY_MANAGER, PRINCIPAL_LINK_CODES_MEMORY_ID, PRINCIPAL_LINK_HISTORY_MEMORY_ID,
+};
+
+pub type PrincipalLinkCodeMemory = BTreeMap<PrincipalLinkCodeId, PrincipalLinkCode, Memory>;
+pub type PrincipalLinkHistoryMemory =
+    BTreeMap<UserProfileHistoryKey, PrincipalLinkHistoryEntry, Memory>;
+
+pub fn init_principal_link_codes() -> PrincipalLinkCodeMemory {
+    PrincipalLinkCodeMemory::init(get_principal_link_codes_memory())
+}
+
+pub fn init_principal_link_history() -> PrincipalLinkHistoryMemory {
+    PrincipalLinkHistoryMemory::init(get_principal_link_history_memory())
+}
+
+fn get_principal_link_codes_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(PRINCIPAL_LINK_CODES_MEMORY_ID))
+}
+
+fn get_principal_link_history_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(PRINCIPAL_LINK_HISTORY_MEMORY_ID))
+}
@@ -0,0 +1,28 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{ReviewConflict, ReviewConflictId, ReviewConflictProposalKey};
+
+use super::{
+    Memory, MEMORY_MANAGER, REVIEW_CONFLICTS_MEMORY_ID,
+    REVIEW_CONFLICTS_PROPOSAL_ID_INDEX_MEMORY_ID,
+};
+
+pub type ReviewConflictMemory = BTreeMap<ReviewConflictId, ReviewConflict, Memory>;
+pub type ReviewConflictProposalIdIndexMemory =
+    BTreeMap<ReviewConflictProposalKey, ReviewConflictId, Memory>;
+
+pub fn init_review_conflicts() -> ReviewConflictMemory {
+    ReviewConflictMemory::init(get_review_conflicts_memory())
+}
+
+pub fn init_review_conflict_proposal_id_index() -> ReviewConflictProposalIdIndexMemory {
+    ReviewConflictProposalIdIndexMemory::init(get_review_conflict_proposal_id_index_memory())
+}
+
+fn get_review_conflicts_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEW_CONFLICTS_MEMORY_ID))
+}
+
+fn get_review_conflict_proposal_id_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEW_CONFLICTS_PROPOSAL_ID_INDEX_MEMORY_ID))
+}
@@ -0,0 +1,26 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{ProposalReviewId, ReviewScore, ReviewScoreUserKey};
+
+use super::{
+    Memory, MEMORY_MANAGER, REVIEW_SCORES_MEMORY_ID, REVIEW_SCORES_USER_ID_INDEX_MEMORY_ID,
+};
+
+pub type ReviewScoreMemory = BTreeMap<ProposalReviewId, ReviewScore, Memory>;
+pub type ReviewScoreUserIdIndexMemory = BTreeMap<ReviewScoreUserKey, ProposalReviewId, Memory>;
+
+pub fn init_review_scores() -> ReviewScoreMemory {
+    ReviewScoreMemory::init(get_review_scores_memory())
+}
+
+pub fn init_review_score_user_id_index() -> ReviewScoreUserIdIndexMemory {
+    ReviewScoreUserIdIndexMemory::init(get_review_score_user_id_index_memory())
+}
+
+fn get_review_scores_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEW_SCORES_MEMORY_ID))
+}
+
+fn get_review_score_user_id_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEW_SCORES_USER_ID_INDEX_MEMORY_ID))
+}
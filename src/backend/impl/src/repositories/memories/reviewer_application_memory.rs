@@ -0,0 +1,15 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{ReviewerApplication, ReviewerApplicationId};
+
+use super::{Memory, MEMORY_MANAGER, REVIEWER_APPLICATIONS_MEMORY_ID};
+
+pub type ReviewerApplicationMemory = BTreeMap<ReviewerApplicationId, ReviewerApplication, Memory>;
+
+pub fn init_reviewer_applications() -> ReviewerApplicationMemory {
+    ReviewerApplicationMemory::init(get_reviewer_applications_memory())
+}
+
+fn get_reviewer_applications_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEWER_APPLICATIONS_MEMORY_ID))
+}
@@ -0,0 +1,61 @@
+use ic_stable_structures::{BTreeMap, Cell};
+
+use crate::repositories::{
+    ReviewerAssignment, ReviewerAssignmentConfig, ReviewerAssignmentId,
+    ReviewerAssignmentProposalUserKey, ReviewerAssignmentUserKey,
+};
+
+use super::{
+    Memory, MEMORY_MANAGER, REVIEWER_ASSIGNMENTS_MEMORY_ID,
+    REVIEWER_ASSIGNMENTS_PROPOSAL_ID_USER_ID_INDEX_MEMORY_ID,
+    REVIEWER_ASSIGNMENTS_USER_ID_INDEX_MEMORY_ID, REVIEWER_ASSIGNMENT_CONFIG_MEMORY_ID,
+};
+
+pub type ReviewerAssignmentMemory = BTreeMap<ReviewerAssignmentId, ReviewerAssignment, Memory>;
+pub type ReviewerAssignmentProposalIdUserIdIndexMemory =
+    BTreeMap<ReviewerAssignmentProposalUserKey, ReviewerAssignmentId, Memory>;
+pub type ReviewerAssignmentUserIdIndexMemory =
+    BTreeMap<ReviewerAssignmentUserKey, ReviewerAssignmentId, Memory>;
+pub type ReviewerAssignmentConfigMemory = Cell<ReviewerAssignmentConfig, Memory>;
+
+pub fn init_reviewer_assignments() -> ReviewerAssignmentMemory {
+    ReviewerAssignmentMemory::init(get_reviewer_assignments_memory())
+}
+
+pub fn init_reviewer_assignment_proposal_id_user_id_index(
+) -> ReviewerAssignmentProposalIdUserIdIndexMemory {
+    ReviewerAssignmentProposalIdUserIdIndexMemory::init(
+        get_reviewer_assignment_proposal_id_user_id_index_memory(),
+    )
+}
+
+pub fn init_reviewer_assignment_user_id_index() -> ReviewerAssignmentUserIdIndexMemory {
+    ReviewerAssignmentUserIdIndexMemory::init(get_reviewer_assignment_user_id_index_memory())
+}
+
+pub fn init_reviewer_assignment_config() -> ReviewerAssignmentConfigMemory {
+    ReviewerAssignmentConfigMemory::init(
+        get_reviewer_assignment_config_memory(),
+        ReviewerAssignmentConfig::default(),
+    )
+    .unwrap()
+}
+
+fn get_reviewer_assignments_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEWER_ASSIGNMENTS_MEMORY_ID))
+}
+
+fn get_reviewer_assignment_proposal_id_user_id_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| {
+        m.borrow()
+            .get(REVIEWER_ASSIGNMENTS_PROPOSAL_ID_USER_ID_INDEX_MEMORY_ID)
+    })
+}
+
+fn get_reviewer_assignment_user_id_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEWER_ASSIGNMENTS_USER_ID_INDEX_MEMORY_ID))
+}
+
+fn get_reviewer_assignment_config_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REVIEWER_ASSIGNMENT_CONFIG_MEMORY_ID))
+}
@@ -0,0 +1,38 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{
+    RewardPayout, RewardPeriod, RewardPeriodId, RewardStatement, RewardStatementKey,
+};
+
+use super::{
+    Memory, MEMORY_MANAGER, REWARD_PAYOUTS_MEMORY_ID, REWARD_PERIODS_MEMORY_ID,
+    REWARD_STATEMENTS_MEMORY_ID,
+};
+
+pub type RewardPeriodMemory = BTreeMap<RewardPeriodId, RewardPeriod, Memory>;
+pub type RewardStatementMemory = BTreeMap<RewardStatementKey, RewardStatement, Memory>;
+pub type RewardPayoutMemory = BTreeMap<RewardStatementKey, RewardPayout, Memory>;
+
+pub fn init_reward_periods() -> RewardPeriodMemory {
+    RewardPeriodMemory::init(get_reward_periods_memory())
+}
+
+pub fn init_reward_statements() -> RewardStatementMemory {
+    RewardStatementMemory::init(get_reward_statements_memory())
+}
+
+pub fn init_reward_payouts() -> RewardPayoutMemory {
+    RewardPayoutMemory::init(get_reward_payouts_memory())
+}
+
+fn get_reward_periods_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REWARD_PERIODS_MEMORY_ID))
+}
+
+fn get_reward_statements_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REWARD_STATEMENTS_MEMORY_ID))
+}
+
+fn get_reward_payouts_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(REWARD_PAYOUTS_MEMORY_ID))
+}
@@ -0,0 +1,15 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{UserId, UserRoles};
+
+use super::{Memory, MEMORY_MANAGER, USER_ROLES_MEMORY_ID};
+
+pub type UserRolesMemory = BTreeMap<UserId, UserRoles, Memory>;
+
+pub fn init_user_roles() -> UserRolesMemory {
+    UserRolesMemory::init(get_user_roles_memory())
+}
+
+fn get_user_roles_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(USER_ROLES_MEMORY_ID))
+}
@@ -1,10 +1,11 @@
 use super::{
     memory_manager::USER_PROFILE_HISTORY_MEMORY_ID, Memory, MEMORY_MANAGER,
-    USER_PROFILES_MEMORY_ID, USER_PROFILE_HISTORY_ID_MEMORY_ID,
-    USER_PROFILE_PRINCIPAL_INDEX_MEMORY_ID,
+    USERNAME_INDEX_MEMORY_ID, USER_PRINCIPAL_INDEX_MEMORY_ID, USER_PROFILES_MEMORY_ID,
+    USER_PROFILE_HISTORY_ID_MEMORY_ID, USER_PROFILE_PRINCIPAL_INDEX_MEMORY_ID,
+    USER_STATUSES_MEMORY_ID,
 };
 use crate::repositories::{
-    types::{UserId, UserProfile},
+    types::{UserId, UserPrincipalKey, UserProfile, UserStatus},
     UserProfileHistoryEntry, UserProfileHistoryKey,
 };
 use candid::Principal;
@@ -15,6 +16,9 @@ pub type UserProfilePrincipalIndexMemory = BTreeMap<Principal, UserId, Memory>;
 pub type UserProfileHistoryMemory =
     BTreeMap<UserProfileHistoryKey, UserProfileHistoryEntry, Memory>;
 pub type UserProfileHistoryIdMemory = Cell<u128, Memory>;
+pub type UserStatusMemory = BTreeMap<UserId, UserStatus, Memory>;
+pub type UsernameIndexMemory = BTreeMap<String, UserId, Memory>;
+pub type UserPrincipalIndexMemory = BTreeMap<UserPrincipalKey, Principal, Memory>;
 
 pub fn init_user_profiles() -> UserProfileMemory {
     UserProfileMemory::init(get_user_profiles_memory())
@@ -32,6 +36,18 @@ pub fn init_user_profile_history_id() -> UserProfileHistoryIdMemory {
     UserProfileHistoryIdMemory::init(get_user_profile_history_id_memory(), 0).unwrap()
 }
 
+pub fn init_user_statuses() -> UserStatusMemory {
+    UserStatusMemory::init(get_user_statuses_memory())
+}
+
+pub fn init_username_index() -> UsernameIndexMemory {
+    UsernameIndexMemory::init(get_username_index_memory())
+}
+
+pub fn init_user_principal_index() -> UserPrincipalIndexMemory {
+    UserPrincipalIndexMemory::init(get_user_principal_index_memory())
+}
+
 fn get_user_profiles_memory() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(USER_PROFILES_MEMORY_ID))
 }
@@ -47,3 +63,15 @@ fn get_user_profiles_history_memory() -> Memory {
 fn get_user_profile_history_id_memory() -> Memory {
     MEMORY_MANAGER.with(|m| m.borrow().get(USER_PROFILE_HISTORY_ID_MEMORY_ID))
 }
+
+fn get_user_statuses_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(USER_STATUSES_MEMORY_ID))
+}
+
+fn get_username_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(USERNAME_INDEX_MEMORY_ID))
+}
+
+fn get_user_principal_index_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(USER_PRINCIPAL_INDEX_MEMORY_ID))
+}
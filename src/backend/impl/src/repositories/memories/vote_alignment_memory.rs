@@ -0,0 +1,15 @@
+use ic_stable_structures::BTreeMap;
+
+use crate::repositories::{VoteAlignment, VoteAlignmentKey};
+
+use super::{Memory, MEMORY_MANAGER, VOTE_ALIGNMENTS_MEMORY_ID};
+
+pub type VoteAlignmentMemory = BTreeMap<VoteAlignmentKey, VoteAlignment, Memory>;
+
+pub fn init_vote_alignments() -> VoteAlignmentMemory {
+    VoteAlignmentMemory::init(get_vote_alignments_memory())
+}
+
+fn get_vote_alignments_memory() -> Memory {
+    MEMORY_MANAGER.with(|m| m.borrow().get(VOTE_ALIGNMENTS_MEMORY_ID))
+}
@@ -1,19 +1,49 @@
+mod admin_sync_repository;
+mod approval_repository;
+mod audit_log_repository;
 mod certification_repository;
+mod comment_repository;
+mod commit_claim_repository;
+mod endorsement_repository;
 mod image_repository;
 mod log_repository;
 mod memories;
+mod neuron_verification_repository;
+mod principal_link_code_repository;
 mod proposal_repository;
 mod proposal_review_commit_repository;
 mod proposal_review_repository;
+mod review_conflict_repository;
+mod review_score_repository;
+mod reviewer_application_repository;
+mod reviewer_assignment_repository;
+mod reward_repository;
+mod role_repository;
 mod types;
 mod user_profile_repository;
+mod vote_alignment_repository;
 
+pub use admin_sync_repository::*;
+pub use approval_repository::*;
+pub use audit_log_repository::*;
 pub use certification_repository::*;
+pub use comment_repository::*;
+pub use commit_claim_repository::*;
+pub use endorsement_repository::*;
 pub use image_repository::*;
 pub use log_repository::*;
 use memories::*;
+pub use neuron_verification_repository::*;
+pub use principal_link_code_repository::*;
 pub use proposal_repository::*;
 pub use proposal_review_commit_repository::*;
 pub use proposal_review_repository::*;
+pub use review_conflict_repository::*;
+pub use review_score_repository::*;
+pub use reviewer_application_repository::*;
+pub use reviewer_assignment_repository::*;
+pub use reward_repository::*;
+pub use role_repository::*;
 pub use types::*;
 pub use user_profile_repository::*;
+pub use vote_alignment_repository::*;
@@ -0,0 +1,94 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{init_neuron_verifications, NeuronVerification, NeuronVerificationMemory, UserId};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait NeuronVerificationRepository {
+    fn get_neuron_verification_by_user_id(&self, user_id: &UserId) -> Option<NeuronVerification>;
+
+    fn upsert_neuron_verification(
+        &self,
+        user_id: UserId,
+        neuron_verification: NeuronVerification,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct NeuronVerificationRepositoryImpl {}
+
+impl Default for NeuronVerificationRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NeuronVerificationRepository for NeuronVerificationRepositoryImpl {
+    fn get_neuron_verification_by_user_id(&self, user_id: &UserId) -> Option<NeuronVerification> {
+        STATE.with_borrow(|s| s.neuron_verifications.get(user_id))
+    }
+
+    fn upsert_neuron_verification(
+        &self,
+        user_id: UserId,
+        neuron_verification: NeuronVerification,
+    ) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.neuron_verifications.insert(user_id, neuron_verification);
+        });
+
+        Ok(())
+    }
+}
+
+impl NeuronVerificationRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct NeuronVerificationState {
+    neuron_verifications: NeuronVerificationMemory,
+}
+
+impl Default for NeuronVerificationState {
+    fn default() -> Self {
+        Self {
+            neuron_verifications: init_neuron_verifications(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<NeuronVerificationState> = RefCell::new(NeuronVerificationState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn upsert_and_get_neuron_verification() {
+        STATE.set(NeuronVerificationState::default());
+
+        let user_id = fixtures::user_id();
+        let repository = NeuronVerificationRepositoryImpl::default();
+
+        repository
+            .upsert_neuron_verification(user_id, fixtures::neuron_verification_verified())
+            .unwrap();
+        repository
+            .upsert_neuron_verification(user_id, fixtures::neuron_verification_unverified())
+            .unwrap();
+
+        let result = repository.get_neuron_verification_by_user_id(&user_id);
+
+        assert_eq!(result, Some(fixtures::neuron_verification_unverified()));
+        assert_eq!(
+            repository.get_neuron_verification_by_user_id(&fixtures::uuid_a()),
+            None
+        );
+    }
+}
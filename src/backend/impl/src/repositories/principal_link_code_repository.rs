@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_principal_link_codes, PrincipalLinkCode, PrincipalLinkCodeId, PrincipalLinkCodeMemory,
+    UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait PrincipalLinkCodeRepository {
+    fn get_principal_link_code_by_id(
+        &self,
+        principal_link_code_id: &PrincipalLinkCodeId,
+    ) -> Option<PrincipalLinkCode>;
+
+    fn get_principal_link_codes_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Vec<(PrincipalLinkCodeId, PrincipalLinkCode)>;
+
+    /// Stores a code under an id chosen by the caller, since the id is the secret
+    /// that is handed out and must come from a secure randomness source.
+    fn create_principal_link_code(
+        &self,
+        principal_link_code_id: PrincipalLinkCodeId,
+        principal_link_code: PrincipalLinkCode,
+    ) -> Result<(), ApiError>;
+
+    fn delete_principal_link_code(
+        &self,
+        principal_link_code_id: &PrincipalLinkCodeId,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct PrincipalLinkCodeRepositoryImpl {}
+
+impl Default for PrincipalLinkCodeRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrincipalLinkCodeRepository for PrincipalLinkCodeRepositoryImpl {
+    fn get_principal_link_code_by_id(
+        &self,
+        principal_link_code_id: &PrincipalLinkCodeId,
+    ) -> Option<PrincipalLinkCode> {
+        STATE.with_borrow(|s| s.principal_link_codes.get(principal_link_code_id))
+    }
+
+    fn get_principal_link_codes_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Vec<(PrincipalLinkCodeId, PrincipalLinkCode)> {
+        STATE.with_borrow(|s| {
+            s.principal_link_codes
+                .iter()
+                .filter(|(_, principal_link_code)| &principal_link_code.user_id == user_id)
+                .collect()
+        })
+    }
+
+    fn create_principal_link_code(
+        &self,
+        principal_link_code_id: PrincipalLinkCodeId,
+        principal_link_code: PrincipalLinkCode,
+    ) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            if s.principal_link_codes.contains_key(&principal_link_code_id) {
+                return Err(ApiError::conflict(&format!(
+                    "Principal link code with id {} already exists",
+                    principal_link_code_id
+                )));
+            }
+
+            s.principal_link_codes
+                .insert(principal_link_code_id, principal_link_code);
+
+            Ok(())
+        })
+    }
+
+    fn delete_principal_link_code(
+        &self,
+        principal_link_code_id: &PrincipalLinkCodeId,
+    ) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.principal_link_codes
+                .remove(principal_link_code_id)
+                .map(|_| ())
+                .ok_or_else(|| {
+                    ApiError::not_found(&format!(
+                        "Principal link code with id {} not found",
+                        principal_link_code_id
+                    ))
+                })
+        })
+    }
+}
+
+impl PrincipalLinkCodeRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct PrincipalLinkCodeState {
+    principal_link_codes: PrincipalLinkCodeMemory,
+}
+
+impl Default for PrincipalLinkCodeState {
+    fn default() -> Self {
+        Self {
+            principal_link_codes: init_principal_link_codes(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<PrincipalLinkCodeState> = RefCell::new(PrincipalLinkCodeState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn create_get_and_delete_principal_link_code() {
+        STATE.set(PrincipalLinkCodeState::default());
+        let principal_link_code = fixtures::principal_link_code();
+
+        let id = fixtures::uuid();
+
+        let repository = PrincipalLinkCodeRepositoryImpl::default();
+        repository
+            .create_principal_link_code(id, principal_link_code.clone())
+            .unwrap();
+        assert_eq!(
+            repository
+                .create_principal_link_code(id, principal_link_code.clone())
+                .unwrap_err(),
+            ApiError::conflict(&format!(
+                "Principal link code with id {} already exists",
+                id
+            ))
+        );
+
+        assert_eq!(
+            repository.get_principal_link_code_by_id(&id),
+            Some(principal_link_code.clone())
+        );
+        assert_eq!(
+            repository.get_principal_link_codes_by_user_id(&principal_link_code.user_id),
+            vec![(id, principal_link_code)]
+        );
+        assert!(repository
+            .get_principal_link_codes_by_user_id(&fixtures::uuid_a())
+            .is_empty());
+
+        repository.delete_principal_link_code(&id).unwrap();
+
+        assert_eq!(repository.get_principal_link_code_by_id(&id), None);
+        assert_eq!(
+            repository.delete_principal_link_code(&id).unwrap_err(),
+            ApiError::not_found(&format!("Principal link code with id {} not found", id))
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_review_conflict_proposal_id_index, init_review_conflicts, ProposalId, ReviewConflict,
+    ReviewConflictId, ReviewConflictMemory, ReviewConflictProposalIdIndexMemory,
+    ReviewConflictProposalKey, ReviewConflictProposalRange,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewConflictRepository {
+    fn get_review_conflict_by_id(
+        &self,
+        review_conflict_id: &ReviewConflictId,
+    ) -> Option<ReviewConflict>;
+
+    fn get_review_conflicts_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<(ReviewConflictId, ReviewConflict)>, ApiError>;
+
+    fn get_open_review_conflicts(&self) -> Vec<(ReviewConflictId, ReviewConflict)>;
+
+    fn create_review_conflict(
+        &self,
+        review_conflict: ReviewConflict,
+    ) -> Result<ReviewConflictId, ApiError>;
+
+    fn update_review_conflict(
+        &self,
+        review_conflict_id: ReviewConflictId,
+        review_conflict: ReviewConflict,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewConflictRepositoryImpl {}
+
+impl Default for ReviewConflictRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewConflictRepository for ReviewConflictRepositoryImpl {
+    fn get_review_conflict_by_id(
+        &self,
+        review_conflict_id: &ReviewConflictId,
+    ) -> Option<ReviewConflict> {
+        STATE.with_borrow(|s| s.review_conflicts.get(review_conflict_id))
+    }
+
+    fn get_review_conflicts_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<(ReviewConflictId, ReviewConflict)>, ApiError> {
+        let range = ReviewConflictProposalRange::new(proposal_id)?;
+
+        let review_conflicts = STATE.with_borrow(|s| {
+            s.proposal_id_index
+                .range(range)
+                .filter_map(|(_, review_conflict_id)| {
+                    // the None case should never happen
+                    s.review_conflicts
+                        .get(&review_conflict_id)
+                        .map(|r_c| (review_conflict_id, r_c))
+                })
+                .collect()
+        });
+
+        Ok(review_conflicts)
+    }
+
+    fn get_open_review_conflicts(&self) -> Vec<(ReviewConflictId, ReviewConflict)> {
+        STATE.with_borrow(|s| {
+            s.review_conflicts
+                .iter()
+                .filter(|(_, review_conflict)| review_conflict.is_open())
+                .collect()
+        })
+    }
+
+    fn create_review_conflict(
+        &self,
+        review_conflict: ReviewConflict,
+    ) -> Result<ReviewConflictId, ApiError> {
+        let review_conflict_id = ReviewConflictId::new();
+        let proposal_key =
+            ReviewConflictProposalKey::new(review_conflict.proposal_id, review_conflict_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.review_conflicts
+                .insert(review_conflict_id, review_conflict);
+            s.proposal_id_index.insert(proposal_key, review_conflict_id);
+        });
+
+        Ok(review_conflict_id)
+    }
+
+    fn update_review_conflict(
+        &self,
+        review_conflict_id: ReviewConflictId,
+        review_conflict: ReviewConflict,
+    ) -> Result<(), ApiError> {
+        self.get_review_conflict_by_id(&review_conflict_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Review conflict with id {} not found",
+                    review_conflict_id
+                ))
+            })?;
+
+        STATE.with_borrow_mut(|s| {
+            s.review_conflicts
+                .insert(review_conflict_id, review_conflict);
+
+            Ok(())
+        })
+    }
+}
+
+impl ReviewConflictRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ReviewConflictState {
+    review_conflicts: ReviewConflictMemory,
+    proposal_id_index: ReviewConflictProposalIdIndexMemory,
+}
+
+impl Default for ReviewConflictState {
+    fn default() -> Self {
+        Self {
+            review_conflicts: init_review_conflicts(),
+            proposal_id_index: init_review_conflict_proposal_id_index(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ReviewConflictState> = RefCell::new(ReviewConflictState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, uuid_a, uuid_b};
+    use rstest::*;
+
+    #[rstest]
+    #[case::open(fixtures::review_conflict_open())]
+    #[case::resolved(fixtures::review_conflict_resolved())]
+    fn create_and_get_review_conflict_by_id(#[case] review_conflict: ReviewConflict) {
+        STATE.set(ReviewConflictState::default());
+
+        let repository = ReviewConflictRepositoryImpl::default();
+        let review_conflict_id = repository
+            .create_review_conflict(review_conflict.clone())
+            .unwrap();
+
+        let result = repository.get_review_conflict_by_id(&review_conflict_id);
+
+        assert_eq!(result, Some(review_conflict));
+    }
+
+    #[rstest]
+    fn get_review_conflicts_by_proposal_id() {
+        STATE.set(ReviewConflictState::default());
+
+        let repository = ReviewConflictRepositoryImpl::default();
+
+        let review_conflict_id = repository
+            .create_review_conflict(ReviewConflict {
+                proposal_id: uuid_a(),
+                ..fixtures::review_conflict_open()
+            })
+            .unwrap();
+        repository
+            .create_review_conflict(ReviewConflict {
+                proposal_id: uuid_b(),
+                ..fixtures::review_conflict_open()
+            })
+            .unwrap();
+
+        let result = repository
+            .get_review_conflicts_by_proposal_id(uuid_a())
+            .unwrap();
+
+        assert_eq!(
+            result,
+            vec![(
+                review_conflict_id,
+                ReviewConflict {
+                    proposal_id: uuid_a(),
+                    ..fixtures::review_conflict_open()
+                }
+            )]
+        );
+    }
+
+    #[rstest]
+    fn get_open_review_conflicts() {
+        STATE.set(ReviewConflictState::default());
+
+        let repository = ReviewConflictRepositoryImpl::default();
+
+        let open_review_conflict_id = repository
+            .create_review_conflict(fixtures::review_conflict_open())
+            .unwrap();
+        repository
+            .create_review_conflict(fixtures::review_conflict_resolved())
+            .unwrap();
+
+        let result = repository.get_open_review_conflicts();
+
+        assert_eq!(
+            result,
+            vec![(open_review_conflict_id, fixtures::review_conflict_open())]
+        );
+    }
+
+    #[rstest]
+    fn update_review_conflict() {
+        STATE.set(ReviewConflictState::default());
+
+        let repository = ReviewConflictRepositoryImpl::default();
+        let review_conflict_id = repository
+            .create_review_conflict(fixtures::review_conflict_open())
+            .unwrap();
+
+        repository
+            .update_review_conflict(review_conflict_id, fixtures::review_conflict_resolved())
+            .unwrap();
+
+        let result = repository.get_review_conflict_by_id(&review_conflict_id);
+
+        assert_eq!(result, Some(fixtures::review_conflict_resolved()));
+    }
+
+    #[rstest]
+    fn update_review_conflict_not_found() {
+        STATE.set(ReviewConflictState::default());
+
+        let repository = ReviewConflictRepositoryImpl::default();
+        let review_conflict_id = fixtures::uuid();
+
+        let result = repository
+            .update_review_conflict(review_conflict_id, fixtures::review_conflict_resolved())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Review conflict with id {} not found",
+                review_conflict_id
+            ))
+        );
+    }
+}
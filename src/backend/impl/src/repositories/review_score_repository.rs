@@ -0,0 +1,253 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_review_score_user_id_index, init_review_scores, ProposalReviewId, ReviewScore,
+    ReviewScoreMemory, ReviewScoreUserIdIndexMemory, ReviewScoreUserKey, ReviewScoreUserRange,
+    UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewScoreRepository {
+    fn get_review_score_by_proposal_review_id(
+        &self,
+        proposal_review_id: &ProposalReviewId,
+    ) -> Option<ReviewScore>;
+
+    /// Returns the scores of the reviews authored by the given user, oldest first.
+    fn get_review_scores_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<(ProposalReviewId, ReviewScore)>, ApiError>;
+
+    /// Returns at most `limit` scores of the reviews authored by the given user, newest first.
+    fn get_latest_review_scores_by_user_id(
+        &self,
+        user_id: UserId,
+        limit: usize,
+    ) -> Result<Vec<ReviewScore>, ApiError>;
+
+    fn upsert_review_score(
+        &self,
+        proposal_review_id: ProposalReviewId,
+        review_score: ReviewScore,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewScoreRepositoryImpl {}
+
+impl Default for ReviewScoreRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewScoreRepository for ReviewScoreRepositoryImpl {
+    fn get_review_score_by_proposal_review_id(
+        &self,
+        proposal_review_id: &ProposalReviewId,
+    ) -> Option<ReviewScore> {
+        STATE.with_borrow(|s| s.review_scores.get(proposal_review_id))
+    }
+
+    fn get_review_scores_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<(ProposalReviewId, ReviewScore)>, ApiError> {
+        let range = ReviewScoreUserRange::new(user_id)?;
+
+        let review_scores = STATE.with_borrow(|s| {
+            s.user_id_index
+                .range(range)
+                .filter_map(|(_, proposal_review_id)| {
+                    // the None case should never happen
+                    s.review_scores
+                        .get(&proposal_review_id)
+                        .map(|review_score| (proposal_review_id, review_score))
+                })
+                .collect()
+        });
+
+        Ok(review_scores)
+    }
+
+    fn get_latest_review_scores_by_user_id(
+        &self,
+        user_id: UserId,
+        limit: usize,
+    ) -> Result<Vec<ReviewScore>, ApiError> {
+        let range = ReviewScoreUserRange::new(user_id)?;
+
+        let review_scores = STATE.with_borrow(|s| {
+            let proposal_review_ids: Vec<_> = s
+                .user_id_index
+                .range(range)
+                .map(|(_, proposal_review_id)| proposal_review_id)
+                .collect();
+
+            proposal_review_ids
+                .into_iter()
+                .rev()
+                .take(limit)
+                // the None case should never happen
+                .filter_map(|proposal_review_id| s.review_scores.get(&proposal_review_id))
+                .collect()
+        });
+
+        Ok(review_scores)
+    }
+
+    fn upsert_review_score(
+        &self,
+        proposal_review_id: ProposalReviewId,
+        review_score: ReviewScore,
+    ) -> Result<(), ApiError> {
+        let previous_user_key = self
+            .get_review_score_by_proposal_review_id(&proposal_review_id)
+            .map(|previous| {
+                ReviewScoreUserKey::new(
+                    previous.reviewer_user_id,
+                    previous.created_at,
+                    proposal_review_id,
+                )
+            })
+            .transpose()?;
+        let user_key = ReviewScoreUserKey::new(
+            review_score.reviewer_user_id,
+            review_score.created_at,
+            proposal_review_id,
+        )?;
+
+        STATE.with_borrow_mut(|s| {
+            if let Some(previous_user_key) = previous_user_key {
+                s.user_id_index.remove(&previous_user_key);
+            }
+
+            s.review_scores.insert(proposal_review_id, review_score);
+            s.user_id_index.insert(user_key, proposal_review_id);
+        });
+
+        Ok(())
+    }
+}
+
+impl ReviewScoreRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ReviewScoreState {
+    review_scores: ReviewScoreMemory,
+    user_id_index: ReviewScoreUserIdIndexMemory,
+}
+
+impl Default for ReviewScoreState {
+    fn default() -> Self {
+        Self {
+            review_scores: init_review_scores(),
+            user_id_index: init_review_score_user_id_index(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ReviewScoreState> = RefCell::new(ReviewScoreState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, uuid_a, uuid_b};
+    use rstest::*;
+
+    #[rstest]
+    fn upsert_and_get_review_score() {
+        STATE.set(ReviewScoreState::default());
+
+        let proposal_review_id = fixtures::proposal_review_id();
+        let review_score = fixtures::review_score();
+
+        let repository = ReviewScoreRepositoryImpl::default();
+        repository
+            .upsert_review_score(proposal_review_id, review_score.clone())
+            .unwrap();
+
+        let result = repository.get_review_score_by_proposal_review_id(&proposal_review_id);
+
+        assert_eq!(result, Some(review_score));
+    }
+
+    #[rstest]
+    fn upsert_review_score_existing() {
+        STATE.set(ReviewScoreState::default());
+
+        let proposal_review_id = fixtures::proposal_review_id();
+        let updated_review_score = ReviewScore {
+            depth: 1,
+            last_updated_at: Some(fixtures::date_time_b()),
+            ..fixtures::review_score()
+        };
+
+        let repository = ReviewScoreRepositoryImpl::default();
+        repository
+            .upsert_review_score(proposal_review_id, fixtures::review_score())
+            .unwrap();
+        repository
+            .upsert_review_score(proposal_review_id, updated_review_score.clone())
+            .unwrap();
+
+        let result = repository
+            .get_review_scores_by_user_id(updated_review_score.reviewer_user_id)
+            .unwrap();
+
+        assert_eq!(result, vec![(proposal_review_id, updated_review_score)]);
+    }
+
+    #[rstest]
+    fn get_review_scores_by_user_id() {
+        STATE.set(ReviewScoreState::default());
+
+        let user_id = fixtures::user_id();
+        let older_review_score = ReviewScore {
+            created_at: fixtures::date_time_b(),
+            ..fixtures::review_score()
+        };
+        let newer_review_score = ReviewScore {
+            created_at: fixtures::date_time_a(),
+            ..fixtures::review_score()
+        };
+
+        let repository = ReviewScoreRepositoryImpl::default();
+        repository
+            .upsert_review_score(uuid_a(), newer_review_score.clone())
+            .unwrap();
+        repository
+            .upsert_review_score(uuid_b(), older_review_score.clone())
+            .unwrap();
+        repository
+            .upsert_review_score(
+                fixtures::proposal_review_id(),
+                ReviewScore {
+                    reviewer_user_id: uuid_b(),
+                    ..fixtures::review_score()
+                },
+            )
+            .unwrap();
+
+        let all = repository.get_review_scores_by_user_id(user_id).unwrap();
+        let latest = repository
+            .get_latest_review_scores_by_user_id(user_id, 1)
+            .unwrap();
+
+        assert_eq!(
+            all,
+            vec![
+                (uuid_b(), older_review_score),
+                (uuid_a(), newer_review_score.clone()),
+            ]
+        );
+        assert_eq!(latest, vec![newer_review_score]);
+    }
+}
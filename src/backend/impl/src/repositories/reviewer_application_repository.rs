@@ -0,0 +1,195 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_reviewer_applications, ReviewerApplication, ReviewerApplicationId,
+    ReviewerApplicationMemory, UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerApplicationRepository {
+    fn get_reviewer_application_by_id(
+        &self,
+        reviewer_application_id: &ReviewerApplicationId,
+    ) -> Option<ReviewerApplication>;
+
+    fn get_reviewer_applications(&self) -> Vec<(ReviewerApplicationId, ReviewerApplication)>;
+
+    fn get_reviewer_applications_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Vec<(ReviewerApplicationId, ReviewerApplication)>;
+
+    fn create_reviewer_application(
+        &self,
+        reviewer_application: ReviewerApplication,
+    ) -> Result<ReviewerApplicationId, ApiError>;
+
+    fn update_reviewer_application(
+        &self,
+        reviewer_application_id: ReviewerApplicationId,
+        reviewer_application: ReviewerApplication,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewerApplicationRepositoryImpl {}
+
+impl Default for ReviewerApplicationRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewerApplicationRepository for ReviewerApplicationRepositoryImpl {
+    fn get_reviewer_application_by_id(
+        &self,
+        reviewer_application_id: &ReviewerApplicationId,
+    ) -> Option<ReviewerApplication> {
+        STATE.with_borrow(|s| s.reviewer_applications.get(reviewer_application_id))
+    }
+
+    fn get_reviewer_applications(&self) -> Vec<(ReviewerApplicationId, ReviewerApplication)> {
+        STATE.with_borrow(|s| s.reviewer_applications.iter().collect())
+    }
+
+    fn get_reviewer_applications_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Vec<(ReviewerApplicationId, ReviewerApplication)> {
+        STATE.with_borrow(|s| {
+            s.reviewer_applications
+                .iter()
+                .filter(|(_, reviewer_application)| reviewer_application.user_id == *user_id)
+                .collect()
+        })
+    }
+
+    fn create_reviewer_application(
+        &self,
+        reviewer_application: ReviewerApplication,
+    ) -> Result<ReviewerApplicationId, ApiError> {
+        let reviewer_application_id = ReviewerApplicationId::new();
+
+        STATE.with_borrow_mut(|s| {
+            s.reviewer_applications
+                .insert(reviewer_application_id, reviewer_application);
+        });
+
+        Ok(reviewer_application_id)
+    }
+
+    fn update_reviewer_application(
+        &self,
+        reviewer_application_id: ReviewerApplicationId,
+        reviewer_application: ReviewerApplication,
+    ) -> Result<(), ApiError> {
+        self.get_reviewer_application_by_id(&reviewer_application_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Reviewer application with id {} not found",
+                    reviewer_application_id
+                ))
+            })?;
+
+        STATE.with_borrow_mut(|s| {
+            s.reviewer_applications
+                .insert(reviewer_application_id, reviewer_application);
+        });
+
+        Ok(())
+    }
+}
+
+impl ReviewerApplicationRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ReviewerApplicationState {
+    reviewer_applications: ReviewerApplicationMemory,
+}
+
+impl Default for ReviewerApplicationState {
+    fn default() -> Self {
+        Self {
+            reviewer_applications: init_reviewer_applications(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ReviewerApplicationState> = RefCell::new(ReviewerApplicationState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn create_and_get_reviewer_applications() {
+        STATE.set(ReviewerApplicationState::default());
+
+        let repository = ReviewerApplicationRepositoryImpl::default();
+        let other_user_application = ReviewerApplication {
+            user_id: fixtures::uuid_a(),
+            ..fixtures::reviewer_application_pending()
+        };
+
+        let id = repository
+            .create_reviewer_application(fixtures::reviewer_application_pending())
+            .unwrap();
+        let other_id = repository
+            .create_reviewer_application(other_user_application.clone())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_reviewer_application_by_id(&id),
+            Some(fixtures::reviewer_application_pending())
+        );
+        assert_eq!(
+            repository.get_reviewer_applications_by_user_id(&fixtures::uuid_a()),
+            vec![(other_id, other_user_application)]
+        );
+        assert_eq!(repository.get_reviewer_applications().len(), 2);
+    }
+
+    #[rstest]
+    fn update_reviewer_application() {
+        STATE.set(ReviewerApplicationState::default());
+
+        let repository = ReviewerApplicationRepositoryImpl::default();
+        let id = repository
+            .create_reviewer_application(fixtures::reviewer_application_pending())
+            .unwrap();
+
+        repository
+            .update_reviewer_application(id, fixtures::reviewer_application_approved())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_reviewer_application_by_id(&id),
+            Some(fixtures::reviewer_application_approved())
+        );
+    }
+
+    #[rstest]
+    fn update_reviewer_application_not_found() {
+        STATE.set(ReviewerApplicationState::default());
+
+        let repository = ReviewerApplicationRepositoryImpl::default();
+        let id = fixtures::uuid();
+
+        let result = repository
+            .update_reviewer_application(id, fixtures::reviewer_application_approved())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!("Reviewer application with id {} not found", id))
+        );
+    }
+}
@@ -0,0 +1,496 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_reviewer_assignment_config, init_reviewer_assignment_proposal_id_user_id_index,
+    init_reviewer_assignment_user_id_index, init_reviewer_assignments, ProposalId,
+    ReviewerAssignment, ReviewerAssignmentConfig, ReviewerAssignmentConfigMemory,
+    ReviewerAssignmentId, ReviewerAssignmentMemory, ReviewerAssignmentProposalIdUserIdIndexMemory,
+    ReviewerAssignmentProposalUserKey, ReviewerAssignmentProposalUserRange,
+    ReviewerAssignmentStatus, ReviewerAssignmentUserIdIndexMemory, ReviewerAssignmentUserKey,
+    ReviewerAssignmentUserRange, UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerAssignmentRepository {
+    fn get_reviewer_assignment_by_id(
+        &self,
+        reviewer_assignment_id: &ReviewerAssignmentId,
+    ) -> Option<ReviewerAssignment>;
+
+    fn get_reviewer_assignment_by_proposal_id_and_user_id(
+        &self,
+        proposal_id: ProposalId,
+        user_id: UserId,
+    ) -> Option<(ReviewerAssignmentId, ReviewerAssignment)>;
+
+    fn get_reviewer_assignments_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<(ReviewerAssignmentId, ReviewerAssignment)>, ApiError>;
+
+    fn get_reviewer_assignments_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<(ReviewerAssignmentId, ReviewerAssignment)>, ApiError>;
+
+    fn get_open_reviewer_assignments(&self) -> Vec<(ReviewerAssignmentId, ReviewerAssignment)>;
+
+    fn get_unfulfilled_reviewer_assignments(
+        &self,
+    ) -> Vec<(ReviewerAssignmentId, ReviewerAssignment)>;
+
+    fn create_reviewer_assignment(
+        &self,
+        reviewer_assignment: ReviewerAssignment,
+    ) -> Result<ReviewerAssignmentId, ApiError>;
+
+    fn update_reviewer_assignment(
+        &self,
+        reviewer_assignment_id: ReviewerAssignmentId,
+        reviewer_assignment: ReviewerAssignment,
+    ) -> Result<(), ApiError>;
+
+    fn get_reviewer_assignment_config(&self) -> ReviewerAssignmentConfig;
+
+    fn set_reviewer_assignment_config(
+        &self,
+        config: ReviewerAssignmentConfig,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewerAssignmentRepositoryImpl {}
+
+impl Default for ReviewerAssignmentRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReviewerAssignmentRepository for ReviewerAssignmentRepositoryImpl {
+    fn get_reviewer_assignment_by_id(
+        &self,
+        reviewer_assignment_id: &ReviewerAssignmentId,
+    ) -> Option<ReviewerAssignment> {
+        STATE.with_borrow(|s| s.reviewer_assignments.get(reviewer_assignment_id))
+    }
+
+    fn get_reviewer_assignment_by_proposal_id_and_user_id(
+        &self,
+        proposal_id: ProposalId,
+        user_id: UserId,
+    ) -> Option<(ReviewerAssignmentId, ReviewerAssignment)> {
+        let range = ReviewerAssignmentProposalUserRange::new(proposal_id, Some(user_id)).ok()?;
+
+        STATE.with_borrow(|s| {
+            s.proposal_id_user_id_index
+                .range(range)
+                .find_map(|(_, reviewer_assignment_id)| {
+                    // the None case should never happen
+                    s.reviewer_assignments
+                        .get(&reviewer_assignment_id)
+                        .map(|r_a| (reviewer_assignment_id, r_a))
+                })
+        })
+    }
+
+    fn get_reviewer_assignments_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<(ReviewerAssignmentId, ReviewerAssignment)>, ApiError> {
+        let range = ReviewerAssignmentProposalUserRange::new(proposal_id, None)?;
+
+        let reviewer_assignments = STATE.with_borrow(|s| {
+            s.proposal_id_user_id_index
+                .range(range)
+                .filter_map(|(_, reviewer_assignment_id)| {
+                    // the None case should never happen
+                    s.reviewer_assignments
+                        .get(&reviewer_assignment_id)
+                        .map(|r_a| (reviewer_assignment_id, r_a))
+                })
+                .collect()
+        });
+
+        Ok(reviewer_assignments)
+    }
+
+    fn get_reviewer_assignments_by_user_id(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<(ReviewerAssignmentId, ReviewerAssignment)>, ApiError> {
+        let range = ReviewerAssignmentUserRange::new(user_id)?;
+
+        let reviewer_assignments = STATE.with_borrow(|s| {
+            s.user_id_index
+                .range(range)
+                .filter_map(|(_, reviewer_assignment_id)| {
+                    // the None case should never happen
+                    s.reviewer_assignments
+                        .get(&reviewer_assignment_id)
+                        .map(|r_a| (reviewer_assignment_id, r_a))
+                })
+                .collect()
+        });
+
+        Ok(reviewer_assignments)
+    }
+
+    fn get_open_reviewer_assignments(&self) -> Vec<(ReviewerAssignmentId, ReviewerAssignment)> {
+        STATE.with_borrow(|s| {
+            s.reviewer_assignments
+                .iter()
+                .filter(|(_, reviewer_assignment)| reviewer_assignment.is_open())
+                .collect()
+        })
+    }
+
+    fn get_unfulfilled_reviewer_assignments(
+        &self,
+    ) -> Vec<(ReviewerAssignmentId, ReviewerAssignment)> {
+        STATE.with_borrow(|s| {
+            s.reviewer_assignments
+                .iter()
+                .filter(|(_, reviewer_assignment)| {
+                    reviewer_assignment.status == ReviewerAssignmentStatus::Unfulfilled
+                })
+                .collect()
+        })
+    }
+
+    fn create_reviewer_assignment(
+        &self,
+        reviewer_assignment: ReviewerAssignment,
+    ) -> Result<ReviewerAssignmentId, ApiError> {
+        let reviewer_assignment_id = ReviewerAssignmentId::new();
+        let proposal_user_key = ReviewerAssignmentProposalUserKey::new(
+            reviewer_assignment.proposal_id,
+            reviewer_assignment.user_id,
+            reviewer_assignment_id,
+        )?;
+        let user_key =
+            ReviewerAssignmentUserKey::new(reviewer_assignment.user_id, reviewer_assignment_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.reviewer_assignments
+                .insert(reviewer_assignment_id, reviewer_assignment);
+            s.proposal_id_user_id_index
+                .insert(proposal_user_key, reviewer_assignment_id);
+            s.user_id_index.insert(user_key, reviewer_assignment_id);
+        });
+
+        Ok(reviewer_assignment_id)
+    }
+
+    fn update_reviewer_assignment(
+        &self,
+        reviewer_assignment_id: ReviewerAssignmentId,
+        reviewer_assignment: ReviewerAssignment,
+    ) -> Result<(), ApiError> {
+        self.get_reviewer_assignment_by_id(&reviewer_assignment_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Reviewer assignment with id {} not found",
+                    reviewer_assignment_id
+                ))
+            })?;
+
+        STATE.with_borrow_mut(|s| {
+            s.reviewer_assignments
+                .insert(reviewer_assignment_id, reviewer_assignment);
+
+            Ok(())
+        })
+    }
+
+    fn get_reviewer_assignment_config(&self) -> ReviewerAssignmentConfig {
+        STATE.with_borrow(|s| s.config.get().clone())
+    }
+
+    fn set_reviewer_assignment_config(
+        &self,
+        config: ReviewerAssignmentConfig,
+    ) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.config
+                .set(config)
+                .map(|_| ())
+                .map_err(|_| ApiError::internal("Failed to set reviewer assignment config"))
+        })
+    }
+}
+
+impl ReviewerAssignmentRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct ReviewerAssignmentState {
+    reviewer_assignments: ReviewerAssignmentMemory,
+    proposal_id_user_id_index: ReviewerAssignmentProposalIdUserIdIndexMemory,
+    user_id_index: ReviewerAssignmentUserIdIndexMemory,
+    config: ReviewerAssignmentConfigMemory,
+}
+
+impl Default for ReviewerAssignmentState {
+    fn default() -> Self {
+        Self {
+            reviewer_assignments: init_reviewer_assignments(),
+            proposal_id_user_id_index: init_reviewer_assignment_proposal_id_user_id_index(),
+            user_id_index: init_reviewer_assignment_user_id_index(),
+            config: init_reviewer_assignment_config(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<ReviewerAssignmentState> = RefCell::new(ReviewerAssignmentState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b},
+        repositories::ReviewerAssignmentPolicy,
+    };
+    use rstest::*;
+
+    #[rstest]
+    #[case::pending(fixtures::reviewer_assignment_pending())]
+    #[case::accepted(fixtures::reviewer_assignment_accepted())]
+    #[case::declined(fixtures::reviewer_assignment_declined())]
+    fn create_and_get_reviewer_assignment_by_id(#[case] reviewer_assignment: ReviewerAssignment) {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+        let reviewer_assignment_id = repository
+            .create_reviewer_assignment(reviewer_assignment.clone())
+            .unwrap();
+
+        let result = repository.get_reviewer_assignment_by_id(&reviewer_assignment_id);
+
+        assert_eq!(result, Some(reviewer_assignment));
+    }
+
+    #[rstest]
+    fn get_reviewer_assignments_by_proposal_id_and_user_id() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        for (proposal_id, user_id) in proposal_and_user_ids() {
+            repository
+                .create_reviewer_assignment(ReviewerAssignment {
+                    proposal_id,
+                    user_id,
+                    ..fixtures::reviewer_assignment_pending()
+                })
+                .unwrap();
+        }
+
+        for (proposal_id, user_id) in proposal_and_user_ids() {
+            let (_, reviewer_assignment) = repository
+                .get_reviewer_assignment_by_proposal_id_and_user_id(proposal_id, user_id)
+                .unwrap();
+
+            assert_eq!(
+                reviewer_assignment,
+                ReviewerAssignment {
+                    proposal_id,
+                    user_id,
+                    ..fixtures::reviewer_assignment_pending()
+                },
+            );
+        }
+    }
+
+    #[rstest]
+    fn get_reviewer_assignments_by_proposal_id() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        for (proposal_id, user_id) in proposal_and_user_ids() {
+            repository
+                .create_reviewer_assignment(ReviewerAssignment {
+                    proposal_id,
+                    user_id,
+                    ..fixtures::reviewer_assignment_pending()
+                })
+                .unwrap();
+        }
+
+        let result = repository
+            .get_reviewer_assignments_by_proposal_id(uuid_a())
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|(_, r_a)| r_a.proposal_id == uuid_a()));
+    }
+
+    #[rstest]
+    fn get_reviewer_assignments_by_user_id() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        for (proposal_id, user_id) in proposal_and_user_ids() {
+            repository
+                .create_reviewer_assignment(ReviewerAssignment {
+                    proposal_id,
+                    user_id,
+                    ..fixtures::reviewer_assignment_pending()
+                })
+                .unwrap();
+        }
+
+        let result = repository
+            .get_reviewer_assignments_by_user_id(uuid_b())
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().all(|(_, r_a)| r_a.user_id == uuid_b()));
+    }
+
+    #[rstest]
+    fn get_open_reviewer_assignments() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        let pending_id = repository
+            .create_reviewer_assignment(fixtures::reviewer_assignment_pending())
+            .unwrap();
+        let accepted_id = repository
+            .create_reviewer_assignment(fixtures::reviewer_assignment_accepted())
+            .unwrap();
+        repository
+            .create_reviewer_assignment(fixtures::reviewer_assignment_declined())
+            .unwrap();
+
+        let mut result = repository
+            .get_open_reviewer_assignments()
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>();
+        result.sort();
+
+        let mut expected = vec![pending_id, accepted_id];
+        expected.sort();
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn get_unfulfilled_reviewer_assignments() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        let unfulfilled_reviewer_assignment = ReviewerAssignment {
+            status: ReviewerAssignmentStatus::Unfulfilled,
+            ..fixtures::reviewer_assignment_accepted()
+        };
+        let unfulfilled_id = repository
+            .create_reviewer_assignment(unfulfilled_reviewer_assignment.clone())
+            .unwrap();
+        repository
+            .create_reviewer_assignment(ReviewerAssignment {
+                status: ReviewerAssignmentStatus::Fulfilled,
+                ..fixtures::reviewer_assignment_accepted()
+            })
+            .unwrap();
+        repository
+            .create_reviewer_assignment(fixtures::reviewer_assignment_pending())
+            .unwrap();
+
+        let result = repository.get_unfulfilled_reviewer_assignments();
+
+        assert_eq!(
+            result,
+            vec![(unfulfilled_id, unfulfilled_reviewer_assignment)]
+        );
+    }
+
+    #[rstest]
+    fn update_reviewer_assignment() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+        let reviewer_assignment_id = repository
+            .create_reviewer_assignment(fixtures::reviewer_assignment_pending())
+            .unwrap();
+
+        let updated_reviewer_assignment = ReviewerAssignment {
+            status: ReviewerAssignmentStatus::Fulfilled,
+            ..fixtures::reviewer_assignment_pending()
+        };
+        repository
+            .update_reviewer_assignment(reviewer_assignment_id, updated_reviewer_assignment.clone())
+            .unwrap();
+
+        let result = repository
+            .get_reviewer_assignment_by_id(&reviewer_assignment_id)
+            .unwrap();
+
+        assert_eq!(result, updated_reviewer_assignment);
+    }
+
+    #[rstest]
+    fn update_reviewer_assignment_not_found() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+        let reviewer_assignment_id = fixtures::uuid();
+
+        let result = repository
+            .update_reviewer_assignment(
+                reviewer_assignment_id,
+                fixtures::reviewer_assignment_pending(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Reviewer assignment with id {} not found",
+                reviewer_assignment_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_and_set_reviewer_assignment_config() {
+        STATE.set(ReviewerAssignmentState::default());
+
+        let repository = ReviewerAssignmentRepositoryImpl::default();
+
+        assert_eq!(
+            repository.get_reviewer_assignment_config(),
+            ReviewerAssignmentConfig::default()
+        );
+
+        let config = ReviewerAssignmentConfig {
+            policy: ReviewerAssignmentPolicy::LeastLoaded,
+            reviewers_per_proposal: 2,
+            round_robin_cursor: None,
+        };
+        repository
+            .set_reviewer_assignment_config(config.clone())
+            .unwrap();
+
+        assert_eq!(repository.get_reviewer_assignment_config(), config);
+    }
+
+    fn proposal_and_user_ids() -> Vec<(ProposalId, UserId)> {
+        vec![
+            (uuid_a(), uuid_a()),
+            (uuid_a(), uuid_b()),
+            (uuid_b(), uuid_a()),
+            (uuid_b(), uuid_b()),
+        ]
+    }
+}
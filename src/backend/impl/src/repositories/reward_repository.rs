@@ -0,0 +1,497 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_reward_payouts, init_reward_periods, init_reward_statements, DateTime, RewardPayout,
+    RewardPayoutMemory, RewardPeriod, RewardPeriodId, RewardPeriodMemory, RewardPeriodStatus,
+    RewardStatement, RewardStatementKey, RewardStatementMemory, RewardStatementRange, UserId,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RewardRepository {
+    fn get_reward_period_by_id(&self, reward_period_id: &RewardPeriodId) -> Option<RewardPeriod>;
+
+    /// Returns all the reward periods, ordered by start date.
+    fn get_reward_periods(&self) -> Vec<(RewardPeriodId, RewardPeriod)>;
+
+    fn create_reward_period(&self, reward_period: RewardPeriod)
+        -> Result<RewardPeriodId, ApiError>;
+
+    fn update_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+        reward_period: RewardPeriod,
+    ) -> Result<(), ApiError>;
+
+    /// Stores the statements of the reward period and marks it as closed.
+    /// Fails if the reward period is already closed, so that statements are never overwritten.
+    fn close_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+        closed_at: DateTime,
+        reward_statements: Vec<RewardStatement>,
+    ) -> Result<(), ApiError>;
+
+    fn get_reward_statements_by_reward_period_id(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<Vec<RewardStatement>, ApiError>;
+
+    fn get_reward_payout(
+        &self,
+        reward_period_id: RewardPeriodId,
+        user_id: UserId,
+    ) -> Result<Option<RewardPayout>, ApiError>;
+
+    fn get_reward_payouts_by_reward_period_id(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<Vec<RewardPayout>, ApiError>;
+
+    fn upsert_reward_payout(
+        &self,
+        reward_period_id: RewardPeriodId,
+        reward_payout: RewardPayout,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct RewardRepositoryImpl {}
+
+impl Default for RewardRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RewardRepository for RewardRepositoryImpl {
+    fn get_reward_period_by_id(&self, reward_period_id: &RewardPeriodId) -> Option<RewardPeriod> {
+        STATE.with_borrow(|s| s.reward_periods.get(reward_period_id))
+    }
+
+    fn get_reward_periods(&self) -> Vec<(RewardPeriodId, RewardPeriod)> {
+        let mut reward_periods: Vec<_> = STATE.with_borrow(|s| s.reward_periods.iter().collect());
+
+        reward_periods.sort_by_key(|(_, reward_period)| reward_period.starts_at);
+
+        reward_periods
+    }
+
+    fn create_reward_period(
+        &self,
+        reward_period: RewardPeriod,
+    ) -> Result<RewardPeriodId, ApiError> {
+        let reward_period_id = RewardPeriodId::new();
+
+        STATE.with_borrow_mut(|s| {
+            s.reward_periods.insert(reward_period_id, reward_period);
+        });
+
+        Ok(reward_period_id)
+    }
+
+    fn update_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+        reward_period: RewardPeriod,
+    ) -> Result<(), ApiError> {
+        let existing_reward_period =
+            self.get_reward_period_by_id(&reward_period_id)
+                .ok_or_else(|| {
+                    ApiError::not_found(&format!(
+                        "Reward period with Id {} not found",
+                        reward_period_id
+                    ))
+                })?;
+
+        if existing_reward_period.is_closed() {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            )));
+        }
+
+        STATE.with_borrow_mut(|s| {
+            s.reward_periods.insert(reward_period_id, reward_period);
+        });
+
+        Ok(())
+    }
+
+    fn close_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+        closed_at: DateTime,
+        reward_statements: Vec<RewardStatement>,
+    ) -> Result<(), ApiError> {
+        let mut reward_period =
+            self.get_reward_period_by_id(&reward_period_id)
+                .ok_or_else(|| {
+                    ApiError::not_found(&format!(
+                        "Reward period with Id {} not found",
+                        reward_period_id
+                    ))
+                })?;
+
+        if reward_period.is_closed() {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            )));
+        }
+
+        let statement_keys = reward_statements
+            .iter()
+            .map(|reward_statement| {
+                RewardStatementKey::new(reward_period_id, reward_statement.user_id)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        reward_period.status = RewardPeriodStatus::Closed { closed_at };
+
+        STATE.with_borrow_mut(|s| {
+            for (key, reward_statement) in statement_keys.into_iter().zip(reward_statements) {
+                s.reward_statements.insert(key, reward_statement);
+            }
+
+            s.reward_periods.insert(reward_period_id, reward_period);
+        });
+
+        Ok(())
+    }
+
+    fn get_reward_statements_by_reward_period_id(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<Vec<RewardStatement>, ApiError> {
+        let range = RewardStatementRange::new(reward_period_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.reward_statements
+                .range(range)
+                .map(|(_, reward_statement)| reward_statement)
+                .collect()
+        }))
+    }
+
+    fn get_reward_payout(
+        &self,
+        reward_period_id: RewardPeriodId,
+        user_id: UserId,
+    ) -> Result<Option<RewardPayout>, ApiError> {
+        let key = RewardStatementKey::new(reward_period_id, user_id)?;
+
+        Ok(STATE.with_borrow(|s| s.reward_payouts.get(&key)))
+    }
+
+    fn get_reward_payouts_by_reward_period_id(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<Vec<RewardPayout>, ApiError> {
+        let range = RewardStatementRange::new(reward_period_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.reward_payouts
+                .range(range)
+                .map(|(_, reward_payout)| reward_payout)
+                .collect()
+        }))
+    }
+
+    fn upsert_reward_payout(
+        &self,
+        reward_period_id: RewardPeriodId,
+        reward_payout: RewardPayout,
+    ) -> Result<(), ApiError> {
+        let key = RewardStatementKey::new(reward_period_id, reward_payout.user_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            // a completed payout must never be sent again
+            if s.reward_payouts
+                .get(&key)
+                .is_some_and(|existing_reward_payout| existing_reward_payout.is_completed())
+            {
+                return Err(ApiError::conflict(&format!(
+                    "Reward payout for user with Id {} in reward period with Id {} is already completed",
+                    reward_payout.user_id, reward_period_id
+                )));
+            }
+
+            s.reward_payouts.insert(key, reward_payout);
+
+            Ok(())
+        })
+    }
+}
+
+impl RewardRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct RewardState {
+    reward_periods: RewardPeriodMemory,
+    reward_statements: RewardStatementMemory,
+    reward_payouts: RewardPayoutMemory,
+}
+
+impl Default for RewardState {
+    fn default() -> Self {
+        Self {
+            reward_periods: init_reward_periods(),
+            reward_statements: init_reward_statements(),
+            reward_payouts: init_reward_payouts(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<RewardState> = RefCell::new(RewardState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{self, uuid_b};
+    use rstest::*;
+
+    #[rstest]
+    fn create_and_get_reward_period_by_id() {
+        STATE.set(RewardState::default());
+
+        let reward_period = fixtures::reward_period_open();
+
+        let repository = RewardRepositoryImpl::default();
+        let reward_period_id = repository
+            .create_reward_period(reward_period.clone())
+            .unwrap();
+
+        let result = repository.get_reward_period_by_id(&reward_period_id);
+
+        assert_eq!(result, Some(reward_period));
+    }
+
+    #[rstest]
+    fn get_reward_periods() {
+        STATE.set(RewardState::default());
+
+        let later_reward_period = RewardPeriod {
+            starts_at: fixtures::date_time_a(),
+            ends_at: fixtures::date_time_future(),
+            ..fixtures::reward_period_open()
+        };
+        let earlier_reward_period = fixtures::reward_period_open();
+
+        let repository = RewardRepositoryImpl::default();
+        let later_id = repository
+            .create_reward_period(later_reward_period.clone())
+            .unwrap();
+        let earlier_id = repository
+            .create_reward_period(earlier_reward_period.clone())
+            .unwrap();
+
+        let result = repository.get_reward_periods();
+
+        assert_eq!(
+            result,
+            vec![
+                (earlier_id, earlier_reward_period),
+                (later_id, later_reward_period)
+            ]
+        );
+    }
+
+    #[rstest]
+    fn update_reward_period() {
+        STATE.set(RewardState::default());
+
+        let updated_reward_period = RewardPeriod {
+            name: "Updated reward period".to_string(),
+            last_updated_at: Some(fixtures::date_time_a()),
+            ..fixtures::reward_period_open()
+        };
+
+        let repository = RewardRepositoryImpl::default();
+        let reward_period_id = repository
+            .create_reward_period(fixtures::reward_period_open())
+            .unwrap();
+        repository
+            .update_reward_period(reward_period_id, updated_reward_period.clone())
+            .unwrap();
+
+        let result = repository.get_reward_period_by_id(&reward_period_id);
+
+        assert_eq!(result, Some(updated_reward_period));
+    }
+
+    #[rstest]
+    fn close_reward_period() {
+        STATE.set(RewardState::default());
+
+        let reward_statements = vec![
+            fixtures::reward_statement(),
+            RewardStatement {
+                user_id: uuid_b(),
+                ..fixtures::reward_statement()
+            },
+        ];
+
+        let repository = RewardRepositoryImpl::default();
+        let reward_period_id = repository
+            .create_reward_period(fixtures::reward_period_open())
+            .unwrap();
+        let other_reward_period_id = repository
+            .create_reward_period(fixtures::reward_period_open())
+            .unwrap();
+        repository
+            .close_reward_period(
+                reward_period_id,
+                fixtures::date_time_a(),
+                reward_statements.clone(),
+            )
+            .unwrap();
+
+        let reward_period = repository.get_reward_period_by_id(&reward_period_id);
+        let result = repository
+            .get_reward_statements_by_reward_period_id(reward_period_id)
+            .unwrap();
+        let other_result = repository
+            .get_reward_statements_by_reward_period_id(other_reward_period_id)
+            .unwrap();
+
+        assert_eq!(reward_period, Some(fixtures::reward_period_closed()));
+        assert_eq!(result.len(), 2);
+        assert!(reward_statements
+            .iter()
+            .all(|reward_statement| result.contains(reward_statement)));
+        assert!(other_result.is_empty());
+    }
+
+    #[rstest]
+    fn close_reward_period_already_closed() {
+        STATE.set(RewardState::default());
+
+        let repository = RewardRepositoryImpl::default();
+        let reward_period_id = repository
+            .create_reward_period(fixtures::reward_period_open())
+            .unwrap();
+        repository
+            .close_reward_period(
+                reward_period_id,
+                fixtures::date_time_a(),
+                vec![fixtures::reward_statement()],
+            )
+            .unwrap();
+
+        let result = repository
+            .close_reward_period(
+                reward_period_id,
+                fixtures::date_time_a(),
+                vec![RewardStatement {
+                    total_e8s: 0,
+                    ..fixtures::reward_statement()
+                }],
+            )
+            .unwrap_err();
+        let reward_statements = repository
+            .get_reward_statements_by_reward_period_id(reward_period_id)
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            ))
+        );
+        assert_eq!(reward_statements, vec![fixtures::reward_statement()]);
+    }
+
+    #[rstest]
+    fn update_reward_period_closed() {
+        STATE.set(RewardState::default());
+
+        let repository = RewardRepositoryImpl::default();
+        let reward_period_id = repository
+            .create_reward_period(fixtures::reward_period_open())
+            .unwrap();
+        repository
+            .close_reward_period(reward_period_id, fixtures::date_time_a(), vec![])
+            .unwrap();
+
+        let result = repository
+            .update_reward_period(reward_period_id, fixtures::reward_period_open())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn upsert_and_get_reward_payouts() {
+        STATE.set(RewardState::default());
+
+        let reward_period_id = fixtures::uuid();
+        let other_reward_payout = RewardPayout {
+            user_id: uuid_b(),
+            ..fixtures::reward_payout_pending()
+        };
+
+        let repository = RewardRepositoryImpl::default();
+        repository
+            .upsert_reward_payout(reward_period_id, fixtures::reward_payout_pending())
+            .unwrap();
+        repository
+            .upsert_reward_payout(reward_period_id, fixtures::reward_payout_completed())
+            .unwrap();
+        repository
+            .upsert_reward_payout(fixtures::uuid_a(), other_reward_payout)
+            .unwrap();
+
+        let result = repository
+            .get_reward_payout(reward_period_id, fixtures::user_id())
+            .unwrap();
+        let reward_payouts = repository
+            .get_reward_payouts_by_reward_period_id(reward_period_id)
+            .unwrap();
+
+        assert_eq!(result, Some(fixtures::reward_payout_completed()));
+        assert_eq!(reward_payouts, vec![fixtures::reward_payout_completed()]);
+    }
+
+    #[rstest]
+    fn upsert_reward_payout_completed() {
+        STATE.set(RewardState::default());
+
+        let reward_period_id = fixtures::uuid();
+
+        let repository = RewardRepositoryImpl::default();
+        repository
+            .upsert_reward_payout(reward_period_id, fixtures::reward_payout_completed())
+            .unwrap();
+
+        let result = repository
+            .upsert_reward_payout(reward_period_id, fixtures::reward_payout_pending())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward payout for user with Id {} in reward period with Id {} is already completed",
+                fixtures::user_id(),
+                reward_period_id
+            ))
+        );
+        assert_eq!(
+            repository
+                .get_reward_payout(reward_period_id, fixtures::user_id())
+                .unwrap(),
+            Some(fixtures::reward_payout_completed())
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{init_user_roles, UserId, UserRoles, UserRolesMemory};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RoleRepository {
+    fn get_user_roles_by_user_id(&self, user_id: &UserId) -> Option<UserRoles>;
+
+    fn upsert_user_roles(&self, user_id: UserId, user_roles: UserRoles) -> Result<(), ApiError>;
+}
+
+pub struct RoleRepositoryImpl {}
+
+impl Default for RoleRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoleRepository for RoleRepositoryImpl {
+    fn get_user_roles_by_user_id(&self, user_id: &UserId) -> Option<UserRoles> {
+        STATE.with_borrow(|s| s.user_roles.get(user_id))
+    }
+
+    fn upsert_user_roles(&self, user_id: UserId, user_roles: UserRoles) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            s.user_roles.insert(user_id, user_roles);
+        });
+
+        Ok(())
+    }
+}
+
+impl RoleRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct RoleState {
+    user_roles: UserRolesMemory,
+}
+
+impl Default for RoleState {
+    fn default() -> Self {
+        Self {
+            user_roles: init_user_roles(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<RoleState> = RefCell::new(RoleState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn upsert_and_get_user_roles() {
+        STATE.set(RoleState::default());
+
+        let user_id = fixtures::user_id();
+        let repository = RoleRepositoryImpl::default();
+        let updated_user_roles = UserRoles {
+            roles: vec![],
+            ..fixtures::user_roles()
+        };
+
+        repository
+            .upsert_user_roles(user_id, fixtures::user_roles())
+            .unwrap();
+        repository
+            .upsert_user_roles(user_id, updated_user_roles.clone())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_user_roles_by_user_id(&user_id),
+            Some(updated_user_roles)
+        );
+        assert_eq!(
+            repository.get_user_roles_by_user_id(&fixtures::uuid_a()),
+            None
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{storable::Bound, Storable};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AdminSyncConfig {
+    /// Gives admin permissions to the controllers that are not admins yet.
+    pub promote_controllers: bool,
+    /// Revokes admin permissions from the admins that were promoted as controllers
+    /// and are no longer controllers. Admins promoted manually are never demoted.
+    pub demote_removed_controllers: bool,
+}
+
+impl Default for AdminSyncConfig {
+    fn default() -> Self {
+        Self {
+            promote_controllers: true,
+            demote_removed_controllers: false,
+        }
+    }
+}
+
+impl Storable for AdminSyncConfig {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let config = AdminSyncConfig {
+            promote_controllers: false,
+            demote_removed_controllers: true,
+        };
+
+        let serialized_config = config.to_bytes();
+        let deserialized_config = AdminSyncConfig::from_bytes(serialized_config);
+
+        assert_eq!(config, deserialized_config);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{borrow::Cow, fmt::Display};
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::{DateTime, UserId, Uuid};
+
+pub type ApprovalRequestId = Uuid;
+
+/// An admin action that can be configured to require the approval of several admins.
+/// Closing proposals is not one of them: proposals are only closed by the timer job,
+/// and by the `close_proposal` endpoint of `dev` builds, neither of which an admin can call.
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SensitiveAction {
+    SetUserRoles,
+    UpdateUserProfile,
+    ExecuteRewardPayouts,
+    UpdateReviewerAssignmentConfig,
+    UpdateAdminSyncConfig,
+    UpdateApprovalConfig,
+    SuspendUser,
+    RestoreUser,
+    ApproveReviewerApplication,
+}
+
+impl SensitiveAction {
+    pub fn all() -> Vec<Self> {
+        vec![
+            SensitiveAction::SetUserRoles,
+            SensitiveAction::UpdateUserProfile,
+            SensitiveAction::ExecuteRewardPayouts,
+            SensitiveAction::UpdateReviewerAssignmentConfig,
+            SensitiveAction::UpdateAdminSyncConfig,
+            SensitiveAction::UpdateApprovalConfig,
+            SensitiveAction::SuspendUser,
+            SensitiveAction::RestoreUser,
+            SensitiveAction::ApproveReviewerApplication,
+        ]
+    }
+}
+
+impl Display for SensitiveAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            SensitiveAction::SetUserRoles => "set_user_roles",
+            SensitiveAction::UpdateUserProfile => "update_user_profile",
+            SensitiveAction::ExecuteRewardPayouts => "execute_reward_payouts",
+            SensitiveAction::UpdateReviewerAssignmentConfig => "update_reviewer_assignment_config",
+            SensitiveAction::UpdateAdminSyncConfig => "update_admin_sync_config",
+            SensitiveAction::UpdateApprovalConfig => "update_approval_config",
+            SensitiveAction::SuspendUser => "suspend_user",
+            SensitiveAction::RestoreUser => "restore_user",
+            SensitiveAction::ApproveReviewerApplication => "approve_reviewer_application",
+        };
+        write!(f, "{}", val)
+    }
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApprovalConfig {
+    /// The number of distinct approvals, the requester's included, a sensitive action needs.
+    /// Sensitive actions are executed right away while it is 1.
+    pub required_approvals: u8,
+    pub timeout_seconds: u64,
+    pub sensitive_actions: Vec<SensitiveAction>,
+}
+
+impl Default for ApprovalConfig {
+    fn default() -> Self {
+        Self {
+            required_approvals: 1,
+            timeout_seconds: 86_400,
+            sensitive_actions: SensitiveAction::all(),
+        }
+    }
+}
+
+impl Storable for ApprovalConfig {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ApprovalRequestStatus {
+    Pending,
+    Approved,
+    Executed {
+        executed_by: UserId,
+        executed_at: DateTime,
+    },
+}
+
+/// A call to a sensitive endpoint that waits for the approval of other admins.
+/// Once approved, the action is executed by calling the endpoint again with the same arguments.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ApprovalRequest {
+    pub action: SensitiveAction,
+    /// The candid encoded arguments of the endpoint call.
+    pub arguments: Vec<u8>,
+    pub requested_by: UserId,
+    pub requested_at: DateTime,
+    pub expires_at: DateTime,
+    pub required_approvals: u8,
+    pub approved_by: Vec<UserId>,
+    pub status: ApprovalRequestStatus,
+}
+
+impl ApprovalRequest {
+    pub fn is_active(&self, current_time: &DateTime) -> bool {
+        &self.expires_at > current_time
+            && !matches!(self.status, ApprovalRequestStatus::Executed { .. })
+    }
+
+    pub fn matches(&self, action: SensitiveAction, arguments: &[u8]) -> bool {
+        self.action == action && self.arguments == arguments
+    }
+}
+
+impl Storable for ApprovalRequest {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::pending(fixtures::approval_request_pending())]
+    #[case::approved(fixtures::approval_request_approved())]
+    #[case::executed(fixtures::approval_request_executed())]
+    fn storable_impl(#[case] approval_request: ApprovalRequest) {
+        let serialized_approval_request = approval_request.to_bytes();
+        let deserialized_approval_request =
+            ApprovalRequest::from_bytes(serialized_approval_request);
+
+        assert_eq!(approval_request, deserialized_approval_request);
+    }
+
+    #[rstest]
+    fn approval_config_storable_impl() {
+        let config = ApprovalConfig {
+            required_approvals: 2,
+            timeout_seconds: 3_600,
+            sensitive_actions: vec![SensitiveAction::ExecuteRewardPayouts],
+        };
+
+        let serialized_config = config.to_bytes();
+        let deserialized_config = ApprovalConfig::from_bytes(serialized_config);
+
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[rstest]
+    fn is_active() {
+        let approval_request = fixtures::approval_request_pending();
+
+        assert!(approval_request.is_active(&approval_request.requested_at));
+        assert!(!approval_request.is_active(&approval_request.expires_at));
+        assert!(!fixtures::approval_request_executed().is_active(&approval_request.requested_at));
+    }
+}
@@ -0,0 +1,267 @@
+use crate::helpers::{gzip_compress, gzip_decompress};
+use backend_api::ApiError;
+use candid::{CandidType, Deserialize};
+
+const ALLOWED_ATTACHMENT_CONTENT_TYPES: &[&str] = &[
+    "text/plain",
+    "application/gzip",
+    "application/zip",
+    "application/x-tar",
+    "application/x-xz",
+];
+/// Content types that are not already compressed and benefit from
+/// being stored gzipped.
+const COMPRESSIBLE_ATTACHMENT_CONTENT_TYPES: &[&str] = &["text/plain", "application/x-tar"];
+pub const MAX_ATTACHMENT_SIZE_BYTES: usize = 1_500_000;
+const MAX_ATTACHMENT_FILE_NAME_CHARS: usize = 128;
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum AttachmentEncoding {
+    Identity,
+    Gzip,
+}
+
+impl AttachmentEncoding {
+    pub fn for_content_type(content_type: &str) -> Self {
+        if COMPRESSIBLE_ATTACHMENT_CONTENT_TYPES.contains(&content_type) {
+            AttachmentEncoding::Gzip
+        } else {
+            AttachmentEncoding::Identity
+        }
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            AttachmentEncoding::Identity => bytes.to_vec(),
+            AttachmentEncoding::Gzip => gzip_compress(bytes),
+        }
+    }
+
+    pub fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>, ApiError> {
+        match self {
+            AttachmentEncoding::Identity => Ok(bytes.to_vec()),
+            AttachmentEncoding::Gzip => gzip_decompress(bytes),
+        }
+    }
+}
+
+/// Marks an [Image](super::Image) as a generic file attachment (e.g. a build log)
+/// instead of a picture.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ImageAttachment {
+    pub file_name: String,
+    /// The encoding of the image `content_bytes`.
+    pub content_encoding: AttachmentEncoding,
+}
+
+pub trait CreateAttachmentRequest {
+    fn file_name(&self) -> String;
+
+    fn content_type(&self) -> String;
+
+    fn content_bytes(&self) -> Vec<u8>;
+
+    fn validate_fields(&self) -> Result<(), ApiError> {
+        let file_name = self.file_name();
+
+        if file_name.is_empty() {
+            return Err(ApiError::invalid_argument("File name cannot be empty"));
+        }
+
+        if file_name.chars().count() > MAX_ATTACHMENT_FILE_NAME_CHARS {
+            return Err(ApiError::invalid_argument(&format!(
+                "File name must be less than {} characters",
+                MAX_ATTACHMENT_FILE_NAME_CHARS
+            )));
+        }
+
+        // the file name ends up in the content-disposition header
+        // and in the markdown summary, so keep it to a safe subset
+        if !file_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '_' || c == '-')
+            || file_name.starts_with('.')
+        {
+            return Err(ApiError::invalid_argument(&format!(
+                "File name {} is invalid",
+                file_name
+            )));
+        }
+
+        let content_type = self.content_type();
+
+        if content_type.is_empty() {
+            return Err(ApiError::invalid_argument("Content type cannot be empty"));
+        }
+
+        if !ALLOWED_ATTACHMENT_CONTENT_TYPES.contains(&content_type.as_str()) {
+            return Err(ApiError::invalid_argument(&format!(
+                "Content type {} not allowed",
+                content_type
+            )));
+        }
+
+        let content_size = self.content_bytes().len();
+
+        if content_size == 0 {
+            return Err(ApiError::invalid_argument(
+                "Attachment content cannot be empty",
+            ));
+        }
+
+        if content_size > MAX_ATTACHMENT_SIZE_BYTES {
+            return Err(ApiError::invalid_argument(&format!(
+                "Attachment content must be less than {} bytes",
+                MAX_ATTACHMENT_SIZE_BYTES
+            )));
+        }
+
+        if content_type == "text/plain" && std::str::from_utf8(&self.content_bytes()).is_err() {
+            return Err(ApiError::invalid_argument(
+                "Text attachments must be valid UTF-8",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::text("text/plain", AttachmentEncoding::Gzip)]
+    #[case::tar("application/x-tar", AttachmentEncoding::Gzip)]
+    #[case::gzip("application/gzip", AttachmentEncoding::Identity)]
+    #[case::zip("application/zip", AttachmentEncoding::Identity)]
+    fn attachment_encoding_for_content_type(
+        #[case] content_type: &str,
+        #[case] expected_encoding: AttachmentEncoding,
+    ) {
+        assert_eq!(
+            AttachmentEncoding::for_content_type(content_type),
+            expected_encoding
+        );
+    }
+
+    #[rstest]
+    #[case::identity(AttachmentEncoding::Identity)]
+    #[case::gzip(AttachmentEncoding::Gzip)]
+    fn attachment_encoding_roundtrip(#[case] encoding: AttachmentEncoding) {
+        let content = "Build reproduced successfully\n".repeat(1_000).into_bytes();
+
+        let encoded = encoding.encode(&content);
+        let decoded = encoding.decode(&encoded).unwrap();
+
+        assert_eq!(decoded, content);
+    }
+
+    #[rstest]
+    fn attachment_encoding_gzip_compresses() {
+        let content = "Build reproduced successfully\n".repeat(1_000).into_bytes();
+
+        let encoded = AttachmentEncoding::Gzip.encode(&content);
+
+        assert!(encoded.len() < content.len());
+    }
+
+    #[rstest]
+    fn attachment_encoding_gzip_corrupted() {
+        let content = "Build reproduced successfully\n".repeat(10).into_bytes();
+
+        let mut encoded = AttachmentEncoding::Gzip.encode(&content);
+        let len = encoded.len();
+        encoded[len - 5] ^= 0xff;
+
+        assert!(AttachmentEncoding::Gzip.decode(&encoded).is_err());
+    }
+
+    struct TestCreateAttachmentRequest {
+        file_name: String,
+        content_type: String,
+        content_bytes: Vec<u8>,
+    }
+
+    impl TestCreateAttachmentRequest {
+        fn new(file_name: &str, content_type: &str, content_bytes: Vec<u8>) -> Self {
+            Self {
+                file_name: file_name.to_string(),
+                content_type: content_type.to_string(),
+                content_bytes,
+            }
+        }
+    }
+
+    impl CreateAttachmentRequest for TestCreateAttachmentRequest {
+        fn file_name(&self) -> String {
+            self.file_name.clone()
+        }
+
+        fn content_type(&self) -> String {
+            self.content_type.clone()
+        }
+
+        fn content_bytes(&self) -> Vec<u8> {
+            self.content_bytes.clone()
+        }
+    }
+
+    #[rstest]
+    #[case::text("build.log", "text/plain", b"build output".to_vec())]
+    #[case::archive("build-logs_1.tar.gz", "application/gzip", vec![0x1f, 0x8b, 0x08])]
+    fn test_validate_fields(
+        #[case] file_name: &str,
+        #[case] content_type: &str,
+        #[case] content_bytes: Vec<u8>,
+    ) {
+        let request = TestCreateAttachmentRequest::new(file_name, content_type, content_bytes);
+
+        assert!(request.validate_fields().is_ok());
+    }
+
+    #[rstest]
+    #[case::file_name_empty(
+        TestCreateAttachmentRequest::new("", "text/plain", b"log".to_vec()),
+        ApiError::invalid_argument("File name cannot be empty")
+    )]
+    #[case::file_name_too_long(
+        TestCreateAttachmentRequest::new(&"a".repeat(129), "text/plain", b"log".to_vec()),
+        ApiError::invalid_argument("File name must be less than 128 characters")
+    )]
+    #[case::file_name_invalid_chars(
+        TestCreateAttachmentRequest::new("../build.log", "text/plain", b"log".to_vec()),
+        ApiError::invalid_argument("File name ../build.log is invalid")
+    )]
+    #[case::file_name_hidden(
+        TestCreateAttachmentRequest::new(".build.log", "text/plain", b"log".to_vec()),
+        ApiError::invalid_argument("File name .build.log is invalid")
+    )]
+    #[case::content_type_empty(
+        TestCreateAttachmentRequest::new("build.log", "", b"log".to_vec()),
+        ApiError::invalid_argument("Content type cannot be empty")
+    )]
+    #[case::content_type_invalid(
+        TestCreateAttachmentRequest::new("build.log", "text/html", b"log".to_vec()),
+        ApiError::invalid_argument("Content type text/html not allowed")
+    )]
+    #[case::content_bytes_empty(
+        TestCreateAttachmentRequest::new("build.log", "text/plain", vec![]),
+        ApiError::invalid_argument("Attachment content cannot be empty")
+    )]
+    #[case::content_bytes_too_large(
+        TestCreateAttachmentRequest::new("build.log", "text/plain", vec![b'a'; MAX_ATTACHMENT_SIZE_BYTES + 1]),
+        ApiError::invalid_argument("Attachment content must be less than 1500000 bytes")
+    )]
+    #[case::content_bytes_invalid_utf8(
+        TestCreateAttachmentRequest::new("build.log", "text/plain", vec![0xff, 0xfe]),
+        ApiError::invalid_argument("Text attachments must be valid UTF-8")
+    )]
+    fn test_validate_fields_error(
+        #[case] request: TestCreateAttachmentRequest,
+        #[case] expected_error: ApiError,
+    ) {
+        assert_eq!(request.validate_fields().unwrap_err(), expected_error);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{borrow::Cow, fmt::Debug};
+
+use candid::{CandidType, Decode, Deserialize, Encode, Principal};
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::DateTime;
+
+pub type AuditLogEntryId = u64;
+
+/// A privileged action, recorded in the audit log every time it changes the state of the canister.
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, PartialEq, Eq)]
+pub enum AuditAction {
+    UpdateUserProfile,
+    SuspendUser,
+    RestoreUser,
+    SetUserRoles,
+    SyncAdmins,
+    UpdateAdminSyncConfig,
+    ApproveReviewerApplication,
+    RejectReviewerApplication,
+    CreateRewardPeriod,
+    UpdateRewardPeriod,
+    CloseRewardPeriod,
+    ExecuteRewardPayouts,
+    VerifyReviewerNeuron,
+    AssignReviewer,
+    UpdateReviewerAssignmentConfig,
+    ApproveApprovalRequest,
+    UpdateApprovalConfig,
+    ResolveReviewConflict,
+    ScoreProposalReview,
+    SyncProposals,
+    ModerateComment,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct AuditLogEntry {
+    pub actor: Principal,
+    pub action: AuditAction,
+    /// The ids of the records affected by the action.
+    pub target_ids: Vec<String>,
+    /// A snapshot of the affected records before the action, if they existed.
+    pub before: Option<String>,
+    /// A snapshot of the affected records after the action, if they still exist.
+    pub after: Option<String>,
+    pub timestamp: DateTime,
+}
+
+/// Narrows down the listed audit log entries, unset fields match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditLogFilter {
+    pub action: Option<AuditAction>,
+    pub actor: Option<Principal>,
+    pub target_id: Option<String>,
+}
+
+impl AuditLogFilter {
+    pub fn is_empty(&self) -> bool {
+        self.action.is_none() && self.actor.is_none() && self.target_id.is_none()
+    }
+
+    pub fn matches(&self, audit_log_entry: &AuditLogEntry) -> bool {
+        self.action
+            .is_none_or(|action| audit_log_entry.action == action)
+            && self
+                .actor
+                .is_none_or(|actor| audit_log_entry.actor == actor)
+            && self
+                .target_id
+                .as_ref()
+                .is_none_or(|target_id| audit_log_entry.target_ids.contains(target_id))
+    }
+}
+
+/// Snapshots are stored as the debug representation of the records,
+/// so that they stay readable when the record types change.
+pub fn audit_snapshot<T: Debug>(value: &T) -> String {
+    format!("{:?}", value)
+}
+
+impl Storable for AuditLogEntry {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let audit_log_entry = fixtures::audit_log_entry();
+        let serialized_audit_log_entry = audit_log_entry.to_bytes();
+        let deserialized_audit_log_entry = AuditLogEntry::from_bytes(serialized_audit_log_entry);
+
+        assert_eq!(audit_log_entry, deserialized_audit_log_entry);
+    }
+
+    #[rstest]
+    #[case::empty(AuditLogFilter::default(), true)]
+    #[case::action_matching(
+        AuditLogFilter {
+            action: Some(AuditAction::SetUserRoles),
+            ..Default::default()
+        },
+        true
+    )]
+    #[case::action_not_matching(
+        AuditLogFilter {
+            action: Some(AuditAction::SuspendUser),
+            ..Default::default()
+        },
+        false
+    )]
+    #[case::actor_not_matching(
+        AuditLogFilter {
+            actor: Some(fixtures::principal_b()),
+            ..Default::default()
+        },
+        false
+    )]
+    #[case::target_id_matching(
+        AuditLogFilter {
+            actor: Some(fixtures::principal_a()),
+            target_id: Some(fixtures::uuid_c().to_string()),
+            ..Default::default()
+        },
+        true
+    )]
+    #[case::target_id_not_matching(
+        AuditLogFilter {
+            target_id: Some(fixtures::uuid_b().to_string()),
+            ..Default::default()
+        },
+        false
+    )]
+    fn filter_matches(#[case] filter: AuditLogFilter, #[case] expected: bool) {
+        assert_eq!(filter.matches(&fixtures::audit_log_entry()), expected);
+    }
+}
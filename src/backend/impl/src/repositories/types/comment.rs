@@ -0,0 +1,235 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{
+    DateTime, HistoryData, HistoryEntry, ProposalId, ProposalReviewCommitId, ProposalReviewId,
+    UserId, Uuid,
+};
+
+pub type CommentId = Uuid;
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CommentTarget {
+    Proposal(ProposalId),
+    ProposalReview(ProposalReviewId),
+    ProposalReviewCommit(ProposalReviewCommitId),
+}
+
+impl CommentTarget {
+    /// The id of the entity that is commented on.
+    /// All targets use random uuids, so the id alone is enough to identify the target.
+    pub fn id(&self) -> Uuid {
+        match self {
+            CommentTarget::Proposal(id)
+            | CommentTarget::ProposalReview(id)
+            | CommentTarget::ProposalReviewCommit(id) => *id,
+        }
+    }
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub target: CommentTarget,
+    /// The comment that this comment replies to,
+    /// or `None` if this is a top level comment.
+    pub parent_id: Option<CommentId>,
+    pub user_id: UserId,
+    pub content: String,
+    pub created_at: DateTime,
+    pub last_updated_at: Option<DateTime>,
+    /// Deleted comments are kept (with their content removed)
+    /// so that the replies to them stay in the thread.
+    pub deleted_at: Option<DateTime>,
+}
+
+impl Comment {
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+impl Storable for Comment {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+impl HistoryData for Comment {}
+
+pub type CommentHistoryEntry = HistoryEntry<Comment>;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommentTargetKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl CommentTargetKey {
+    const MAX_SIZE: u32 = <(Uuid, (DateTime, CommentId))>::BOUND.max_size();
+
+    pub fn new(
+        target_id: Uuid,
+        created_at: DateTime,
+        comment_id: CommentId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((target_id, (created_at, comment_id)).to_bytes().as_ref()).map_err(
+                |_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert target id {:?}, date time {:?} and comment id {:?} to bytes.",
+                        target_id, created_at, comment_id
+                    ))
+                },
+            )?,
+        ))
+    }
+}
+
+impl Storable for CommentTargetKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct CommentTargetRange {
+    start_bound: CommentTargetKey,
+    end_bound: CommentTargetKey,
+}
+
+impl CommentTargetRange {
+    pub fn new(target_id: Uuid) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: CommentTargetKey::new(target_id, DateTime::min(), CommentId::min())?,
+            end_bound: CommentTargetKey::new(target_id, DateTime::max()?, CommentId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<CommentTargetKey> for CommentTargetRange {
+    fn start_bound(&self) -> std::ops::Bound<&CommentTargetKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&CommentTargetKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommentHistoryKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl CommentHistoryKey {
+    const MAX_SIZE: u32 = <(CommentId, (DateTime, u128))>::BOUND.max_size();
+
+    pub fn new(comment_id: CommentId, date_time: DateTime, id: u128) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((comment_id, (date_time, id)).to_bytes().as_ref()).map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to convert date time {:?} and comment id {:?} to bytes.",
+                    date_time, comment_id
+                ))
+            })?,
+        ))
+    }
+}
+
+impl Storable for CommentHistoryKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct CommentHistoryRange {
+    start_bound: CommentHistoryKey,
+    end_bound: CommentHistoryKey,
+}
+
+impl CommentHistoryRange {
+    pub fn new(comment_id: CommentId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: CommentHistoryKey::new(comment_id, DateTime::min(), u128::MIN)?,
+            end_bound: CommentHistoryKey::new(comment_id, DateTime::max()?, u128::MAX)?,
+        })
+    }
+}
+
+impl RangeBounds<CommentHistoryKey> for CommentHistoryRange {
+    fn start_bound(&self) -> std::ops::Bound<&CommentHistoryKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&CommentHistoryKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::proposal(fixtures::comment_proposal())]
+    #[case::reply(fixtures::comment_reply())]
+    #[case::deleted(fixtures::comment_deleted())]
+    fn storable_impl(#[case] comment: Comment) {
+        let serialized_comment = comment.to_bytes();
+        let deserialized_comment = Comment::from_bytes(serialized_comment);
+
+        assert_eq!(comment, deserialized_comment);
+    }
+
+    #[rstest]
+    fn comment_target_key_storable_impl() {
+        let key = CommentTargetKey::new(
+            fixtures::proposal_id(),
+            fixtures::date_time_a(),
+            fixtures::uuid(),
+        )
+        .unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = CommentTargetKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    fn comment_target_key_ordering() {
+        let target_id = fixtures::proposal_id();
+
+        let earlier_key =
+            CommentTargetKey::new(target_id, fixtures::date_time_b(), CommentId::max()).unwrap();
+        let later_key =
+            CommentTargetKey::new(target_id, fixtures::date_time_a(), CommentId::min()).unwrap();
+
+        assert!(earlier_key < later_key);
+    }
+}
@@ -0,0 +1,132 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{CommitSha, DateTime, ProposalId, UserId};
+
+/// A reviewer's claim on a commit of a proposal, used to let other reviewers know
+/// who is working on which commit. Claims are released when they expire
+/// or when the review period of the proposal ends.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct CommitClaim {
+    pub proposal_id: ProposalId,
+    pub commit_sha: CommitSha,
+    pub user_id: UserId,
+    pub claimed_at: DateTime,
+    pub expires_at: DateTime,
+}
+
+impl CommitClaim {
+    pub fn is_active(&self, current_time: &DateTime) -> bool {
+        &self.expires_at > current_time
+    }
+}
+
+impl Storable for CommitClaim {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CommitClaimKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl CommitClaimKey {
+    const MAX_SIZE: u32 = <(ProposalId, CommitSha)>::BOUND.max_size();
+
+    pub fn new(proposal_id: ProposalId, commit_sha: CommitSha) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((proposal_id, commit_sha).to_bytes().as_ref()).map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to convert proposal id {:?} and commit sha {:?} to bytes.",
+                    proposal_id, commit_sha
+                ))
+            })?,
+        ))
+    }
+}
+
+impl Storable for CommitClaimKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct CommitClaimProposalRange {
+    start_bound: CommitClaimKey,
+    end_bound: CommitClaimKey,
+}
+
+impl CommitClaimProposalRange {
+    pub fn new(proposal_id: ProposalId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: CommitClaimKey::new(proposal_id, CommitSha::min())?,
+            end_bound: CommitClaimKey::new(proposal_id, CommitSha::max())?,
+        })
+    }
+}
+
+impl RangeBounds<CommitClaimKey> for CommitClaimProposalRange {
+    fn start_bound(&self) -> std::ops::Bound<&CommitClaimKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&CommitClaimKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let commit_claim = fixtures::commit_claim();
+
+        let serialized_commit_claim = commit_claim.to_bytes();
+        let deserialized_commit_claim = CommitClaim::from_bytes(serialized_commit_claim);
+
+        assert_eq!(commit_claim, deserialized_commit_claim);
+    }
+
+    #[rstest]
+    fn commit_claim_key_storable_impl() {
+        let key = CommitClaimKey::new(fixtures::proposal_id(), fixtures::commit_sha_a()).unwrap();
+
+        let serialized_key = key.to_bytes();
+        let deserialized_key = CommitClaimKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    fn commit_claim_is_active() {
+        let commit_claim = fixtures::commit_claim();
+
+        assert!(commit_claim.is_active(&commit_claim.claimed_at));
+        assert!(!commit_claim.is_active(&commit_claim.expires_at));
+    }
+}
@@ -43,6 +43,10 @@ impl DateTime {
         Self(self.0 - duration)
     }
 
+    pub fn add(&self, duration: chrono::Duration) -> Self {
+        Self(self.0 + duration)
+    }
+
     pub fn min() -> Self {
         Self(chrono::DateTime::<chrono::Utc>::UNIX_EPOCH)
     }
@@ -0,0 +1,135 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, ProposalReviewCommitId, ProposalReviewId, UserId, Uuid};
+
+pub type EndorsementId = Uuid;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct Endorsement {
+    pub proposal_review_id: ProposalReviewId,
+    /// The commit assessment that is endorsed,
+    /// or `None` if the endorsement is for the whole proposal review.
+    pub proposal_review_commit_id: Option<ProposalReviewCommitId>,
+    pub user_id: UserId,
+    pub created_at: DateTime,
+    pub note: Option<String>,
+}
+
+impl Endorsement {
+    pub fn is_for_proposal_review(&self) -> bool {
+        self.proposal_review_commit_id.is_none()
+    }
+}
+
+impl Storable for Endorsement {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EndorsementProposalReviewKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl EndorsementProposalReviewKey {
+    const MAX_SIZE: u32 = <(ProposalReviewId, EndorsementId)>::BOUND.max_size();
+
+    pub fn new(
+        proposal_review_id: ProposalReviewId,
+        endorsement_id: EndorsementId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((proposal_review_id, endorsement_id).to_bytes().as_ref()).map_err(
+                |_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert proposal review id {:?} and endorsement id {:?} to bytes.",
+                        proposal_review_id, endorsement_id
+                    ))
+                },
+            )?,
+        ))
+    }
+}
+
+impl Storable for EndorsementProposalReviewKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct EndorsementProposalReviewRange {
+    start_bound: EndorsementProposalReviewKey,
+    end_bound: EndorsementProposalReviewKey,
+}
+
+impl EndorsementProposalReviewRange {
+    pub fn new(proposal_review_id: ProposalReviewId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: EndorsementProposalReviewKey::new(
+                proposal_review_id,
+                EndorsementId::min(),
+            )?,
+            end_bound: EndorsementProposalReviewKey::new(proposal_review_id, EndorsementId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<EndorsementProposalReviewKey> for EndorsementProposalReviewRange {
+    fn start_bound(&self) -> std::ops::Bound<&EndorsementProposalReviewKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&EndorsementProposalReviewKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::proposal_review(fixtures::endorsement_proposal_review())]
+    #[case::proposal_review_commit(fixtures::endorsement_proposal_review_commit())]
+    fn storable_impl(#[case] endorsement: Endorsement) {
+        let serialized_endorsement = endorsement.to_bytes();
+        let deserialized_endorsement = Endorsement::from_bytes(serialized_endorsement);
+
+        assert_eq!(endorsement, deserialized_endorsement);
+    }
+
+    #[rstest]
+    fn endorsement_proposal_review_key_storable_impl() {
+        let proposal_review_id = fixtures::proposal_review_id();
+        let endorsement_id = fixtures::uuid();
+
+        let key = EndorsementProposalReviewKey::new(proposal_review_id, endorsement_id).unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = EndorsementProposalReviewKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+}
@@ -10,6 +10,7 @@ pub enum HistoryAction {
     Create,
     Update,
     Delete,
+    Suspend,
     Restore,
 }
 
@@ -21,16 +22,23 @@ pub struct HistoryEntry<T> {
     pub data: T,
 }
 
-impl<T> Storable for HistoryEntry<T>
-where
-    T: CandidType + for<'de> Deserialize<'de>,
-{
+/// Data recorded in a [HistoryEntry].
+pub trait HistoryData: CandidType + for<'de> Deserialize<'de> {
+    /// Migrates data stored by earlier versions of the canister,
+    /// `history_entry_bytes` being the whole stored history entry.
+    fn migrate(&mut self, _history_entry_bytes: &[u8]) {}
+}
+
+impl<T: HistoryData> Storable for HistoryEntry<T> {
     fn to_bytes(&self) -> Cow<[u8]> {
         Cow::Owned(Encode!(self).unwrap())
     }
 
     fn from_bytes(bytes: Cow<[u8]>) -> Self {
-        Decode!(&bytes, Self).unwrap()
+        let mut history_entry = Decode!(&bytes, Self).unwrap();
+        history_entry.data.migrate(&bytes);
+
+        history_entry
     }
 
     const BOUND: Bound = Bound::Unbounded;
@@ -55,6 +63,18 @@ impl<T> HistoryEntry<T> {
     pub fn update_action(calling_principal: Principal, data: T) -> Result<Self, ApiError> {
         Self::new(HistoryAction::Update, calling_principal, data)
     }
+
+    pub fn delete_action(calling_principal: Principal, data: T) -> Result<Self, ApiError> {
+        Self::new(HistoryAction::Delete, calling_principal, data)
+    }
+
+    pub fn suspend_action(calling_principal: Principal, data: T) -> Result<Self, ApiError> {
+        Self::new(HistoryAction::Suspend, calling_principal, data)
+    }
+
+    pub fn restore_action(calling_principal: Principal, data: T) -> Result<Self, ApiError> {
+        Self::new(HistoryAction::Restore, calling_principal, data)
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +86,9 @@ mod tests {
     #[rstest]
     #[case::create_action(create_action())]
     #[case::update_date(update_action())]
+    #[case::delete_action(delete_action())]
+    #[case::suspend_action(suspend_action())]
+    #[case::restore_action(restore_action())]
     fn storable_impl_user_profile(#[case] history_entry: UserProfileHistoryEntry) {
         let bytes = history_entry.to_bytes();
         let deserialized_history_entry = HistoryEntry::from_bytes(bytes);
@@ -88,4 +111,28 @@ mod tests {
 
         HistoryEntry::update_action(principal, user_profile).unwrap()
     }
+
+    #[fixture]
+    fn delete_action() -> UserProfileHistoryEntry {
+        let user_profile = fixtures::reviewer_user_profile();
+        let principal = fixtures::principal_a();
+
+        HistoryEntry::delete_action(principal, user_profile).unwrap()
+    }
+
+    #[fixture]
+    fn suspend_action() -> UserProfileHistoryEntry {
+        let user_profile = fixtures::reviewer_user_profile();
+        let principal = fixtures::principal_a();
+
+        HistoryEntry::suspend_action(principal, user_profile).unwrap()
+    }
+
+    #[fixture]
+    fn restore_action() -> UserProfileHistoryEntry {
+        let user_profile = fixtures::reviewer_user_profile();
+        let principal = fixtures::principal_a();
+
+        HistoryEntry::restore_action(principal, user_profile).unwrap()
+    }
 }
@@ -1,6 +1,10 @@
-use std::{borrow::Cow, path::PathBuf, str::FromStr};
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use super::{DateTime, UserId, Uuid};
+use super::{DateTime, ImageAttachment, UserId, Uuid};
 use backend_api::ApiError;
 use candid::{CandidType, Decode, Deserialize, Encode};
 use ic_stable_structures::{storable::Bound, Storable};
@@ -18,6 +22,9 @@ pub struct Image {
     pub content_type: String,
     pub sub_path: Option<PathBuf>,
     pub content_bytes: Vec<u8>,
+    /// Set when the image is a file attachment rather than a picture,
+    /// see [ImageAttachment].
+    pub attachment: Option<ImageAttachment>,
 }
 
 impl Storable for Image {
@@ -36,16 +43,30 @@ impl Image {
     /// Returns the path of the image in the form of `/images/<image_id>`
     /// or `/images/<sub_path>/<image_id>` if the `sub_path` field is set.
     pub fn path(&self, id: &ImageId) -> String {
-        let mut path = PathBuf::from_str(IMAGES_BASE_PATH).unwrap();
+        image_path(self.sub_path.as_deref(), id)
+    }
 
-        if let Some(sub_path) = &self.sub_path {
-            path = path.join(sub_path);
+    /// Returns the original content of the image,
+    /// decoding it if the image is an attachment.
+    pub fn decoded_content_bytes(&self) -> Result<Vec<u8>, ApiError> {
+        match &self.attachment {
+            Some(attachment) => attachment.content_encoding.decode(&self.content_bytes),
+            None => Ok(self.content_bytes.clone()),
         }
+    }
+}
 
-        let id = PathBuf::from(id.to_string());
+/// Returns the path of an image without loading it, see [Image::path].
+pub fn image_path(sub_path: Option<&Path>, id: &ImageId) -> String {
+    let mut path = PathBuf::from_str(IMAGES_BASE_PATH).unwrap();
 
-        path.join(id).to_string_lossy().to_string()
+    if let Some(sub_path) = sub_path {
+        path = path.join(sub_path);
     }
+
+    let id = PathBuf::from(id.to_string());
+
+    path.join(id).to_string_lossy().to_string()
 }
 
 pub trait CreateImageRequest {
@@ -94,6 +115,8 @@ mod tests {
     #[rstest]
     #[case::image_with_path(fixtures::image_with_subpath())]
     #[case::image_without_path(fixtures::image_without_subpath())]
+    #[case::attachment_with_path(fixtures::attachment_with_subpath())]
+    #[case::attachment_without_path(fixtures::attachment_without_subpath())]
     fn image_storable_impl(#[case] image: Image) {
         let serialized_image = image.to_bytes();
         let deserialized_image = Image::from_bytes(serialized_image);
@@ -108,6 +131,14 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::image(fixtures::image_with_subpath(), fixtures::image_with_subpath().content_bytes)]
+    #[case::attachment_gzip(fixtures::attachment_with_subpath(), fixtures::SAMPLE_BUILD_LOG.as_bytes().to_vec())]
+    #[case::attachment_identity(fixtures::attachment_without_subpath(), fixtures::attachment_without_subpath().content_bytes)]
+    fn image_decoded_content_bytes(#[case] image: Image, #[case] expected_content: Vec<u8>) {
+        assert_eq!(image.decoded_content_bytes().unwrap(), expected_content);
+    }
+
     #[fixture]
     fn images() -> Vec<(ImageId, Image, String)> {
         vec![
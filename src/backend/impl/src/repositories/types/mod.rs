@@ -1,23 +1,63 @@
+mod admin_sync;
+mod approval;
+mod attachment;
+mod audit_log;
+mod comment;
+mod commit_claim;
 mod commit_sha;
 mod date_time;
+mod endorsement;
 mod history;
 mod image;
 mod log;
+mod neuron_verification;
+mod principal_link;
 mod proposal;
 mod proposal_review;
 mod proposal_review_commit;
+mod review_conflict;
+mod review_score;
+mod reviewer_application;
+mod reviewer_assignment;
+mod reward;
+mod role;
+mod social_link;
 mod user_profile;
 mod user_profile_history;
+mod user_status;
+mod username;
 mod uuid;
+mod vote_alignment;
+mod wallet_address;
 
+pub use admin_sync::*;
+pub use approval::*;
+pub use attachment::*;
+pub use audit_log::*;
+pub use comment::*;
+pub use commit_claim::*;
 pub use commit_sha::*;
 pub use date_time::*;
+pub use endorsement::*;
 pub use history::*;
 pub use image::*;
 pub use log::*;
+pub use neuron_verification::*;
+pub use principal_link::*;
 pub use proposal::*;
 pub use proposal_review::*;
 pub use proposal_review_commit::*;
+pub use review_conflict::*;
+pub use review_score::*;
+pub use reviewer_application::*;
+pub use reviewer_assignment::*;
+pub use reward::*;
+pub use role::*;
+pub use social_link::*;
 pub use user_profile::*;
 pub use user_profile_history::*;
+pub use user_status::*;
+pub use username::*;
 pub use uuid::*;
+pub use vote_alignment::*;
+pub use wallet_address::*;
@@ -0,0 +1,96 @@
+use std::borrow::Cow;
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use chrono::Duration;
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::{DateTime, NeuronId};
+
+/// How long a successful verification is trusted before the neuron is checked again.
+pub const NEURON_VERIFICATION_VALIDITY_HOURS: i64 = 24;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum NeuronVerificationStatus {
+    /// The neuron is controlled by, or has a hot key of, one of the reviewer's principals.
+    Verified,
+    Unverified {
+        reason: String,
+    },
+}
+
+/// The outcome of the last ownership check of a reviewer's neuron.
+/// There is at most one verification per reviewer, stored by user id.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct NeuronVerification {
+    /// The neuron that was checked, which may differ from the reviewer's
+    /// current neuron if it was changed after the check.
+    pub neuron_id: NeuronId,
+    pub status: NeuronVerificationStatus,
+    pub checked_at: DateTime,
+    /// When the ownership was last confirmed.
+    pub verified_at: Option<DateTime>,
+}
+
+impl NeuronVerification {
+    pub fn is_verified(&self, neuron_id: NeuronId) -> bool {
+        self.neuron_id == neuron_id && self.status == NeuronVerificationStatus::Verified
+    }
+
+    pub fn is_stale(&self, now: &DateTime) -> bool {
+        *now >= self
+            .checked_at
+            .add(Duration::hours(NEURON_VERIFICATION_VALIDITY_HOURS))
+    }
+}
+
+impl Storable for NeuronVerification {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::verified(fixtures::neuron_verification_verified())]
+    #[case::unverified(fixtures::neuron_verification_unverified())]
+    fn storable_impl(#[case] neuron_verification: NeuronVerification) {
+        let serialized_neuron_verification = neuron_verification.to_bytes();
+        let deserialized_neuron_verification =
+            NeuronVerification::from_bytes(serialized_neuron_verification);
+
+        assert_eq!(neuron_verification, deserialized_neuron_verification);
+    }
+
+    #[rstest]
+    fn is_verified() {
+        let neuron_verification = fixtures::neuron_verification_verified();
+
+        assert!(neuron_verification.is_verified(fixtures::neuron_id()));
+        assert!(!neuron_verification.is_verified(fixtures::neuron_id() + 1));
+        assert!(!fixtures::neuron_verification_unverified().is_verified(fixtures::neuron_id()));
+    }
+
+    #[rstest]
+    #[case::fresh(Duration::hours(23), false)]
+    #[case::expired(Duration::hours(24), true)]
+    fn is_stale(#[case] checked_before: Duration, #[case] expected: bool) {
+        let now = fixtures::date_time_a();
+        let neuron_verification = NeuronVerification {
+            checked_at: now.sub(checked_before),
+            ..fixtures::neuron_verification_verified()
+        };
+
+        assert_eq!(neuron_verification.is_stale(&now), expected);
+    }
+}
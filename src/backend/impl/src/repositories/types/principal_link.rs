@@ -0,0 +1,160 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode, Principal};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, HistoryData, HistoryEntry, UserId, Uuid};
+
+pub type PrincipalLinkCodeId = Uuid;
+
+/// A one-time code that links the principal redeeming it
+/// to the user profile of the principal that created it.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct PrincipalLinkCode {
+    pub user_id: UserId,
+    pub created_by: Principal,
+    pub created_at: DateTime,
+    pub expires_at: DateTime,
+}
+
+impl PrincipalLinkCode {
+    pub fn is_active(&self, current_time: &DateTime) -> bool {
+        &self.expires_at > current_time
+    }
+}
+
+impl Storable for PrincipalLinkCode {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+/// A principal linked to a user profile. Linking is recorded in the history
+/// as a [Create](super::HistoryAction::Create) action, unlinking as a [Delete](super::HistoryAction::Delete) action.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PrincipalLink {
+    pub principal: Principal,
+}
+
+impl HistoryData for PrincipalLink {}
+
+pub type PrincipalLinkHistoryEntry = HistoryEntry<PrincipalLink>;
+
+/// Key of the index from a user to the principals linked to it.
+/// Principals vary in length, so the key is the user id followed by the raw principal bytes
+/// and every principal of a user sorts between the bounds of [UserPrincipalRange].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UserPrincipalKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl UserPrincipalKey {
+    const MAX_SIZE: u32 = UserId::BOUND.max_size() + Principal::BOUND.max_size();
+
+    pub fn new(user_id: UserId, principal: Principal) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from(
+                [user_id.to_bytes(), principal.to_bytes()]
+                    .concat()
+                    .as_slice(),
+            )
+            .map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to convert user id {:?} and principal {} to bytes.",
+                    user_id,
+                    principal.to_text()
+                ))
+            })?,
+        ))
+    }
+}
+
+impl Storable for UserPrincipalKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: false,
+    };
+}
+
+pub struct UserPrincipalRange {
+    start_bound: UserPrincipalKey,
+    end_bound: UserPrincipalKey,
+}
+
+impl UserPrincipalRange {
+    pub fn new(user_id: UserId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: UserPrincipalKey::new(user_id, Principal::from_slice(&[]))?,
+            end_bound: UserPrincipalKey::new(
+                user_id,
+                Principal::from_slice(&[u8::MAX; Principal::BOUND.max_size() as usize]),
+            )?,
+        })
+    }
+}
+
+impl RangeBounds<UserPrincipalKey> for UserPrincipalRange {
+    fn start_bound(&self) -> std::ops::Bound<&UserPrincipalKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&UserPrincipalKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let principal_link_code = fixtures::principal_link_code();
+        let serialized_principal_link_code = principal_link_code.to_bytes();
+        let deserialized_principal_link_code =
+            PrincipalLinkCode::from_bytes(serialized_principal_link_code);
+
+        assert_eq!(principal_link_code, deserialized_principal_link_code);
+    }
+
+    #[rstest]
+    fn is_active() {
+        let principal_link_code = fixtures::principal_link_code();
+
+        assert!(principal_link_code.is_active(&principal_link_code.created_at));
+        assert!(!principal_link_code.is_active(&principal_link_code.expires_at));
+    }
+
+    #[rstest]
+    fn user_principal_range() {
+        let range = UserPrincipalRange::new(fixtures::uuid_a()).unwrap();
+
+        for principal in [
+            Principal::anonymous(),
+            Principal::management_canister(),
+            fixtures::principal_a(),
+            fixtures::principal_b(),
+        ] {
+            assert!(range.contains(&UserPrincipalKey::new(fixtures::uuid_a(), principal).unwrap()));
+            assert!(!range.contains(&UserPrincipalKey::new(fixtures::uuid_b(), principal).unwrap()));
+        }
+    }
+}
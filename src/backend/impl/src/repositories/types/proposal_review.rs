@@ -2,6 +2,7 @@ use std::{borrow::Cow, fmt::Display, ops::RangeBounds};
 
 use backend_api::ApiError;
 use candid::{CandidType, Decode, Deserialize, Encode};
+use external_canisters::nns::BallotVote;
 use ic_stable_structures::{
     storable::{Blob, Bound},
     Storable,
@@ -37,6 +38,16 @@ impl Display for ProposalVote {
     }
 }
 
+impl From<BallotVote> for ProposalVote {
+    fn from(vote: BallotVote) -> Self {
+        match vote {
+            BallotVote::Unspecified => ProposalVote::Unspecified,
+            BallotVote::Yes => ProposalVote::Yes,
+            BallotVote::No => ProposalVote::No,
+        }
+    }
+}
+
 #[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
 pub struct ProposalReview {
     pub proposal_id: ProposalId,
@@ -48,6 +59,11 @@ pub struct ProposalReview {
     pub build_reproduced: Option<bool>,
     pub images_ids: Vec<ImageId>,
     pub vote: ProposalVote,
+    /// Optional to stay compatible with reviews stored before attachments were introduced.
+    pub attachments_ids: Option<Vec<ImageId>>,
+    /// Set the first time the review is published and kept when it is
+    /// set back to draft and published again.
+    pub published_at: Option<DateTime>,
 }
 
 impl ProposalReview {
@@ -58,6 +74,19 @@ impl ProposalReview {
     pub fn is_published(&self) -> bool {
         self.status == ProposalReviewStatus::Published
     }
+
+    pub fn attachments_ids(&self) -> &[ImageId] {
+        self.attachments_ids.as_deref().unwrap_or_default()
+    }
+
+    /// Returns when the review was first published.
+    ///
+    /// Reviews published before `published_at` was stored fall back to their last update.
+    pub fn first_published_at(&self) -> DateTime {
+        self.published_at
+            .or(self.last_updated_at)
+            .unwrap_or(self.created_at)
+    }
 }
 
 impl Storable for ProposalReview {
@@ -216,6 +245,29 @@ mod tests {
         assert_eq!(proposal_review, deserialized_proposal_review);
     }
 
+    #[rstest]
+    #[case::published(
+        Some(fixtures::date_time_a()),
+        Some(fixtures::date_time_b()),
+        fixtures::date_time_a()
+    )]
+    #[case::published_before_stored(None, Some(fixtures::date_time_b()), fixtures::date_time_b())]
+    #[case::never_updated(None, None, fixtures::date_time_a())]
+    fn first_published_at(
+        #[case] published_at: Option<DateTime>,
+        #[case] last_updated_at: Option<DateTime>,
+        #[case] expected: DateTime,
+    ) {
+        let proposal_review = ProposalReview {
+            created_at: fixtures::date_time_a(),
+            published_at,
+            last_updated_at,
+            ..fixtures::proposal_review_published()
+        };
+
+        assert_eq!(proposal_review.first_published_at(), expected);
+    }
+
     #[rstest]
     fn proposal_review_proposal_user_key_storable_impl() {
         let proposal_id = fixtures::proposal_id();
@@ -0,0 +1,150 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{CommitSha, DateTime, ProposalId, ProposalReviewId, UserId, Uuid};
+
+pub type ReviewConflictId = Uuid;
+
+/// The field of the published reviews that the reviewers disagree on.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewConflictKind {
+    Vote,
+    BuildReproduced,
+    CommitMatchesDescription { commit_sha: CommitSha },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewConflictStatus {
+    Open,
+    Resolved {
+        resolved_by: UserId,
+        resolved_at: DateTime,
+        note: String,
+    },
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewConflict {
+    pub proposal_id: ProposalId,
+    pub kind: ReviewConflictKind,
+    /// The published reviews that answered the conflicting field, on either side.
+    pub proposal_review_ids: Vec<ProposalReviewId>,
+    pub detected_at: DateTime,
+    pub last_updated_at: Option<DateTime>,
+    pub status: ReviewConflictStatus,
+}
+
+impl ReviewConflict {
+    pub fn is_open(&self) -> bool {
+        self.status == ReviewConflictStatus::Open
+    }
+}
+
+impl Storable for ReviewConflict {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReviewConflictProposalKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl ReviewConflictProposalKey {
+    const MAX_SIZE: u32 = <(ProposalId, ReviewConflictId)>::BOUND.max_size();
+
+    pub fn new(
+        proposal_id: ProposalId,
+        review_conflict_id: ReviewConflictId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((proposal_id, review_conflict_id).to_bytes().as_ref()).map_err(
+                |_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert proposal id {:?} and review conflict id {:?} to bytes.",
+                        proposal_id, review_conflict_id
+                    ))
+                },
+            )?,
+        ))
+    }
+}
+
+impl Storable for ReviewConflictProposalKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct ReviewConflictProposalRange {
+    start_bound: ReviewConflictProposalKey,
+    end_bound: ReviewConflictProposalKey,
+}
+
+impl ReviewConflictProposalRange {
+    pub fn new(proposal_id: ProposalId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: ReviewConflictProposalKey::new(proposal_id, ReviewConflictId::min())?,
+            end_bound: ReviewConflictProposalKey::new(proposal_id, ReviewConflictId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<ReviewConflictProposalKey> for ReviewConflictProposalRange {
+    fn start_bound(&self) -> std::ops::Bound<&ReviewConflictProposalKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&ReviewConflictProposalKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::open(fixtures::review_conflict_open())]
+    #[case::resolved(fixtures::review_conflict_resolved())]
+    fn storable_impl(#[case] review_conflict: ReviewConflict) {
+        let serialized_review_conflict = review_conflict.to_bytes();
+        let deserialized_review_conflict = ReviewConflict::from_bytes(serialized_review_conflict);
+
+        assert_eq!(review_conflict, deserialized_review_conflict);
+    }
+
+    #[rstest]
+    fn review_conflict_proposal_key_storable_impl() {
+        let proposal_id = fixtures::proposal_id();
+        let review_conflict_id = fixtures::uuid();
+
+        let key = ReviewConflictProposalKey::new(proposal_id, review_conflict_id).unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = ReviewConflictProposalKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, ProposalId, ProposalReviewId, UserId};
+
+pub const MIN_REVIEW_SCORE: u8 = 1;
+pub const MAX_REVIEW_SCORE: u8 = 5;
+
+/// Number of most recently scored reviews that make up a reviewer's reputation.
+pub const REPUTATION_WINDOW_SIZE: usize = 20;
+
+/// An admin's assessment of a published proposal review.
+/// There is at most one score per proposal review, stored by proposal review id.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewScore {
+    pub proposal_id: ProposalId,
+    /// The author of the scored proposal review.
+    pub reviewer_user_id: UserId,
+    /// The admin that last scored the proposal review.
+    pub scored_by: UserId,
+    pub depth: u8,
+    pub accuracy: u8,
+    pub timeliness: u8,
+    pub comment: String,
+    pub created_at: DateTime,
+    pub last_updated_at: Option<DateTime>,
+}
+
+impl ReviewScore {
+    /// The score normalized to a 0-100 range, where 0 means every criterion
+    /// got [MIN_REVIEW_SCORE] and 100 means every criterion got [MAX_REVIEW_SCORE].
+    pub fn percentage(&self) -> u8 {
+        let (sum, max) = self.normalized_sum();
+
+        ((sum * 100 + max / 2) / max) as u8
+    }
+
+    fn normalized_sum(&self) -> (u32, u32) {
+        let criteria = [self.depth, self.accuracy, self.timeliness];
+        let sum = criteria
+            .iter()
+            .map(|score| u32::from(score.saturating_sub(MIN_REVIEW_SCORE)))
+            .sum();
+        let max = criteria.len() as u32 * u32::from(MAX_REVIEW_SCORE - MIN_REVIEW_SCORE);
+
+        (sum, max)
+    }
+}
+
+impl Storable for ReviewScore {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewerReputation {
+    /// Average of the scores' percentages, in the 0-100 range.
+    pub score: u8,
+    pub scored_reviews_count: u32,
+}
+
+impl ReviewerReputation {
+    /// Computes the reputation from the given scores.
+    /// Callers are expected to pass at most the [REPUTATION_WINDOW_SIZE] latest scores.
+    ///
+    /// Returns `None` if there are no scores.
+    pub fn from_review_scores(review_scores: &[ReviewScore]) -> Option<Self> {
+        if review_scores.is_empty() {
+            return None;
+        }
+
+        let (sum, max) = review_scores
+            .iter()
+            .map(|review_score| review_score.normalized_sum())
+            .fold((0, 0), |(sum, max), (s, m)| (sum + s, max + m));
+
+        Some(Self {
+            score: ((sum * 100 + max / 2) / max) as u8,
+            scored_reviews_count: review_scores.len() as u32,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReviewScoreUserKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl ReviewScoreUserKey {
+    const MAX_SIZE: u32 = <(UserId, (DateTime, ProposalReviewId))>::BOUND.max_size();
+
+    pub fn new(
+        user_id: UserId,
+        created_at: DateTime,
+        proposal_review_id: ProposalReviewId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((user_id, (created_at, proposal_review_id)).to_bytes().as_ref())
+                .map_err(|_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert user id {:?}, date time {:?} and proposal review id {:?} to bytes.",
+                        user_id, created_at, proposal_review_id
+                    ))
+                })?,
+        ))
+    }
+}
+
+impl Storable for ReviewScoreUserKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct ReviewScoreUserRange {
+    start_bound: ReviewScoreUserKey,
+    end_bound: ReviewScoreUserKey,
+}
+
+impl ReviewScoreUserRange {
+    pub fn new(user_id: UserId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: ReviewScoreUserKey::new(
+                user_id,
+                DateTime::min(),
+                ProposalReviewId::min(),
+            )?,
+            end_bound: ReviewScoreUserKey::new(user_id, DateTime::max()?, ProposalReviewId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<ReviewScoreUserKey> for ReviewScoreUserRange {
+    fn start_bound(&self) -> std::ops::Bound<&ReviewScoreUserKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&ReviewScoreUserKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let review_score = fixtures::review_score();
+        let serialized_review_score = review_score.to_bytes();
+        let deserialized_review_score = ReviewScore::from_bytes(serialized_review_score);
+
+        assert_eq!(review_score, deserialized_review_score);
+    }
+
+    #[rstest]
+    fn review_score_user_key_storable_impl() {
+        let key = ReviewScoreUserKey::new(
+            fixtures::user_id(),
+            fixtures::date_time_a(),
+            fixtures::proposal_review_id(),
+        )
+        .unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = ReviewScoreUserKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    #[case::min((1, 1, 1), 0)]
+    #[case::max((5, 5, 5), 100)]
+    #[case::mixed((5, 4, 2), 67)]
+    fn percentage(#[case] scores: (u8, u8, u8), #[case] expected: u8) {
+        let (depth, accuracy, timeliness) = scores;
+        let review_score = ReviewScore {
+            depth,
+            accuracy,
+            timeliness,
+            ..fixtures::review_score()
+        };
+
+        assert_eq!(review_score.percentage(), expected);
+    }
+
+    #[rstest]
+    fn reviewer_reputation_from_review_scores() {
+        let review_scores = [
+            ReviewScore {
+                depth: 5,
+                accuracy: 5,
+                timeliness: 5,
+                ..fixtures::review_score()
+            },
+            ReviewScore {
+                depth: 3,
+                accuracy: 2,
+                timeliness: 1,
+                ..fixtures::review_score()
+            },
+        ];
+
+        let result = ReviewerReputation::from_review_scores(&review_scores);
+
+        // (12 + 3) / 24 = 62.5%
+        assert_eq!(
+            result,
+            Some(ReviewerReputation {
+                score: 63,
+                scored_reviews_count: 2,
+            })
+        );
+    }
+
+    #[rstest]
+    fn reviewer_reputation_from_review_scores_empty() {
+        let result = ReviewerReputation::from_review_scores(&[]);
+
+        assert_eq!(result, None);
+    }
+}
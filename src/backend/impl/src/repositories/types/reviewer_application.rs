@@ -0,0 +1,72 @@
+use std::borrow::Cow;
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::{DateTime, NeuronId, SocialLink, UserId, Uuid, WalletAddress};
+
+pub type ReviewerApplicationId = Uuid;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerApplicationStatus {
+    Pending,
+    Approved {
+        reviewed_by: UserId,
+        reviewed_at: DateTime,
+    },
+    Rejected {
+        reviewed_by: UserId,
+        reviewed_at: DateTime,
+        reason: String,
+    },
+}
+
+/// A request of an anonymous user to become a reviewer.
+/// The reviewer config is copied to the user profile when an admin approves it.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerApplication {
+    pub user_id: UserId,
+    pub bio: String,
+    pub neuron_id: NeuronId,
+    pub wallet_address: WalletAddress,
+    pub social_links: Vec<SocialLink>,
+    pub created_at: DateTime,
+    pub status: ReviewerApplicationStatus,
+}
+
+impl ReviewerApplication {
+    pub fn is_pending(&self) -> bool {
+        self.status == ReviewerApplicationStatus::Pending
+    }
+}
+
+impl Storable for ReviewerApplication {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::pending(fixtures::reviewer_application_pending())]
+    #[case::approved(fixtures::reviewer_application_approved())]
+    #[case::rejected(fixtures::reviewer_application_rejected())]
+    fn storable_impl(#[case] reviewer_application: ReviewerApplication) {
+        let serialized_reviewer_application = reviewer_application.to_bytes();
+        let deserialized_reviewer_application =
+            ReviewerApplication::from_bytes(serialized_reviewer_application);
+
+        assert_eq!(reviewer_application, deserialized_reviewer_application);
+    }
+}
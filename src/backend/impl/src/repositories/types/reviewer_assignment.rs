@@ -0,0 +1,293 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, ProposalId, UserId, Uuid};
+
+pub type ReviewerAssignmentId = Uuid;
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum ReviewerAssignmentStatus {
+    Pending,
+    Accepted,
+    Declined { reason: Option<String> },
+    Fulfilled,
+    Unfulfilled,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerAssignment {
+    pub proposal_id: ProposalId,
+    pub user_id: UserId,
+    /// The admin that created the assignment,
+    /// or `None` if it was created by the automatic assignment policy.
+    pub assigned_by: Option<UserId>,
+    pub created_at: DateTime,
+    pub last_updated_at: Option<DateTime>,
+    pub status: ReviewerAssignmentStatus,
+}
+
+impl ReviewerAssignment {
+    /// An assignment is open until the reviewer declines it
+    /// or the review period of the proposal ends.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            ReviewerAssignmentStatus::Pending | ReviewerAssignmentStatus::Accepted
+        )
+    }
+
+    pub fn is_declined(&self) -> bool {
+        matches!(self.status, ReviewerAssignmentStatus::Declined { .. })
+    }
+}
+
+impl Storable for ReviewerAssignment {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewerAssignmentPolicy {
+    /// Reviewers are only assigned by admins.
+    Manual,
+    /// Reviewers are assigned in turns, ordered by their user id.
+    RoundRobin,
+    /// Reviewers with the fewest open assignments are assigned first.
+    LeastLoaded,
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct ReviewerAssignmentConfig {
+    pub policy: ReviewerAssignmentPolicy,
+    pub reviewers_per_proposal: u8,
+    /// The last reviewer assigned by the [ReviewerAssignmentPolicy::RoundRobin] policy.
+    pub round_robin_cursor: Option<UserId>,
+}
+
+impl Default for ReviewerAssignmentConfig {
+    fn default() -> Self {
+        Self {
+            policy: ReviewerAssignmentPolicy::Manual,
+            reviewers_per_proposal: 3,
+            round_robin_cursor: None,
+        }
+    }
+}
+
+impl Storable for ReviewerAssignmentConfig {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReviewerAssignmentProposalUserKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl ReviewerAssignmentProposalUserKey {
+    const MAX_SIZE: u32 = <((ProposalId, UserId), ReviewerAssignmentId)>::BOUND.max_size();
+
+    pub fn new(
+        proposal_id: ProposalId,
+        user_id: UserId,
+        reviewer_assignment_id: ReviewerAssignmentId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from(((proposal_id, user_id), reviewer_assignment_id).to_bytes().as_ref()).map_err(
+                |_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert proposal id {:?}, user id {:?} and reviewer assignment id {:?} to bytes.",
+                        proposal_id, user_id, reviewer_assignment_id
+                    ))
+                },
+            )?,
+        ))
+    }
+}
+
+impl Storable for ReviewerAssignmentProposalUserKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct ReviewerAssignmentProposalUserRange {
+    start_bound: ReviewerAssignmentProposalUserKey,
+    end_bound: ReviewerAssignmentProposalUserKey,
+}
+
+impl ReviewerAssignmentProposalUserRange {
+    pub fn new(proposal_id: ProposalId, user_id: Option<UserId>) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: ReviewerAssignmentProposalUserKey::new(
+                proposal_id,
+                user_id.unwrap_or(UserId::min()),
+                ReviewerAssignmentId::min(),
+            )?,
+            end_bound: ReviewerAssignmentProposalUserKey::new(
+                proposal_id,
+                user_id.unwrap_or(UserId::max()),
+                ReviewerAssignmentId::max(),
+            )?,
+        })
+    }
+}
+
+impl RangeBounds<ReviewerAssignmentProposalUserKey> for ReviewerAssignmentProposalUserRange {
+    fn start_bound(&self) -> std::ops::Bound<&ReviewerAssignmentProposalUserKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&ReviewerAssignmentProposalUserKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReviewerAssignmentUserKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl ReviewerAssignmentUserKey {
+    const MAX_SIZE: u32 = <(UserId, ReviewerAssignmentId)>::BOUND.max_size();
+
+    pub fn new(
+        user_id: UserId,
+        reviewer_assignment_id: ReviewerAssignmentId,
+    ) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((user_id, reviewer_assignment_id).to_bytes().as_ref()).map_err(
+                |_| {
+                    ApiError::internal(&format!(
+                        "Failed to convert user id {:?} and reviewer assignment id {:?} to bytes.",
+                        user_id, reviewer_assignment_id
+                    ))
+                },
+            )?,
+        ))
+    }
+}
+
+impl Storable for ReviewerAssignmentUserKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct ReviewerAssignmentUserRange {
+    start_bound: ReviewerAssignmentUserKey,
+    end_bound: ReviewerAssignmentUserKey,
+}
+
+impl ReviewerAssignmentUserRange {
+    pub fn new(user_id: UserId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: ReviewerAssignmentUserKey::new(user_id, ReviewerAssignmentId::min())?,
+            end_bound: ReviewerAssignmentUserKey::new(user_id, ReviewerAssignmentId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<ReviewerAssignmentUserKey> for ReviewerAssignmentUserRange {
+    fn start_bound(&self) -> std::ops::Bound<&ReviewerAssignmentUserKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&ReviewerAssignmentUserKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::pending(fixtures::reviewer_assignment_pending())]
+    #[case::accepted(fixtures::reviewer_assignment_accepted())]
+    #[case::declined(fixtures::reviewer_assignment_declined())]
+    fn storable_impl(#[case] reviewer_assignment: ReviewerAssignment) {
+        let serialized_reviewer_assignment = reviewer_assignment.to_bytes();
+        let deserialized_reviewer_assignment =
+            ReviewerAssignment::from_bytes(serialized_reviewer_assignment);
+
+        assert_eq!(reviewer_assignment, deserialized_reviewer_assignment);
+    }
+
+    #[rstest]
+    fn reviewer_assignment_config_storable_impl() {
+        let config = ReviewerAssignmentConfig {
+            policy: ReviewerAssignmentPolicy::RoundRobin,
+            reviewers_per_proposal: 5,
+            round_robin_cursor: Some(fixtures::user_id()),
+        };
+
+        let serialized_config = config.to_bytes();
+        let deserialized_config = ReviewerAssignmentConfig::from_bytes(serialized_config);
+
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[rstest]
+    fn reviewer_assignment_proposal_user_key_storable_impl() {
+        let proposal_id = fixtures::proposal_id();
+        let user_id = fixtures::user_id();
+        let reviewer_assignment_id = fixtures::uuid();
+
+        let key =
+            ReviewerAssignmentProposalUserKey::new(proposal_id, user_id, reviewer_assignment_id)
+                .unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = ReviewerAssignmentProposalUserKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    fn reviewer_assignment_user_key_storable_impl() {
+        let user_id = fixtures::user_id();
+        let reviewer_assignment_id = fixtures::uuid();
+
+        let key = ReviewerAssignmentUserKey::new(user_id, reviewer_assignment_id).unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = ReviewerAssignmentUserKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+}
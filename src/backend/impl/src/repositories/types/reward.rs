@@ -0,0 +1,349 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use chrono::Duration;
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, ProposalId, ProposalReviewId, UserId, Uuid};
+
+pub type RewardPeriodId = Uuid;
+
+/// Amounts are expressed in e8s of the token that is paid out.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardRules {
+    pub reward_per_review_e8s: u64,
+    /// Added when the reviewer reproduced the build of the proposal.
+    pub verified_build_bonus_e8s: u64,
+    /// Subtracted when the review was published later than
+    /// `late_review_after_hours` after the proposal was proposed.
+    pub late_review_penalty_e8s: u64,
+    pub late_review_after_hours: u32,
+}
+
+impl RewardRules {
+    /// The amount earned for a single review. It never goes below zero.
+    pub fn reward_for_review(&self, verified_build: bool, late: bool) -> u64 {
+        let mut amount = self.reward_per_review_e8s;
+
+        if verified_build {
+            amount = amount.saturating_add(self.verified_build_bonus_e8s);
+        }
+
+        if late {
+            amount = amount.saturating_sub(self.late_review_penalty_e8s);
+        }
+
+        amount
+    }
+
+    /// Checks if a review published at `published_at` is late
+    /// for a proposal proposed at `proposed_at`.
+    pub fn is_late(&self, proposed_at: &DateTime, published_at: &DateTime) -> bool {
+        *published_at > proposed_at.add(Duration::hours(self.late_review_after_hours.into()))
+    }
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RewardPeriodStatus {
+    Open,
+    Closed { closed_at: DateTime },
+}
+
+/// A reward period covers the published reviews of all the proposals
+/// that completed between `starts_at` (included) and `ends_at` (excluded).
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardPeriod {
+    pub name: String,
+    pub starts_at: DateTime,
+    pub ends_at: DateTime,
+    pub rules: RewardRules,
+    pub status: RewardPeriodStatus,
+    pub created_by: UserId,
+    pub created_at: DateTime,
+    pub last_updated_at: Option<DateTime>,
+}
+
+impl RewardPeriod {
+    pub fn is_closed(&self) -> bool {
+        matches!(self.status, RewardPeriodStatus::Closed { .. })
+    }
+
+    pub fn contains(&self, date_time: &DateTime) -> bool {
+        self.starts_at <= *date_time && *date_time < self.ends_at
+    }
+
+    pub fn overlaps(&self, other: &RewardPeriod) -> bool {
+        self.starts_at < other.ends_at && other.starts_at < self.ends_at
+    }
+}
+
+impl Storable for RewardPeriod {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardStatementItem {
+    pub proposal_id: ProposalId,
+    pub proposal_review_id: ProposalReviewId,
+    pub verified_build: bool,
+    pub late: bool,
+    pub amount_e8s: u64,
+}
+
+/// The payout of a single reviewer for a reward period.
+/// Statements are written once, when the reward period is closed.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardStatement {
+    pub user_id: UserId,
+    /// The reviewer's wallet address at the time the reward period was closed.
+    pub wallet_address: String,
+    pub items: Vec<RewardStatementItem>,
+    pub total_e8s: u64,
+}
+
+impl Storable for RewardStatement {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum RewardPayoutStatus {
+    /// The payout was not executed yet, or the outcome of the last attempt is unknown.
+    Pending {
+        attempts: u32,
+        last_error: Option<String>,
+    },
+    Completed {
+        block_index: u64,
+        completed_at: DateTime,
+    },
+    /// The ledger rejected the last attempt, so nothing was transferred.
+    Failed { error: String, failed_at: DateTime },
+}
+
+/// The transfer of a reward statement's total to the reviewer's wallet.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardPayout {
+    pub user_id: UserId,
+    pub wallet_address: String,
+    pub amount_e8s: u64,
+    /// Sent to the ledger together with `created_at_time` so that
+    /// retried transfers are deduplicated instead of being executed twice.
+    pub memo: Vec<u8>,
+    /// Nanoseconds since the UNIX epoch.
+    pub created_at_time: u64,
+    pub status: RewardPayoutStatus,
+}
+
+impl RewardPayout {
+    pub fn is_completed(&self) -> bool {
+        matches!(self.status, RewardPayoutStatus::Completed { .. })
+    }
+}
+
+impl Storable for RewardPayout {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+/// The memo of a reward payout, unique per reward period and reviewer (32 bytes).
+pub fn reward_payout_memo(reward_period_id: RewardPeriodId, user_id: UserId) -> Vec<u8> {
+    [reward_period_id.to_bytes(), user_id.to_bytes()].concat()
+}
+
+/// Identifies a reviewer's statement, and its payout, within a reward period.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RewardStatementKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl RewardStatementKey {
+    const MAX_SIZE: u32 = <(RewardPeriodId, UserId)>::BOUND.max_size();
+
+    pub fn new(reward_period_id: RewardPeriodId, user_id: UserId) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((reward_period_id, user_id).to_bytes().as_ref()).map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to convert reward period id {:?} and user id {:?} to bytes.",
+                    reward_period_id, user_id
+                ))
+            })?,
+        ))
+    }
+}
+
+impl Storable for RewardStatementKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct RewardStatementRange {
+    start_bound: RewardStatementKey,
+    end_bound: RewardStatementKey,
+}
+
+impl RewardStatementRange {
+    pub fn new(reward_period_id: RewardPeriodId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: RewardStatementKey::new(reward_period_id, UserId::min())?,
+            end_bound: RewardStatementKey::new(reward_period_id, UserId::max())?,
+        })
+    }
+}
+
+impl RangeBounds<RewardStatementKey> for RewardStatementRange {
+    fn start_bound(&self) -> std::ops::Bound<&RewardStatementKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&RewardStatementKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn reward_period_storable_impl() {
+        let reward_period = fixtures::reward_period_open();
+        let serialized_reward_period = reward_period.to_bytes();
+        let deserialized_reward_period = RewardPeriod::from_bytes(serialized_reward_period);
+
+        assert_eq!(reward_period, deserialized_reward_period);
+    }
+
+    #[rstest]
+    fn reward_statement_storable_impl() {
+        let reward_statement = fixtures::reward_statement();
+        let serialized_reward_statement = reward_statement.to_bytes();
+        let deserialized_reward_statement =
+            RewardStatement::from_bytes(serialized_reward_statement);
+
+        assert_eq!(reward_statement, deserialized_reward_statement);
+    }
+
+    #[rstest]
+    fn reward_payout_storable_impl() {
+        let reward_payout = fixtures::reward_payout_pending();
+        let serialized_reward_payout = reward_payout.to_bytes();
+        let deserialized_reward_payout = RewardPayout::from_bytes(serialized_reward_payout);
+
+        assert_eq!(reward_payout, deserialized_reward_payout);
+    }
+
+    #[rstest]
+    fn reward_payout_memo_is_unique() {
+        let memo = reward_payout_memo(fixtures::uuid_a(), fixtures::uuid_b());
+        let other_memo = reward_payout_memo(fixtures::uuid_b(), fixtures::uuid_a());
+
+        assert_eq!(memo.len(), 32);
+        assert_ne!(memo, other_memo);
+    }
+
+    #[rstest]
+    fn reward_statement_key_storable_impl() {
+        let key = RewardStatementKey::new(fixtures::uuid_a(), fixtures::user_id()).unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = RewardStatementKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    #[case::base(false, false, 100)]
+    #[case::verified_build(true, false, 150)]
+    #[case::late(false, true, 70)]
+    #[case::verified_build_late(true, true, 120)]
+    fn reward_for_review(#[case] verified_build: bool, #[case] late: bool, #[case] expected: u64) {
+        let rules = fixtures::reward_rules();
+
+        assert_eq!(rules.reward_for_review(verified_build, late), expected);
+    }
+
+    #[rstest]
+    fn reward_for_review_never_negative() {
+        let rules = RewardRules {
+            late_review_penalty_e8s: 1_000,
+            ..fixtures::reward_rules()
+        };
+
+        assert_eq!(rules.reward_for_review(false, true), 0);
+    }
+
+    #[rstest]
+    #[case::on_time(Duration::hours(47), false)]
+    #[case::deadline(Duration::hours(48), false)]
+    #[case::late(Duration::hours(49), true)]
+    fn is_late(#[case] published_after: Duration, #[case] expected: bool) {
+        let rules = fixtures::reward_rules();
+        let proposed_at = fixtures::date_time_b();
+
+        assert_eq!(
+            rules.is_late(&proposed_at, &proposed_at.add(published_after)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case::before(fixtures::date_time_c(), false, false)]
+    #[case::start(fixtures::date_time_b(), true, false)]
+    #[case::end(fixtures::date_time_a(), false, true)]
+    #[case::after(fixtures::date_time_future(), false, false)]
+    fn contains_and_overlaps(
+        #[case] date_time: DateTime,
+        #[case] contains: bool,
+        #[case] overlaps: bool,
+    ) {
+        // the fixture period goes from date_time_b to date_time_a
+        let reward_period = fixtures::reward_period_open();
+        let other_reward_period = RewardPeriod {
+            starts_at: date_time.sub(Duration::days(1)),
+            ends_at: date_time.add(Duration::days(1)),
+            ..fixtures::reward_period_open()
+        };
+
+        assert_eq!(reward_period.contains(&date_time), contains);
+        assert_eq!(
+            reward_period.overlaps(&other_reward_period),
+            contains || overlaps
+        );
+    }
+}
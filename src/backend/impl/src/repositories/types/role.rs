@@ -0,0 +1,151 @@
+use std::{borrow::Cow, fmt::Display};
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::{DateTime, UserConfig, UserId};
+
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Permission {
+    /// Assign roles to users.
+    ManageRoles,
+    /// Update other users' profiles and handle reviewer applications and neuron verifications.
+    ManageUsers,
+    ReadLogs,
+    /// Read and export the audit log of privileged actions.
+    ReadAuditLog,
+    SyncProposals,
+    ManageReviewerAssignments,
+    /// Resolve review conflicts and score reviews.
+    ModerateReviews,
+    /// Manage reward periods and execute their payouts.
+    ManageRewards,
+    /// Write reviews and everything attached to them. Requires a reviewer profile.
+    ReviewProposals,
+    Comment,
+}
+
+impl Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let val = match self {
+            Permission::ManageRoles => "manage_roles",
+            Permission::ManageUsers => "manage_users",
+            Permission::ReadLogs => "read_logs",
+            Permission::ReadAuditLog => "read_audit_log",
+            Permission::SyncProposals => "sync_proposals",
+            Permission::ManageReviewerAssignments => "manage_reviewer_assignments",
+            Permission::ModerateReviews => "moderate_reviews",
+            Permission::ManageRewards => "manage_rewards",
+            Permission::ReviewProposals => "review_proposals",
+            Permission::Comment => "comment",
+        };
+        write!(f, "{}", val)
+    }
+}
+
+/// A role that can be assigned to a user on top of their [UserConfig],
+/// granting part of the admin permissions.
+#[derive(Debug, Clone, Copy, CandidType, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Moderator,
+    RewardsManager,
+    SyncOperator,
+}
+
+impl Role {
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Moderator => &[Permission::ModerateReviews, Permission::Comment],
+            Role::RewardsManager => &[Permission::ManageRewards],
+            Role::SyncOperator => &[Permission::SyncProposals, Permission::ReadLogs],
+        }
+    }
+}
+
+impl UserConfig {
+    /// The permissions that every user with this config has, regardless of their roles.
+    pub fn default_permissions(&self) -> &'static [Permission] {
+        match self {
+            UserConfig::Admin { .. } => &[
+                Permission::ManageRoles,
+                Permission::ManageUsers,
+                Permission::ReadLogs,
+                Permission::ReadAuditLog,
+                Permission::SyncProposals,
+                Permission::ManageReviewerAssignments,
+                Permission::ModerateReviews,
+                Permission::ManageRewards,
+                Permission::Comment,
+            ],
+            UserConfig::Reviewer { .. } => &[Permission::ReviewProposals, Permission::Comment],
+            UserConfig::Anonymous => &[],
+        }
+    }
+}
+
+/// The roles assigned to a user. Users without any assignment have no roles.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct UserRoles {
+    pub roles: Vec<Role>,
+    pub updated_by: UserId,
+    pub updated_at: DateTime,
+}
+
+impl UserRoles {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.roles
+            .iter()
+            .any(|role| role.permissions().contains(&permission))
+    }
+}
+
+impl Storable for UserRoles {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn storable_impl() {
+        let user_roles = fixtures::user_roles();
+        let serialized_user_roles = user_roles.to_bytes();
+        let deserialized_user_roles = UserRoles::from_bytes(serialized_user_roles);
+
+        assert_eq!(user_roles, deserialized_user_roles);
+    }
+
+    #[rstest]
+    #[case::admin(fixtures::admin_user_profile().config, Permission::ManageRoles, true)]
+    #[case::admin_audit_log(fixtures::admin_user_profile().config, Permission::ReadAuditLog, true)]
+    #[case::admin_review(fixtures::admin_user_profile().config, Permission::ReviewProposals, false)]
+    #[case::reviewer(fixtures::reviewer_user_profile().config, Permission::ReviewProposals, true)]
+    #[case::reviewer_rewards(fixtures::reviewer_user_profile().config, Permission::ManageRewards, false)]
+    #[case::anonymous(UserConfig::Anonymous, Permission::Comment, false)]
+    fn default_permissions(
+        #[case] config: UserConfig,
+        #[case] permission: Permission,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(config.default_permissions().contains(&permission), expected);
+    }
+
+    #[rstest]
+    #[case::granted(Permission::ManageRewards, true)]
+    #[case::not_granted(Permission::ManageRoles, false)]
+    #[case::audit_log_not_granted(Permission::ReadAuditLog, false)]
+    fn has_permission(#[case] permission: Permission, #[case] expected: bool) {
+        assert_eq!(fixtures::user_roles().has_permission(permission), expected);
+    }
+}
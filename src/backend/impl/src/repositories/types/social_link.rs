@@ -0,0 +1,373 @@
+use backend_api::ApiError;
+use candid::Principal;
+
+use super::{SocialLink, SocialLinkPlatform};
+
+const MAX_WEBSITE_URL_LENGTH: usize = 2048;
+
+impl SocialLinkPlatform {
+    fn name(&self) -> &'static str {
+        match self {
+            SocialLinkPlatform::Dscvr => "DSCVR",
+            SocialLinkPlatform::OpenChat => "OpenChat",
+            SocialLinkPlatform::Taggr => "Taggr",
+            SocialLinkPlatform::X => "X",
+            SocialLinkPlatform::GitHub => "GitHub",
+            SocialLinkPlatform::DfinityForum => "DFINITY forum",
+            SocialLinkPlatform::Discord | SocialLinkPlatform::LegacyDiscord => "Discord",
+            SocialLinkPlatform::Website => "website",
+        }
+    }
+
+    /// The URL prefixes, without scheme and `www.`, that users paste in front of their handle.
+    fn url_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            SocialLinkPlatform::Dscvr => &["dscvr.one/u/"],
+            SocialLinkPlatform::OpenChat => &["oc.app/user/"],
+            SocialLinkPlatform::Taggr => &["taggr.link/#/user/", "taggr.link/user/"],
+            SocialLinkPlatform::X => &["x.com/", "twitter.com/"],
+            SocialLinkPlatform::GitHub => &["github.com/"],
+            SocialLinkPlatform::DfinityForum => &["forum.dfinity.org/u/"],
+            SocialLinkPlatform::Discord | SocialLinkPlatform::LegacyDiscord => {
+                &["discord.com/users/"]
+            }
+            SocialLinkPlatform::Website => &[],
+        }
+    }
+}
+
+impl SocialLink {
+    /// Validates the handle for the platform and normalizes it, accepting
+    /// profile URLs and handles with a leading `@`. For websites, the handle is the URL.
+    pub fn new(platform: SocialLinkPlatform, handle: &str) -> Result<Self, ApiError> {
+        let handle = handle.trim();
+        let invalid_handle = || {
+            ApiError::invalid_argument(&format!(
+                "{} is not a valid {} {}",
+                handle,
+                platform.name(),
+                match platform {
+                    SocialLinkPlatform::OpenChat => "user id",
+                    SocialLinkPlatform::Website => "URL",
+                    _ => "username",
+                }
+            ))
+        };
+
+        let username = match platform {
+            SocialLinkPlatform::Website => {
+                normalize_website_url(handle).ok_or_else(invalid_handle)?
+            }
+            SocialLinkPlatform::OpenChat => {
+                let user_id = extract_handle(handle, platform.url_prefixes());
+                Principal::from_text(user_id)
+                    .map_err(|_| invalid_handle())?
+                    .to_text()
+            }
+            SocialLinkPlatform::Discord | SocialLinkPlatform::LegacyDiscord => {
+                let username = extract_handle(handle, platform.url_prefixes()).to_lowercase();
+                let is_user_id = (17..=20).contains(&username.len())
+                    && username.chars().all(|c| c.is_ascii_digit());
+                let is_username =
+                    is_valid_handle(&username, 2, 32, &['_', '.']) && !username.contains("..");
+                if !is_user_id && !is_username {
+                    return Err(invalid_handle());
+                }
+
+                username
+            }
+            SocialLinkPlatform::GitHub => {
+                let username = extract_handle(handle, platform.url_prefixes());
+                if !is_valid_handle(username, 1, 39, &['-'])
+                    || username.starts_with('-')
+                    || username.ends_with('-')
+                    || username.contains("--")
+                {
+                    return Err(invalid_handle());
+                }
+
+                username.to_string()
+            }
+            SocialLinkPlatform::X => valid_handle(handle, platform.url_prefixes(), 1, 15, &['_'])
+                .ok_or_else(invalid_handle)?,
+            SocialLinkPlatform::DfinityForum => {
+                valid_handle(handle, platform.url_prefixes(), 3, 20, &['_', '-', '.'])
+                    .ok_or_else(invalid_handle)?
+            }
+            SocialLinkPlatform::Dscvr => {
+                valid_handle(handle, platform.url_prefixes(), 1, 32, &['_'])
+                    .ok_or_else(invalid_handle)?
+            }
+            SocialLinkPlatform::Taggr => {
+                valid_handle(handle, platform.url_prefixes(), 1, 32, &['_', '-'])
+                    .ok_or_else(invalid_handle)?
+            }
+        };
+
+        Ok(Self {
+            platform: match platform {
+                SocialLinkPlatform::LegacyDiscord => SocialLinkPlatform::Discord,
+                platform => platform,
+            },
+            username,
+        })
+    }
+
+    /// The canonical URL of the profile on the platform.
+    /// Discord only has profile URLs for user ids, not for usernames.
+    pub fn url(&self) -> Option<String> {
+        let username = &self.username;
+
+        match self.platform {
+            SocialLinkPlatform::Dscvr => Some(format!("https://dscvr.one/u/{}", username)),
+            SocialLinkPlatform::OpenChat => Some(format!("https://oc.app/user/{}", username)),
+            SocialLinkPlatform::Taggr => Some(format!("https://taggr.link/#/user/{}", username)),
+            SocialLinkPlatform::X => Some(format!("https://x.com/{}", username)),
+            SocialLinkPlatform::GitHub => Some(format!("https://github.com/{}", username)),
+            SocialLinkPlatform::DfinityForum => {
+                Some(format!("https://forum.dfinity.org/u/{}", username))
+            }
+            SocialLinkPlatform::Discord | SocialLinkPlatform::LegacyDiscord => username
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                .then(|| format!("https://discord.com/users/{}", username)),
+            SocialLinkPlatform::Website => Some(username.clone()),
+        }
+    }
+}
+
+/// Strips the scheme, `www.`, a known profile URL prefix, anything after the handle and a leading `@`.
+fn extract_handle<'a>(input: &'a str, url_prefixes: &[&str]) -> &'a str {
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let without_www = without_scheme
+        .strip_prefix("www.")
+        .unwrap_or(without_scheme);
+
+    let handle = url_prefixes
+        .iter()
+        .find_map(|prefix| {
+            without_www
+                .get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &without_www[prefix.len()..])
+        })
+        .map(|handle| handle.split(['/', '?', '#']).next().unwrap_or_default())
+        .unwrap_or(input);
+
+    handle.strip_prefix('@').unwrap_or(handle)
+}
+
+fn is_valid_handle(handle: &str, min_length: usize, max_length: usize, extra: &[char]) -> bool {
+    (min_length..=max_length).contains(&handle.len())
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || extra.contains(&c))
+}
+
+fn valid_handle(
+    input: &str,
+    url_prefixes: &[&str],
+    min_length: usize,
+    max_length: usize,
+    extra: &[char],
+) -> Option<String> {
+    let handle = extract_handle(input, url_prefixes);
+
+    is_valid_handle(handle, min_length, max_length, extra).then(|| handle.to_string())
+}
+
+/// Adds `https://` to URLs without a scheme and lowercases the host.
+fn normalize_website_url(input: &str) -> Option<String> {
+    let (scheme, rest) = match input.split_once("://") {
+        Some((scheme, rest)) => (scheme.to_lowercase(), rest),
+        None => ("https".to_string(), input),
+    };
+    if scheme != "https" && scheme != "http" {
+        return None;
+    }
+
+    let host_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    let (host, path) = rest.split_at(host_end);
+    let is_valid_host = host.contains('.')
+        && !host.starts_with('.')
+        && !host.ends_with('.')
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | ':'));
+    if !is_valid_host || path.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return None;
+    }
+
+    let url = format!("{}://{}{}", scheme, host.to_lowercase(), path);
+    (url.len() <= MAX_WEBSITE_URL_LENGTH).then_some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::github(
+        SocialLinkPlatform::GitHub,
+        "ZurichExplorer",
+        "ZurichExplorer",
+        Some("https://github.com/ZurichExplorer")
+    )]
+    #[case::github_url(
+        SocialLinkPlatform::GitHub,
+        "https://www.github.com/zurich-explorer/",
+        "zurich-explorer",
+        Some("https://github.com/zurich-explorer")
+    )]
+    #[case::x_handle(
+        SocialLinkPlatform::X,
+        "@zurich_2023",
+        "zurich_2023",
+        Some("https://x.com/zurich_2023")
+    )]
+    #[case::x_twitter_url(
+        SocialLinkPlatform::X,
+        "twitter.com/zurich_2023?lang=en",
+        "zurich_2023",
+        Some("https://x.com/zurich_2023")
+    )]
+    #[case::open_chat(
+        SocialLinkPlatform::OpenChat,
+        "rrkah-fqaaa-aaaaa-aaaaq-cai",
+        "rrkah-fqaaa-aaaaa-aaaaq-cai",
+        Some("https://oc.app/user/rrkah-fqaaa-aaaaa-aaaaq-cai")
+    )]
+    #[case::open_chat_url(
+        SocialLinkPlatform::OpenChat,
+        "https://oc.app/user/rrkah-fqaaa-aaaaa-aaaaq-cai",
+        "rrkah-fqaaa-aaaaa-aaaaq-cai",
+        Some("https://oc.app/user/rrkah-fqaaa-aaaaa-aaaaq-cai")
+    )]
+    #[case::dfinity_forum(
+        SocialLinkPlatform::DfinityForum,
+        "https://forum.dfinity.org/u/zurich.explorer/summary",
+        "zurich.explorer",
+        Some("https://forum.dfinity.org/u/zurich.explorer")
+    )]
+    #[case::discord_username(
+        SocialLinkPlatform::Discord,
+        "Zurich_Explorer",
+        "zurich_explorer",
+        None
+    )]
+    #[case::discord_user_id(
+        SocialLinkPlatform::Discord,
+        "https://discord.com/users/123456789012345678",
+        "123456789012345678",
+        Some("https://discord.com/users/123456789012345678")
+    )]
+    #[case::taggr(
+        SocialLinkPlatform::Taggr,
+        "https://taggr.link/#/user/ZurichExplorer2023",
+        "ZurichExplorer2023",
+        Some("https://taggr.link/#/user/ZurichExplorer2023")
+    )]
+    #[case::dscvr(
+        SocialLinkPlatform::Dscvr,
+        " ZurichExplorer2023 ",
+        "ZurichExplorer2023",
+        Some("https://dscvr.one/u/ZurichExplorer2023")
+    )]
+    #[case::website(
+        SocialLinkPlatform::Website,
+        "Example.com/about",
+        "https://example.com/about",
+        Some("https://example.com/about")
+    )]
+    #[case::website_http(
+        SocialLinkPlatform::Website,
+        "http://blog.example.org",
+        "http://blog.example.org",
+        Some("http://blog.example.org")
+    )]
+    fn new_valid(
+        #[case] platform: SocialLinkPlatform,
+        #[case] handle: &str,
+        #[case] expected_username: &str,
+        #[case] expected_url: Option<&str>,
+    ) {
+        let social_link = SocialLink::new(platform.clone(), handle).unwrap();
+
+        assert_eq!(
+            social_link,
+            SocialLink {
+                platform,
+                username: expected_username.to_string(),
+            }
+        );
+        assert_eq!(social_link.url(), expected_url.map(|url| url.to_string()));
+    }
+
+    #[rstest]
+    #[case::github_spaces(
+        SocialLinkPlatform::GitHub,
+        "zurich explorer",
+        "zurich explorer is not a valid GitHub username"
+    )]
+    #[case::github_leading_hyphen(
+        SocialLinkPlatform::GitHub,
+        "-zurich",
+        "-zurich is not a valid GitHub username"
+    )]
+    #[case::x_too_long(
+        SocialLinkPlatform::X,
+        "zurich_explorer_2023",
+        "zurich_explorer_2023 is not a valid X username"
+    )]
+    #[case::x_other_site(
+        SocialLinkPlatform::X,
+        "https://example.com/zurich",
+        "https://example.com/zurich is not a valid X username"
+    )]
+    #[case::open_chat_username(
+        SocialLinkPlatform::OpenChat,
+        "ZurichExplorer",
+        "ZurichExplorer is not a valid OpenChat user id"
+    )]
+    #[case::dfinity_forum_too_short(
+        SocialLinkPlatform::DfinityForum,
+        "zu",
+        "zu is not a valid DFINITY forum username"
+    )]
+    #[case::discord_consecutive_dots(
+        SocialLinkPlatform::Discord,
+        "zurich..explorer",
+        "zurich..explorer is not a valid Discord username"
+    )]
+    #[case::website_scheme(
+        SocialLinkPlatform::Website,
+        "ftp://example.com",
+        "ftp://example.com is not a valid website URL"
+    )]
+    #[case::website_no_domain(
+        SocialLinkPlatform::Website,
+        "my website",
+        "my website is not a valid website URL"
+    )]
+    #[case::empty(SocialLinkPlatform::Taggr, "", " is not a valid Taggr username")]
+    fn new_invalid(
+        #[case] platform: SocialLinkPlatform,
+        #[case] handle: &str,
+        #[case] expected_message: &str,
+    ) {
+        let result = SocialLink::new(platform, handle).unwrap_err();
+
+        assert_eq!(result, ApiError::invalid_argument(expected_message));
+    }
+
+    #[rstest]
+    fn new_legacy_discord() {
+        let social_link = SocialLink::new(SocialLinkPlatform::LegacyDiscord, "zurich").unwrap();
+
+        assert_eq!(social_link.platform, SocialLinkPlatform::Discord);
+    }
+}
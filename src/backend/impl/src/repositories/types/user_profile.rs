@@ -1,10 +1,14 @@
-use super::{NeuronId, Uuid};
+use super::{image_path, HistoryData, ImageId, NeuronId, Uuid, WalletAddress};
 use candid::{CandidType, Decode, Deserialize, Encode};
 use ic_stable_structures::{storable::Bound, Storable};
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
 
 pub type UserId = Uuid;
 
+pub const DEFAULT_ADMIN_BIO: &str = "Default admin profile created for canister controllers";
+
+pub const USER_AVATARS_SUB_PATH: &str = "avatars";
+
 #[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
 pub enum UserConfig {
     Admin {
@@ -13,7 +17,7 @@ pub enum UserConfig {
     Reviewer {
         bio: String,
         neuron_id: NeuronId,
-        wallet_address: String,
+        wallet_address: Option<WalletAddress>,
         social_links: Vec<SocialLink>,
     },
     Anonymous,
@@ -23,6 +27,11 @@ pub enum UserConfig {
 pub struct UserProfile {
     pub username: String,
     pub config: UserConfig,
+    /// Profiles stored before avatars were introduced decode as `None`.
+    pub avatar_id: Option<ImageId>,
+    /// A wallet address stored as text before addresses were validated, that cannot be parsed.
+    /// It is kept so that it can be corrected, until the reviewer sets a valid wallet address.
+    pub legacy_wallet_address: Option<String>,
 }
 
 #[derive(Debug, Clone, CandidType, Deserialize, PartialEq, Eq)]
@@ -45,9 +54,14 @@ pub enum SocialLinkPlatform {
     #[serde(rename = "dfinityforum")]
     DfinityForum,
 
-    #[serde(rename = "Discord")]
+    #[serde(rename = "discord")]
     Discord,
 
+    /// Discord links stored before the variant got its lowercase name.
+    /// Only decoded from stable memory and migrated to [SocialLinkPlatform::Discord] when read.
+    #[serde(rename = "Discord")]
+    LegacyDiscord,
+
     #[serde(rename = "website")]
     Website,
 }
@@ -63,6 +77,8 @@ impl UserProfile {
         Self {
             username: "Anonymous".to_string(),
             config: UserConfig::Anonymous,
+            avatar_id: None,
+            legacy_wallet_address: None,
         }
     }
 
@@ -70,8 +86,10 @@ impl UserProfile {
         Self {
             username: "Admin".to_string(),
             config: UserConfig::Admin {
-                bio: "Default admin profile created for canister controllers".to_string(),
+                bio: DEFAULT_ADMIN_BIO.to_string(),
             },
+            avatar_id: None,
+            legacy_wallet_address: None,
         }
     }
 
@@ -82,6 +100,58 @@ impl UserProfile {
     pub fn is_reviewer(&self) -> bool {
         matches!(self.config, UserConfig::Reviewer { .. })
     }
+
+    /// Returns the path of the avatar in the form of `/images/avatars/<image_id>`.
+    pub fn avatar_path(&self) -> Option<String> {
+        self.avatar_id
+            .map(|avatar_id| image_path(Some(Path::new(USER_AVATARS_SUB_PATH)), &avatar_id))
+    }
+
+    /// Drops the legacy wallet address once it no longer stands in for a missing one.
+    pub fn clear_replaced_legacy_wallet_address(&mut self) {
+        if !matches!(
+            self.config,
+            UserConfig::Reviewer {
+                wallet_address: None,
+                ..
+            }
+        ) {
+            self.legacy_wallet_address = None;
+        }
+    }
+
+    /// Migrates the fields of profiles stored by earlier versions of the canister.
+    /// `legacy_config` decodes the stored config with the legacy types, it is only called
+    /// when the wallet address may have been stored as text.
+    fn migrate_legacy_fields(&mut self, legacy_config: impl FnOnce() -> Option<LegacyUserConfig>) {
+        if let UserConfig::Reviewer { social_links, .. } = &mut self.config {
+            for social_link in social_links.iter_mut() {
+                if social_link.platform == SocialLinkPlatform::LegacyDiscord {
+                    social_link.platform = SocialLinkPlatform::Discord;
+                }
+            }
+        }
+
+        // wallet addresses used to be stored as text, which decodes as `None`
+        if let UserConfig::Reviewer {
+            wallet_address: wallet_address @ None,
+            ..
+        } = &mut self.config
+        {
+            if let Some(LegacyUserConfig::Reviewer {
+                wallet_address: legacy_wallet_address,
+            }) = legacy_config()
+            {
+                match WalletAddress::try_from(legacy_wallet_address.as_str()) {
+                    Ok(parsed_wallet_address) => *wallet_address = Some(parsed_wallet_address),
+                    Err(_) if !legacy_wallet_address.is_empty() => {
+                        self.legacy_wallet_address = Some(legacy_wallet_address);
+                    }
+                    Err(_) => {}
+                }
+            }
+        }
+    }
 }
 
 impl Storable for UserProfile {
@@ -90,26 +160,250 @@ impl Storable for UserProfile {
     }
 
     fn from_bytes(bytes: Cow<[u8]>) -> Self {
-        Decode!(bytes.as_ref(), Self).unwrap()
+        let mut user_profile = Decode!(bytes.as_ref(), Self).unwrap();
+        user_profile.migrate_legacy_fields(|| {
+            Decode!(bytes.as_ref(), LegacyUserProfile)
+                .ok()
+                .map(|legacy_user_profile| legacy_user_profile.config)
+        });
+
+        user_profile
     }
 
     const BOUND: Bound = Bound::Unbounded;
 }
 
+impl HistoryData for UserProfile {
+    fn migrate(&mut self, history_entry_bytes: &[u8]) {
+        self.migrate_legacy_fields(|| {
+            Decode!(history_entry_bytes, LegacyUserProfileHistoryEntry)
+                .ok()
+                .map(|legacy_history_entry| legacy_history_entry.data.config)
+        });
+    }
+}
+
+#[derive(CandidType, Deserialize)]
+struct LegacyUserProfile {
+    config: LegacyUserConfig,
+}
+
+#[derive(CandidType, Deserialize)]
+struct LegacyUserProfileHistoryEntry {
+    data: LegacyUserProfile,
+}
+
+#[derive(CandidType, Deserialize)]
+enum LegacyUserConfig {
+    Admin {},
+    Reviewer { wallet_address: String },
+    Anonymous,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fixtures;
+    use crate::{
+        fixtures,
+        repositories::{DateTime, HistoryAction, UserProfileHistoryEntry},
+    };
+    use candid::Principal;
     use rstest::*;
 
     #[rstest]
     #[case::anonymous_user(fixtures::anonymous_user_profile())]
     #[case::reviewer(fixtures::reviewer_user_profile())]
     #[case::admin(fixtures::admin_user_profile())]
+    #[case::with_avatar(UserProfile {
+        avatar_id: Some(fixtures::uuid_a()),
+        ..fixtures::reviewer_user_profile()
+    })]
     fn storable_impl(#[case] profile: UserProfile) {
         let serialized_user_profile = profile.to_bytes();
         let deserialized_user_profile = UserProfile::from_bytes(serialized_user_profile);
 
         assert_eq!(profile, deserialized_user_profile);
     }
+
+    #[rstest]
+    #[case::valid(
+        fixtures::wallet_address().to_string(),
+        Some(fixtures::wallet_address()),
+        None
+    )]
+    #[case::invalid("my wallet".to_string(), None, Some("my wallet".to_string()))]
+    #[case::empty(String::new(), None, None)]
+    fn storable_impl_legacy_wallet_address(
+        #[case] legacy_wallet_address: String,
+        #[case] expected_wallet_address: Option<WalletAddress>,
+        #[case] expected_legacy_wallet_address: Option<String>,
+    ) {
+        #[derive(CandidType)]
+        struct LegacyUserProfile {
+            username: String,
+            config: LegacyUserConfig,
+        }
+
+        #[derive(CandidType)]
+        enum LegacyUserConfig {
+            Reviewer {
+                bio: String,
+                neuron_id: NeuronId,
+                wallet_address: String,
+                social_links: Vec<SocialLink>,
+            },
+        }
+
+        let legacy_profile = LegacyUserProfile {
+            username: "Reviewer".to_string(),
+            config: LegacyUserConfig::Reviewer {
+                bio: "Bio".to_string(),
+                neuron_id: fixtures::neuron_id(),
+                wallet_address: legacy_wallet_address,
+                social_links: vec![fixtures::taggr_social_link()],
+            },
+        };
+
+        let deserialized_user_profile =
+            UserProfile::from_bytes(Cow::Owned(Encode!(&legacy_profile).unwrap()));
+
+        assert_eq!(
+            deserialized_user_profile,
+            UserProfile {
+                username: "Reviewer".to_string(),
+                config: UserConfig::Reviewer {
+                    bio: "Bio".to_string(),
+                    neuron_id: fixtures::neuron_id(),
+                    wallet_address: expected_wallet_address,
+                    social_links: vec![fixtures::taggr_social_link()],
+                },
+                avatar_id: None,
+                legacy_wallet_address: expected_legacy_wallet_address,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::valid(
+        fixtures::wallet_address().to_string(),
+        Some(fixtures::wallet_address()),
+        None
+    )]
+    #[case::invalid("my wallet".to_string(), None, Some("my wallet".to_string()))]
+    fn storable_impl_history_entry_legacy_wallet_address(
+        #[case] legacy_wallet_address: String,
+        #[case] expected_wallet_address: Option<WalletAddress>,
+        #[case] expected_legacy_wallet_address: Option<String>,
+    ) {
+        #[derive(CandidType)]
+        struct LegacyUserProfileHistoryEntry {
+            action: HistoryAction,
+            date_time: DateTime,
+            principal: Principal,
+            data: LegacyUserProfile,
+        }
+
+        #[derive(CandidType)]
+        struct LegacyUserProfile {
+            username: String,
+            config: LegacyUserConfig,
+        }
+
+        #[derive(CandidType)]
+        enum LegacyUserConfig {
+            Reviewer {
+                bio: String,
+                neuron_id: NeuronId,
+                wallet_address: String,
+                social_links: Vec<SocialLink>,
+            },
+        }
+
+        let legacy_history_entry = LegacyUserProfileHistoryEntry {
+            action: HistoryAction::Update,
+            date_time: fixtures::date_time_a(),
+            principal: fixtures::principal_a(),
+            data: LegacyUserProfile {
+                username: "Reviewer".to_string(),
+                config: LegacyUserConfig::Reviewer {
+                    bio: "Bio".to_string(),
+                    neuron_id: fixtures::neuron_id(),
+                    wallet_address: legacy_wallet_address,
+                    social_links: vec![fixtures::taggr_social_link()],
+                },
+            },
+        };
+
+        let deserialized_history_entry = UserProfileHistoryEntry::from_bytes(Cow::Owned(
+            Encode!(&legacy_history_entry).unwrap(),
+        ));
+
+        assert_eq!(
+            deserialized_history_entry,
+            UserProfileHistoryEntry {
+                action: HistoryAction::Update,
+                date_time: fixtures::date_time_a(),
+                principal: fixtures::principal_a(),
+                data: UserProfile {
+                    username: "Reviewer".to_string(),
+                    config: UserConfig::Reviewer {
+                        bio: "Bio".to_string(),
+                        neuron_id: fixtures::neuron_id(),
+                        wallet_address: expected_wallet_address,
+                        social_links: vec![fixtures::taggr_social_link()],
+                    },
+                    avatar_id: None,
+                    legacy_wallet_address: expected_legacy_wallet_address,
+                },
+            }
+        );
+    }
+
+    #[rstest]
+    fn storable_impl_legacy_discord_platform() {
+        let legacy_profile = UserProfile {
+            config: UserConfig::Reviewer {
+                bio: "Bio".to_string(),
+                neuron_id: fixtures::neuron_id(),
+                wallet_address: None,
+                social_links: vec![SocialLink {
+                    platform: SocialLinkPlatform::LegacyDiscord,
+                    username: "zurich_explorer".to_string(),
+                }],
+            },
+            ..fixtures::reviewer_user_profile()
+        };
+
+        let deserialized_user_profile = UserProfile::from_bytes(legacy_profile.to_bytes());
+
+        assert_eq!(
+            deserialized_user_profile,
+            UserProfile {
+                config: UserConfig::Reviewer {
+                    bio: "Bio".to_string(),
+                    neuron_id: fixtures::neuron_id(),
+                    wallet_address: None,
+                    social_links: vec![SocialLink {
+                        platform: SocialLinkPlatform::Discord,
+                        username: "zurich_explorer".to_string(),
+                    }],
+                },
+                ..fixtures::reviewer_user_profile()
+            }
+        );
+    }
+
+    #[rstest]
+    fn avatar_path() {
+        let profile = UserProfile {
+            avatar_id: Some(fixtures::uuid_a()),
+            ..fixtures::reviewer_user_profile()
+        };
+
+        assert_eq!(
+            profile.avatar_path(),
+            Some(format!("/images/avatars/{}", fixtures::uuid_a()))
+        );
+        assert_eq!(fixtures::reviewer_user_profile().avatar_path(), None);
+    }
 }
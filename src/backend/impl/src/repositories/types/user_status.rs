@@ -0,0 +1,50 @@
+use std::borrow::Cow;
+
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{storable::Bound, Storable};
+
+use super::{DateTime, UserId};
+
+/// The status of a user profile that is no longer active.
+/// Profiles without a status are active, the profile itself is kept
+/// untouched so that restoring it brings back its prior config.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum UserStatus {
+    Suspended {
+        reason: String,
+        suspended_by: UserId,
+        suspended_at: DateTime,
+    },
+    Deleted {
+        deleted_at: DateTime,
+    },
+}
+
+impl Storable for UserStatus {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::suspended(fixtures::suspended_user_status())]
+    #[case::deleted(fixtures::deleted_user_status())]
+    fn storable_impl(#[case] user_status: UserStatus) {
+        let serialized_user_status = user_status.to_bytes();
+        let deserialized_user_status = UserStatus::from_bytes(serialized_user_status);
+
+        assert_eq!(user_status, deserialized_user_status);
+    }
+}
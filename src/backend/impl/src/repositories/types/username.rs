@@ -0,0 +1,113 @@
+use backend_api::ApiError;
+
+pub const MIN_USERNAME_LENGTH: usize = 3;
+pub const MAX_USERNAME_LENGTH: usize = 32;
+
+/// Names that users cannot choose, compared case-insensitively.
+/// The defaults of [UserProfile::new_anonymous](super::UserProfile::new_anonymous) and
+/// [UserProfile::new_admin](super::UserProfile::new_admin) are among them, so they can be shared
+/// by all profiles that were never renamed.
+const RESERVED_USERNAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "anonymous",
+    "codegov",
+    "dfinity",
+    "moderator",
+    "root",
+    "support",
+    "system",
+];
+
+/// The key of a username in the uniqueness index.
+pub fn normalize_username(username: &str) -> String {
+    username.to_lowercase()
+}
+
+pub fn is_reserved_username(username: &str) -> bool {
+    RESERVED_USERNAMES.contains(&normalize_username(username).as_str())
+}
+
+pub fn validate_username(username: &str) -> Result<(), ApiError> {
+    let length = username.chars().count();
+    if !(MIN_USERNAME_LENGTH..=MAX_USERNAME_LENGTH).contains(&length) {
+        return Err(ApiError::invalid_argument(&format!(
+            "Username must be between {} and {} characters long",
+            MIN_USERNAME_LENGTH, MAX_USERNAME_LENGTH
+        )));
+    }
+
+    if !username
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    {
+        return Err(ApiError::invalid_argument(
+            "Username can only contain letters, digits, '_', '-' and '.'",
+        ));
+    }
+
+    if !username
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(ApiError::invalid_argument(
+            "Username must start with a letter or a digit",
+        ));
+    }
+
+    if is_reserved_username(username) {
+        return Err(ApiError::invalid_argument(&format!(
+            "Username {} is reserved",
+            username
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::letters("ZurichExplorer")]
+    #[case::digits("2023")]
+    #[case::separators("zurich_explorer-2023.icp")]
+    #[case::min_length("abc")]
+    #[case::max_length(&"a".repeat(MAX_USERNAME_LENGTH))]
+    #[case::reserved_prefix("AdminFan")]
+    fn validate_username_valid(#[case] username: &str) {
+        validate_username(username).unwrap();
+    }
+
+    #[rstest]
+    #[case::empty("", "Username must be between 3 and 32 characters long")]
+    #[case::too_short("ab", "Username must be between 3 and 32 characters long")]
+    #[case::too_long(&"a".repeat(MAX_USERNAME_LENGTH + 1), "Username must be between 3 and 32 characters long")]
+    #[case::whitespace(
+        "Zurich Explorer",
+        "Username can only contain letters, digits, '_', '-' and '.'"
+    )]
+    #[case::non_ascii(
+        "Zürich",
+        "Username can only contain letters, digits, '_', '-' and '.'"
+    )]
+    #[case::leading_separator("_zurich", "Username must start with a letter or a digit")]
+    #[case::reserved("Admin", "Username Admin is reserved")]
+    #[case::reserved_case_insensitive("aNoNyMoUs", "Username aNoNyMoUs is reserved")]
+    fn validate_username_invalid(#[case] username: &str, #[case] expected_message: &str) {
+        let result = validate_username(username).unwrap_err();
+
+        assert_eq!(result, ApiError::invalid_argument(expected_message));
+    }
+
+    #[rstest]
+    fn normalize_username_case_insensitive() {
+        assert_eq!(
+            normalize_username("ZurichExplorer"),
+            normalize_username("zurichexplorer")
+        );
+    }
+}
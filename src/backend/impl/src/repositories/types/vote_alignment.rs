@@ -0,0 +1,174 @@
+use std::{borrow::Cow, ops::RangeBounds};
+
+use backend_api::ApiError;
+use candid::{CandidType, Decode, Deserialize, Encode};
+use ic_stable_structures::{
+    storable::{Blob, Bound},
+    Storable,
+};
+
+use super::{DateTime, NeuronId, ProposalId, ProposalReviewId, ProposalVote, UserId, Uuid};
+
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum VoteAlignmentStatus {
+    /// The reviewer's neuron voted the same way as their published review.
+    Matched,
+    /// The reviewer's neuron voted against their published review.
+    Mismatched,
+    /// The reviewer's neuron did not vote, or had no ballot on the proposal.
+    NotVoted,
+}
+
+/// How a reviewer's neuron actually voted on a proposal,
+/// compared with the vote of their published review.
+/// There is at most one vote alignment per reviewer and proposal.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub struct VoteAlignment {
+    pub proposal_id: ProposalId,
+    pub user_id: UserId,
+    pub proposal_review_id: ProposalReviewId,
+    pub neuron_id: NeuronId,
+    pub review_vote: ProposalVote,
+    /// `None` if the neuron had no ballot on the proposal.
+    pub neuron_vote: Option<ProposalVote>,
+    pub status: VoteAlignmentStatus,
+    pub checked_at: DateTime,
+}
+
+impl VoteAlignmentStatus {
+    pub fn new(review_vote: &ProposalVote, neuron_vote: Option<&ProposalVote>) -> Self {
+        match neuron_vote {
+            None | Some(ProposalVote::Unspecified) => Self::NotVoted,
+            Some(neuron_vote) if neuron_vote == review_vote => Self::Matched,
+            Some(_) => Self::Mismatched,
+        }
+    }
+}
+
+impl Storable for VoteAlignment {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        Cow::Owned(Encode!(self).unwrap())
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Decode!(bytes.as_ref(), Self).unwrap()
+    }
+
+    const BOUND: Bound = Bound::Unbounded;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct VoteAlignmentKey(Blob<{ Self::MAX_SIZE as usize }>);
+
+impl VoteAlignmentKey {
+    const MAX_SIZE: u32 = <(ProposalId, UserId)>::BOUND.max_size();
+
+    pub fn new(proposal_id: ProposalId, user_id: UserId) -> Result<Self, ApiError> {
+        Ok(Self(
+            Blob::try_from((proposal_id, user_id).to_bytes().as_ref()).map_err(|_| {
+                ApiError::internal(&format!(
+                    "Failed to convert proposal id {:?} and user id {:?} to bytes.",
+                    proposal_id, user_id
+                ))
+            })?,
+        ))
+    }
+}
+
+impl Storable for VoteAlignmentKey {
+    fn to_bytes(&self) -> Cow<[u8]> {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: Cow<[u8]>) -> Self {
+        Self(Blob::from_bytes(bytes))
+    }
+
+    const BOUND: Bound = Bound::Bounded {
+        max_size: Self::MAX_SIZE,
+        is_fixed_size: true,
+    };
+}
+
+pub struct VoteAlignmentProposalRange {
+    start_bound: VoteAlignmentKey,
+    end_bound: VoteAlignmentKey,
+}
+
+impl VoteAlignmentProposalRange {
+    pub fn new(proposal_id: ProposalId) -> Result<Self, ApiError> {
+        Ok(Self {
+            start_bound: VoteAlignmentKey::new(proposal_id, Uuid::min())?,
+            end_bound: VoteAlignmentKey::new(proposal_id, Uuid::max())?,
+        })
+    }
+}
+
+impl RangeBounds<VoteAlignmentKey> for VoteAlignmentProposalRange {
+    fn start_bound(&self) -> std::ops::Bound<&VoteAlignmentKey> {
+        std::ops::Bound::Included(&self.start_bound)
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&VoteAlignmentKey> {
+        std::ops::Bound::Included(&self.end_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    #[case::matched(fixtures::vote_alignment_matched())]
+    #[case::not_voted(fixtures::vote_alignment_not_voted())]
+    fn storable_impl(#[case] vote_alignment: VoteAlignment) {
+        let serialized_vote_alignment = vote_alignment.to_bytes();
+        let deserialized_vote_alignment = VoteAlignment::from_bytes(serialized_vote_alignment);
+
+        assert_eq!(vote_alignment, deserialized_vote_alignment);
+    }
+
+    #[rstest]
+    fn vote_alignment_key_storable_impl() {
+        let key = VoteAlignmentKey::new(fixtures::proposal_id(), fixtures::user_id()).unwrap();
+        let serialized_key = key.to_bytes();
+        let deserialized_key = VoteAlignmentKey::from_bytes(serialized_key);
+
+        assert_eq!(key, deserialized_key);
+    }
+
+    #[rstest]
+    #[case::matched(
+        ProposalVote::Yes,
+        Some(ProposalVote::Yes),
+        VoteAlignmentStatus::Matched
+    )]
+    #[case::mismatched(
+        ProposalVote::Yes,
+        Some(ProposalVote::No),
+        VoteAlignmentStatus::Mismatched
+    )]
+    #[case::abstained_review(
+        ProposalVote::Unspecified,
+        Some(ProposalVote::No),
+        VoteAlignmentStatus::Mismatched
+    )]
+    #[case::not_voted(
+        ProposalVote::No,
+        Some(ProposalVote::Unspecified),
+        VoteAlignmentStatus::NotVoted
+    )]
+    #[case::no_ballot(ProposalVote::No, None, VoteAlignmentStatus::NotVoted)]
+    fn vote_alignment_status(
+        #[case] review_vote: ProposalVote,
+        #[case] neuron_vote: Option<ProposalVote>,
+        #[case] expected: VoteAlignmentStatus,
+    ) {
+        assert_eq!(
+            VoteAlignmentStatus::new(&review_vote, neuron_vote.as_ref()),
+            expected
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use core::fmt::{Display, Formatter};
+
+use backend_api::ApiError;
+use candid::{CandidType, Deserialize, Principal};
+
+const ACCOUNT_IDENTIFIER_HASH_SIZE: usize = 28;
+const ACCOUNT_IDENTIFIER_SIZE: usize = ACCOUNT_IDENTIFIER_HASH_SIZE + 4;
+const SUBACCOUNT_SIZE: usize = 32;
+
+type AccountIdentifierHash = [u8; ACCOUNT_IDENTIFIER_HASH_SIZE];
+type Subaccount = [u8; SUBACCOUNT_SIZE];
+
+/// A reviewer's wallet, parsed from either of the textual formats
+/// supported by the ICP ledger.
+#[derive(Debug, CandidType, Deserialize, Clone, PartialEq, Eq)]
+pub enum WalletAddress {
+    /// A legacy ICP ledger account identifier, stored without its checksum.
+    AccountIdentifier { hash: AccountIdentifierHash },
+    /// An ICRC-1 account. The default subaccount is stored as `None`.
+    Icrc1Account {
+        owner: Principal,
+        subaccount: Option<Subaccount>,
+    },
+}
+
+impl WalletAddress {
+    fn icrc1_account(owner: Principal, subaccount: Subaccount) -> Self {
+        Self::Icrc1Account {
+            owner,
+            subaccount: Some(subaccount).filter(|subaccount| *subaccount != [0; SUBACCOUNT_SIZE]),
+        }
+    }
+
+    fn parse_account_identifier(value: &str) -> Result<Self, ApiError> {
+        let bytes = hex::decode(value).map_err(|_| invalid_wallet_address(value))?;
+        let (checksum, hash) = bytes.split_at(4);
+
+        if checksum != crc32fast::hash(hash).to_be_bytes() {
+            return Err(ApiError::invalid_argument(&format!(
+                "Wallet address {} has an invalid checksum",
+                value
+            )));
+        }
+
+        Ok(Self::AccountIdentifier {
+            hash: hash.try_into().unwrap(),
+        })
+    }
+
+    /// Parses the textual encoding defined by the ICRC-1 standard:
+    /// `<owner>` for the default subaccount and
+    /// `<owner>-<checksum>.<subaccount>` for any other subaccount.
+    fn parse_icrc1_account(value: &str) -> Result<Self, ApiError> {
+        let Some((owner_and_checksum, subaccount_hex)) = value.split_once('.') else {
+            let owner = Principal::from_text(value).map_err(|_| invalid_wallet_address(value))?;
+
+            return Ok(Self::icrc1_account(owner, [0; SUBACCOUNT_SIZE]));
+        };
+
+        let (owner, checksum) = owner_and_checksum
+            .rsplit_once('-')
+            .ok_or_else(|| invalid_wallet_address(value))?;
+        let owner = Principal::from_text(owner).map_err(|_| invalid_wallet_address(value))?;
+
+        if subaccount_hex.is_empty()
+            || subaccount_hex.len() > SUBACCOUNT_SIZE * 2
+            || subaccount_hex.starts_with('0')
+        {
+            return Err(invalid_wallet_address(value));
+        }
+        let padded_subaccount_hex = format!("{:0>64}", subaccount_hex);
+        let mut subaccount = [0; SUBACCOUNT_SIZE];
+        hex::decode_to_slice(padded_subaccount_hex, &mut subaccount)
+            .map_err(|_| invalid_wallet_address(value))?;
+
+        if checksum != icrc1_account_checksum(&owner, &subaccount) {
+            return Err(ApiError::invalid_argument(&format!(
+                "Wallet address {} has an invalid checksum",
+                value
+            )));
+        }
+
+        Ok(Self::icrc1_account(owner, subaccount))
+    }
+}
+
+impl TryFrom<&str> for WalletAddress {
+    type Error = ApiError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        if value.len() == ACCOUNT_IDENTIFIER_SIZE * 2
+            && value.chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Self::parse_account_identifier(&value.to_ascii_lowercase());
+        }
+
+        Self::parse_icrc1_account(&value.to_ascii_lowercase())
+    }
+}
+
+/// Formats the wallet address in its canonical textual form.
+impl Display for WalletAddress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WalletAddress::AccountIdentifier { hash } => write!(
+                f,
+                "{}{}",
+                hex::encode(crc32fast::hash(hash).to_be_bytes()),
+                hex::encode(hash)
+            ),
+            WalletAddress::Icrc1Account {
+                owner,
+                subaccount: None,
+            } => write!(f, "{}", owner),
+            WalletAddress::Icrc1Account {
+                owner,
+                subaccount: Some(subaccount),
+            } => write!(
+                f,
+                "{}-{}.{}",
+                owner,
+                icrc1_account_checksum(owner, subaccount),
+                hex::encode(subaccount).trim_start_matches('0')
+            ),
+        }
+    }
+}
+
+fn invalid_wallet_address(value: &str) -> ApiError {
+    ApiError::invalid_argument(&format!(
+        "Wallet address {} must be an ICP account identifier or an ICRC-1 account",
+        value
+    ))
+}
+
+/// The CRC32 of the owner and subaccount, encoded as unpadded lowercase base32.
+fn icrc1_account_checksum(owner: &Principal, subaccount: &Subaccount) -> String {
+    const ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(owner.as_slice());
+    hasher.update(subaccount);
+    let checksum = u64::from(hasher.finalize());
+
+    // 32 bits are encoded as 7 characters of 5 bits, padded with 3 zero bits
+    (0..7)
+        .map(|i| ALPHABET[((checksum << 3) >> (30 - i * 5)) as usize & 0x1f] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    const OWNER: &str = "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae";
+
+    #[rstest]
+    #[case::account_identifier("da01eead5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb")]
+    #[case::icrc1_default_account(OWNER)]
+    #[case::icrc1_account(
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1"
+    )]
+    #[case::icrc1_account_full_subaccount(
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-dfxgiyy.102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20"
+    )]
+    fn try_from_and_display(#[case] value: &str) {
+        let wallet_address = WalletAddress::try_from(value).unwrap();
+
+        assert_eq!(wallet_address.to_string(), value);
+    }
+
+    #[rstest]
+    fn try_from_account_identifier() {
+        let wallet_address = WalletAddress::try_from(
+            "DA01EEAD5EB00BB853B9C42E1637433C81348A8856F4CFF1BB917E2CD04DF2CB ",
+        )
+        .unwrap();
+
+        assert_eq!(wallet_address, fixtures::wallet_address());
+        assert_eq!(
+            wallet_address.to_string(),
+            "da01eead5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb"
+        );
+    }
+
+    #[rstest]
+    fn try_from_icrc1_account() {
+        let mut subaccount = [0; SUBACCOUNT_SIZE];
+        subaccount[31] = 1;
+
+        let wallet_address = WalletAddress::try_from(
+            "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.1",
+        )
+        .unwrap();
+
+        assert_eq!(
+            wallet_address,
+            WalletAddress::Icrc1Account {
+                owner: Principal::from_text(OWNER).unwrap(),
+                subaccount: Some(subaccount),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::not_an_address("my wallet")]
+    #[case::short_hex("da01eead5eb00bb853b9c42e1637433c")]
+    #[case::invalid_principal("k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6aa")]
+    #[case::missing_checksum("k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae.1")]
+    #[case::empty_subaccount(
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i."
+    )]
+    #[case::leading_zeros(
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.01"
+    )]
+    fn try_from_invalid(#[case] value: &str) {
+        let result = WalletAddress::try_from(value).unwrap_err();
+
+        assert_eq!(result, invalid_wallet_address(value.trim()));
+    }
+
+    #[rstest]
+    #[case::account_identifier("da01eeae5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb")]
+    #[case::icrc1_account(
+        "k2t6j-2nvnp-4zjm3-25dtz-6xhaa-c7boj-5gayf-oj3xs-i43lp-teztq-6ae-6cc627i.2"
+    )]
+    fn try_from_invalid_checksum(#[case] value: &str) {
+        let result = WalletAddress::try_from(value).unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(&format!(
+                "Wallet address {} has an invalid checksum",
+                value
+            ))
+        );
+    }
+}
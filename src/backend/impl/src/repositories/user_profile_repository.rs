@@ -1,9 +1,13 @@
 use super::{
-    init_user_profile_history_id, init_user_profile_principal_index, init_user_profiles,
+    init_principal_link_history, init_user_principal_index, init_user_profile_history_id,
+    init_user_profile_principal_index, init_user_profiles, init_user_statuses, init_username_index,
+    is_reserved_username,
     memories::{init_user_profiles_history, UserProfileHistoryMemory},
-    UserId, UserProfile, UserProfileHistoryEntry, UserProfileHistoryIdMemory,
-    UserProfileHistoryKey, UserProfileHistoryRange, UserProfileMemory,
-    UserProfilePrincipalIndexMemory,
+    normalize_username, PrincipalLink, PrincipalLinkHistoryEntry, PrincipalLinkHistoryMemory,
+    UserId, UserPrincipalIndexMemory, UserPrincipalKey, UserPrincipalRange, UserProfile,
+    UserProfileHistoryEntry, UserProfileHistoryIdMemory, UserProfileHistoryKey,
+    UserProfileHistoryRange, UserProfileMemory, UserProfilePrincipalIndexMemory, UserStatus,
+    UserStatusMemory, UsernameIndexMemory,
 };
 use backend_api::ApiError;
 use candid::Principal;
@@ -11,6 +15,10 @@ use std::cell::RefCell;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait UserProfileRepository {
+    /// Returns all profiles ordered by user id, including suspended and deleted ones.
+    fn get_all_user_profiles(&self) -> Vec<(UserId, UserProfile)>;
+
+    /// Returns the reviewer profiles that are neither suspended nor deleted.
     fn get_all_reviewer_profiles(&self) -> Vec<(UserId, UserProfile)>;
 
     fn get_user_profile_by_principal(&self, principal: &Principal)
@@ -25,18 +33,70 @@ pub trait UserProfileRepository {
 
     fn get_user_id_by_principal(&self, principal: &Principal) -> Option<UserId>;
 
+    fn get_principals_by_user_id(&self, user_id: &UserId) -> Result<Vec<Principal>, ApiError>;
+
+    /// Fails with a conflict if another user already has the username, ignoring case.
     fn create_user_profile(
         &self,
         calling_principal: Principal,
         user_profile: UserProfile,
     ) -> Result<UserId, ApiError>;
 
+    /// Fails with a conflict if the username changes to one that another user already has, ignoring case.
     fn update_user_profile(
         &self,
         calling_principal: Principal,
         user_id: UserId,
         user_profile: UserProfile,
     ) -> Result<(), ApiError>;
+
+    fn get_principal_link_history_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PrincipalLinkHistoryEntry>, ApiError>;
+
+    /// Adds the principal to the principals that resolve to the user.
+    fn link_principal(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        principal: Principal,
+    ) -> Result<(), ApiError>;
+
+    fn unlink_principal(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        principal: Principal,
+    ) -> Result<(), ApiError>;
+
+    fn get_user_status_by_user_id(&self, user_id: &UserId) -> Option<UserStatus>;
+
+    /// Returns the user that owns the username in the uniqueness index, ignoring case.
+    fn get_user_id_by_username(&self, username: &str) -> Option<UserId>;
+
+    /// Adds the usernames of existing profiles to the uniqueness index.
+    /// When several profiles share a username, the one with the lowest user id claims it.
+    fn index_usernames(&self);
+
+    /// Adds the principals linked before the user principal index existed to the index.
+    fn index_user_principals(&self) -> Result<(), ApiError>;
+
+    /// Suspends or deletes the profile, recording a [Suspend](super::HistoryAction::Suspend)
+    /// or [Delete](super::HistoryAction::Delete) history entry respectively.
+    fn set_user_status(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        user_status: UserStatus,
+    ) -> Result<(), ApiError>;
+
+    /// Makes the profile active again, recording a [Restore](super::HistoryAction::Restore) history entry.
+    fn remove_user_status(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+    ) -> Result<(), ApiError>;
 }
 
 pub struct UserProfileRepositoryImpl {}
@@ -48,11 +108,17 @@ impl Default for UserProfileRepositoryImpl {
 }
 
 impl UserProfileRepository for UserProfileRepositoryImpl {
+    fn get_all_user_profiles(&self) -> Vec<(UserId, UserProfile)> {
+        STATE.with_borrow(|s| s.profiles.iter().collect())
+    }
+
     fn get_all_reviewer_profiles(&self) -> Vec<(UserId, UserProfile)> {
         STATE.with_borrow(|s| {
             s.profiles
                 .iter()
-                .filter(|(_, profile)| profile.is_reviewer())
+                .filter(|(user_id, profile)| {
+                    profile.is_reviewer() && !s.user_statuses.contains_key(user_id)
+                })
                 .collect()
         })
     }
@@ -85,6 +151,17 @@ impl UserProfileRepository for UserProfileRepositoryImpl {
         STATE.with_borrow(|s| s.principal_index.get(principal))
     }
 
+    fn get_principals_by_user_id(&self, user_id: &UserId) -> Result<Vec<Principal>, ApiError> {
+        let range = UserPrincipalRange::new(*user_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.user_principal_index
+                .range(range)
+                .map(|(_, principal)| principal)
+                .collect()
+        }))
+    }
+
     fn create_user_profile(
         &self,
         calling_principal: Principal,
@@ -94,8 +171,13 @@ impl UserProfileRepository for UserProfileRepositoryImpl {
         let history_entry_id = Self::get_next_history_id()?;
 
         STATE.with_borrow_mut(|s| {
+            s.claim_username(&user_profile.username, user_id)?;
             s.profiles.insert(user_id, user_profile.clone());
             s.principal_index.insert(calling_principal, user_id);
+            s.user_principal_index.insert(
+                UserPrincipalKey::new(user_id, calling_principal)?,
+                calling_principal,
+            );
 
             let history_entry =
                 UserProfileHistoryEntry::create_action(calling_principal, user_profile)?;
@@ -119,6 +201,15 @@ impl UserProfileRepository for UserProfileRepositoryImpl {
         let history_entry_id = Self::get_next_history_id()?;
 
         STATE.with_borrow_mut(|s| {
+            let previous_username = s.profiles.get(&user_id).map(|profile| profile.username);
+            if previous_username.as_deref().map(normalize_username)
+                != Some(normalize_username(&user_profile.username))
+            {
+                s.claim_username(&user_profile.username, user_id)?;
+                if let Some(previous_username) = previous_username {
+                    s.release_username(&previous_username, user_id);
+                }
+            }
             s.profiles.insert(user_id, user_profile.clone());
 
             let history_entry =
@@ -131,6 +222,172 @@ impl UserProfileRepository for UserProfileRepositoryImpl {
             Ok(())
         })
     }
+
+    fn get_principal_link_history_by_user_id(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<PrincipalLinkHistoryEntry>, ApiError> {
+        let range = UserProfileHistoryRange::new(*user_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.principal_link_history
+                .range(range)
+                .map(|(_, entry)| entry)
+                .collect()
+        }))
+    }
+
+    fn link_principal(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        principal: Principal,
+    ) -> Result<(), ApiError> {
+        let history_entry_id = Self::get_next_history_id()?;
+
+        STATE.with_borrow_mut(|s| {
+            if s.principal_index.contains_key(&principal) {
+                return Err(ApiError::conflict(&format!(
+                    "Principal {} is already linked to a user profile",
+                    principal.to_text()
+                )));
+            }
+            s.principal_index.insert(principal, user_id);
+            s.user_principal_index
+                .insert(UserPrincipalKey::new(user_id, principal)?, principal);
+
+            let history_entry = PrincipalLinkHistoryEntry::create_action(
+                calling_principal,
+                PrincipalLink { principal },
+            )?;
+            s.principal_link_history.insert(
+                UserProfileHistoryKey::new(user_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })
+    }
+
+    fn unlink_principal(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        principal: Principal,
+    ) -> Result<(), ApiError> {
+        let history_entry_id = Self::get_next_history_id()?;
+
+        STATE.with_borrow_mut(|s| {
+            if s.principal_index.get(&principal) != Some(user_id) {
+                return Err(ApiError::not_found(&format!(
+                    "Principal {} is not linked to user with id {}",
+                    principal.to_text(),
+                    user_id
+                )));
+            }
+            s.principal_index.remove(&principal);
+            s.user_principal_index
+                .remove(&UserPrincipalKey::new(user_id, principal)?);
+
+            let history_entry = PrincipalLinkHistoryEntry::delete_action(
+                calling_principal,
+                PrincipalLink { principal },
+            )?;
+            s.principal_link_history.insert(
+                UserProfileHistoryKey::new(user_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })
+    }
+
+    fn get_user_status_by_user_id(&self, user_id: &UserId) -> Option<UserStatus> {
+        STATE.with_borrow(|s| s.user_statuses.get(user_id))
+    }
+
+    fn get_user_id_by_username(&self, username: &str) -> Option<UserId> {
+        STATE.with_borrow(|s| s.username_index.get(&normalize_username(username)))
+    }
+
+    fn index_usernames(&self) {
+        STATE.with_borrow_mut(|s| {
+            let profiles: Vec<(UserId, UserProfile)> = s.profiles.iter().collect();
+            for (user_id, profile) in profiles {
+                // collisions are expected here and listed by the username report instead
+                let _ = s.claim_username(&profile.username, user_id);
+            }
+        })
+    }
+
+    fn index_user_principals(&self) -> Result<(), ApiError> {
+        STATE.with_borrow_mut(|s| {
+            let principals: Vec<(Principal, UserId)> = s.principal_index.iter().collect();
+            for (principal, user_id) in principals {
+                s.user_principal_index
+                    .insert(UserPrincipalKey::new(user_id, principal)?, principal);
+            }
+
+            Ok(())
+        })
+    }
+
+    fn set_user_status(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+        user_status: UserStatus,
+    ) -> Result<(), ApiError> {
+        let user_profile = self.get_existing_user_profile(&user_id)?;
+        let history_entry_id = Self::get_next_history_id()?;
+
+        let history_entry = match user_status {
+            UserStatus::Suspended { .. } => {
+                UserProfileHistoryEntry::suspend_action(calling_principal, user_profile)?
+            }
+            UserStatus::Deleted { .. } => {
+                UserProfileHistoryEntry::delete_action(calling_principal, user_profile)?
+            }
+        };
+
+        STATE.with_borrow_mut(|s| {
+            s.user_statuses.insert(user_id, user_status);
+
+            s.profiles_history.insert(
+                UserProfileHistoryKey::new(user_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })
+    }
+
+    fn remove_user_status(
+        &self,
+        calling_principal: Principal,
+        user_id: UserId,
+    ) -> Result<(), ApiError> {
+        let user_profile = self.get_existing_user_profile(&user_id)?;
+        let history_entry_id = Self::get_next_history_id()?;
+
+        STATE.with_borrow_mut(|s| {
+            s.user_statuses.remove(&user_id).ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User with id {} is neither suspended nor deleted",
+                    user_id
+                ))
+            })?;
+
+            let history_entry =
+                UserProfileHistoryEntry::restore_action(calling_principal, user_profile)?;
+            s.profiles_history.insert(
+                UserProfileHistoryKey::new(user_id, history_entry.date_time, history_entry_id)?,
+                history_entry,
+            );
+
+            Ok(())
+        })
+    }
 }
 
 impl UserProfileRepositoryImpl {
@@ -150,6 +407,15 @@ impl UserProfileRepositoryImpl {
         })
     }
 
+    fn get_existing_user_profile(&self, user_id: &UserId) -> Result<UserProfile, ApiError> {
+        self.get_user_profile_by_user_id(user_id).ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "User profile for user with id {} not found",
+                user_id
+            ))
+        })
+    }
+
     fn get_next_history_id() -> Result<u128, ApiError> {
         STATE.with_borrow_mut(|s| {
             // This id is used to ensure that parallel writes on profile history for the same
@@ -174,6 +440,38 @@ struct UserProfileState {
     principal_index: UserProfilePrincipalIndexMemory,
     profiles_history: UserProfileHistoryMemory,
     profiles_history_id: UserProfileHistoryIdMemory,
+    principal_link_history: PrincipalLinkHistoryMemory,
+    user_statuses: UserStatusMemory,
+    username_index: UsernameIndexMemory,
+    user_principal_index: UserPrincipalIndexMemory,
+}
+
+impl UserProfileState {
+    fn claim_username(&mut self, username: &str, user_id: UserId) -> Result<(), ApiError> {
+        // reserved names are only assigned by the canister itself and shared by default profiles
+        if is_reserved_username(username) {
+            return Ok(());
+        }
+
+        let key = normalize_username(username);
+        match self.username_index.get(&key) {
+            Some(owner_id) if owner_id != user_id => Err(ApiError::conflict(&format!(
+                "Username {} is already taken",
+                username
+            ))),
+            _ => {
+                self.username_index.insert(key, user_id);
+                Ok(())
+            }
+        }
+    }
+
+    fn release_username(&mut self, username: &str, user_id: UserId) {
+        let key = normalize_username(username);
+        if self.username_index.get(&key) == Some(user_id) {
+            self.username_index.remove(&key);
+        }
+    }
 }
 
 impl Default for UserProfileState {
@@ -183,6 +481,10 @@ impl Default for UserProfileState {
             principal_index: init_user_profile_principal_index(),
             profiles_history: init_user_profiles_history(),
             profiles_history_id: init_user_profile_history_id(),
+            principal_link_history: init_principal_link_history(),
+            user_statuses: init_user_statuses(),
+            username_index: init_username_index(),
+            user_principal_index: init_user_principal_index(),
         }
     }
 }
@@ -201,6 +503,36 @@ mod tests {
     };
     use rstest::*;
 
+    #[rstest]
+    fn get_all_user_profiles() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+
+        let anonymous_id = repository
+            .create_user_profile(fixtures::principal_a(), fixtures::anonymous_user_profile())
+            .unwrap();
+        let reviewer_id = repository
+            .create_user_profile(fixtures::principal_b(), fixtures::reviewer_user_profile())
+            .unwrap();
+        repository
+            .set_user_status(
+                fixtures::principal_b(),
+                reviewer_id,
+                fixtures::deleted_user_status(),
+            )
+            .unwrap();
+
+        let mut expected = vec![
+            (anonymous_id, fixtures::anonymous_user_profile()),
+            (reviewer_id, fixtures::reviewer_user_profile()),
+        ];
+        expected.sort_by_key(|(id, _)| *id);
+
+        let result = repository.get_all_user_profiles();
+
+        assert_eq!(result, expected);
+    }
+
     #[rstest]
     fn get_all_reviewer_profiles() {
         STATE.set(UserProfileState::default());
@@ -210,7 +542,13 @@ mod tests {
             .create_user_profile(fixtures::principal_a(), fixtures::anonymous_user_profile())
             .unwrap();
         repository
-            .create_user_profile(fixtures::principal_b(), fixtures::admin_user_profile())
+            .create_user_profile(
+                fixtures::principal_b(),
+                UserProfile {
+                    username: "LausanneLeader2023".to_string(),
+                    ..fixtures::admin_user_profile()
+                },
+            )
             .unwrap();
         let reviewer_id = repository
             .create_user_profile(fixtures::principal_c(), fixtures::reviewer_user_profile())
@@ -222,6 +560,27 @@ mod tests {
         assert_eq!(result[0], (reviewer_id, fixtures::reviewer_user_profile()));
     }
 
+    #[rstest]
+    fn get_principals_by_user_id() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+
+        repository
+            .create_user_profile(fixtures::principal_a(), fixtures::anonymous_user_profile())
+            .unwrap();
+        let user_id = repository
+            .create_user_profile(fixtures::principal_b(), fixtures::reviewer_user_profile())
+            .unwrap();
+
+        let result = repository.get_principals_by_user_id(&user_id).unwrap();
+
+        assert_eq!(result, vec![fixtures::principal_b()]);
+        assert!(repository
+            .get_principals_by_user_id(&fixtures::uuid_a())
+            .unwrap()
+            .is_empty());
+    }
+
     #[rstest]
     #[case::anonymous_user(fixtures::anonymous_user_profile())]
     #[case::reviewer(fixtures::reviewer_user_profile())]
@@ -304,6 +663,463 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn link_and_unlink_principal() {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+        let linked_principal = fixtures::principal_b();
+        let date_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = repository
+            .create_user_profile(principal, fixtures::reviewer_user_profile())
+            .unwrap();
+
+        repository
+            .link_principal(principal, user_id, linked_principal)
+            .unwrap();
+
+        assert_eq!(
+            repository.get_user_profile_by_principal(&linked_principal),
+            Some((user_id, fixtures::reviewer_user_profile()))
+        );
+        assert_eq!(
+            repository
+                .get_principals_by_user_id(&user_id)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        repository
+            .unlink_principal(linked_principal, user_id, linked_principal)
+            .unwrap();
+
+        assert_eq!(repository.get_user_id_by_principal(&linked_principal), None);
+        assert_eq!(
+            repository.get_principals_by_user_id(&user_id).unwrap(),
+            vec![principal]
+        );
+        assert_eq!(
+            repository
+                .get_principal_link_history_by_user_id(&user_id)
+                .unwrap(),
+            vec![
+                PrincipalLinkHistoryEntry {
+                    action: HistoryAction::Create,
+                    date_time,
+                    principal,
+                    data: PrincipalLink {
+                        principal: linked_principal
+                    },
+                },
+                PrincipalLinkHistoryEntry {
+                    action: HistoryAction::Delete,
+                    date_time,
+                    principal: linked_principal,
+                    data: PrincipalLink {
+                        principal: linked_principal
+                    },
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn link_principal_already_linked() {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+        let other_principal = fixtures::principal_b();
+
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = repository
+            .create_user_profile(principal, fixtures::reviewer_user_profile())
+            .unwrap();
+        let other_user_id = repository
+            .create_user_profile(other_principal, fixtures::anonymous_user_profile())
+            .unwrap();
+
+        let result = repository
+            .link_principal(principal, user_id, other_principal)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Principal {} is already linked to a user profile",
+                other_principal.to_text()
+            ))
+        );
+        assert_eq!(
+            repository.get_user_id_by_principal(&other_principal),
+            Some(other_user_id)
+        );
+        assert!(repository
+            .get_principal_link_history_by_user_id(&user_id)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[rstest]
+    fn unlink_principal_not_linked() {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+        let other_principal = fixtures::principal_b();
+
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = repository
+            .create_user_profile(principal, fixtures::reviewer_user_profile())
+            .unwrap();
+        repository
+            .create_user_profile(other_principal, fixtures::anonymous_user_profile())
+            .unwrap();
+
+        let result = repository
+            .unlink_principal(principal, user_id, other_principal)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Principal {} is not linked to user with id {}",
+                other_principal.to_text(),
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_all_reviewer_profiles_hides_inactive_profiles() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+
+        let suspended_id = repository
+            .create_user_profile(
+                fixtures::principal_a(),
+                UserProfile {
+                    username: "SuspendedReviewer".to_string(),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )
+            .unwrap();
+        let deleted_id = repository
+            .create_user_profile(
+                fixtures::principal_b(),
+                UserProfile {
+                    username: "DeletedReviewer".to_string(),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )
+            .unwrap();
+        let reviewer_id = repository
+            .create_user_profile(fixtures::principal_c(), fixtures::reviewer_user_profile())
+            .unwrap();
+        repository
+            .set_user_status(
+                fixtures::principal_a(),
+                suspended_id,
+                fixtures::suspended_user_status(),
+            )
+            .unwrap();
+        repository
+            .set_user_status(
+                fixtures::principal_b(),
+                deleted_id,
+                fixtures::deleted_user_status(),
+            )
+            .unwrap();
+
+        let result = repository.get_all_reviewer_profiles();
+
+        assert_eq!(
+            result,
+            vec![(reviewer_id, fixtures::reviewer_user_profile())]
+        );
+    }
+
+    #[rstest]
+    #[case::suspended(fixtures::suspended_user_status(), HistoryAction::Suspend)]
+    #[case::deleted(fixtures::deleted_user_status(), HistoryAction::Delete)]
+    fn set_and_remove_user_status(
+        #[case] user_status: UserStatus,
+        #[case] history_action: HistoryAction,
+    ) {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+        let date_time = DateTime::new(get_date_time().unwrap()).unwrap();
+        let profile = fixtures::reviewer_user_profile();
+
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = repository
+            .create_user_profile(principal, profile.clone())
+            .unwrap();
+
+        repository
+            .set_user_status(principal, user_id, user_status.clone())
+            .unwrap();
+        assert_eq!(
+            repository.get_user_status_by_user_id(&user_id),
+            Some(user_status)
+        );
+
+        repository.remove_user_status(principal, user_id).unwrap();
+        assert_eq!(repository.get_user_status_by_user_id(&user_id), None);
+        assert_eq!(
+            repository.get_user_profile_by_user_id(&user_id),
+            Some(profile.clone())
+        );
+
+        let history_result = repository
+            .get_user_profile_history_by_principal(&principal)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            history_result,
+            vec![
+                UserProfileHistoryEntry {
+                    action: HistoryAction::Create,
+                    principal,
+                    date_time,
+                    data: profile.clone(),
+                },
+                UserProfileHistoryEntry {
+                    action: history_action,
+                    principal,
+                    date_time,
+                    data: profile.clone(),
+                },
+                UserProfileHistoryEntry {
+                    action: HistoryAction::Restore,
+                    principal,
+                    date_time,
+                    data: profile,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn set_user_status_no_profile() {
+        STATE.set(UserProfileState::default());
+        let user_id = fixtures::user_id();
+
+        let repository = UserProfileRepositoryImpl::default();
+
+        let result = repository
+            .set_user_status(
+                fixtures::principal_a(),
+                user_id,
+                fixtures::deleted_user_status(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for user with id {} not found",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn remove_user_status_active_profile() {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = repository
+            .create_user_profile(principal, fixtures::reviewer_user_profile())
+            .unwrap();
+
+        let result = repository
+            .remove_user_status(principal, user_id)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User with id {} is neither suspended nor deleted",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn create_user_profile_username_taken() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+
+        let user_id = repository
+            .create_user_profile(fixtures::principal_a(), fixtures::reviewer_user_profile())
+            .unwrap();
+
+        let result = repository
+            .create_user_profile(
+                fixtures::principal_b(),
+                UserProfile {
+                    username: "zurichexplorer2023".to_string(),
+                    ..fixtures::anonymous_user_profile()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict("Username zurichexplorer2023 is already taken")
+        );
+        assert_eq!(
+            repository.get_user_id_by_username("ZURICHEXPLORER2023"),
+            Some(user_id)
+        );
+        assert_eq!(
+            repository.get_user_profile_by_principal(&fixtures::principal_b()),
+            None
+        );
+    }
+
+    #[rstest]
+    fn create_user_profile_shared_default_username() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+
+        repository
+            .create_user_profile(fixtures::principal_a(), UserProfile::new_anonymous())
+            .unwrap();
+        repository
+            .create_user_profile(fixtures::principal_b(), UserProfile::new_anonymous())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_user_id_by_username(&UserProfile::new_anonymous().username),
+            None
+        );
+    }
+
+    #[rstest]
+    fn update_user_profile_username() {
+        STATE.set(UserProfileState::default());
+        let principal = fixtures::principal_a();
+        let other_principal = fixtures::principal_b();
+        let repository = UserProfileRepositoryImpl::default();
+
+        let user_id = repository
+            .create_user_profile(principal, fixtures::reviewer_user_profile())
+            .unwrap();
+        let other_user_id = repository
+            .create_user_profile(other_principal, fixtures::anonymous_user_profile())
+            .unwrap();
+
+        // changing the case of the own username is allowed
+        repository
+            .update_user_profile(
+                principal,
+                user_id,
+                UserProfile {
+                    username: "ZURICHEXPLORER2023".to_string(),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )
+            .unwrap();
+
+        let result = repository
+            .update_user_profile(
+                other_principal,
+                other_user_id,
+                UserProfile {
+                    username: "ZurichExplorer2023".to_string(),
+                    ..fixtures::anonymous_user_profile()
+                },
+            )
+            .unwrap_err();
+        assert_eq!(
+            result,
+            ApiError::conflict("Username ZurichExplorer2023 is already taken")
+        );
+
+        repository
+            .update_user_profile(principal, user_id, updated_reviewer_user_profile())
+            .unwrap();
+        repository
+            .update_user_profile(
+                other_principal,
+                other_user_id,
+                UserProfile {
+                    username: "ZurichExplorer2023".to_string(),
+                    ..fixtures::anonymous_user_profile()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            repository.get_user_id_by_username("zurichexplorer2024"),
+            Some(user_id)
+        );
+        assert_eq!(
+            repository.get_user_id_by_username("zurichexplorer2023"),
+            Some(other_user_id)
+        );
+        assert_eq!(
+            repository.get_user_id_by_username("AlpineAdventurer2023"),
+            None
+        );
+    }
+
+    #[rstest]
+    fn index_usernames() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+        let first_id = fixtures::uuid_a();
+        let second_id = fixtures::uuid_b();
+
+        // profiles stored before the index existed
+        STATE.with_borrow_mut(|s| {
+            s.profiles
+                .insert(second_id, fixtures::reviewer_user_profile());
+            s.profiles.insert(
+                first_id,
+                UserProfile {
+                    username: "zurichexplorer2023".to_string(),
+                    ..fixtures::anonymous_user_profile()
+                },
+            );
+        });
+
+        repository.index_usernames();
+        repository.index_usernames();
+
+        assert_eq!(
+            repository.get_user_id_by_username("ZurichExplorer2023"),
+            Some(first_id.min(second_id))
+        );
+    }
+
+    #[rstest]
+    fn index_user_principals() {
+        STATE.set(UserProfileState::default());
+        let repository = UserProfileRepositoryImpl::default();
+        let user_id = fixtures::uuid_a();
+
+        // principals linked before the index existed
+        STATE.with_borrow_mut(|s| {
+            s.principal_index.insert(fixtures::principal_a(), user_id);
+            s.principal_index.insert(fixtures::principal_b(), user_id);
+            s.principal_index
+                .insert(fixtures::principal_c(), fixtures::uuid_b());
+        });
+
+        repository.index_user_principals().unwrap();
+        repository.index_user_principals().unwrap();
+
+        let mut result = repository.get_principals_by_user_id(&user_id).unwrap();
+        result.sort();
+
+        let mut expected = vec![fixtures::principal_a(), fixtures::principal_b()];
+        expected.sort();
+
+        assert_eq!(result, expected);
+    }
+
     #[fixture]
     fn updated_anonymous_user_profile() -> UserProfile {
         UserProfile {
@@ -0,0 +1,124 @@
+use std::cell::RefCell;
+
+use backend_api::ApiError;
+
+use super::{
+    init_vote_alignments, ProposalId, VoteAlignment, VoteAlignmentKey, VoteAlignmentMemory,
+    VoteAlignmentProposalRange,
+};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait VoteAlignmentRepository {
+    fn get_vote_alignments_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<VoteAlignment>, ApiError>;
+
+    fn get_vote_alignments(&self) -> Vec<VoteAlignment>;
+
+    fn upsert_vote_alignment(&self, vote_alignment: VoteAlignment) -> Result<(), ApiError>;
+}
+
+pub struct VoteAlignmentRepositoryImpl {}
+
+impl Default for VoteAlignmentRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VoteAlignmentRepository for VoteAlignmentRepositoryImpl {
+    fn get_vote_alignments_by_proposal_id(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<VoteAlignment>, ApiError> {
+        let range = VoteAlignmentProposalRange::new(proposal_id)?;
+
+        Ok(STATE.with_borrow(|s| {
+            s.vote_alignments
+                .range(range)
+                .map(|(_, vote_alignment)| vote_alignment)
+                .collect()
+        }))
+    }
+
+    fn get_vote_alignments(&self) -> Vec<VoteAlignment> {
+        STATE.with_borrow(|s| {
+            s.vote_alignments
+                .iter()
+                .map(|(_, vote_alignment)| vote_alignment)
+                .collect()
+        })
+    }
+
+    fn upsert_vote_alignment(&self, vote_alignment: VoteAlignment) -> Result<(), ApiError> {
+        let key = VoteAlignmentKey::new(vote_alignment.proposal_id, vote_alignment.user_id)?;
+
+        STATE.with_borrow_mut(|s| {
+            s.vote_alignments.insert(key, vote_alignment);
+        });
+
+        Ok(())
+    }
+}
+
+impl VoteAlignmentRepositoryImpl {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+struct VoteAlignmentState {
+    vote_alignments: VoteAlignmentMemory,
+}
+
+impl Default for VoteAlignmentState {
+    fn default() -> Self {
+        Self {
+            vote_alignments: init_vote_alignments(),
+        }
+    }
+}
+
+thread_local! {
+    static STATE: RefCell<VoteAlignmentState> = RefCell::new(VoteAlignmentState::default());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use rstest::*;
+
+    #[rstest]
+    fn upsert_and_get_vote_alignments() {
+        STATE.set(VoteAlignmentState::default());
+
+        let repository = VoteAlignmentRepositoryImpl::default();
+        let other_proposal_vote_alignment = VoteAlignment {
+            proposal_id: fixtures::uuid_a(),
+            ..fixtures::vote_alignment_matched()
+        };
+
+        repository
+            .upsert_vote_alignment(fixtures::vote_alignment_matched())
+            .unwrap();
+        repository
+            .upsert_vote_alignment(fixtures::vote_alignment_not_voted())
+            .unwrap();
+        repository
+            .upsert_vote_alignment(other_proposal_vote_alignment.clone())
+            .unwrap();
+
+        let result = repository
+            .get_vote_alignments_by_proposal_id(fixtures::proposal_id())
+            .unwrap();
+
+        assert_eq!(result, vec![fixtures::vote_alignment_not_voted()]);
+        assert_eq!(repository.get_vote_alignments().len(), 2);
+        assert!(repository
+            .get_vote_alignments_by_proposal_id(fixtures::uuid_b())
+            .unwrap()
+            .is_empty());
+    }
+}
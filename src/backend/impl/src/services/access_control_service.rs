@@ -1,28 +1,44 @@
-use crate::repositories::{UserProfileRepository, UserProfileRepositoryImpl};
+use crate::repositories::{
+    Permission, RoleRepository, RoleRepositoryImpl, UserId, UserProfileRepository,
+    UserProfileRepositoryImpl, UserStatus,
+};
 use backend_api::ApiError;
 use candid::Principal;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait AccessControlService {
+    /// Checks that the principal is authenticated and, if it has a profile,
+    /// that the profile is neither suspended nor deleted.
     fn assert_principal_not_anonymous(&self, calling_principal: &Principal)
         -> Result<(), ApiError>;
 
-    fn assert_principal_is_admin(&self, calling_principal: &Principal) -> Result<(), ApiError>;
-
-    fn assert_principal_is_reviewer(&self, calling_principal: &Principal) -> Result<(), ApiError>;
+    /// Checks that the principal's profile grants the permission,
+    /// either through its [UserConfig](crate::repositories::UserConfig) or one of its assigned roles.
+    /// Suspended and deleted profiles have no permissions.
+    fn assert_permission(
+        &self,
+        calling_principal: &Principal,
+        permission: Permission,
+    ) -> Result<(), ApiError>;
 }
 
-pub struct AccessControlServiceImpl<T: UserProfileRepository> {
+pub struct AccessControlServiceImpl<T: UserProfileRepository, R: RoleRepository> {
     user_profile_repository: T,
+    role_repository: R,
 }
 
-impl Default for AccessControlServiceImpl<UserProfileRepositoryImpl> {
+impl Default for AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl> {
     fn default() -> Self {
-        Self::new(UserProfileRepositoryImpl::default())
+        Self::new(
+            UserProfileRepositoryImpl::default(),
+            RoleRepositoryImpl::default(),
+        )
     }
 }
 
-impl<T: UserProfileRepository> AccessControlService for AccessControlServiceImpl<T> {
+impl<T: UserProfileRepository, R: RoleRepository> AccessControlService
+    for AccessControlServiceImpl<T, R>
+{
     fn assert_principal_not_anonymous(
         &self,
         calling_principal: &Principal,
@@ -31,32 +47,22 @@ impl<T: UserProfileRepository> AccessControlService for AccessControlServiceImpl
             return Err(ApiError::unauthenticated());
         }
 
-        Ok(())
-    }
-
-    fn assert_principal_is_admin(&self, calling_principal: &Principal) -> Result<(), ApiError> {
-        let (_id, profile) = self
+        if let Some(id) = self
             .user_profile_repository
-            .get_user_profile_by_principal(calling_principal)
-            .ok_or_else(|| {
-                ApiError::not_found(&format!(
-                    "Principal {} must have a profile to call this endpoint",
-                    calling_principal.to_text()
-                ))
-            })?;
-
-        if !profile.is_admin() {
-            return Err(ApiError::permission_denied(&format!(
-                "Principal {} must be an admin to call this endpoint",
-                calling_principal.to_text()
-            )));
+            .get_user_id_by_principal(calling_principal)
+        {
+            self.assert_user_active(calling_principal, &id)?;
         }
 
         Ok(())
     }
 
-    fn assert_principal_is_reviewer(&self, calling_principal: &Principal) -> Result<(), ApiError> {
-        let (_id, profile) = self
+    fn assert_permission(
+        &self,
+        calling_principal: &Principal,
+        permission: Permission,
+    ) -> Result<(), ApiError> {
+        let (id, profile) = self
             .user_profile_repository
             .get_user_profile_by_principal(calling_principal)
             .ok_or_else(|| {
@@ -65,11 +71,18 @@ impl<T: UserProfileRepository> AccessControlService for AccessControlServiceImpl
                     calling_principal.to_text()
                 ))
             })?;
-
-        if !profile.is_reviewer() {
+        self.assert_user_active(calling_principal, &id)?;
+
+        let has_permission = profile.config.default_permissions().contains(&permission)
+            || self
+                .role_repository
+                .get_user_roles_by_user_id(&id)
+                .is_some_and(|user_roles| user_roles.has_permission(permission));
+        if !has_permission {
             return Err(ApiError::permission_denied(&format!(
-                "Principal {} must be a reviewer to call this endpoint",
-                calling_principal.to_text()
+                "Principal {} must have the {} permission to call this endpoint",
+                calling_principal.to_text(),
+                permission
             )));
         }
 
@@ -77,10 +90,32 @@ impl<T: UserProfileRepository> AccessControlService for AccessControlServiceImpl
     }
 }
 
-impl<T: UserProfileRepository> AccessControlServiceImpl<T> {
-    fn new(user_profile_repository: T) -> Self {
+impl<T: UserProfileRepository, R: RoleRepository> AccessControlServiceImpl<T, R> {
+    fn new(user_profile_repository: T, role_repository: R) -> Self {
         Self {
             user_profile_repository,
+            role_repository,
+        }
+    }
+
+    fn assert_user_active(
+        &self,
+        calling_principal: &Principal,
+        user_id: &UserId,
+    ) -> Result<(), ApiError> {
+        match self
+            .user_profile_repository
+            .get_user_status_by_user_id(user_id)
+        {
+            None => Ok(()),
+            Some(UserStatus::Suspended { .. }) => Err(ApiError::permission_denied(&format!(
+                "Principal {} is suspended",
+                calling_principal.to_text()
+            ))),
+            Some(UserStatus::Deleted { .. }) => Err(ApiError::permission_denied(&format!(
+                "Principal {} belongs to a deleted user profile",
+                calling_principal.to_text()
+            ))),
         }
     }
 }
@@ -88,115 +123,102 @@ impl<T: UserProfileRepository> AccessControlServiceImpl<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{fixtures, repositories::MockUserProfileRepository};
+    use crate::{
+        fixtures,
+        repositories::{MockRoleRepository, MockUserProfileRepository, UserProfile, UserRoles},
+    };
     use mockall::predicate::*;
     use rstest::*;
 
     #[rstest]
-    fn assert_principal_not_anonymous() {
+    #[case::no_profile(None)]
+    #[case::active_profile(Some(fixtures::user_id()))]
+    fn assert_principal_not_anonymous(#[case] id: Option<UserId>) {
         let calling_principal = fixtures::principal_a();
 
-        let repository_mock = MockUserProfileRepository::new();
-        let service = AccessControlServiceImpl::new(repository_mock);
-
-        service
-            .assert_principal_not_anonymous(&calling_principal)
-            .unwrap();
-    }
-
-    #[rstest]
-    fn assert_principal_not_anonymous_anonymous_principal() {
-        let calling_principal = Principal::anonymous();
-
-        let repository_mock = MockUserProfileRepository::new();
-        let service = AccessControlServiceImpl::new(repository_mock);
-
-        let result = service
-            .assert_principal_not_anonymous(&calling_principal)
-            .unwrap_err();
-
-        assert_eq!(result, ApiError::unauthenticated());
-    }
-
-    #[rstest]
-    fn assert_principal_is_admin() {
-        let calling_principal = fixtures::principal_a();
-        let id = fixtures::user_id();
-        let profile = fixtures::admin_user_profile();
-
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
-            .expect_get_user_profile_by_principal()
+            .expect_get_user_id_by_principal()
             .once()
             .with(eq(calling_principal))
-            .return_const(Some((id, profile)));
+            .return_const(id);
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .times(usize::from(id.is_some()))
+            .return_const(None);
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(repository_mock, MockRoleRepository::new());
 
         service
-            .assert_principal_is_admin(&calling_principal)
+            .assert_principal_not_anonymous(&calling_principal)
             .unwrap();
     }
 
     #[rstest]
-    fn assert_principal_is_admin_no_profile() {
+    #[case::suspended(fixtures::suspended_user_status(), "is suspended")]
+    #[case::deleted(fixtures::deleted_user_status(), "belongs to a deleted user profile")]
+    fn assert_principal_not_anonymous_inactive_profile(
+        #[case] user_status: UserStatus,
+        #[case] expected_message: &str,
+    ) {
         let calling_principal = fixtures::principal_a();
+        let id = fixtures::user_id();
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
-            .expect_get_user_profile_by_principal()
+            .expect_get_user_id_by_principal()
             .once()
             .with(eq(calling_principal))
-            .return_const(None);
+            .return_const(Some(id));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(user_status));
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(repository_mock, MockRoleRepository::new());
 
         let result = service
-            .assert_principal_is_admin(&calling_principal)
+            .assert_principal_not_anonymous(&calling_principal)
             .unwrap_err();
 
         assert_eq!(
             result,
-            ApiError::not_found(&format!(
-                "Principal {} must have a profile to call this endpoint",
-                &calling_principal.to_text()
+            ApiError::permission_denied(&format!(
+                "Principal {} {}",
+                calling_principal.to_text(),
+                expected_message
             ))
         );
     }
 
     #[rstest]
-    fn assert_principal_is_admin_anonymous_user() {
-        let calling_principal = fixtures::principal_a();
-        let id = fixtures::user_id();
-        let profile = fixtures::anonymous_user_profile();
-
-        let mut repository_mock = MockUserProfileRepository::new();
-        repository_mock
-            .expect_get_user_profile_by_principal()
-            .once()
-            .with(eq(calling_principal))
-            .return_const(Some((id, profile)));
+    fn assert_principal_not_anonymous_anonymous_principal() {
+        let calling_principal = Principal::anonymous();
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(
+            MockUserProfileRepository::new(),
+            MockRoleRepository::new(),
+        );
 
         let result = service
-            .assert_principal_is_admin(&calling_principal)
+            .assert_principal_not_anonymous(&calling_principal)
             .unwrap_err();
 
-        assert_eq!(
-            result,
-            ApiError::permission_denied(&format!(
-                "Principal {} must be an admin to call this endpoint",
-                &calling_principal.to_text()
-            ))
-        );
+        assert_eq!(result, ApiError::unauthenticated());
     }
 
     #[rstest]
-    fn assert_principal_is_admin_reviewer() {
+    #[case::admin(fixtures::admin_user_profile(), Permission::ManageRewards)]
+    #[case::admin_comment(fixtures::admin_user_profile(), Permission::Comment)]
+    #[case::reviewer(fixtures::reviewer_user_profile(), Permission::ReviewProposals)]
+    #[case::reviewer_comment(fixtures::reviewer_user_profile(), Permission::Comment)]
+    fn assert_permission_default_permissions(
+        #[case] profile: UserProfile,
+        #[case] permission: Permission,
+    ) {
         let calling_principal = fixtures::principal_a();
         let id = fixtures::user_id();
-        let profile = fixtures::reviewer_user_profile();
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
@@ -204,44 +226,57 @@ mod tests {
             .once()
             .with(eq(calling_principal))
             .return_const(Some((id, profile)));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(None);
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .never();
 
-        let result = service
-            .assert_principal_is_admin(&calling_principal)
-            .unwrap_err();
+        let service = AccessControlServiceImpl::new(repository_mock, role_repository_mock);
 
-        assert_eq!(
-            result,
-            ApiError::permission_denied(&format!(
-                "Principal {} must be an admin to call this endpoint",
-                &calling_principal.to_text()
-            ))
-        );
+        service
+            .assert_permission(&calling_principal, permission)
+            .unwrap();
     }
 
     #[rstest]
-    fn assert_principal_is_reviewer() {
+    fn assert_permission_assigned_role() {
         let calling_principal = fixtures::principal_a();
         let id = fixtures::user_id();
-        let profile = fixtures::reviewer_user_profile();
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
             .expect_get_user_profile_by_principal()
             .once()
             .with(eq(calling_principal))
-            .return_const(Some((id, profile)));
+            .return_const(Some((id, fixtures::reviewer_user_profile())));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(None);
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::user_roles()));
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(repository_mock, role_repository_mock);
 
         service
-            .assert_principal_is_reviewer(&calling_principal)
+            .assert_permission(&calling_principal, Permission::ManageRewards)
             .unwrap();
     }
 
     #[rstest]
-    fn assert_principal_is_reviewer_no_profile() {
+    fn assert_permission_no_profile() {
         let calling_principal = fixtures::principal_a();
 
         let mut repository_mock = MockUserProfileRepository::new();
@@ -251,10 +286,10 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(None);
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(repository_mock, MockRoleRepository::new());
 
         let result = service
-            .assert_principal_is_reviewer(&calling_principal)
+            .assert_permission(&calling_principal, Permission::ManageUsers)
             .unwrap_err();
 
         assert_eq!(
@@ -267,10 +302,21 @@ mod tests {
     }
 
     #[rstest]
-    fn assert_principal_is_reviewer_anonymous_user() {
+    #[case::anonymous_user(fixtures::anonymous_user_profile(), None, Permission::Comment)]
+    #[case::reviewer(fixtures::reviewer_user_profile(), None, Permission::ManageUsers)]
+    #[case::admin(fixtures::admin_user_profile(), None, Permission::ReviewProposals)]
+    #[case::other_roles(
+        fixtures::reviewer_user_profile(),
+        Some(fixtures::user_roles()),
+        Permission::ManageRoles
+    )]
+    fn assert_permission_denied(
+        #[case] profile: UserProfile,
+        #[case] user_roles: Option<UserRoles>,
+        #[case] permission: Permission,
+    ) {
         let calling_principal = fixtures::principal_a();
         let id = fixtures::user_id();
-        let profile = fixtures::anonymous_user_profile();
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
@@ -278,45 +324,67 @@ mod tests {
             .once()
             .with(eq(calling_principal))
             .return_const(Some((id, profile)));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(None);
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(user_roles);
+
+        let service = AccessControlServiceImpl::new(repository_mock, role_repository_mock);
 
         let result = service
-            .assert_principal_is_reviewer(&calling_principal)
+            .assert_permission(&calling_principal, permission)
             .unwrap_err();
 
         assert_eq!(
             result,
             ApiError::permission_denied(&format!(
-                "Principal {} must be a reviewer to call this endpoint",
-                &calling_principal.to_text()
+                "Principal {} must have the {} permission to call this endpoint",
+                &calling_principal.to_text(),
+                permission
             ))
         );
     }
 
     #[rstest]
-    fn assert_principal_is_reviewer_admin() {
+    fn assert_permission_suspended_admin() {
         let calling_principal = fixtures::principal_a();
         let id = fixtures::user_id();
-        let profile = fixtures::admin_user_profile();
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
             .expect_get_user_profile_by_principal()
             .once()
             .with(eq(calling_principal))
-            .return_const(Some((id, profile)));
+            .return_const(Some((id, fixtures::admin_user_profile())));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::suspended_user_status()));
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .never();
 
-        let service = AccessControlServiceImpl::new(repository_mock);
+        let service = AccessControlServiceImpl::new(repository_mock, role_repository_mock);
 
         let result = service
-            .assert_principal_is_reviewer(&calling_principal)
+            .assert_permission(&calling_principal, Permission::ManageUsers)
             .unwrap_err();
 
         assert_eq!(
             result,
             ApiError::permission_denied(&format!(
-                "Principal {} must be a reviewer to call this endpoint",
+                "Principal {} is suspended",
                 &calling_principal.to_text()
             ))
         );
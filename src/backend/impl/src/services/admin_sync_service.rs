@@ -0,0 +1,814 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    repositories::{
+        audit_snapshot, AdminSyncRepository, AdminSyncRepositoryImpl, AuditAction,
+        AuditLogRepositoryImpl, DateTime, UserConfig, UserId, UserProfile, UserProfileRepository,
+        UserProfileRepositoryImpl, DEFAULT_ADMIN_BIO,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::{get_canister_id, get_date_time},
+};
+use backend_api::{
+    ApiError, GetAdminSyncConfigResponse, SyncAdminsResponse, UpdateAdminSyncConfigRequest,
+};
+use candid::Principal;
+use external_canisters::management::{ControllerReader, ManagementCanisterService};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AdminSyncService {
+    fn get_admin_sync_config(&self) -> GetAdminSyncConfigResponse;
+
+    fn update_admin_sync_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateAdminSyncConfigRequest,
+    ) -> Result<(), ApiError>;
+
+    /// Tracks the admins that exist when the canister is installed or upgraded as controller admins,
+    /// so that they are demoted once they are no longer controllers, even if they were removed from
+    /// the controllers before the next sync. These are the admin of `calling_principal`,
+    /// which is a controller since it installed or upgraded the canister, and the admins that still
+    /// have the default profile created for controllers.
+    fn seed_controller_admins(&self, calling_principal: Principal) -> Result<(), ApiError>;
+
+    /// Reconciles the admin profiles with the current controllers of the canister,
+    /// according to the [AdminSyncConfig](crate::repositories::AdminSyncConfig).
+    /// Profile changes are recorded in the history and in the audit log
+    /// on behalf of `calling_principal`.
+    ///
+    /// The controllers are read with `canister_status`, which only succeeds
+    /// if the canister is one of its own controllers.
+    async fn sync_admins(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<SyncAdminsResponse, ApiError>;
+}
+
+pub struct AdminSyncServiceImpl<
+    AS: AdminSyncRepository,
+    U: UserProfileRepository,
+    C: ControllerReader,
+    AL: AuditLogService,
+> {
+    admin_sync_repository: AS,
+    user_profile_repository: U,
+    controller_reader: C,
+    audit_log_service: AL,
+}
+
+impl Default
+    for AdminSyncServiceImpl<
+        AdminSyncRepositoryImpl,
+        UserProfileRepositoryImpl,
+        ManagementCanisterService,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            AdminSyncRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            ManagementCanisterService,
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        AS: AdminSyncRepository,
+        U: UserProfileRepository,
+        C: ControllerReader,
+        AL: AuditLogService,
+    > AdminSyncService for AdminSyncServiceImpl<AS, U, C, AL>
+{
+    fn get_admin_sync_config(&self) -> GetAdminSyncConfigResponse {
+        self.admin_sync_repository.get_admin_sync_config().into()
+    }
+
+    fn update_admin_sync_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateAdminSyncConfigRequest,
+    ) -> Result<(), ApiError> {
+        let mut config = self.admin_sync_repository.get_admin_sync_config();
+        let before = config.clone();
+
+        if let Some(promote_controllers) = request.promote_controllers {
+            config.promote_controllers = promote_controllers;
+        }
+        if let Some(demote_removed_controllers) = request.demote_removed_controllers {
+            config.demote_removed_controllers = demote_removed_controllers;
+        }
+
+        self.admin_sync_repository
+            .set_admin_sync_config(config.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::UpdateAdminSyncConfig,
+            vec![],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&config)),
+        )
+    }
+
+    fn seed_controller_admins(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        let controller_admin_ids = self
+            .admin_sync_repository
+            .get_controller_admins()
+            .into_iter()
+            .map(|(user_id, _)| user_id)
+            .collect::<BTreeSet<_>>();
+        let calling_user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal);
+        let now = DateTime::new(get_date_time()?)?;
+
+        for (user_id, user_profile) in self.user_profile_repository.get_all_user_profiles() {
+            let is_default_admin = matches!(
+                &user_profile.config,
+                UserConfig::Admin { bio } if bio == DEFAULT_ADMIN_BIO
+            );
+            let is_calling_admin =
+                user_profile.is_admin() && calling_user_id.is_some_and(|id| id == user_id);
+
+            if (is_default_admin || is_calling_admin) && !controller_admin_ids.contains(&user_id) {
+                self.admin_sync_repository
+                    .add_controller_admin(user_id, now);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn sync_admins(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<SyncAdminsResponse, ApiError> {
+        let canister_id = get_canister_id();
+        let controllers = self
+            .controller_reader
+            .get_controllers(canister_id)
+            .await
+            .map_err(|err| {
+                ApiError::internal(&format!("Failed to get canister controllers: {:?}", err))
+            })?
+            .into_iter()
+            .filter(|controller| {
+                *controller != canister_id && *controller != Principal::anonymous()
+            })
+            .collect::<Vec<_>>();
+
+        let config = self.admin_sync_repository.get_admin_sync_config();
+        let controller_admin_ids = self
+            .admin_sync_repository
+            .get_controller_admins()
+            .into_iter()
+            .map(|(user_id, _)| user_id)
+            .collect::<BTreeSet<_>>();
+        let now = DateTime::new(get_date_time()?)?;
+
+        let mut controller_user_ids = BTreeSet::new();
+        let mut promoted_user_ids = vec![];
+        let mut demoted_user_ids = vec![];
+
+        for controller in controllers {
+            let Some((user_id, user_profile)) = self
+                .user_profile_repository
+                .get_user_profile_by_principal(&controller)
+            else {
+                if config.promote_controllers {
+                    let admin_profile = UserProfile::new_admin();
+                    let user_id = self
+                        .user_profile_repository
+                        .create_user_profile(controller, admin_profile.clone())?;
+                    self.admin_sync_repository
+                        .add_controller_admin(user_id, now);
+                    self.audit_log_service.record_action(
+                        calling_principal,
+                        AuditAction::SyncAdmins,
+                        vec![user_id.to_string()],
+                        None,
+                        Some(audit_snapshot(&admin_profile)),
+                    )?;
+
+                    controller_user_ids.insert(user_id);
+                    promoted_user_ids.push(user_id);
+                }
+                continue;
+            };
+
+            controller_user_ids.insert(user_id);
+
+            if user_profile.is_admin() {
+                // admins created at install time are adopted, so that they are
+                // demoted once they are removed from the controllers
+                if !controller_admin_ids.contains(&user_id) {
+                    self.admin_sync_repository
+                        .add_controller_admin(user_id, now);
+                }
+                continue;
+            }
+
+            let is_restricted = self
+                .user_profile_repository
+                .get_user_status_by_user_id(&user_id)
+                .is_some();
+            if !config.promote_controllers || is_restricted {
+                continue;
+            }
+
+            let bio = match &user_profile.config {
+                UserConfig::Reviewer { bio, .. } => bio.clone(),
+                _ => DEFAULT_ADMIN_BIO.to_string(),
+            };
+            let admin_profile = UserProfile {
+                config: UserConfig::Admin { bio },
+                ..user_profile.clone()
+            };
+            self.user_profile_repository.update_user_profile(
+                calling_principal,
+                user_id,
+                admin_profile.clone(),
+            )?;
+            self.admin_sync_repository
+                .add_controller_admin(user_id, now);
+            self.audit_log_service.record_action(
+                calling_principal,
+                AuditAction::SyncAdmins,
+                vec![user_id.to_string()],
+                Some(audit_snapshot(&user_profile)),
+                Some(audit_snapshot(&admin_profile)),
+            )?;
+
+            promoted_user_ids.push(user_id);
+        }
+
+        // never demote every admin because of a canister without user controllers
+        if config.demote_removed_controllers && !controller_user_ids.is_empty() {
+            for user_id in controller_admin_ids.difference(&controller_user_ids) {
+                if let Some(user_profile) = self
+                    .user_profile_repository
+                    .get_user_profile_by_user_id(user_id)
+                    .filter(|user_profile| user_profile.is_admin())
+                {
+                    let anonymous_profile = UserProfile {
+                        config: UserConfig::Anonymous,
+                        ..user_profile.clone()
+                    };
+                    self.user_profile_repository.update_user_profile(
+                        calling_principal,
+                        *user_id,
+                        anonymous_profile.clone(),
+                    )?;
+                    self.audit_log_service.record_action(
+                        calling_principal,
+                        AuditAction::SyncAdmins,
+                        vec![user_id.to_string()],
+                        Some(audit_snapshot(&user_profile)),
+                        Some(audit_snapshot(&anonymous_profile)),
+                    )?;
+
+                    demoted_user_ids.push(*user_id);
+                }
+
+                self.admin_sync_repository.remove_controller_admin(user_id);
+            }
+        }
+
+        Ok(SyncAdminsResponse {
+            promoted_user_ids: promoted_user_ids
+                .into_iter()
+                .map(|user_id| user_id.to_string())
+                .collect(),
+            demoted_user_ids: demoted_user_ids
+                .into_iter()
+                .map(|user_id| user_id.to_string())
+                .collect(),
+        })
+    }
+}
+
+impl<
+        AS: AdminSyncRepository,
+        U: UserProfileRepository,
+        C: ControllerReader,
+        AL: AuditLogService,
+    > AdminSyncServiceImpl<AS, U, C, AL>
+{
+    fn new(
+        admin_sync_repository: AS,
+        user_profile_repository: U,
+        controller_reader: C,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            admin_sync_repository,
+            user_profile_repository,
+            controller_reader,
+            audit_log_service,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{
+            AdminSyncConfig, MockAdminSyncRepository, MockUserProfileRepository, UserStatus,
+        },
+        services::MockAuditLogService,
+    };
+    use external_canisters::nns::CanisterResult;
+    use ic_cdk::api::call::RejectionCode;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    struct FakeControllerReader(CanisterResult<Vec<Principal>>);
+
+    impl ControllerReader for FakeControllerReader {
+        async fn get_controllers(&self, _canister_id: Principal) -> CanisterResult<Vec<Principal>> {
+            self.0.clone()
+        }
+    }
+
+    fn admin_sync_repository_mock(
+        config: AdminSyncConfig,
+        controller_admin_ids: Vec<UserId>,
+    ) -> MockAdminSyncRepository {
+        let mut repository_mock = MockAdminSyncRepository::new();
+        repository_mock
+            .expect_get_admin_sync_config()
+            .once()
+            .return_const(config);
+        repository_mock
+            .expect_get_controller_admins()
+            .once()
+            .return_const(
+                controller_admin_ids
+                    .into_iter()
+                    .map(|user_id| (user_id, fixtures::date_time_a()))
+                    .collect::<Vec<_>>(),
+            );
+
+        repository_mock
+    }
+
+    #[rstest]
+    fn get_admin_sync_config() {
+        let mut repository_mock = MockAdminSyncRepository::new();
+        repository_mock
+            .expect_get_admin_sync_config()
+            .once()
+            .return_const(AdminSyncConfig::default());
+
+        let service = AdminSyncServiceImpl::new(
+            repository_mock,
+            MockUserProfileRepository::new(),
+            FakeControllerReader(Ok(vec![])),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.get_admin_sync_config();
+
+        assert_eq!(
+            result,
+            GetAdminSyncConfigResponse {
+                promote_controllers: true,
+                demote_removed_controllers: false,
+            }
+        );
+    }
+
+    #[rstest]
+    fn update_admin_sync_config() {
+        let calling_principal = fixtures::principal_a();
+        let config = AdminSyncConfig {
+            promote_controllers: true,
+            demote_removed_controllers: true,
+        };
+
+        let mut repository_mock = MockAdminSyncRepository::new();
+        repository_mock
+            .expect_get_admin_sync_config()
+            .once()
+            .return_const(AdminSyncConfig::default());
+        repository_mock
+            .expect_set_admin_sync_config()
+            .once()
+            .with(eq(config.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::UpdateAdminSyncConfig),
+                eq(Vec::<String>::new()),
+                eq(Some(audit_snapshot(&AdminSyncConfig::default()))),
+                eq(Some(audit_snapshot(&config))),
+            )
+            .return_const(Ok(()));
+
+        let service = AdminSyncServiceImpl::new(
+            repository_mock,
+            MockUserProfileRepository::new(),
+            FakeControllerReader(Ok(vec![])),
+            audit_log_service_mock,
+        );
+
+        service
+            .update_admin_sync_config(
+                calling_principal,
+                UpdateAdminSyncConfigRequest {
+                    promote_controllers: None,
+                    demote_removed_controllers: Some(true),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    async fn sync_admins_promotes_controllers() {
+        let calling_principal = fixtures::principal_c();
+        let new_controller = fixtures::principal_a();
+        let reviewer_controller = fixtures::principal_b();
+        let reviewer_profile = fixtures::reviewer_user_profile();
+        let promoted_profile = UserProfile {
+            config: UserConfig::Admin {
+                bio: "Active in the ICP community and on the developer forum.".to_string(),
+            },
+            ..reviewer_profile.clone()
+        };
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(new_controller))
+            .return_const(None);
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(reviewer_controller))
+            .return_const(Some((fixtures::uuid_b(), reviewer_profile.clone())));
+        up_repository_mock
+            .expect_create_user_profile()
+            .once()
+            .with(eq(new_controller), eq(UserProfile::new_admin()))
+            .return_const(Ok(fixtures::uuid_a()));
+        up_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(None);
+        up_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(fixtures::uuid_b()),
+                eq(promoted_profile.clone()),
+            )
+            .return_const(Ok(()));
+
+        let mut as_repository_mock = admin_sync_repository_mock(AdminSyncConfig::default(), vec![]);
+        as_repository_mock
+            .expect_add_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_a()), always())
+            .return_const(());
+        as_repository_mock
+            .expect_add_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_b()), always())
+            .return_const(());
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SyncAdmins),
+                eq(vec![fixtures::uuid_a().to_string()]),
+                eq(None::<String>),
+                eq(Some(audit_snapshot(&UserProfile::new_admin()))),
+            )
+            .return_const(Ok(()));
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SyncAdmins),
+                eq(vec![fixtures::uuid_b().to_string()]),
+                eq(Some(audit_snapshot(&reviewer_profile))),
+                eq(Some(audit_snapshot(&promoted_profile))),
+            )
+            .return_const(Ok(()));
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![
+                get_canister_id(),
+                new_controller,
+                reviewer_controller,
+            ])),
+            audit_log_service_mock,
+        );
+
+        let result = service.sync_admins(calling_principal).await.unwrap();
+
+        assert_eq!(
+            result,
+            SyncAdminsResponse {
+                promoted_user_ids: vec![
+                    fixtures::uuid_a().to_string(),
+                    fixtures::uuid_b().to_string()
+                ],
+                demoted_user_ids: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    fn seed_controller_admins() {
+        let calling_principal = fixtures::principal_a();
+        let manual_admin_profile = UserProfile {
+            config: UserConfig::Admin {
+                bio: "Promoted manually".to_string(),
+            },
+            ..fixtures::admin_user_profile()
+        };
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+        up_repository_mock
+            .expect_get_all_user_profiles()
+            .once()
+            .return_const(vec![
+                (fixtures::uuid_a(), manual_admin_profile.clone()),
+                (fixtures::uuid_b(), UserProfile::new_admin()),
+                (fixtures::uuid_c(), UserProfile::new_admin()),
+                (fixtures::uuid(), manual_admin_profile),
+            ]);
+
+        let mut as_repository_mock = MockAdminSyncRepository::new();
+        as_repository_mock
+            .expect_get_controller_admins()
+            .once()
+            .return_const(vec![(fixtures::uuid_c(), fixtures::date_time_a())]);
+        as_repository_mock
+            .expect_add_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_a()), always())
+            .return_const(());
+        as_repository_mock
+            .expect_add_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_b()), always())
+            .return_const(());
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![])),
+            MockAuditLogService::new(),
+        );
+
+        service.seed_controller_admins(calling_principal).unwrap();
+    }
+
+    #[rstest]
+    async fn sync_admins_adopts_existing_admins() {
+        let controller = fixtures::principal_a();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(controller))
+            .return_const(Some((fixtures::uuid_a(), fixtures::admin_user_profile())));
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut as_repository_mock = admin_sync_repository_mock(AdminSyncConfig::default(), vec![]);
+        as_repository_mock
+            .expect_add_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_a()), always())
+            .return_const(());
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![controller])),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.sync_admins(fixtures::principal_c()).await.unwrap();
+
+        assert_eq!(
+            result,
+            SyncAdminsResponse {
+                promoted_user_ids: vec![],
+                demoted_user_ids: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::promotion_disabled(
+        AdminSyncConfig {
+            promote_controllers: false,
+            demote_removed_controllers: false,
+        },
+        None
+    )]
+    #[case::suspended(AdminSyncConfig::default(), Some(fixtures::suspended_user_status()))]
+    async fn sync_admins_does_not_promote(
+        #[case] config: AdminSyncConfig,
+        #[case] user_status: Option<UserStatus>,
+    ) {
+        let controller = fixtures::principal_a();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(controller))
+            .return_const(Some((
+                fixtures::uuid_a(),
+                fixtures::reviewer_user_profile(),
+            )));
+        up_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_a()))
+            .return_const(user_status);
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut as_repository_mock = admin_sync_repository_mock(config, vec![]);
+        as_repository_mock.expect_add_controller_admin().never();
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![controller])),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.sync_admins(fixtures::principal_c()).await.unwrap();
+
+        assert_eq!(
+            result,
+            SyncAdminsResponse {
+                promoted_user_ids: vec![],
+                demoted_user_ids: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    async fn sync_admins_demotes_removed_controllers() {
+        let calling_principal = fixtures::principal_c();
+        let controller = fixtures::principal_a();
+        let admin_profile = fixtures::admin_user_profile();
+        let demoted_profile = UserProfile {
+            config: UserConfig::Anonymous,
+            ..admin_profile.clone()
+        };
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(controller))
+            .return_const(Some((fixtures::uuid_a(), admin_profile.clone())));
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(Some(admin_profile.clone()));
+        up_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(fixtures::uuid_b()),
+                eq(demoted_profile.clone()),
+            )
+            .return_const(Ok(()));
+
+        let mut as_repository_mock = admin_sync_repository_mock(
+            AdminSyncConfig {
+                promote_controllers: true,
+                demote_removed_controllers: true,
+            },
+            vec![fixtures::uuid_a(), fixtures::uuid_b()],
+        );
+        as_repository_mock.expect_add_controller_admin().never();
+        as_repository_mock
+            .expect_remove_controller_admin()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(());
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SyncAdmins),
+                eq(vec![fixtures::uuid_b().to_string()]),
+                eq(Some(audit_snapshot(&admin_profile))),
+                eq(Some(audit_snapshot(&demoted_profile))),
+            )
+            .return_const(Ok(()));
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![controller])),
+            audit_log_service_mock,
+        );
+
+        let result = service.sync_admins(calling_principal).await.unwrap();
+
+        assert_eq!(
+            result,
+            SyncAdminsResponse {
+                promoted_user_ids: vec![],
+                demoted_user_ids: vec![fixtures::uuid_b().to_string()],
+            }
+        );
+    }
+
+    #[rstest]
+    async fn sync_admins_without_user_controllers() {
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut as_repository_mock = admin_sync_repository_mock(
+            AdminSyncConfig {
+                promote_controllers: true,
+                demote_removed_controllers: true,
+            },
+            vec![fixtures::uuid_a()],
+        );
+        as_repository_mock.expect_remove_controller_admin().never();
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            up_repository_mock,
+            FakeControllerReader(Ok(vec![get_canister_id()])),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.sync_admins(fixtures::principal_c()).await.unwrap();
+
+        assert_eq!(
+            result,
+            SyncAdminsResponse {
+                promoted_user_ids: vec![],
+                demoted_user_ids: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    async fn sync_admins_controller_reader_error() {
+        let error = (RejectionCode::CanisterError, "Not a controller".to_string());
+
+        let mut as_repository_mock = MockAdminSyncRepository::new();
+        as_repository_mock.expect_get_admin_sync_config().never();
+
+        let service = AdminSyncServiceImpl::new(
+            as_repository_mock,
+            MockUserProfileRepository::new(),
+            FakeControllerReader(Err(error.clone())),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .sync_admins(fixtures::principal_c())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::internal(&format!("Failed to get canister controllers: {:?}", error))
+        );
+    }
+}
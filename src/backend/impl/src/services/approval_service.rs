@@ -0,0 +1,782 @@
+use crate::{
+    mappings::map_approval_request,
+    repositories::{
+        audit_snapshot, ApprovalRepository, ApprovalRepositoryImpl, ApprovalRequest,
+        ApprovalRequestId, ApprovalRequestStatus, AuditAction, AuditLogRepositoryImpl, DateTime,
+        SensitiveAction, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ApproveApprovalRequestRequest, ApproveApprovalRequestResponse,
+    GetApprovalConfigResponse, ListApprovalRequestsResponse, UpdateApprovalConfigRequest,
+};
+use candid::Principal;
+
+const MAX_REQUIRED_APPROVALS: u8 = 10;
+const MAX_APPROVAL_TIMEOUT_SECONDS: u64 = 30 * 86_400;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ApprovalService {
+    fn get_approval_config(&self) -> GetApprovalConfigResponse;
+
+    fn update_approval_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateApprovalConfigRequest,
+    ) -> Result<(), ApiError>;
+
+    /// Lists all approval requests, the most recent first.
+    fn list_approval_requests(&self) -> Result<ListApprovalRequestsResponse, ApiError>;
+
+    /// Adds the caller's approval to an active request.
+    /// The caller's permission to approve requests is checked by the controller.
+    fn approve_approval_request(
+        &self,
+        calling_principal: Principal,
+        request: ApproveApprovalRequestRequest,
+    ) -> Result<ApproveApprovalRequestResponse, ApiError>;
+
+    /// Lets a call to a sensitive endpoint through if the action is not configured to need approvals,
+    /// or if an approved request with the same arguments exists, which is then marked as executed.
+    /// Otherwise, the call fails and an [ApprovalRequest] is created if there is none yet.
+    fn assert_approved(
+        &self,
+        calling_principal: Principal,
+        action: SensitiveAction,
+        arguments: Vec<u8>,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ApprovalServiceImpl<A: ApprovalRepository, U: UserProfileRepository, AL: AuditLogService>
+{
+    approval_repository: A,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for ApprovalServiceImpl<
+        ApprovalRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            ApprovalRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<A: ApprovalRepository, U: UserProfileRepository, AL: AuditLogService> ApprovalService
+    for ApprovalServiceImpl<A, U, AL>
+{
+    fn get_approval_config(&self) -> GetApprovalConfigResponse {
+        self.approval_repository.get_approval_config().into()
+    }
+
+    fn update_approval_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateApprovalConfigRequest,
+    ) -> Result<(), ApiError> {
+        let before = self.approval_repository.get_approval_config();
+        let mut config = before.clone();
+
+        if let Some(required_approvals) = request.required_approvals {
+            if required_approvals == 0 || required_approvals > MAX_REQUIRED_APPROVALS {
+                return Err(ApiError::invalid_argument(&format!(
+                    "Required approvals must be between 1 and {}",
+                    MAX_REQUIRED_APPROVALS
+                )));
+            }
+
+            config.required_approvals = required_approvals;
+        }
+        if let Some(timeout_seconds) = request.timeout_seconds {
+            if timeout_seconds == 0 || timeout_seconds > MAX_APPROVAL_TIMEOUT_SECONDS {
+                return Err(ApiError::invalid_argument(&format!(
+                    "Approval timeout must be between 1 and {} seconds",
+                    MAX_APPROVAL_TIMEOUT_SECONDS
+                )));
+            }
+
+            config.timeout_seconds = timeout_seconds;
+        }
+        if let Some(sensitive_actions) = request.sensitive_actions {
+            let mut sensitive_actions: Vec<SensitiveAction> =
+                sensitive_actions.into_iter().map(Into::into).collect();
+            sensitive_actions.sort();
+            sensitive_actions.dedup();
+
+            config.sensitive_actions = sensitive_actions;
+        }
+
+        self.approval_repository
+            .set_approval_config(config.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::UpdateApprovalConfig,
+            vec![],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&config)),
+        )
+    }
+
+    fn list_approval_requests(&self) -> Result<ListApprovalRequestsResponse, ApiError> {
+        let current_time = DateTime::new(get_date_time()?)?;
+        let mut approval_requests = self.approval_repository.get_approval_requests();
+        approval_requests.sort_by(|(_, a), (_, b)| b.requested_at.cmp(&a.requested_at));
+
+        Ok(ListApprovalRequestsResponse {
+            approval_requests: approval_requests
+                .into_iter()
+                .map(|(id, approval_request)| {
+                    map_approval_request(id, approval_request, &current_time)
+                })
+                .collect(),
+        })
+    }
+
+    fn approve_approval_request(
+        &self,
+        calling_principal: Principal,
+        request: ApproveApprovalRequestRequest,
+    ) -> Result<ApproveApprovalRequestResponse, ApiError> {
+        let approval_request_id = ApprovalRequestId::try_from(request.id.as_str())?;
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        let mut approval_request = self
+            .approval_repository
+            .get_approval_request_by_id(&approval_request_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Approval request with id {} not found",
+                    approval_request_id
+                ))
+            })?;
+
+        let current_time = DateTime::new(get_date_time()?)?;
+        if !approval_request.is_active(&current_time) {
+            return Err(ApiError::conflict(&format!(
+                "Approval request with id {} is no longer active",
+                approval_request_id
+            )));
+        }
+        if approval_request.approved_by.contains(&user_id) {
+            return Err(ApiError::conflict(&format!(
+                "User with id {} already approved request with id {}",
+                user_id, approval_request_id
+            )));
+        }
+
+        let before = approval_request.clone();
+        approval_request.approved_by.push(user_id);
+        if approval_request.approved_by.len() >= approval_request.required_approvals as usize {
+            approval_request.status = ApprovalRequestStatus::Approved;
+        }
+
+        self.approval_repository
+            .update_approval_request(approval_request_id, approval_request.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ApproveApprovalRequest,
+            vec![approval_request_id.to_string()],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&approval_request)),
+        )?;
+
+        Ok(map_approval_request(
+            approval_request_id,
+            approval_request,
+            &current_time,
+        ))
+    }
+
+    fn assert_approved(
+        &self,
+        calling_principal: Principal,
+        action: SensitiveAction,
+        arguments: Vec<u8>,
+    ) -> Result<(), ApiError> {
+        let config = self.approval_repository.get_approval_config();
+        if config.required_approvals <= 1 || !config.sensitive_actions.contains(&action) {
+            return Ok(());
+        }
+
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        let existing_request = self
+            .approval_repository
+            .get_approval_requests()
+            .into_iter()
+            .find(|(_, approval_request)| {
+                approval_request.is_active(&current_time)
+                    && approval_request.matches(action, &arguments)
+            });
+
+        match existing_request {
+            Some((approval_request_id, mut approval_request))
+                if approval_request.status == ApprovalRequestStatus::Approved =>
+            {
+                approval_request.status = ApprovalRequestStatus::Executed {
+                    executed_by: user_id,
+                    executed_at: current_time,
+                };
+
+                self.approval_repository
+                    .update_approval_request(approval_request_id, approval_request)
+            }
+            Some((approval_request_id, approval_request)) => Err(ApiError::conflict(&format!(
+                "Action {} is waiting for {} more approvals in request with id {}",
+                action,
+                (approval_request.required_approvals as usize)
+                    .saturating_sub(approval_request.approved_by.len()),
+                approval_request_id
+            ))),
+            None => {
+                let approval_request_id =
+                    self.approval_repository
+                        .create_approval_request(ApprovalRequest {
+                            action,
+                            arguments,
+                            requested_by: user_id,
+                            requested_at: current_time,
+                            expires_at: current_time
+                                .add(chrono::Duration::seconds(config.timeout_seconds as i64)),
+                            required_approvals: config.required_approvals,
+                            approved_by: vec![user_id],
+                            status: ApprovalRequestStatus::Pending,
+                        })?;
+
+                Err(ApiError::conflict(&format!(
+                    "Action {} requires {} approvals, created approval request with id {}",
+                    action, config.required_approvals, approval_request_id
+                )))
+            }
+        }
+    }
+}
+
+impl<A: ApprovalRepository, U: UserProfileRepository, AL: AuditLogService>
+    ApprovalServiceImpl<A, U, AL>
+{
+    fn new(approval_repository: A, user_profile_repository: U, audit_log_service: AL) -> Self {
+        Self {
+            approval_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{ApprovalConfig, MockApprovalRepository, MockUserProfileRepository},
+        services::MockAuditLogService,
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn approval_config(required_approvals: u8) -> ApprovalConfig {
+        ApprovalConfig {
+            required_approvals,
+            ..ApprovalConfig::default()
+        }
+    }
+
+    #[rstest]
+    fn update_approval_config() {
+        let calling_principal = fixtures::principal_a();
+        let config = ApprovalConfig {
+            required_approvals: 2,
+            timeout_seconds: 3_600,
+            sensitive_actions: vec![
+                SensitiveAction::SetUserRoles,
+                SensitiveAction::ExecuteRewardPayouts,
+            ],
+        };
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(ApprovalConfig::default());
+        repository_mock
+            .expect_set_approval_config()
+            .once()
+            .with(eq(config.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::UpdateApprovalConfig),
+                eq(Vec::<String>::new()),
+                eq(Some(audit_snapshot(&ApprovalConfig::default()))),
+                eq(Some(audit_snapshot(&config))),
+            )
+            .return_const(Ok(()));
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            MockUserProfileRepository::new(),
+            audit_log_service_mock,
+        );
+
+        service
+            .update_approval_config(
+                calling_principal,
+                UpdateApprovalConfigRequest {
+                    required_approvals: Some(2),
+                    timeout_seconds: Some(3_600),
+                    sensitive_actions: Some(vec![
+                        backend_api::SensitiveAction::ExecuteRewardPayouts,
+                        backend_api::SensitiveAction::SetUserRoles,
+                        backend_api::SensitiveAction::ExecuteRewardPayouts,
+                    ]),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    #[case::no_approvals(
+        UpdateApprovalConfigRequest {
+            required_approvals: Some(0),
+            timeout_seconds: None,
+            sensitive_actions: None,
+        },
+        "Required approvals must be between 1 and 10"
+    )]
+    #[case::too_many_approvals(
+        UpdateApprovalConfigRequest {
+            required_approvals: Some(11),
+            timeout_seconds: None,
+            sensitive_actions: None,
+        },
+        "Required approvals must be between 1 and 10"
+    )]
+    #[case::timeout_too_long(
+        UpdateApprovalConfigRequest {
+            required_approvals: None,
+            timeout_seconds: Some(MAX_APPROVAL_TIMEOUT_SECONDS + 1),
+            sensitive_actions: None,
+        },
+        "Approval timeout must be between 1 and 2592000 seconds"
+    )]
+    fn update_approval_config_invalid(
+        #[case] request: UpdateApprovalConfigRequest,
+        #[case] message: &str,
+    ) {
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(ApprovalConfig::default());
+        repository_mock.expect_set_approval_config().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .update_approval_config(fixtures::principal_a(), request)
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::invalid_argument(message));
+    }
+
+    #[rstest]
+    fn approve_approval_request() {
+        let calling_principal = fixtures::principal_b();
+        let approval_request_id = fixtures::uuid_c();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_b()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_request_by_id()
+            .once()
+            .with(eq(approval_request_id))
+            .return_const(Some(fixtures::approval_request_pending()));
+        repository_mock
+            .expect_update_approval_request()
+            .once()
+            .with(
+                eq(approval_request_id),
+                eq(fixtures::approval_request_approved()),
+            )
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::ApproveApprovalRequest),
+                eq(vec![approval_request_id.to_string()]),
+                eq(Some(audit_snapshot(&fixtures::approval_request_pending()))),
+                eq(Some(audit_snapshot(&fixtures::approval_request_approved()))),
+            )
+            .return_const(Ok(()));
+
+        let service =
+            ApprovalServiceImpl::new(repository_mock, up_repository_mock, audit_log_service_mock);
+
+        let result = service
+            .approve_approval_request(
+                calling_principal,
+                ApproveApprovalRequestRequest {
+                    id: approval_request_id.to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result.status, backend_api::ApprovalRequestStatus::Approved);
+        assert_eq!(
+            result.approved_by,
+            vec![
+                fixtures::uuid_a().to_string(),
+                fixtures::uuid_b().to_string()
+            ]
+        );
+    }
+
+    #[rstest]
+    fn approve_approval_request_twice() {
+        let calling_principal = fixtures::principal_a();
+        let approval_request_id = fixtures::uuid_c();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_request_by_id()
+            .once()
+            .with(eq(approval_request_id))
+            .return_const(Some(fixtures::approval_request_pending()));
+        repository_mock.expect_update_approval_request().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .approve_approval_request(
+                calling_principal,
+                ApproveApprovalRequestRequest {
+                    id: approval_request_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} already approved request with id {}",
+                fixtures::uuid_a(),
+                approval_request_id
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::expired(ApprovalRequest {
+        expires_at: fixtures::date_time_b(),
+        ..fixtures::approval_request_pending()
+    })]
+    #[case::executed(fixtures::approval_request_executed())]
+    fn approve_approval_request_inactive(#[case] approval_request: ApprovalRequest) {
+        let calling_principal = fixtures::principal_b();
+        let approval_request_id = fixtures::uuid_c();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_b()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_request_by_id()
+            .once()
+            .with(eq(approval_request_id))
+            .return_const(Some(approval_request));
+        repository_mock.expect_update_approval_request().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .approve_approval_request(
+                calling_principal,
+                ApproveApprovalRequestRequest {
+                    id: approval_request_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Approval request with id {} is no longer active",
+                approval_request_id
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::single_approval(approval_config(1), SensitiveAction::SetUserRoles)]
+    #[case::not_sensitive(
+        ApprovalConfig {
+            required_approvals: 2,
+            timeout_seconds: 3_600,
+            sensitive_actions: vec![SensitiveAction::ExecuteRewardPayouts],
+        },
+        SensitiveAction::SetUserRoles
+    )]
+    fn assert_approved_without_approval(
+        #[case] config: ApprovalConfig,
+        #[case] action: SensitiveAction,
+    ) {
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(config);
+        repository_mock.expect_get_approval_requests().never();
+        repository_mock.expect_create_approval_request().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        service
+            .assert_approved(
+                fixtures::principal_a(),
+                action,
+                fixtures::approval_request_arguments(),
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn assert_approved_creates_approval_request() {
+        let calling_principal = fixtures::principal_a();
+        let approval_request_id = fixtures::uuid_c();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(approval_config(2));
+        repository_mock
+            .expect_get_approval_requests()
+            .once()
+            .return_const(vec![(
+                fixtures::uuid_b(),
+                fixtures::approval_request_executed(),
+            )]);
+        repository_mock
+            .expect_create_approval_request()
+            .once()
+            .with(eq(ApprovalRequest {
+                requested_at: current_time,
+                expires_at: current_time.add(chrono::Duration::days(1)),
+                ..fixtures::approval_request_pending()
+            }))
+            .return_const(Ok(approval_request_id));
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .assert_approved(
+                calling_principal,
+                SensitiveAction::SetUserRoles,
+                fixtures::approval_request_arguments(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Action set_user_roles requires 2 approvals, created approval request with id {}",
+                approval_request_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn assert_approved_pending() {
+        let calling_principal = fixtures::principal_a();
+        let approval_request_id = fixtures::uuid_c();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(approval_config(2));
+        repository_mock
+            .expect_get_approval_requests()
+            .once()
+            .return_const(vec![(
+                approval_request_id,
+                ApprovalRequest {
+                    expires_at: current_time.add(chrono::Duration::days(1)),
+                    ..fixtures::approval_request_pending()
+                },
+            )]);
+        repository_mock.expect_create_approval_request().never();
+        repository_mock.expect_update_approval_request().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .assert_approved(
+                calling_principal,
+                SensitiveAction::SetUserRoles,
+                fixtures::approval_request_arguments(),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Action set_user_roles is waiting for 1 more approvals in request with id {}",
+                approval_request_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn assert_approved_executes_approved_request() {
+        let calling_principal = fixtures::principal_a();
+        let approval_request_id = fixtures::uuid_c();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+        let approval_request = ApprovalRequest {
+            expires_at: current_time.add(chrono::Duration::days(1)),
+            ..fixtures::approval_request_approved()
+        };
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+
+        let mut repository_mock = MockApprovalRepository::new();
+        repository_mock
+            .expect_get_approval_config()
+            .once()
+            .return_const(approval_config(2));
+        repository_mock
+            .expect_get_approval_requests()
+            .once()
+            .return_const(vec![(approval_request_id, approval_request.clone())]);
+        repository_mock
+            .expect_update_approval_request()
+            .once()
+            .with(
+                eq(approval_request_id),
+                eq(ApprovalRequest {
+                    status: ApprovalRequestStatus::Executed {
+                        executed_by: fixtures::uuid_a(),
+                        executed_at: current_time,
+                    },
+                    ..approval_request
+                }),
+            )
+            .return_const(Ok(()));
+        repository_mock.expect_create_approval_request().never();
+
+        let service = ApprovalServiceImpl::new(
+            repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        service
+            .assert_approved(
+                calling_principal,
+                SensitiveAction::SetUserRoles,
+                fixtures::approval_request_arguments(),
+            )
+            .unwrap();
+    }
+}
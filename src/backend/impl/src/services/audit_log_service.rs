@@ -0,0 +1,375 @@
+use crate::{
+    mappings::map_audit_log_entry,
+    repositories::{
+        AuditAction, AuditLogEntry, AuditLogFilter, AuditLogRepository, AuditLogRepositoryImpl,
+        DateTime,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ExportAuditLogRequest, ExportAuditLogResponse, ListAuditLogEntriesRequest,
+    ListAuditLogEntriesResponse,
+};
+use candid::Principal;
+
+const DEFAULT_AUDIT_LOG_ENTRIES_LIMIT: u64 = 50;
+const MAX_AUDIT_LOG_ENTRIES_LIMIT: u64 = 100;
+const DEFAULT_AUDIT_LOG_EXPORT_LIMIT: u64 = 500;
+const MAX_AUDIT_LOG_EXPORT_LIMIT: u64 = 1_000;
+const MICROS_PER_MS: u64 = 1_000;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait AuditLogService {
+    fn list_audit_log_entries(
+        &self,
+        request: ListAuditLogEntriesRequest,
+    ) -> Result<ListAuditLogEntriesResponse, ApiError>;
+
+    /// Returns a page of the entries recorded in the given time range, both bounds included.
+    fn export_audit_log(
+        &self,
+        request: ExportAuditLogRequest,
+    ) -> Result<ExportAuditLogResponse, ApiError>;
+
+    /// Appends an entry for a privileged action performed by `calling_principal`.
+    /// The snapshots are built with [audit_snapshot](crate::repositories::audit_snapshot).
+    fn record_action(
+        &self,
+        calling_principal: Principal,
+        action: AuditAction,
+        target_ids: Vec<String>,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct AuditLogServiceImpl<A: AuditLogRepository> {
+    audit_log_repository: A,
+}
+
+impl Default for AuditLogServiceImpl<AuditLogRepositoryImpl> {
+    fn default() -> Self {
+        Self::new(AuditLogRepositoryImpl::default())
+    }
+}
+
+impl<A: AuditLogRepository> AuditLogService for AuditLogServiceImpl<A> {
+    fn list_audit_log_entries(
+        &self,
+        request: ListAuditLogEntriesRequest,
+    ) -> Result<ListAuditLogEntriesResponse, ApiError> {
+        let offset = request.offset.unwrap_or(0);
+        let limit = request.limit.unwrap_or(DEFAULT_AUDIT_LOG_ENTRIES_LIMIT);
+
+        if limit > MAX_AUDIT_LOG_ENTRIES_LIMIT {
+            return Err(ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_AUDIT_LOG_ENTRIES_LIMIT
+            )));
+        }
+
+        let filter = AuditLogFilter {
+            action: request.action.map(AuditAction::from),
+            actor: request.actor,
+            target_id: request.target_id,
+        };
+        let entries = self
+            .audit_log_repository
+            .get_audit_log_entries(&filter, offset as usize, limit as usize)
+            .into_iter()
+            .map(|(id, entry)| map_audit_log_entry(id, entry))
+            .collect();
+        let total = self.audit_log_repository.count_audit_log_entries(&filter);
+
+        Ok(ListAuditLogEntriesResponse { entries, total })
+    }
+
+    fn export_audit_log(
+        &self,
+        request: ExportAuditLogRequest,
+    ) -> Result<ExportAuditLogResponse, ApiError> {
+        let offset = request.offset.unwrap_or(0);
+        let limit = request.limit.unwrap_or(DEFAULT_AUDIT_LOG_EXPORT_LIMIT);
+
+        if limit > MAX_AUDIT_LOG_EXPORT_LIMIT {
+            return Err(ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_AUDIT_LOG_EXPORT_LIMIT
+            )));
+        }
+
+        let after = request
+            .after_timestamp_ms
+            .map(date_time_from_timestamp_ms)
+            .transpose()?;
+        let before = request
+            .before_timestamp_ms
+            .map(date_time_from_timestamp_ms)
+            .transpose()?;
+
+        let entries = self
+            .audit_log_repository
+            .get_audit_log_entries_by_timestamp(after, before, offset as usize, limit as usize)?
+            .into_iter()
+            .map(|(id, entry)| map_audit_log_entry(id, entry))
+            .collect();
+
+        Ok(ExportAuditLogResponse { entries })
+    }
+
+    fn record_action(
+        &self,
+        calling_principal: Principal,
+        action: AuditAction,
+        target_ids: Vec<String>,
+        before: Option<String>,
+        after: Option<String>,
+    ) -> Result<(), ApiError> {
+        let audit_log_entry = AuditLogEntry {
+            actor: calling_principal,
+            action,
+            target_ids,
+            before,
+            after,
+            timestamp: DateTime::new(get_date_time()?)?,
+        };
+
+        self.audit_log_repository
+            .append_audit_log_entry(audit_log_entry)
+            .map(|_| ())
+    }
+}
+
+impl<A: AuditLogRepository> AuditLogServiceImpl<A> {
+    fn new(audit_log_repository: A) -> Self {
+        Self {
+            audit_log_repository,
+        }
+    }
+}
+
+fn date_time_from_timestamp_ms(timestamp_ms: u64) -> Result<DateTime, ApiError> {
+    let timestamp_micros = timestamp_ms.checked_mul(MICROS_PER_MS).ok_or_else(|| {
+        ApiError::invalid_argument(&format!("Invalid timestamp {}", timestamp_ms))
+    })?;
+
+    DateTime::from_timestamp_micros(timestamp_micros)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{AuditLogEntryId, MockAuditLogRepository},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[fixture]
+    fn list_audit_log_entries_request() -> ListAuditLogEntriesRequest {
+        ListAuditLogEntriesRequest {
+            action: None,
+            actor: None,
+            target_id: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    fn stored_audit_log_entries() -> Vec<(AuditLogEntryId, AuditLogEntry)> {
+        fixtures::audit_log_entries()
+            .into_iter()
+            .enumerate()
+            .map(|(id, entry)| (id as AuditLogEntryId, entry))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::no_filters(list_audit_log_entries_request(), AuditLogFilter::default(), 0, 50)]
+    #[case::filters(
+        ListAuditLogEntriesRequest {
+            action: Some(backend_api::AuditAction::SuspendUser),
+            actor: Some(fixtures::principal_a()),
+            target_id: Some(fixtures::uuid_b().to_string()),
+            ..list_audit_log_entries_request()
+        },
+        AuditLogFilter {
+            action: Some(AuditAction::SuspendUser),
+            actor: Some(fixtures::principal_a()),
+            target_id: Some(fixtures::uuid_b().to_string()),
+        },
+        0,
+        50
+    )]
+    #[case::pagination(
+        ListAuditLogEntriesRequest {
+            offset: Some(1),
+            limit: Some(1),
+            ..list_audit_log_entries_request()
+        },
+        AuditLogFilter::default(),
+        1,
+        1
+    )]
+    fn list_audit_log_entries(
+        #[case] request: ListAuditLogEntriesRequest,
+        #[case] filter: AuditLogFilter,
+        #[case] offset: usize,
+        #[case] limit: usize,
+    ) {
+        let mut repository_mock = MockAuditLogRepository::new();
+        repository_mock
+            .expect_get_audit_log_entries()
+            .once()
+            .with(eq(filter.clone()), eq(offset), eq(limit))
+            .return_const(stored_audit_log_entries());
+        repository_mock
+            .expect_count_audit_log_entries()
+            .once()
+            .with(eq(filter))
+            .return_const(10u64);
+
+        let service = AuditLogServiceImpl::new(repository_mock);
+
+        let result = service.list_audit_log_entries(request).unwrap();
+
+        assert_eq!(
+            result,
+            ListAuditLogEntriesResponse {
+                entries: stored_audit_log_entries()
+                    .into_iter()
+                    .map(|(id, entry)| map_audit_log_entry(id, entry))
+                    .collect(),
+                total: 10,
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_audit_log_entries_limit_too_high() {
+        let mut repository_mock = MockAuditLogRepository::new();
+        repository_mock.expect_get_audit_log_entries().never();
+
+        let service = AuditLogServiceImpl::new(repository_mock);
+
+        let result = service
+            .list_audit_log_entries(ListAuditLogEntriesRequest {
+                limit: Some(101),
+                ..list_audit_log_entries_request()
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("Limit cannot be greater than 100")
+        );
+    }
+
+    #[rstest]
+    #[case::no_range(None, None, None, None, 0, 500)]
+    #[case::range(
+        Some(fixtures::date_time_b()),
+        Some(fixtures::date_time_a()),
+        None,
+        None,
+        0,
+        500
+    )]
+    #[case::pagination(None, None, Some(500), Some(1_000), 500, 1_000)]
+    fn export_audit_log(
+        #[case] after: Option<DateTime>,
+        #[case] before: Option<DateTime>,
+        #[case] request_offset: Option<u64>,
+        #[case] request_limit: Option<u64>,
+        #[case] offset: usize,
+        #[case] limit: usize,
+    ) {
+        let mut repository_mock = MockAuditLogRepository::new();
+        repository_mock
+            .expect_get_audit_log_entries_by_timestamp()
+            .once()
+            .with(eq(after), eq(before), eq(offset), eq(limit))
+            .return_const(Ok(stored_audit_log_entries()));
+
+        let service = AuditLogServiceImpl::new(repository_mock);
+
+        let result = service
+            .export_audit_log(ExportAuditLogRequest {
+                after_timestamp_ms: after.map(|t| t.timestamp_micros() / MICROS_PER_MS),
+                before_timestamp_ms: before.map(|t| t.timestamp_micros() / MICROS_PER_MS),
+                offset: request_offset,
+                limit: request_limit,
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ExportAuditLogResponse {
+                entries: stored_audit_log_entries()
+                    .into_iter()
+                    .map(|(id, entry)| map_audit_log_entry(id, entry))
+                    .collect(),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::limit_too_high(
+        ExportAuditLogRequest {
+            after_timestamp_ms: None,
+            before_timestamp_ms: None,
+            offset: None,
+            limit: Some(1_001),
+        },
+        ApiError::invalid_argument("Limit cannot be greater than 1000")
+    )]
+    #[case::timestamp_overflow(
+        ExportAuditLogRequest {
+            after_timestamp_ms: Some(u64::MAX),
+            before_timestamp_ms: None,
+            offset: None,
+            limit: None,
+        },
+        ApiError::invalid_argument(&format!("Invalid timestamp {}", u64::MAX))
+    )]
+    fn export_audit_log_invalid(#[case] request: ExportAuditLogRequest, #[case] error: ApiError) {
+        let mut repository_mock = MockAuditLogRepository::new();
+        repository_mock
+            .expect_get_audit_log_entries_by_timestamp()
+            .never();
+
+        let service = AuditLogServiceImpl::new(repository_mock);
+
+        let result = service.export_audit_log(request).unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn record_action() {
+        let audit_log_entry = AuditLogEntry {
+            timestamp: DateTime::new(get_date_time().unwrap()).unwrap(),
+            ..fixtures::audit_log_entry()
+        };
+
+        let mut repository_mock = MockAuditLogRepository::new();
+        repository_mock
+            .expect_append_audit_log_entry()
+            .once()
+            .with(eq(audit_log_entry.clone()))
+            .return_const(Ok(0));
+
+        let service = AuditLogServiceImpl::new(repository_mock);
+
+        service
+            .record_action(
+                audit_log_entry.actor,
+                audit_log_entry.action,
+                audit_log_entry.target_ids,
+                audit_log_entry.before,
+                audit_log_entry.after,
+            )
+            .unwrap();
+    }
+}
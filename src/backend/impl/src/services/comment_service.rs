@@ -0,0 +1,1156 @@
+use crate::{
+    mappings::{map_comment, map_comments, map_get_comment_history_response},
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, Comment, CommentId, CommentRepository,
+        CommentRepositoryImpl, CommentTarget, DateTime, Permission, ProposalRepository,
+        ProposalRepositoryImpl, ProposalReviewCommitRepository, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewRepository, ProposalReviewRepositoryImpl, RoleRepositoryImpl, UserId,
+        UserProfile, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    services::{
+        AccessControlService, AccessControlServiceImpl, AuditLogService, AuditLogServiceImpl,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, CreateCommentRequest, CreateCommentResponse, DeleteCommentRequest,
+    GetCommentHistoryRequest, GetCommentHistoryResponse, ListCommentsRequest, ListCommentsResponse,
+    UpdateCommentRequest,
+};
+use candid::Principal;
+
+const MAX_COMMENT_CHARS: usize = 5000;
+const DEFAULT_COMMENTS_LIMIT: u64 = 50;
+const MAX_COMMENTS_LIMIT: u64 = 100;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait CommentService {
+    fn create_comment(
+        &self,
+        calling_principal: Principal,
+        request: CreateCommentRequest,
+    ) -> Result<CreateCommentResponse, ApiError>;
+
+    fn update_comment(
+        &self,
+        calling_principal: Principal,
+        request: UpdateCommentRequest,
+    ) -> Result<(), ApiError>;
+
+    fn delete_comment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteCommentRequest,
+    ) -> Result<(), ApiError>;
+
+    fn list_comments(&self, request: ListCommentsRequest)
+        -> Result<ListCommentsResponse, ApiError>;
+
+    fn get_comment_history(
+        &self,
+        calling_principal: Principal,
+        request: GetCommentHistoryRequest,
+    ) -> Result<GetCommentHistoryResponse, ApiError>;
+}
+
+pub struct CommentServiceImpl<
+    C: CommentRepository,
+    U: UserProfileRepository,
+    P: ProposalRepository,
+    PR: ProposalReviewRepository,
+    PRC: ProposalReviewCommitRepository,
+    AC: AccessControlService,
+    AL: AuditLogService,
+> {
+    comment_repository: C,
+    user_profile_repository: U,
+    proposal_repository: P,
+    proposal_review_repository: PR,
+    proposal_review_commit_repository: PRC,
+    access_control_service: AC,
+    audit_log_service: AL,
+}
+
+impl Default
+    for CommentServiceImpl<
+        CommentRepositoryImpl,
+        UserProfileRepositoryImpl,
+        ProposalRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        ProposalReviewCommitRepositoryImpl,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            CommentRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            ProposalReviewCommitRepositoryImpl::default(),
+            AccessControlServiceImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        C: CommentRepository,
+        U: UserProfileRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        AC: AccessControlService,
+        AL: AuditLogService,
+    > CommentService for CommentServiceImpl<C, U, P, PR, PRC, AC, AL>
+{
+    fn create_comment(
+        &self,
+        calling_principal: Principal,
+        request: CreateCommentRequest,
+    ) -> Result<CreateCommentResponse, ApiError> {
+        validate_comment_content(&request.content)?;
+
+        let (user_id, _) = self.get_user_profile(&calling_principal)?;
+        let target = CommentTarget::try_from(request.target)?;
+        self.assert_target_exists(target)?;
+
+        let parent_id = request
+            .parent_id
+            .map(|id| {
+                let parent_id = CommentId::try_from(id.as_str())?;
+                self.assert_can_reply_to(target, parent_id)?;
+
+                Ok::<_, ApiError>(parent_id)
+            })
+            .transpose()?;
+
+        let comment = Comment {
+            target,
+            parent_id,
+            user_id,
+            content: request.content,
+            created_at: DateTime::new(get_date_time()?)?,
+            last_updated_at: None,
+            deleted_at: None,
+        };
+
+        let id = self
+            .comment_repository
+            .create_comment(calling_principal, comment.clone())?;
+
+        Ok(map_comment(id, comment))
+    }
+
+    fn update_comment(
+        &self,
+        calling_principal: Principal,
+        request: UpdateCommentRequest,
+    ) -> Result<(), ApiError> {
+        validate_comment_content(&request.content)?;
+
+        let (user_id, _) = self.get_user_profile(&calling_principal)?;
+        let id = CommentId::try_from(request.id.as_str())?;
+        let comment = self.get_comment(id)?;
+
+        // moderation is done by deleting comments, admins cannot edit other users' comments
+        if comment.user_id != user_id {
+            return Err(ApiError::permission_denied("Not authorized"));
+        }
+
+        if comment.is_deleted() {
+            return Err(ApiError::conflict(&format!(
+                "Comment with Id {} is deleted",
+                id
+            )));
+        }
+
+        self.comment_repository.update_comment(
+            calling_principal,
+            id,
+            Comment {
+                content: request.content,
+                last_updated_at: Some(DateTime::new(get_date_time()?)?),
+                ..comment
+            },
+        )
+    }
+
+    fn delete_comment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteCommentRequest,
+    ) -> Result<(), ApiError> {
+        let (user_id, _) = self.get_user_profile(&calling_principal)?;
+        let id = CommentId::try_from(request.id.as_str())?;
+        let comment = self.get_comment(id)?;
+
+        let is_moderation = comment.user_id != user_id;
+        if is_moderation && !self.can_moderate(&calling_principal) {
+            return Err(ApiError::permission_denied("Not authorized"));
+        }
+
+        if comment.is_deleted() {
+            return Err(ApiError::conflict(&format!(
+                "Comment with Id {} is already deleted",
+                id
+            )));
+        }
+
+        // the content stays available in the comment history
+        let deleted_comment = Comment {
+            content: String::new(),
+            deleted_at: Some(DateTime::new(get_date_time()?)?),
+            ..comment.clone()
+        };
+        self.comment_repository
+            .delete_comment(calling_principal, id, deleted_comment.clone())?;
+
+        if !is_moderation {
+            return Ok(());
+        }
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ModerateComment,
+            vec![id.to_string()],
+            Some(audit_snapshot(&comment)),
+            Some(audit_snapshot(&deleted_comment)),
+        )
+    }
+
+    fn list_comments(
+        &self,
+        request: ListCommentsRequest,
+    ) -> Result<ListCommentsResponse, ApiError> {
+        let target = CommentTarget::try_from(request.target)?;
+        let offset = request.offset.unwrap_or(0);
+        let limit = request.limit.unwrap_or(DEFAULT_COMMENTS_LIMIT);
+
+        if limit > MAX_COMMENTS_LIMIT {
+            return Err(ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_COMMENTS_LIMIT
+            )));
+        }
+
+        let comments = self.comment_repository.get_comments_by_target_id(
+            target.id(),
+            offset as usize,
+            limit as usize,
+        )?;
+        let total = self
+            .comment_repository
+            .count_comments_by_target_id(target.id())?;
+
+        Ok(ListCommentsResponse {
+            comments: map_comments(comments),
+            total,
+        })
+    }
+
+    fn get_comment_history(
+        &self,
+        calling_principal: Principal,
+        request: GetCommentHistoryRequest,
+    ) -> Result<GetCommentHistoryResponse, ApiError> {
+        let id = CommentId::try_from(request.id.as_str())?;
+        let comment = self.get_comment(id)?;
+
+        // the history contains the content of deleted comments,
+        // so only the author and moderators can see it
+        let is_authorized = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .is_some_and(|user_id| user_id == comment.user_id)
+            || self.can_moderate(&calling_principal);
+        if !is_authorized {
+            return Err(ApiError::permission_denied("Not authorized"));
+        }
+
+        let history = self.comment_repository.get_comment_history_by_id(id)?;
+
+        Ok(map_get_comment_history_response(history))
+    }
+}
+
+impl<
+        C: CommentRepository,
+        U: UserProfileRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        AC: AccessControlService,
+        AL: AuditLogService,
+    > CommentServiceImpl<C, U, P, PR, PRC, AC, AL>
+{
+    fn new(
+        comment_repository: C,
+        user_profile_repository: U,
+        proposal_repository: P,
+        proposal_review_repository: PR,
+        proposal_review_commit_repository: PRC,
+        access_control_service: AC,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            comment_repository,
+            user_profile_repository,
+            proposal_repository,
+            proposal_review_repository,
+            proposal_review_commit_repository,
+            access_control_service,
+            audit_log_service,
+        }
+    }
+
+    fn can_moderate(&self, calling_principal: &Principal) -> bool {
+        self.access_control_service
+            .assert_permission(calling_principal, Permission::ModerateReviews)
+            .is_ok()
+    }
+
+    fn get_user_profile(
+        &self,
+        calling_principal: &Principal,
+    ) -> Result<(UserId, UserProfile), ApiError> {
+        self.user_profile_repository
+            .get_user_profile_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    fn get_comment(&self, id: CommentId) -> Result<Comment, ApiError> {
+        self.comment_repository
+            .get_comment_by_id(&id)
+            .ok_or_else(|| ApiError::not_found(&format!("Comment with Id {} not found", id)))
+    }
+
+    fn assert_target_exists(&self, target: CommentTarget) -> Result<(), ApiError> {
+        let proposal_review_id = match target {
+            CommentTarget::Proposal(proposal_id) => {
+                self.proposal_repository
+                    .get_proposal_by_id(&proposal_id)
+                    .ok_or_else(|| {
+                        ApiError::not_found(&format!("Proposal with Id {} not found", proposal_id))
+                    })?;
+
+                return Ok(());
+            }
+            CommentTarget::ProposalReview(proposal_review_id) => proposal_review_id,
+            CommentTarget::ProposalReviewCommit(proposal_review_commit_id) => {
+                self.proposal_review_commit_repository
+                    .get_proposal_review_commit_by_id(&proposal_review_commit_id)
+                    .ok_or_else(|| {
+                        ApiError::not_found(&format!(
+                            "Proposal review commit with Id {} not found",
+                            proposal_review_commit_id
+                        ))
+                    })?
+                    .proposal_review_id
+            }
+        };
+
+        let proposal_review = self
+            .proposal_review_repository
+            .get_proposal_review_by_id(&proposal_review_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Proposal review with Id {} not found",
+                    proposal_review_id
+                ))
+            })?;
+
+        // draft reviews are only visible to their author and admins
+        if !proposal_review.is_published() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal review with Id {} is not published",
+                proposal_review_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn assert_can_reply_to(
+        &self,
+        target: CommentTarget,
+        parent_id: CommentId,
+    ) -> Result<(), ApiError> {
+        let parent = self.get_comment(parent_id)?;
+
+        if parent.target != target {
+            return Err(ApiError::invalid_argument(&format!(
+                "Comment with Id {} belongs to a different target",
+                parent_id
+            )));
+        }
+
+        if parent.is_deleted() {
+            return Err(ApiError::conflict(&format!(
+                "Comment with Id {} is deleted",
+                parent_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates the markdown content of a comment.
+///
+/// Comments are rendered as markdown by the frontend, so raw HTML and `javascript:` links
+/// are rejected outside of code. Code blocks must be closed so that they don't swallow
+/// the rest of the thread.
+fn validate_comment_content(content: &str) -> Result<(), ApiError> {
+    if content.trim().is_empty() {
+        return Err(ApiError::invalid_argument("Comment cannot be empty"));
+    }
+
+    if content.chars().count() > MAX_COMMENT_CHARS {
+        return Err(ApiError::invalid_argument(&format!(
+            "Comment must be less than {} characters",
+            MAX_COMMENT_CHARS
+        )));
+    }
+
+    let mut in_code_block = false;
+    for line in content.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        // every odd part is inside an inline code span
+        for text in line.split('`').step_by(2) {
+            if contains_html_tag(text) {
+                return Err(ApiError::invalid_argument(
+                    "Comment cannot contain HTML tags",
+                ));
+            }
+            if text.to_lowercase().contains("](javascript:") {
+                return Err(ApiError::invalid_argument(
+                    "Comment cannot contain javascript links",
+                ));
+            }
+        }
+    }
+
+    if in_code_block {
+        return Err(ApiError::invalid_argument(
+            "Comment contains an unclosed code block",
+        ));
+    }
+
+    Ok(())
+}
+
+fn contains_html_tag(text: &str) -> bool {
+    text.char_indices().any(|(i, c)| {
+        c == '<'
+            && text[i + 1..]
+                .chars()
+                .next()
+                .is_some_and(|next| next.is_ascii_alphabetic() || next == '/' || next == '!')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b},
+        repositories::{
+            MockCommentRepository, MockProposalRepository, MockProposalReviewCommitRepository,
+            MockProposalReviewRepository, MockUserProfileRepository,
+        },
+        services::{MockAccessControlService, MockAuditLogService},
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_comment() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let proposal_id = fixtures::proposal_id();
+        let comment = Comment {
+            created_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            ..fixtures::comment_proposal()
+        };
+        let id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_create_comment()
+            .once()
+            .with(eq(calling_principal), eq(comment.clone()))
+            .return_const(Ok(id));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            access_control_service_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_comment(
+                calling_principal,
+                CreateCommentRequest {
+                    target: backend_api::CommentTarget::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                    parent_id: None,
+                    content: comment.content.clone(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result, map_comment(id, comment));
+    }
+
+    #[rstest]
+    fn create_comment_reply() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let proposal_review_id = fixtures::proposal_review_id();
+        let parent_id = uuid_a();
+        let comment = Comment {
+            target: CommentTarget::ProposalReview(proposal_review_id),
+            parent_id: Some(parent_id),
+            created_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            ..fixtures::comment_proposal()
+        };
+        let id = uuid_b();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_published()));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(parent_id))
+            .return_const(Some(Comment {
+                target: CommentTarget::ProposalReview(proposal_review_id),
+                ..fixtures::comment_proposal()
+            }));
+        c_repository_mock
+            .expect_create_comment()
+            .once()
+            .with(eq(calling_principal), eq(comment.clone()))
+            .return_const(Ok(id));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            pr_repository_mock,
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_comment(
+                calling_principal,
+                CreateCommentRequest {
+                    target: backend_api::CommentTarget::ProposalReview {
+                        proposal_review_id: proposal_review_id.to_string(),
+                    },
+                    parent_id: Some(parent_id.to_string()),
+                    content: comment.content.clone(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result, map_comment(id, comment));
+    }
+
+    #[rstest]
+    fn create_comment_proposal_review_commit_draft() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_commit_id = fixtures::proposal_review_commit_id();
+        let proposal_review_id = fixtures::proposal_review_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((
+                fixtures::user_id(),
+                fixtures::reviewer_user_profile(),
+            )));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commit_by_id()
+            .once()
+            .with(eq(proposal_review_commit_id))
+            .return_const(Some(fixtures::proposal_review_commit_reviewed()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_draft()));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock.expect_create_comment().never();
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            pr_repository_mock,
+            prc_repository_mock,
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_comment(
+                calling_principal,
+                CreateCommentRequest {
+                    target: backend_api::CommentTarget::ProposalReviewCommit {
+                        proposal_review_commit_id: proposal_review_commit_id.to_string(),
+                    },
+                    parent_id: None,
+                    content: "Looks good".to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal review with Id {} is not published",
+                proposal_review_id
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::other_target(
+        Comment {
+            target: CommentTarget::Proposal(uuid_b()),
+            ..fixtures::comment_proposal()
+        },
+        ApiError::invalid_argument(&format!(
+            "Comment with Id {} belongs to a different target",
+            uuid_a()
+        ))
+    )]
+    #[case::deleted(
+        fixtures::comment_deleted(),
+        ApiError::conflict(&format!("Comment with Id {} is deleted", uuid_a()))
+    )]
+    fn create_comment_invalid_parent(#[case] parent: Comment, #[case] error: ApiError) {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let parent_id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((
+                fixtures::user_id(),
+                fixtures::reviewer_user_profile(),
+            )));
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(parent_id))
+            .return_const(Some(parent));
+        c_repository_mock.expect_create_comment().never();
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_comment(
+                calling_principal,
+                CreateCommentRequest {
+                    target: backend_api::CommentTarget::Proposal {
+                        proposal_id: proposal_id.to_string(),
+                    },
+                    parent_id: Some(parent_id.to_string()),
+                    content: "Agreed".to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn update_comment() {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+        let comment = fixtures::comment_proposal();
+        let content = "Edited: the build is *reproducible*".to_string();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((comment.user_id, fixtures::reviewer_user_profile())));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(comment.clone()));
+        c_repository_mock
+            .expect_update_comment()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(id),
+                eq(Comment {
+                    content: content.clone(),
+                    last_updated_at: Some(DateTime::new(get_date_time().unwrap()).unwrap()),
+                    ..comment
+                }),
+            )
+            .return_const(Ok(()));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        service
+            .update_comment(
+                calling_principal,
+                UpdateCommentRequest {
+                    id: id.to_string(),
+                    content,
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn update_comment_admin_other_user() {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((uuid_b(), fixtures::admin_user_profile())));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::comment_proposal()));
+        c_repository_mock.expect_update_comment().never();
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .update_comment(
+                calling_principal,
+                UpdateCommentRequest {
+                    id: id.to_string(),
+                    content: "Edited".to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::permission_denied("Not authorized"));
+    }
+
+    #[rstest]
+    #[case::author(fixtures::user_id(), false)]
+    #[case::moderator(uuid_b(), true)]
+    fn delete_comment(#[case] user_id: UserId, #[case] is_moderator: bool) {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+        let comment = fixtures::comment_proposal();
+        let deleted_comment = Comment {
+            content: String::new(),
+            deleted_at: Some(DateTime::new(get_date_time().unwrap()).unwrap()),
+            ..comment.clone()
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .times(usize::from(is_moderator))
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Ok(()));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(comment.clone()));
+        c_repository_mock
+            .expect_delete_comment()
+            .once()
+            .with(eq(calling_principal), eq(id), eq(deleted_comment.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .times(usize::from(is_moderator))
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::ModerateComment),
+                eq(vec![id.to_string()]),
+                eq(Some(audit_snapshot(&comment))),
+                eq(Some(audit_snapshot(&deleted_comment))),
+            )
+            .return_const(Ok(()));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            access_control_service_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .delete_comment(
+                calling_principal,
+                DeleteCommentRequest { id: id.to_string() },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_comment_other_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((uuid_b(), fixtures::reviewer_user_profile())));
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::comment_proposal()));
+        c_repository_mock.expect_delete_comment().never();
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .once()
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(Err(ApiError::permission_denied("Missing permission")));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            access_control_service_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .delete_comment(
+                calling_principal,
+                DeleteCommentRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::permission_denied("Not authorized"));
+    }
+
+    #[rstest]
+    fn list_comments() {
+        let proposal_id = fixtures::proposal_id();
+        let comments = vec![
+            (uuid_a(), fixtures::comment_proposal()),
+            (uuid_b(), fixtures::comment_deleted()),
+        ];
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comments_by_target_id()
+            .once()
+            .with(eq(proposal_id), eq(10), eq(DEFAULT_COMMENTS_LIMIT as usize))
+            .return_const(Ok(comments.clone()));
+        c_repository_mock
+            .expect_count_comments_by_target_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(12));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            MockUserProfileRepository::new(),
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_comments(ListCommentsRequest {
+                target: backend_api::CommentTarget::Proposal {
+                    proposal_id: proposal_id.to_string(),
+                },
+                offset: Some(10),
+                limit: None,
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListCommentsResponse {
+                comments: map_comments(comments),
+                total: 12,
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_comments_limit_too_high() {
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock.expect_get_comments_by_target_id().never();
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            MockUserProfileRepository::new(),
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_comments(ListCommentsRequest {
+                target: backend_api::CommentTarget::Proposal {
+                    proposal_id: fixtures::proposal_id().to_string(),
+                },
+                offset: None,
+                limit: Some(MAX_COMMENTS_LIMIT + 1),
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_COMMENTS_LIMIT
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::author(Some(fixtures::user_id()), None)]
+    #[case::moderator(Some(uuid_b()), Some(true))]
+    #[case::other_reviewer(Some(uuid_b()), Some(false))]
+    #[case::anonymous(None, Some(false))]
+    fn get_comment_history(
+        #[case] calling_user_id: Option<UserId>,
+        #[case] is_moderator: Option<bool>,
+    ) {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+        let is_authorized = is_moderator.unwrap_or(true);
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(calling_user_id);
+
+        let mut access_control_service_mock = MockAccessControlService::new();
+        access_control_service_mock
+            .expect_assert_permission()
+            .times(usize::from(is_moderator.is_some()))
+            .with(eq(calling_principal), eq(Permission::ModerateReviews))
+            .return_const(if is_authorized {
+                Ok(())
+            } else {
+                Err(ApiError::permission_denied("Missing permission"))
+            });
+
+        let mut c_repository_mock = MockCommentRepository::new();
+        c_repository_mock
+            .expect_get_comment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::comment_proposal()));
+        c_repository_mock
+            .expect_get_comment_history_by_id()
+            .times(usize::from(is_authorized))
+            .with(eq(id))
+            .return_const(Ok(vec![]));
+
+        let service = CommentServiceImpl::new(
+            c_repository_mock,
+            u_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockAccessControlService::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.get_comment_history(
+            calling_principal,
+            GetCommentHistoryRequest { id: id.to_string() },
+        );
+
+        if is_authorized {
+            assert_eq!(
+                result.unwrap(),
+                GetCommentHistoryResponse { history: vec![] }
+            );
+        } else {
+            assert_eq!(
+                result.unwrap_err(),
+                ApiError::permission_denied("Not authorized")
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::plain("Looks good to me")]
+    #[case::markdown("**Bold**, _italic_ and [a link](https://github.com/dfinity/ic)")]
+    #[case::inline_code("Use `<div>` for that")]
+    #[case::code_block("```html\n<script>alert(1)</script>\n```")]
+    #[case::comparison("a < b and b > c")]
+    fn validate_comment_content_valid(#[case] content: &str) {
+        validate_comment_content(content).unwrap();
+    }
+
+    #[rstest]
+    #[case::empty("   ", ApiError::invalid_argument("Comment cannot be empty"))]
+    #[case::too_long(
+        &"a".repeat(MAX_COMMENT_CHARS + 1),
+        ApiError::invalid_argument(&format!(
+            "Comment must be less than {} characters",
+            MAX_COMMENT_CHARS
+        ))
+    )]
+    #[case::html(
+        "Hello <img src=x onerror=alert(1)>",
+        ApiError::invalid_argument("Comment cannot contain HTML tags")
+    )]
+    #[case::html_comment(
+        "Hello <!-- hidden -->",
+        ApiError::invalid_argument("Comment cannot contain HTML tags")
+    )]
+    #[case::javascript_link(
+        "[click](JavaScript:alert(1))",
+        ApiError::invalid_argument("Comment cannot contain javascript links")
+    )]
+    #[case::unclosed_code_block(
+        "```rust\nfn main() {}",
+        ApiError::invalid_argument("Comment contains an unclosed code block")
+    )]
+    fn validate_comment_content_invalid(#[case] content: &str, #[case] error: ApiError) {
+        let result = validate_comment_content(content).unwrap_err();
+
+        assert_eq!(result, error);
+    }
+}
@@ -0,0 +1,701 @@
+use std::collections::BTreeSet;
+
+use crate::{
+    repositories::{
+        CommitClaim, CommitClaimRepository, CommitClaimRepositoryImpl, CommitSha, DateTime,
+        ProposalId, ProposalRepository, ProposalRepositoryImpl, UserId, UserProfileRepository,
+        UserProfileRepositoryImpl,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ClaimCommitRequest, ClaimCommitResponse, ListCommitClaimsRequest,
+    ListCommitClaimsResponse, ListUnclaimedCommitsRequest, ListUnclaimedCommitsResponse,
+    ReleaseCommitClaimRequest,
+};
+use candid::Principal;
+
+/// Claims can be renewed by claiming the same commit again before they expire.
+const COMMIT_CLAIM_DURATION_HOURS: i64 = 8;
+
+const MAX_UNCLAIMED_COMMITS_REQUEST_SHAS: usize = 500;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait CommitClaimService {
+    fn claim_commit(
+        &self,
+        calling_principal: Principal,
+        request: ClaimCommitRequest,
+    ) -> Result<ClaimCommitResponse, ApiError>;
+
+    fn release_commit_claim(
+        &self,
+        calling_principal: Principal,
+        request: ReleaseCommitClaimRequest,
+    ) -> Result<(), ApiError>;
+
+    fn list_commit_claims(
+        &self,
+        request: ListCommitClaimsRequest,
+    ) -> Result<ListCommitClaimsResponse, ApiError>;
+
+    fn list_unclaimed_commits(
+        &self,
+        request: ListUnclaimedCommitsRequest,
+    ) -> Result<ListUnclaimedCommitsResponse, ApiError>;
+
+    /// Removes the claims that expired and the claims on completed proposals.
+    /// Returns the number of released claims.
+    fn release_commit_claims(&self) -> Result<usize, ApiError>;
+}
+
+pub struct CommitClaimServiceImpl<
+    C: CommitClaimRepository,
+    U: UserProfileRepository,
+    P: ProposalRepository,
+> {
+    commit_claim_repository: C,
+    user_profile_repository: U,
+    proposal_repository: P,
+}
+
+impl Default
+    for CommitClaimServiceImpl<
+        CommitClaimRepositoryImpl,
+        UserProfileRepositoryImpl,
+        ProposalRepositoryImpl,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            CommitClaimRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+        )
+    }
+}
+
+impl<C: CommitClaimRepository, U: UserProfileRepository, P: ProposalRepository> CommitClaimService
+    for CommitClaimServiceImpl<C, U, P>
+{
+    fn claim_commit(
+        &self,
+        calling_principal: Principal,
+        request: ClaimCommitRequest,
+    ) -> Result<ClaimCommitResponse, ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        let commit_sha = CommitSha::try_from(request.commit_sha.as_str())?;
+
+        self.assert_proposal_in_progress(proposal_id)?;
+
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        if let Some(existing_claim) = self
+            .commit_claim_repository
+            .get_commit_claim(proposal_id, commit_sha)?
+        {
+            if existing_claim.is_active(&current_time) && existing_claim.user_id != user_id {
+                return Err(ApiError::conflict(&format!(
+                    "Commit {} of proposal with Id {} is already claimed by user with Id {}",
+                    commit_sha, proposal_id, existing_claim.user_id
+                )));
+            }
+        }
+
+        let commit_claim = CommitClaim {
+            proposal_id,
+            commit_sha,
+            user_id,
+            claimed_at: current_time,
+            expires_at: current_time.add(chrono::Duration::hours(COMMIT_CLAIM_DURATION_HOURS)),
+        };
+
+        self.commit_claim_repository
+            .upsert_commit_claim(commit_claim.clone())?;
+
+        Ok(commit_claim.into())
+    }
+
+    fn release_commit_claim(
+        &self,
+        calling_principal: Principal,
+        request: ReleaseCommitClaimRequest,
+    ) -> Result<(), ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        let commit_sha = CommitSha::try_from(request.commit_sha.as_str())?;
+
+        self.commit_claim_repository
+            .get_commit_claim(proposal_id, commit_sha)?
+            .filter(|commit_claim| commit_claim.user_id == user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Commit claim for commit {} of proposal with Id {} not found",
+                    commit_sha, proposal_id
+                ))
+            })?;
+
+        self.commit_claim_repository
+            .delete_commit_claim(proposal_id, commit_sha)
+    }
+
+    fn list_commit_claims(
+        &self,
+        request: ListCommitClaimsRequest,
+    ) -> Result<ListCommitClaimsResponse, ApiError> {
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        let commit_claims = self
+            .commit_claim_repository
+            .get_commit_claims_by_proposal_id(proposal_id)?
+            .into_iter()
+            .filter(|commit_claim| commit_claim.is_active(&current_time))
+            .map(|commit_claim| commit_claim.into())
+            .collect();
+
+        Ok(ListCommitClaimsResponse { commit_claims })
+    }
+
+    fn list_unclaimed_commits(
+        &self,
+        request: ListUnclaimedCommitsRequest,
+    ) -> Result<ListUnclaimedCommitsResponse, ApiError> {
+        if request.commit_shas.len() > MAX_UNCLAIMED_COMMITS_REQUEST_SHAS {
+            return Err(ApiError::invalid_argument(&format!(
+                "Cannot request more than {} commits",
+                MAX_UNCLAIMED_COMMITS_REQUEST_SHAS
+            )));
+        }
+
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        let claimed_commit_shas = self
+            .commit_claim_repository
+            .get_commit_claims_by_proposal_id(proposal_id)?
+            .into_iter()
+            .filter(|commit_claim| commit_claim.is_active(&current_time))
+            .map(|commit_claim| commit_claim.commit_sha)
+            .collect::<BTreeSet<_>>();
+
+        let mut commit_shas = vec![];
+        for commit_sha in request.commit_shas {
+            if !claimed_commit_shas.contains(&CommitSha::try_from(commit_sha.as_str())?) {
+                commit_shas.push(commit_sha);
+            }
+        }
+
+        Ok(ListUnclaimedCommitsResponse { commit_shas })
+    }
+
+    fn release_commit_claims(&self) -> Result<usize, ApiError> {
+        let current_time = DateTime::new(get_date_time()?)?;
+        let mut completed_proposal_ids = BTreeSet::<ProposalId>::new();
+        let mut released_count = 0;
+
+        for commit_claim in self.commit_claim_repository.get_all_commit_claims() {
+            let is_proposal_completed = completed_proposal_ids.contains(&commit_claim.proposal_id)
+                || self
+                    .proposal_repository
+                    .get_proposal_by_id(&commit_claim.proposal_id)
+                    .is_none_or(|proposal| proposal.is_completed());
+
+            if is_proposal_completed {
+                completed_proposal_ids.insert(commit_claim.proposal_id);
+            } else if commit_claim.is_active(&current_time) {
+                continue;
+            }
+
+            self.commit_claim_repository
+                .delete_commit_claim(commit_claim.proposal_id, commit_claim.commit_sha)?;
+            released_count += 1;
+        }
+
+        Ok(released_count)
+    }
+}
+
+impl<C: CommitClaimRepository, U: UserProfileRepository, P: ProposalRepository>
+    CommitClaimServiceImpl<C, U, P>
+{
+    fn new(commit_claim_repository: C, user_profile_repository: U, proposal_repository: P) -> Self {
+        Self {
+            commit_claim_repository,
+            user_profile_repository,
+            proposal_repository,
+        }
+    }
+
+    fn get_user_id(&self, calling_principal: &Principal) -> Result<UserId, ApiError> {
+        self.user_profile_repository
+            .get_user_id_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    fn assert_proposal_in_progress(&self, proposal_id: ProposalId) -> Result<(), ApiError> {
+        let proposal = self
+            .proposal_repository
+            .get_proposal_by_id(&proposal_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!("Proposal with Id {} not found", proposal_id))
+            })?;
+
+        if proposal.is_completed() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal with Id {} is already completed",
+                proposal_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, commit_sha_a, commit_sha_b, commit_sha_c, uuid_a, uuid_b},
+        repositories::{
+            MockCommitClaimRepository, MockProposalRepository, MockUserProfileRepository,
+        },
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::unclaimed(None)]
+    #[case::claimed_by_same_user(Some(active_commit_claim(fixtures::user_id())))]
+    #[case::claim_expired(Some(expired_commit_claim(uuid_b())))]
+    fn claim_commit(#[case] existing_claim: Option<CommitClaim>) {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let proposal_id = fixtures::proposal_id();
+        let commit_sha = commit_sha_a();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+        let commit_claim = CommitClaim {
+            proposal_id,
+            commit_sha,
+            user_id,
+            claimed_at: current_time,
+            expires_at: current_time.add(chrono::Duration::hours(COMMIT_CLAIM_DURATION_HOURS)),
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claim()
+            .once()
+            .with(eq(proposal_id), eq(commit_sha))
+            .return_const(Ok(existing_claim));
+        c_repository_mock
+            .expect_upsert_commit_claim()
+            .once()
+            .with(eq(commit_claim.clone()))
+            .return_const(Ok(()));
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .claim_commit(
+                calling_principal,
+                ClaimCommitRequest {
+                    proposal_id: proposal_id.to_string(),
+                    commit_sha: commit_sha.to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result, commit_claim.into());
+    }
+
+    #[rstest]
+    fn claim_commit_claimed_by_other_user() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let commit_sha = commit_sha_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::user_id()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claim()
+            .once()
+            .with(eq(proposal_id), eq(commit_sha))
+            .return_const(Ok(Some(active_commit_claim(uuid_b()))));
+        c_repository_mock.expect_upsert_commit_claim().never();
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .claim_commit(
+                calling_principal,
+                ClaimCommitRequest {
+                    proposal_id: proposal_id.to_string(),
+                    commit_sha: commit_sha.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Commit {} of proposal with Id {} is already claimed by user with Id {}",
+                commit_sha,
+                proposal_id,
+                uuid_b()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn claim_commit_proposal_completed() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::user_id()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            ));
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock.expect_upsert_commit_claim().never();
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .claim_commit(
+                calling_principal,
+                ClaimCommitRequest {
+                    proposal_id: proposal_id.to_string(),
+                    commit_sha: commit_sha_a().to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal with Id {} is already completed",
+                proposal_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn release_commit_claim() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let proposal_id = fixtures::proposal_id();
+        let commit_sha = commit_sha_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let p_repository_mock = MockProposalRepository::new();
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claim()
+            .once()
+            .with(eq(proposal_id), eq(commit_sha))
+            .return_const(Ok(Some(active_commit_claim(user_id))));
+        c_repository_mock
+            .expect_delete_commit_claim()
+            .once()
+            .with(eq(proposal_id), eq(commit_sha))
+            .return_const(Ok(()));
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        service
+            .release_commit_claim(
+                calling_principal,
+                ReleaseCommitClaimRequest {
+                    proposal_id: proposal_id.to_string(),
+                    commit_sha: commit_sha.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn release_commit_claim_other_user() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let commit_sha = commit_sha_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::user_id()));
+        let p_repository_mock = MockProposalRepository::new();
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claim()
+            .once()
+            .with(eq(proposal_id), eq(commit_sha))
+            .return_const(Ok(Some(active_commit_claim(uuid_b()))));
+        c_repository_mock.expect_delete_commit_claim().never();
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .release_commit_claim(
+                calling_principal,
+                ReleaseCommitClaimRequest {
+                    proposal_id: proposal_id.to_string(),
+                    commit_sha: commit_sha.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Commit claim for commit {} of proposal with Id {} not found",
+                commit_sha, proposal_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn list_commit_claims() {
+        let proposal_id = fixtures::proposal_id();
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claims_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                active_commit_claim(uuid_a()),
+                CommitClaim {
+                    commit_sha: commit_sha_b(),
+                    ..expired_commit_claim(uuid_b())
+                },
+            ]));
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .list_commit_claims(ListCommitClaimsRequest {
+                proposal_id: proposal_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListCommitClaimsResponse {
+                commit_claims: vec![active_commit_claim(uuid_a()).into()],
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_unclaimed_commits() {
+        let proposal_id = fixtures::proposal_id();
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_commit_claims_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                active_commit_claim(uuid_a()),
+                CommitClaim {
+                    commit_sha: commit_sha_b(),
+                    ..expired_commit_claim(uuid_b())
+                },
+            ]));
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .list_unclaimed_commits(ListUnclaimedCommitsRequest {
+                proposal_id: proposal_id.to_string(),
+                commit_shas: vec![
+                    commit_sha_a().to_string(),
+                    commit_sha_b().to_string(),
+                    commit_sha_c().to_string(),
+                ],
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListUnclaimedCommitsResponse {
+                commit_shas: vec![commit_sha_b().to_string(), commit_sha_c().to_string()],
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_unclaimed_commits_too_many() {
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let c_repository_mock = MockCommitClaimRepository::new();
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        let result = service
+            .list_unclaimed_commits(ListUnclaimedCommitsRequest {
+                proposal_id: fixtures::proposal_id().to_string(),
+                commit_shas: vec![
+                    commit_sha_a().to_string();
+                    MAX_UNCLAIMED_COMMITS_REQUEST_SHAS + 1
+                ],
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("Cannot request more than 500 commits")
+        );
+    }
+
+    #[rstest]
+    fn release_commit_claims() {
+        let in_progress_proposal_id = uuid_a();
+        let completed_proposal_id = uuid_b();
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .times(2)
+            .with(eq(in_progress_proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        // looked up only once, then cached
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(completed_proposal_id))
+            .return_const(Some(
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            ));
+        let mut c_repository_mock = MockCommitClaimRepository::new();
+        c_repository_mock
+            .expect_get_all_commit_claims()
+            .once()
+            .return_const(vec![
+                CommitClaim {
+                    proposal_id: in_progress_proposal_id,
+                    commit_sha: commit_sha_a(),
+                    ..active_commit_claim(uuid_a())
+                },
+                CommitClaim {
+                    proposal_id: in_progress_proposal_id,
+                    commit_sha: commit_sha_b(),
+                    ..expired_commit_claim(uuid_a())
+                },
+                CommitClaim {
+                    proposal_id: completed_proposal_id,
+                    commit_sha: commit_sha_a(),
+                    ..active_commit_claim(uuid_a())
+                },
+                CommitClaim {
+                    proposal_id: completed_proposal_id,
+                    commit_sha: commit_sha_b(),
+                    ..active_commit_claim(uuid_b())
+                },
+            ]);
+        for (proposal_id, commit_sha) in [
+            (in_progress_proposal_id, commit_sha_b()),
+            (completed_proposal_id, commit_sha_a()),
+            (completed_proposal_id, commit_sha_b()),
+        ] {
+            c_repository_mock
+                .expect_delete_commit_claim()
+                .once()
+                .with(eq(proposal_id), eq(commit_sha))
+                .return_const(Ok(()));
+        }
+
+        let service =
+            CommitClaimServiceImpl::new(c_repository_mock, u_repository_mock, p_repository_mock);
+
+        assert_eq!(service.release_commit_claims().unwrap(), 3);
+    }
+
+    fn active_commit_claim(user_id: UserId) -> CommitClaim {
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        CommitClaim {
+            user_id,
+            claimed_at: current_time,
+            expires_at: current_time.add(chrono::Duration::hours(1)),
+            ..fixtures::commit_claim()
+        }
+    }
+
+    fn expired_commit_claim(user_id: UserId) -> CommitClaim {
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        CommitClaim {
+            user_id,
+            claimed_at: current_time.sub(chrono::Duration::hours(9)),
+            expires_at: current_time.sub(chrono::Duration::hours(1)),
+            ..fixtures::commit_claim()
+        }
+    }
+}
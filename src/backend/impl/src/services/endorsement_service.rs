@@ -0,0 +1,582 @@
+use crate::{
+    mappings::map_endorsement,
+    repositories::{
+        DateTime, Endorsement, EndorsementId, EndorsementRepository, EndorsementRepositoryImpl,
+        ProposalReviewCommitId, ProposalReviewCommitRepository, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewId, ProposalReviewRepository, ProposalReviewRepositoryImpl, UserId,
+        UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, CreateEndorsementRequest, CreateEndorsementResponse, DeleteEndorsementRequest,
+};
+use candid::Principal;
+
+const MAX_ENDORSEMENT_NOTE_CHARS: usize = 500;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait EndorsementService {
+    fn create_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: CreateEndorsementRequest,
+    ) -> Result<CreateEndorsementResponse, ApiError>;
+
+    fn delete_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: DeleteEndorsementRequest,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct EndorsementServiceImpl<
+    E: EndorsementRepository,
+    PR: ProposalReviewRepository,
+    PRC: ProposalReviewCommitRepository,
+    U: UserProfileRepository,
+> {
+    endorsement_repository: E,
+    proposal_review_repository: PR,
+    proposal_review_commit_repository: PRC,
+    user_profile_repository: U,
+}
+
+impl Default
+    for EndorsementServiceImpl<
+        EndorsementRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        ProposalReviewCommitRepositoryImpl,
+        UserProfileRepositoryImpl,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            EndorsementRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            ProposalReviewCommitRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+        )
+    }
+}
+
+impl<
+        E: EndorsementRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        U: UserProfileRepository,
+    > EndorsementService for EndorsementServiceImpl<E, PR, PRC, U>
+{
+    fn create_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: CreateEndorsementRequest,
+    ) -> Result<CreateEndorsementResponse, ApiError> {
+        self.validate_note(request.note.as_ref())?;
+
+        let user_id = self.get_user_id(&calling_principal)?;
+        let proposal_review_id = ProposalReviewId::try_from(request.proposal_review_id.as_str())?;
+
+        let proposal_review = self
+            .proposal_review_repository
+            .get_proposal_review_by_id(&proposal_review_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Proposal review with Id {} not found",
+                    proposal_review_id
+                ))
+            })?;
+
+        if !proposal_review.is_published() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal review with Id {} is not published",
+                proposal_review_id
+            )));
+        }
+
+        if proposal_review.user_id == user_id {
+            return Err(ApiError::conflict(
+                "Cannot endorse your own proposal review",
+            ));
+        }
+
+        let proposal_review_commit_id = request
+            .proposal_review_commit_id
+            .map(|id| {
+                let proposal_review_commit_id = ProposalReviewCommitId::try_from(id.as_str())?;
+                self.assert_proposal_review_commit_is_reviewed(
+                    proposal_review_id,
+                    proposal_review_commit_id,
+                )?;
+
+                Ok::<_, ApiError>(proposal_review_commit_id)
+            })
+            .transpose()?;
+
+        let already_endorsed = self
+            .endorsement_repository
+            .get_endorsements_by_proposal_review_id(proposal_review_id)?
+            .iter()
+            .any(|(_, endorsement)| {
+                endorsement.user_id == user_id
+                    && endorsement.proposal_review_commit_id == proposal_review_commit_id
+            });
+        if already_endorsed {
+            return Err(ApiError::conflict(&format!(
+                "User with Id {} has already endorsed this assessment",
+                user_id
+            )));
+        }
+
+        let endorsement = Endorsement {
+            proposal_review_id,
+            proposal_review_commit_id,
+            user_id,
+            created_at: DateTime::new(get_date_time()?)?,
+            note: request.note,
+        };
+
+        let id = self
+            .endorsement_repository
+            .create_endorsement(endorsement.clone())?;
+
+        Ok(map_endorsement(id, endorsement))
+    }
+
+    fn delete_endorsement(
+        &self,
+        calling_principal: Principal,
+        request: DeleteEndorsementRequest,
+    ) -> Result<(), ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+        let id = EndorsementId::try_from(request.id.as_str())?;
+
+        self.endorsement_repository
+            .get_endorsement_by_id(&id)
+            .filter(|endorsement| endorsement.user_id == user_id)
+            .ok_or_else(|| ApiError::not_found(&format!("Endorsement with Id {} not found", id)))?;
+
+        self.endorsement_repository.delete_endorsement(&id)
+    }
+}
+
+impl<
+        E: EndorsementRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        U: UserProfileRepository,
+    > EndorsementServiceImpl<E, PR, PRC, U>
+{
+    fn new(
+        endorsement_repository: E,
+        proposal_review_repository: PR,
+        proposal_review_commit_repository: PRC,
+        user_profile_repository: U,
+    ) -> Self {
+        Self {
+            endorsement_repository,
+            proposal_review_repository,
+            proposal_review_commit_repository,
+            user_profile_repository,
+        }
+    }
+
+    fn get_user_id(&self, calling_principal: &Principal) -> Result<UserId, ApiError> {
+        self.user_profile_repository
+            .get_user_id_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    fn assert_proposal_review_commit_is_reviewed(
+        &self,
+        proposal_review_id: ProposalReviewId,
+        proposal_review_commit_id: ProposalReviewCommitId,
+    ) -> Result<(), ApiError> {
+        let proposal_review_commit = self
+            .proposal_review_commit_repository
+            .get_proposal_review_commit_by_id(&proposal_review_commit_id)
+            .filter(|commit| commit.proposal_review_id == proposal_review_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Proposal review commit with Id {} not found in proposal review with Id {}",
+                    proposal_review_commit_id, proposal_review_id
+                ))
+            })?;
+
+        if !proposal_review_commit.is_reviewed() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal review commit with Id {} is not reviewed",
+                proposal_review_commit_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn validate_note(&self, note: Option<&String>) -> Result<(), ApiError> {
+        if let Some(note) = note {
+            if note.trim().is_empty() {
+                return Err(ApiError::invalid_argument("Note cannot be empty"));
+            }
+            if note.chars().count() > MAX_ENDORSEMENT_NOTE_CHARS {
+                return Err(ApiError::invalid_argument(&format!(
+                    "Note must be less than {} characters",
+                    MAX_ENDORSEMENT_NOTE_CHARS
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b},
+        repositories::{
+            MockEndorsementRepository, MockProposalReviewCommitRepository,
+            MockProposalReviewRepository, MockUserProfileRepository, ProposalReview,
+        },
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case::proposal_review(None)]
+    #[case::proposal_review_commit(Some(fixtures::proposal_review_commit_id()))]
+    fn create_endorsement(#[case] proposal_review_commit_id: Option<ProposalReviewCommitId>) {
+        let calling_principal = fixtures::principal_a();
+        let user_id = uuid_b();
+        let proposal_review_id = fixtures::proposal_review_id();
+        let endorsement = Endorsement {
+            proposal_review_id,
+            proposal_review_commit_id,
+            user_id,
+            created_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            note: Some("I independently confirm this".to_string()),
+        };
+        let id = fixtures::uuid();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_published()));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commit_by_id()
+            .times(usize::from(proposal_review_commit_id.is_some()))
+            .return_const(Some(fixtures::proposal_review_commit_reviewed()));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock
+            .expect_get_endorsements_by_proposal_review_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Ok(vec![(
+                uuid_a(),
+                Endorsement {
+                    user_id: uuid_a(),
+                    ..endorsement.clone()
+                },
+            )]));
+        e_repository_mock
+            .expect_create_endorsement()
+            .once()
+            .with(eq(endorsement.clone()))
+            .return_const(Ok(id));
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            pr_repository_mock,
+            prc_repository_mock,
+            u_repository_mock,
+        );
+
+        let result = service
+            .create_endorsement(
+                calling_principal,
+                CreateEndorsementRequest {
+                    proposal_review_id: proposal_review_id.to_string(),
+                    proposal_review_commit_id: proposal_review_commit_id.map(|id| id.to_string()),
+                    note: endorsement.note.clone(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result, map_endorsement(id, endorsement));
+    }
+
+    #[rstest]
+    #[case::draft(
+        ProposalReview {
+            user_id: uuid_a(),
+            ..fixtures::proposal_review_draft()
+        },
+        ApiError::conflict(&format!(
+            "Proposal review with Id {} is not published",
+            fixtures::proposal_review_id()
+        ))
+    )]
+    #[case::own_review(
+        ProposalReview {
+            user_id: uuid_b(),
+            ..fixtures::proposal_review_published()
+        },
+        ApiError::conflict("Cannot endorse your own proposal review")
+    )]
+    fn create_endorsement_invalid_proposal_review(
+        #[case] proposal_review: ProposalReview,
+        #[case] error: ApiError,
+    ) {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_id = fixtures::proposal_review_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(proposal_review));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock.expect_create_endorsement().never();
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            pr_repository_mock,
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+        );
+
+        let result = service
+            .create_endorsement(
+                calling_principal,
+                CreateEndorsementRequest {
+                    proposal_review_id: proposal_review_id.to_string(),
+                    proposal_review_commit_id: None,
+                    note: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn create_endorsement_already_endorsed() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = uuid_b();
+        let proposal_review_id = fixtures::proposal_review_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_published()));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock
+            .expect_get_endorsements_by_proposal_review_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Ok(vec![(
+                uuid_a(),
+                fixtures::endorsement_proposal_review(),
+            )]));
+        e_repository_mock.expect_create_endorsement().never();
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            pr_repository_mock,
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+        );
+
+        let result = service
+            .create_endorsement(
+                calling_principal,
+                CreateEndorsementRequest {
+                    proposal_review_id: proposal_review_id.to_string(),
+                    proposal_review_commit_id: None,
+                    note: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with Id {} has already endorsed this assessment",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn create_endorsement_commit_not_reviewed() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_id = fixtures::proposal_review_id();
+        let proposal_review_commit_id = fixtures::proposal_review_commit_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_published()));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commit_by_id()
+            .once()
+            .with(eq(proposal_review_commit_id))
+            .return_const(Some(fixtures::proposal_review_commit_not_reviewed()));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock.expect_create_endorsement().never();
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            pr_repository_mock,
+            prc_repository_mock,
+            u_repository_mock,
+        );
+
+        let result = service
+            .create_endorsement(
+                calling_principal,
+                CreateEndorsementRequest {
+                    proposal_review_id: proposal_review_id.to_string(),
+                    proposal_review_commit_id: Some(proposal_review_commit_id.to_string()),
+                    note: None,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal review commit with Id {} is not reviewed",
+                proposal_review_commit_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn delete_endorsement() {
+        let calling_principal = fixtures::principal_a();
+        let id = fixtures::uuid();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock
+            .expect_get_endorsement_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::endorsement_proposal_review()));
+        e_repository_mock
+            .expect_delete_endorsement()
+            .once()
+            .with(eq(id))
+            .return_const(Ok(()));
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+        );
+
+        service
+            .delete_endorsement(
+                calling_principal,
+                DeleteEndorsementRequest { id: id.to_string() },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_endorsement_other_user() {
+        let calling_principal = fixtures::principal_a();
+        let id = fixtures::uuid();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_a()));
+
+        let mut e_repository_mock = MockEndorsementRepository::new();
+        e_repository_mock
+            .expect_get_endorsement_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::endorsement_proposal_review()));
+        e_repository_mock.expect_delete_endorsement().never();
+
+        let service = EndorsementServiceImpl::new(
+            e_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+        );
+
+        let result = service
+            .delete_endorsement(
+                calling_principal,
+                DeleteEndorsementRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!("Endorsement with Id {} not found", id))
+        );
+    }
+}
@@ -1,9 +1,10 @@
+use backend_api::ApiError;
 use ic_http_certification::{HttpRequest, HttpResponse};
 
 use crate::{
-    helpers::create_image_http_response,
+    helpers::{create_attachment_http_response, create_image_http_response},
     repositories::{
-        CertificationRepository, CertificationRepositoryImpl, ImageId, ImageRepository,
+        CertificationRepository, CertificationRepositoryImpl, Image, ImageId, ImageRepository,
         ImageRepositoryImpl,
     },
 };
@@ -15,7 +16,7 @@ pub trait ImageService {
         req: &'a HttpRequest<'a>,
     ) -> Option<HttpResponse<'static>>;
 
-    /// Certifies all images responses.
+    /// Certifies all images responses, attachments included.
     ///
     /// Use this method during canister upgrades.
     fn certify_all_http_responses(&self);
@@ -45,7 +46,7 @@ impl<I: ImageRepository, C: CertificationRepository> ImageService for ImageServi
             .and_then(|s| ImageId::try_from(s).ok())?;
 
         let image = self.image_repository.get_image_by_id(&image_id)?;
-        let image_http_response = create_image_http_response(image);
+        let image_http_response = create_http_response(image).ok()?;
 
         let certified_response = self.certification_repository.get_certified_http_response(
             &req_path,
@@ -59,9 +60,12 @@ impl<I: ImageRepository, C: CertificationRepository> ImageService for ImageServi
     fn certify_all_http_responses(&self) {
         for (image_id, image) in self.image_repository.get_all_images() {
             let image_path = image.path(&image_id);
-            let image_http_response = create_image_http_response(image);
-            self.certification_repository
-                .certify_http_response(&image_path, &image_http_response);
+            // attachments that cannot be decoded are not served,
+            // so there is nothing to certify for them
+            if let Ok(image_http_response) = create_http_response(image) {
+                self.certification_repository
+                    .certify_http_response(&image_path, &image_http_response);
+            }
         }
     }
 }
@@ -74,3 +78,11 @@ impl<I: ImageRepository, C: CertificationRepository> ImageServiceImpl<I, C> {
         }
     }
 }
+
+fn create_http_response(image: Image) -> Result<HttpResponse<'static>, ApiError> {
+    if image.attachment.is_some() {
+        create_attachment_http_response(image)
+    } else {
+        Ok(create_image_http_response(image))
+    }
+}
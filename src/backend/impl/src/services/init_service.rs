@@ -20,6 +20,9 @@ impl Default for InitServiceImpl<UserProfileRepositoryImpl> {
 
 impl<T: UserProfileRepository> InitService for InitServiceImpl<T> {
     fn init(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        self.user_profile_repository.index_usernames();
+        self.user_profile_repository.index_user_principals()?;
+
         if self
             .user_profile_repository
             .get_user_id_by_principal(&calling_principal)
@@ -59,6 +62,14 @@ mod tests {
         let profile = UserProfile::new_admin();
 
         let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_index_usernames()
+            .once()
+            .return_const(());
+        repository_mock
+            .expect_index_user_principals()
+            .once()
+            .return_const(Ok(()));
         repository_mock
             .expect_get_user_id_by_principal()
             .once()
@@ -81,6 +92,14 @@ mod tests {
         let id = fixtures::user_id();
 
         let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_index_usernames()
+            .once()
+            .return_const(());
+        repository_mock
+            .expect_index_user_principals()
+            .once()
+            .return_const(Ok(()));
         repository_mock
             .expect_get_user_id_by_principal()
             .once()
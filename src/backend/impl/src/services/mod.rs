@@ -1,23 +1,61 @@
 mod access_control_service;
+mod admin_sync_service;
+mod approval_service;
+mod audit_log_service;
+mod comment_service;
+mod commit_claim_service;
 #[cfg(feature = "dev")]
 mod dev_service;
+mod endorsement_service;
 mod http_service;
 mod image_service;
 mod init_service;
 mod log_service;
+mod neuron_verification_service;
+mod principal_link_service;
 mod proposal_review_commit_service;
 mod proposal_review_service;
 mod proposal_service;
+mod review_conflict_service;
+mod review_score_service;
+mod reviewer_application_service;
+mod reviewer_assignment_service;
+mod reviewer_profile_service;
+mod reward_payout_service;
+mod reward_service;
+mod role_service;
+mod user_avatar_service;
+mod user_directory_service;
 mod user_profile_service;
+mod vote_alignment_service;
 
 pub use access_control_service::*;
+pub use admin_sync_service::*;
+pub use approval_service::*;
+pub use audit_log_service::*;
+pub use comment_service::*;
+pub use commit_claim_service::*;
 #[cfg(feature = "dev")]
 pub use dev_service::*;
+pub use endorsement_service::*;
 pub use http_service::*;
 pub use image_service::*;
 pub use init_service::*;
 pub use log_service::*;
+pub use neuron_verification_service::*;
+pub use principal_link_service::*;
 pub use proposal_review_commit_service::*;
 pub use proposal_review_service::*;
 pub use proposal_service::*;
+pub use review_conflict_service::*;
+pub use review_score_service::*;
+pub use reviewer_application_service::*;
+pub use reviewer_assignment_service::*;
+pub use reviewer_profile_service::*;
+pub use reward_payout_service::*;
+pub use reward_service::*;
+pub use role_service::*;
+pub use user_avatar_service::*;
+pub use user_directory_service::*;
 pub use user_profile_service::*;
+pub use vote_alignment_service::*;
@@ -0,0 +1,709 @@
+use std::str::FromStr;
+
+use crate::{
+    mappings::map_neuron_verification,
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, NeuronVerification,
+        NeuronVerificationRepository, NeuronVerificationRepositoryImpl, NeuronVerificationStatus,
+        UserConfig, UserId, UserProfile, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, GetNeuronVerificationRequest, GetNeuronVerificationResponse, VerifyMyNeuronResponse,
+    VerifyReviewerNeuronRequest, VerifyReviewerNeuronResponse,
+};
+use candid::Principal;
+use external_canisters::nns::{GovernanceCanisterService, NeuronReader};
+
+use super::NNS_GOVERNANCE_CANISTER_ID;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait NeuronVerificationService {
+    async fn verify_my_neuron(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<VerifyMyNeuronResponse, ApiError>;
+
+    async fn verify_reviewer_neuron(
+        &self,
+        calling_principal: Principal,
+        request: VerifyReviewerNeuronRequest,
+    ) -> Result<VerifyReviewerNeuronResponse, ApiError>;
+
+    fn get_neuron_verification(
+        &self,
+        request: GetNeuronVerificationRequest,
+    ) -> Result<GetNeuronVerificationResponse, ApiError>;
+
+    /// Verifies the neurons of the reviewers that were never verified,
+    /// were verified too long ago or changed their neuron since.
+    /// A reviewer whose neuron cannot be verified is reported in the result
+    /// and does not stop the remaining reviewers from being verified.
+    async fn reverify_neurons(&self) -> Result<ReverifyNeuronsResult, ApiError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverifyNeuronsResult {
+    pub verified_neurons_count: usize,
+    pub failures: Vec<(UserId, ApiError)>,
+}
+
+pub struct NeuronVerificationServiceImpl<
+    NV: NeuronVerificationRepository,
+    U: UserProfileRepository,
+    N: NeuronReader,
+    AL: AuditLogService,
+> {
+    neuron_verification_repository: NV,
+    user_profile_repository: U,
+    neuron_reader: N,
+    audit_log_service: AL,
+}
+
+impl Default
+    for NeuronVerificationServiceImpl<
+        NeuronVerificationRepositoryImpl,
+        UserProfileRepositoryImpl,
+        GovernanceCanisterService,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            NeuronVerificationRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            GovernanceCanisterService(Principal::from_str(NNS_GOVERNANCE_CANISTER_ID).unwrap()),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        NV: NeuronVerificationRepository,
+        U: UserProfileRepository,
+        N: NeuronReader,
+        AL: AuditLogService,
+    > NeuronVerificationService for NeuronVerificationServiceImpl<NV, U, N, AL>
+{
+    async fn verify_my_neuron(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<VerifyMyNeuronResponse, ApiError> {
+        let (user_id, user_profile) = self
+            .user_profile_repository
+            .get_user_profile_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        let neuron_verification = self.verify_neuron(user_id, user_profile).await?;
+
+        Ok(map_neuron_verification(user_id, neuron_verification))
+    }
+
+    async fn verify_reviewer_neuron(
+        &self,
+        calling_principal: Principal,
+        request: VerifyReviewerNeuronRequest,
+    ) -> Result<VerifyReviewerNeuronResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let user_profile = self
+            .user_profile_repository
+            .get_user_profile_by_user_id(&user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for user with id {} not found",
+                    user_id
+                ))
+            })?;
+        let previous_verification = self
+            .neuron_verification_repository
+            .get_neuron_verification_by_user_id(&user_id);
+
+        let neuron_verification = self.verify_neuron(user_id, user_profile).await?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::VerifyReviewerNeuron,
+            vec![user_id.to_string()],
+            previous_verification.as_ref().map(audit_snapshot),
+            Some(audit_snapshot(&neuron_verification)),
+        )?;
+
+        Ok(map_neuron_verification(user_id, neuron_verification))
+    }
+
+    fn get_neuron_verification(
+        &self,
+        request: GetNeuronVerificationRequest,
+    ) -> Result<GetNeuronVerificationResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let neuron_verification = self
+            .neuron_verification_repository
+            .get_neuron_verification_by_user_id(&user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Neuron verification for user with id {} not found",
+                    user_id
+                ))
+            })?;
+
+        Ok(map_neuron_verification(user_id, neuron_verification))
+    }
+
+    async fn reverify_neurons(&self) -> Result<ReverifyNeuronsResult, ApiError> {
+        let now = DateTime::new(get_date_time()?)?;
+        let mut verified_neurons_count = 0;
+        let mut failures = vec![];
+
+        for (user_id, user_profile) in self.user_profile_repository.get_all_reviewer_profiles() {
+            let UserConfig::Reviewer { neuron_id, .. } = user_profile.config else {
+                continue;
+            };
+
+            let is_up_to_date = self
+                .neuron_verification_repository
+                .get_neuron_verification_by_user_id(&user_id)
+                .is_some_and(|neuron_verification| {
+                    neuron_verification.neuron_id == neuron_id
+                        && !neuron_verification.is_stale(&now)
+                });
+            if is_up_to_date {
+                continue;
+            }
+
+            match self.verify_neuron(user_id, user_profile).await {
+                Ok(_) => verified_neurons_count += 1,
+                Err(err) => failures.push((user_id, err)),
+            }
+        }
+
+        Ok(ReverifyNeuronsResult {
+            verified_neurons_count,
+            failures,
+        })
+    }
+}
+
+impl<
+        NV: NeuronVerificationRepository,
+        U: UserProfileRepository,
+        N: NeuronReader,
+        AL: AuditLogService,
+    > NeuronVerificationServiceImpl<NV, U, N, AL>
+{
+    fn new(
+        neuron_verification_repository: NV,
+        user_profile_repository: U,
+        neuron_reader: N,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            neuron_verification_repository,
+            user_profile_repository,
+            neuron_reader,
+            audit_log_service,
+        }
+    }
+
+    /// Checks that the reviewer's neuron is controlled by, or has a hot key of,
+    /// one of the reviewer's principals, and stores the outcome.
+    async fn verify_neuron(
+        &self,
+        user_id: UserId,
+        user_profile: UserProfile,
+    ) -> Result<NeuronVerification, ApiError> {
+        let UserConfig::Reviewer { neuron_id, .. } = user_profile.config else {
+            return Err(ApiError::conflict(&format!(
+                "User with id {} must be a reviewer to verify their neuron",
+                user_id
+            )));
+        };
+
+        let neuron_access = self
+            .neuron_reader
+            .get_neuron_access(neuron_id)
+            .await
+            .map_err(|err| {
+                ApiError::internal(&format!("Failed to fetch neuron {}: {:?}", neuron_id, err))
+            })?;
+        let principals = self
+            .user_profile_repository
+            .get_principals_by_user_id(&user_id)?;
+
+        let status = match neuron_access {
+            None => NeuronVerificationStatus::Unverified {
+                reason: format!("Neuron {} was not found or is not public", neuron_id),
+            },
+            Some(neuron_access)
+                if principals
+                    .iter()
+                    .any(|principal| neuron_access.is_controlled_by(principal)) =>
+            {
+                NeuronVerificationStatus::Verified
+            }
+            Some(_) => NeuronVerificationStatus::Unverified {
+                reason: format!("Neuron {} is not controlled by the reviewer", neuron_id),
+            },
+        };
+
+        let now = DateTime::new(get_date_time()?)?;
+        let verified_at = match status {
+            NeuronVerificationStatus::Verified => Some(now),
+            NeuronVerificationStatus::Unverified { .. } => self
+                .neuron_verification_repository
+                .get_neuron_verification_by_user_id(&user_id)
+                .filter(|neuron_verification| neuron_verification.neuron_id == neuron_id)
+                .and_then(|neuron_verification| neuron_verification.verified_at),
+        };
+
+        let neuron_verification = NeuronVerification {
+            neuron_id,
+            status,
+            checked_at: now,
+            verified_at,
+        };
+        self.neuron_verification_repository
+            .upsert_neuron_verification(user_id, neuron_verification.clone())?;
+
+        Ok(neuron_verification)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{MockNeuronVerificationRepository, MockUserProfileRepository},
+        services::MockAuditLogService,
+    };
+    use external_canisters::nns::{CanisterResult, NeuronAccess};
+    use ic_cdk::api::call::RejectionCode;
+    use mockall::predicate::*;
+    use rstest::*;
+    use std::{cell::RefCell, collections::VecDeque};
+
+    struct FakeNeuronReader(CanisterResult<Option<NeuronAccess>>);
+
+    impl NeuronReader for FakeNeuronReader {
+        async fn get_neuron_access(&self, _neuron_id: u64) -> CanisterResult<Option<NeuronAccess>> {
+            self.0.clone()
+        }
+    }
+
+    /// Answers each call with the next of the given results.
+    struct FakeNeuronReaderSequence(RefCell<VecDeque<CanisterResult<Option<NeuronAccess>>>>);
+
+    impl NeuronReader for FakeNeuronReaderSequence {
+        async fn get_neuron_access(&self, _neuron_id: u64) -> CanisterResult<Option<NeuronAccess>> {
+            self.0.borrow_mut().pop_front().unwrap()
+        }
+    }
+
+    fn neuron_access_controlled_by(principal: Principal) -> NeuronAccess {
+        NeuronAccess {
+            controller: Some(principal),
+            hot_keys: vec![],
+        }
+    }
+
+    fn neuron_access_with_hot_key(principal: Principal) -> NeuronAccess {
+        NeuronAccess {
+            controller: Some(fixtures::principal_c()),
+            hot_keys: vec![principal],
+        }
+    }
+
+    #[rstest]
+    #[case::controller(neuron_access_controlled_by(fixtures::principal_a()))]
+    #[case::hot_key(neuron_access_with_hot_key(fixtures::principal_a()))]
+    async fn verify_my_neuron(#[case] neuron_access: NeuronAccess) {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![calling_principal]));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .never();
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .once()
+            .withf(move |id, neuron_verification| {
+                *id == user_id
+                    && neuron_verification.is_verified(fixtures::neuron_id())
+                    && neuron_verification.verified_at == Some(neuron_verification.checked_at)
+            })
+            .return_const(Ok(()));
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReader(Ok(Some(neuron_access))),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.verify_my_neuron(calling_principal).await.unwrap();
+
+        assert_eq!(result.user_id, user_id.to_string());
+        assert_eq!(result.neuron_id, fixtures::neuron_id());
+        assert_eq!(
+            result.status,
+            backend_api::NeuronVerificationStatus::Verified
+        );
+    }
+
+    #[rstest]
+    #[case::not_controlled(
+        Some(neuron_access_controlled_by(fixtures::principal_b())),
+        format!("Neuron {} is not controlled by the reviewer", fixtures::neuron_id())
+    )]
+    #[case::not_found(
+        None,
+        format!("Neuron {} was not found or is not public", fixtures::neuron_id())
+    )]
+    async fn verify_reviewer_neuron_unverified(
+        #[case] neuron_access: Option<NeuronAccess>,
+        #[case] reason: String,
+    ) {
+        let user_id = fixtures::user_id();
+        let previous_verification = fixtures::neuron_verification_verified();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![fixtures::principal_a()]));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .times(2)
+            .with(eq(user_id))
+            .return_const(Some(previous_verification.clone()));
+        let expected_status = NeuronVerificationStatus::Unverified {
+            reason: reason.clone(),
+        };
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .once()
+            .withf(move |id, neuron_verification| {
+                *id == user_id
+                    && neuron_verification.status == expected_status
+                    && neuron_verification.verified_at == previous_verification.verified_at
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(fixtures::principal_b()),
+                eq(AuditAction::VerifyReviewerNeuron),
+                eq(vec![user_id.to_string()]),
+                eq(Some(audit_snapshot(
+                    &fixtures::neuron_verification_verified(),
+                ))),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReader(Ok(neuron_access)),
+            audit_log_service_mock,
+        );
+
+        let result = service
+            .verify_reviewer_neuron(
+                fixtures::principal_b(),
+                VerifyReviewerNeuronRequest {
+                    user_id: user_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.status,
+            backend_api::NeuronVerificationStatus::Unverified { reason }
+        );
+    }
+
+    #[rstest]
+    async fn verify_reviewer_neuron_not_reviewer() {
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::anonymous_user_profile()));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .never();
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReader(Ok(None)),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .verify_reviewer_neuron(
+                fixtures::principal_b(),
+                VerifyReviewerNeuronRequest {
+                    user_id: user_id.to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} must be a reviewer to verify their neuron",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    async fn verify_reviewer_neuron_governance_error() {
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .never();
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReader(Err((
+                RejectionCode::SysTransient,
+                "Canister is stopping".to_string(),
+            ))),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .verify_reviewer_neuron(
+                fixtures::principal_b(),
+                VerifyReviewerNeuronRequest {
+                    user_id: user_id.to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert!(result
+            .message()
+            .starts_with(&format!("Failed to fetch neuron {}", fixtures::neuron_id())));
+    }
+
+    #[rstest]
+    fn get_neuron_verification() {
+        let user_id = fixtures::user_id();
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::neuron_verification_verified()));
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            MockUserProfileRepository::new(),
+            FakeNeuronReader(Ok(None)),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .get_neuron_verification(GetNeuronVerificationRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            map_neuron_verification(user_id, fixtures::neuron_verification_verified())
+        );
+    }
+
+    #[rstest]
+    async fn reverify_neurons() {
+        let now = DateTime::new(get_date_time().unwrap()).unwrap();
+        let up_to_date_user_id = fixtures::uuid_a();
+        let stale_user_id = fixtures::uuid_b();
+        let changed_neuron_user_id = fixtures::uuid_c();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_all_reviewer_profiles()
+            .once()
+            .return_const(vec![
+                (up_to_date_user_id, fixtures::reviewer_user_profile()),
+                (stale_user_id, fixtures::reviewer_user_profile()),
+                (changed_neuron_user_id, fixtures::reviewer_user_profile()),
+            ]);
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .times(2)
+            .return_const(Ok(vec![fixtures::principal_a()]));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .with(eq(up_to_date_user_id))
+            .return_const(Some(NeuronVerification {
+                checked_at: now,
+                ..fixtures::neuron_verification_verified()
+            }));
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .with(eq(stale_user_id))
+            .return_const(Some(fixtures::neuron_verification_verified()));
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .with(eq(changed_neuron_user_id))
+            .return_const(Some(NeuronVerification {
+                neuron_id: fixtures::neuron_id() + 1,
+                checked_at: now,
+                ..fixtures::neuron_verification_verified()
+            }));
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .with(eq(stale_user_id), always())
+            .once()
+            .return_const(Ok(()));
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .with(eq(changed_neuron_user_id), always())
+            .once()
+            .return_const(Ok(()));
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReader(Ok(Some(neuron_access_controlled_by(
+                fixtures::principal_a(),
+            )))),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.reverify_neurons().await.unwrap();
+
+        assert_eq!(
+            result,
+            ReverifyNeuronsResult {
+                verified_neurons_count: 2,
+                failures: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    async fn reverify_neurons_continues_after_failure() {
+        let failing_user_id = fixtures::uuid_a();
+        let user_id = fixtures::uuid_b();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_all_reviewer_profiles()
+            .once()
+            .return_const(vec![
+                (failing_user_id, fixtures::reviewer_user_profile()),
+                (user_id, fixtures::reviewer_user_profile()),
+            ]);
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![fixtures::principal_a()]));
+
+        let mut nv_repository_mock = MockNeuronVerificationRepository::new();
+        nv_repository_mock
+            .expect_get_neuron_verification_by_user_id()
+            .return_const(None);
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .with(eq(failing_user_id), always())
+            .never();
+        nv_repository_mock
+            .expect_upsert_neuron_verification()
+            .with(eq(user_id), always())
+            .once()
+            .return_const(Ok(()));
+
+        let service = NeuronVerificationServiceImpl::new(
+            nv_repository_mock,
+            up_repository_mock,
+            FakeNeuronReaderSequence(RefCell::new(VecDeque::from([
+                Err((
+                    RejectionCode::SysTransient,
+                    "Canister is stopping".to_string(),
+                )),
+                Ok(Some(neuron_access_controlled_by(fixtures::principal_a()))),
+            ]))),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.reverify_neurons().await.unwrap();
+
+        assert_eq!(result.verified_neurons_count, 1);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].0, failing_user_id);
+        assert!(result.failures[0]
+            .1
+            .message()
+            .starts_with(&format!("Failed to fetch neuron {}", fixtures::neuron_id())));
+    }
+}
@@ -0,0 +1,626 @@
+use crate::{
+    mappings::map_get_my_principal_link_history_response,
+    repositories::{
+        DateTime, PrincipalLinkCode, PrincipalLinkCodeId, PrincipalLinkCodeRepository,
+        PrincipalLinkCodeRepositoryImpl, UserId, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, CreatePrincipalLinkCodeResponse, GetMyPrincipalLinkHistoryResponse,
+    ListMyPrincipalsResponse, RedeemPrincipalLinkCodeRequest, UnlinkMyPrincipalRequest,
+};
+use candid::Principal;
+use external_canisters::management::{ManagementCanisterService, RandomnessSource};
+
+const PRINCIPAL_LINK_CODE_DURATION_MINUTES: i64 = 10;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait PrincipalLinkService {
+    /// Creates a code that links another principal to the caller's profile.
+    /// Codes created earlier by the same user are invalidated.
+    /// The code is generated from the management canister's randomness,
+    /// so it cannot be guessed from other codes or ids.
+    async fn create_principal_link_code(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<CreatePrincipalLinkCodeResponse, ApiError>;
+
+    fn redeem_principal_link_code(
+        &self,
+        calling_principal: Principal,
+        request: RedeemPrincipalLinkCodeRequest,
+    ) -> Result<(), ApiError>;
+
+    fn list_my_principals(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<ListMyPrincipalsResponse, ApiError>;
+
+    fn unlink_my_principal(
+        &self,
+        calling_principal: Principal,
+        request: UnlinkMyPrincipalRequest,
+    ) -> Result<(), ApiError>;
+
+    fn get_my_principal_link_history(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPrincipalLinkHistoryResponse, ApiError>;
+}
+
+pub struct PrincipalLinkServiceImpl<
+    P: PrincipalLinkCodeRepository,
+    U: UserProfileRepository,
+    R: RandomnessSource,
+> {
+    principal_link_code_repository: P,
+    user_profile_repository: U,
+    randomness_source: R,
+}
+
+impl Default
+    for PrincipalLinkServiceImpl<
+        PrincipalLinkCodeRepositoryImpl,
+        UserProfileRepositoryImpl,
+        ManagementCanisterService,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            PrincipalLinkCodeRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            ManagementCanisterService,
+        )
+    }
+}
+
+impl<P: PrincipalLinkCodeRepository, U: UserProfileRepository, R: RandomnessSource>
+    PrincipalLinkService for PrincipalLinkServiceImpl<P, U, R>
+{
+    async fn create_principal_link_code(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<CreatePrincipalLinkCodeResponse, ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+        let random_bytes = self
+            .randomness_source
+            .get_random_bytes()
+            .await
+            .map_err(|err| ApiError::internal(&format!("Failed to get random bytes: {:?}", err)))?;
+        let mut id_bytes = [0u8; 16];
+        id_bytes.copy_from_slice(&random_bytes[..16]);
+        let id = PrincipalLinkCodeId::from_random_bytes(id_bytes);
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        for (id, _) in self
+            .principal_link_code_repository
+            .get_principal_link_codes_by_user_id(&user_id)
+        {
+            self.principal_link_code_repository
+                .delete_principal_link_code(&id)?;
+        }
+
+        let expires_at = current_time.add(chrono::Duration::minutes(
+            PRINCIPAL_LINK_CODE_DURATION_MINUTES,
+        ));
+        self.principal_link_code_repository
+            .create_principal_link_code(
+                id,
+                PrincipalLinkCode {
+                    user_id,
+                    created_by: calling_principal,
+                    created_at: current_time,
+                    expires_at,
+                },
+            )?;
+
+        Ok(CreatePrincipalLinkCodeResponse {
+            code: id.to_string(),
+            expires_at: expires_at.to_string(),
+        })
+    }
+
+    fn redeem_principal_link_code(
+        &self,
+        calling_principal: Principal,
+        request: RedeemPrincipalLinkCodeRequest,
+    ) -> Result<(), ApiError> {
+        let id = PrincipalLinkCodeId::try_from(request.code.as_str())
+            .map_err(|_| ApiError::invalid_argument("Invalid principal link code"))?;
+        let principal_link_code = self
+            .principal_link_code_repository
+            .get_principal_link_code_by_id(&id)
+            .ok_or_else(|| ApiError::not_found("Principal link code not found"))?;
+
+        // the code can only be redeemed once, whether linking succeeds or not
+        self.principal_link_code_repository
+            .delete_principal_link_code(&id)?;
+
+        let current_time = DateTime::new(get_date_time()?)?;
+        if !principal_link_code.is_active(&current_time) {
+            return Err(ApiError::invalid_argument(
+                "Principal link code has expired",
+            ));
+        }
+
+        if self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .is_some()
+        {
+            return Err(ApiError::conflict(&format!(
+                "Principal {} already has a user profile",
+                calling_principal.to_text()
+            )));
+        }
+
+        self.user_profile_repository.link_principal(
+            calling_principal,
+            principal_link_code.user_id,
+            calling_principal,
+        )
+    }
+
+    fn list_my_principals(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<ListMyPrincipalsResponse, ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+
+        Ok(ListMyPrincipalsResponse {
+            principals: self
+                .user_profile_repository
+                .get_principals_by_user_id(&user_id)?,
+        })
+    }
+
+    fn unlink_my_principal(
+        &self,
+        calling_principal: Principal,
+        request: UnlinkMyPrincipalRequest,
+    ) -> Result<(), ApiError> {
+        if request.principal == calling_principal {
+            return Err(ApiError::invalid_argument(
+                "The calling principal cannot unlink itself",
+            ));
+        }
+
+        let user_id = self.get_user_id(&calling_principal)?;
+
+        self.user_profile_repository
+            .unlink_principal(calling_principal, user_id, request.principal)
+    }
+
+    fn get_my_principal_link_history(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPrincipalLinkHistoryResponse, ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+        let history = self
+            .user_profile_repository
+            .get_principal_link_history_by_user_id(&user_id)?;
+
+        Ok(map_get_my_principal_link_history_response(history))
+    }
+}
+
+impl<P: PrincipalLinkCodeRepository, U: UserProfileRepository, R: RandomnessSource>
+    PrincipalLinkServiceImpl<P, U, R>
+{
+    fn new(
+        principal_link_code_repository: P,
+        user_profile_repository: U,
+        randomness_source: R,
+    ) -> Self {
+        Self {
+            principal_link_code_repository,
+            user_profile_repository,
+            randomness_source,
+        }
+    }
+
+    fn get_user_id(&self, calling_principal: &Principal) -> Result<UserId, ApiError> {
+        self.user_profile_repository
+            .get_user_id_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{
+            HistoryAction, MockPrincipalLinkCodeRepository, MockUserProfileRepository,
+            PrincipalLink, PrincipalLinkHistoryEntry,
+        },
+    };
+    use external_canisters::nns::CanisterResult;
+    use ic_cdk::api::call::RejectionCode;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    struct FakeRandomnessSource(CanisterResult<[u8; 32]>);
+
+    impl RandomnessSource for FakeRandomnessSource {
+        async fn get_random_bytes(&self) -> CanisterResult<[u8; 32]> {
+            self.0.clone()
+        }
+    }
+
+    #[rstest]
+    async fn create_principal_link_code() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let previous_code_id = fixtures::uuid_a();
+        let code_id = PrincipalLinkCodeId::from_random_bytes([7; 16]);
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_codes_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(vec![(previous_code_id, fixtures::principal_link_code())]);
+        plc_repository_mock
+            .expect_delete_principal_link_code()
+            .once()
+            .with(eq(previous_code_id))
+            .return_const(Ok(()));
+        plc_repository_mock
+            .expect_create_principal_link_code()
+            .once()
+            .withf(move |id, principal_link_code| {
+                *id == code_id
+                    && principal_link_code.user_id == user_id
+                    && principal_link_code.created_by == calling_principal
+                    && principal_link_code.expires_at
+                        == principal_link_code
+                            .created_at
+                            .add(chrono::Duration::minutes(
+                                PRINCIPAL_LINK_CODE_DURATION_MINUTES,
+                            ))
+            })
+            .return_const(Ok(()));
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            up_repository_mock,
+            FakeRandomnessSource(Ok([7; 32])),
+        );
+
+        let result = service
+            .create_principal_link_code(calling_principal)
+            .await
+            .unwrap();
+
+        assert_eq!(result.code, code_id.to_string());
+    }
+
+    #[rstest]
+    async fn create_principal_link_code_randomness_error() {
+        let calling_principal = fixtures::principal_a();
+        let error = (RejectionCode::CanisterError, "Out of cycles".to_string());
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::user_id()));
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_codes_by_user_id()
+            .never();
+        plc_repository_mock
+            .expect_create_principal_link_code()
+            .never();
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            up_repository_mock,
+            FakeRandomnessSource(Err(error.clone())),
+        );
+
+        let result = service
+            .create_principal_link_code(calling_principal)
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::internal(&format!("Failed to get random bytes: {:?}", error))
+        );
+    }
+
+    #[rstest]
+    fn redeem_principal_link_code() {
+        let calling_principal = fixtures::principal_b();
+        let code_id = fixtures::uuid_a();
+        let principal_link_code = PrincipalLinkCode {
+            expires_at: DateTime::new(get_date_time().unwrap())
+                .unwrap()
+                .add(chrono::Duration::minutes(5)),
+            ..fixtures::principal_link_code()
+        };
+        let user_id = principal_link_code.user_id;
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_code_by_id()
+            .once()
+            .with(eq(code_id))
+            .return_const(Some(principal_link_code));
+        plc_repository_mock
+            .expect_delete_principal_link_code()
+            .once()
+            .with(eq(code_id))
+            .return_const(Ok(()));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(None);
+        up_repository_mock
+            .expect_link_principal()
+            .once()
+            .with(eq(calling_principal), eq(user_id), eq(calling_principal))
+            .return_const(Ok(()));
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        service
+            .redeem_principal_link_code(
+                calling_principal,
+                RedeemPrincipalLinkCodeRequest {
+                    code: code_id.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn redeem_principal_link_code_expired() {
+        let calling_principal = fixtures::principal_b();
+        let code_id = fixtures::uuid_a();
+        let principal_link_code = PrincipalLinkCode {
+            expires_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            ..fixtures::principal_link_code()
+        };
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_code_by_id()
+            .once()
+            .with(eq(code_id))
+            .return_const(Some(principal_link_code));
+        plc_repository_mock
+            .expect_delete_principal_link_code()
+            .once()
+            .with(eq(code_id))
+            .return_const(Ok(()));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock.expect_link_principal().never();
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        let result = service
+            .redeem_principal_link_code(
+                calling_principal,
+                RedeemPrincipalLinkCodeRequest {
+                    code: code_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("Principal link code has expired")
+        );
+    }
+
+    #[rstest]
+    fn redeem_principal_link_code_existing_profile() {
+        let calling_principal = fixtures::principal_b();
+        let code_id = fixtures::uuid_a();
+        let principal_link_code = PrincipalLinkCode {
+            expires_at: DateTime::new(get_date_time().unwrap())
+                .unwrap()
+                .add(chrono::Duration::minutes(5)),
+            ..fixtures::principal_link_code()
+        };
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_code_by_id()
+            .once()
+            .return_const(Some(principal_link_code));
+        plc_repository_mock
+            .expect_delete_principal_link_code()
+            .once()
+            .return_const(Ok(()));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_b()));
+        up_repository_mock.expect_link_principal().never();
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        let result = service
+            .redeem_principal_link_code(
+                calling_principal,
+                RedeemPrincipalLinkCodeRequest {
+                    code: code_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Principal {} already has a user profile",
+                calling_principal.to_text()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn redeem_principal_link_code_not_found() {
+        let code_id = fixtures::uuid_a();
+
+        let mut plc_repository_mock = MockPrincipalLinkCodeRepository::new();
+        plc_repository_mock
+            .expect_get_principal_link_code_by_id()
+            .once()
+            .with(eq(code_id))
+            .return_const(None);
+        plc_repository_mock
+            .expect_delete_principal_link_code()
+            .never();
+
+        let service = PrincipalLinkServiceImpl::new(
+            plc_repository_mock,
+            MockUserProfileRepository::new(),
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        let result = service
+            .redeem_principal_link_code(
+                fixtures::principal_b(),
+                RedeemPrincipalLinkCodeRequest {
+                    code: code_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::not_found("Principal link code not found"));
+    }
+
+    #[rstest]
+    fn unlink_my_principal() {
+        let calling_principal = fixtures::principal_a();
+        let principal = fixtures::principal_b();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        up_repository_mock
+            .expect_unlink_principal()
+            .once()
+            .with(eq(calling_principal), eq(user_id), eq(principal))
+            .return_const(Ok(()));
+
+        let service = PrincipalLinkServiceImpl::new(
+            MockPrincipalLinkCodeRepository::new(),
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        service
+            .unlink_my_principal(calling_principal, UnlinkMyPrincipalRequest { principal })
+            .unwrap();
+    }
+
+    #[rstest]
+    fn unlink_my_principal_calling_principal() {
+        let calling_principal = fixtures::principal_a();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock.expect_unlink_principal().never();
+
+        let service = PrincipalLinkServiceImpl::new(
+            MockPrincipalLinkCodeRepository::new(),
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        let result = service
+            .unlink_my_principal(
+                calling_principal,
+                UnlinkMyPrincipalRequest {
+                    principal: calling_principal,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("The calling principal cannot unlink itself")
+        );
+    }
+
+    #[rstest]
+    fn get_my_principal_link_history() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let history = vec![PrincipalLinkHistoryEntry {
+            action: HistoryAction::Create,
+            date_time: fixtures::date_time_a(),
+            principal: calling_principal,
+            data: PrincipalLink {
+                principal: fixtures::principal_b(),
+            },
+        }];
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        up_repository_mock
+            .expect_get_principal_link_history_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(history.clone()));
+
+        let service = PrincipalLinkServiceImpl::new(
+            MockPrincipalLinkCodeRepository::new(),
+            up_repository_mock,
+            FakeRandomnessSource(Ok([0; 32])),
+        );
+
+        let result = service
+            .get_my_principal_link_history(calling_principal)
+            .unwrap();
+
+        assert_eq!(result, map_get_my_principal_link_history_response(history));
+    }
+}
@@ -1,30 +1,42 @@
 use crate::{
-    helpers::create_image_http_response,
+    helpers::{create_attachment_http_response, create_image_http_response},
     mappings::map_proposal_review,
     repositories::{
-        CertificationRepository, CertificationRepositoryImpl, CreateImageRequest, DateTime, Image,
-        ImageRepository, ImageRepositoryImpl, Proposal, ProposalId, ProposalRepository,
+        image_path, AttachmentEncoding, CertificationRepository, CertificationRepositoryImpl,
+        CreateAttachmentRequest, CreateImageRequest, DateTime, Endorsement, EndorsementId,
+        EndorsementRepository, EndorsementRepositoryImpl, Image, ImageAttachment, ImageRepository,
+        ImageRepositoryImpl, Permission, Proposal, ProposalId, ProposalRepository,
         ProposalRepositoryImpl, ProposalReview, ProposalReviewCommit, ProposalReviewCommitId,
         ProposalReviewCommitRepository, ProposalReviewCommitRepositoryImpl, ProposalReviewId,
         ProposalReviewRepository, ProposalReviewRepositoryImpl, ProposalReviewStatus, ProposalVote,
-        UserId, UserProfileRepository, UserProfileRepositoryImpl,
+        RoleRepositoryImpl, UserId, UserProfileRepository, UserProfileRepositoryImpl,
     },
+    services::{AccessControlService, AccessControlServiceImpl},
     system_api::get_date_time,
 };
 use backend_api::{
-    ApiError, CreateProposalReviewImageRequest, CreateProposalReviewImageResponse,
-    CreateProposalReviewRequest, CreateProposalReviewResponse, DeleteProposalReviewImageRequest,
+    ApiError, CreateProposalReviewAttachmentRequest, CreateProposalReviewAttachmentResponse,
+    CreateProposalReviewImageRequest, CreateProposalReviewImageResponse,
+    CreateProposalReviewRequest, CreateProposalReviewResponse,
+    DeleteProposalReviewAttachmentRequest, DeleteProposalReviewImageRequest,
     GetMyProposalReviewRequest, GetMyProposalReviewResponse, GetMyProposalReviewSummaryRequest,
     GetMyProposalReviewSummaryResponse, GetProposalReviewRequest, GetProposalReviewResponse,
     ListProposalReviewsRequest, ListProposalReviewsResponse, UpdateProposalReviewRequest,
 };
 use candid::Principal;
-use std::{path::PathBuf, str::FromStr};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 const MAX_PROPOSAL_REVIEW_SUMMARY_CHARS: usize = 1500;
 
 const PROPOSAL_REVIEW_IMAGES_SUB_PATH: &str = "reviews";
 
+const MAX_PROPOSAL_REVIEW_ATTACHMENTS: usize = 5;
+
+const PROPOSAL_REVIEW_ATTACHMENTS_SUB_PATH: &str = "reviews/attachments";
+
 #[cfg_attr(test, mockall::automock)]
 pub trait ProposalReviewService {
     fn create_proposal_review(
@@ -63,6 +75,18 @@ pub trait ProposalReviewService {
         request: DeleteProposalReviewImageRequest,
     ) -> Result<(), ApiError>;
 
+    fn create_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: CreateProposalReviewAttachmentRequest,
+    ) -> Result<CreateProposalReviewAttachmentResponse, ApiError>;
+
+    fn delete_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteProposalReviewAttachmentRequest,
+    ) -> Result<(), ApiError>;
+
     fn get_my_proposal_review(
         &self,
         calling_principal: Principal,
@@ -83,6 +107,8 @@ pub struct ProposalReviewServiceImpl<
     PRC: ProposalReviewCommitRepository,
     I: ImageRepository,
     C: CertificationRepository,
+    E: EndorsementRepository,
+    AC: AccessControlService,
 > {
     proposal_review_repository: PR,
     user_profile_repository: U,
@@ -90,6 +116,8 @@ pub struct ProposalReviewServiceImpl<
     proposal_review_commit_repository: PRC,
     image_repository: I,
     certification_repository: C,
+    endorsement_repository: E,
+    access_control_service: AC,
 }
 
 impl Default
@@ -100,6 +128,8 @@ impl Default
         ProposalReviewCommitRepositoryImpl,
         ImageRepositoryImpl,
         CertificationRepositoryImpl,
+        EndorsementRepositoryImpl,
+        AccessControlServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl>,
     >
 {
     fn default() -> Self {
@@ -110,6 +140,8 @@ impl Default
             ProposalReviewCommitRepositoryImpl::default(),
             ImageRepositoryImpl::default(),
             CertificationRepositoryImpl::default(),
+            EndorsementRepositoryImpl::default(),
+            AccessControlServiceImpl::default(),
         )
     }
 }
@@ -121,7 +153,9 @@ impl<
         PRC: ProposalReviewCommitRepository,
         I: ImageRepository,
         C: CertificationRepository,
-    > ProposalReviewService for ProposalReviewServiceImpl<PR, U, P, PRC, I, C>
+        E: EndorsementRepository,
+        AC: AccessControlService,
+    > ProposalReviewService for ProposalReviewServiceImpl<PR, U, P, PRC, I, C, E, AC>
 {
     fn create_proposal_review(
         &self,
@@ -184,13 +218,22 @@ impl<
             vote: request
                 .vote
                 .map_or(ProposalVote::Unspecified, |vote| vote.into()),
+            attachments_ids: None,
+            published_at: None,
         };
 
         let id = self
             .proposal_review_repository
             .create_proposal_review(proposal_review.clone())?;
 
-        Ok(map_proposal_review(id, proposal_review, vec![], vec![]))
+        Ok(map_proposal_review(
+            id,
+            proposal_review,
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        ))
     }
 
     fn update_proposal_review(
@@ -230,6 +273,10 @@ impl<
                         e.message()
                     ))
                 })?;
+
+                if current_proposal_review.published_at.is_none() {
+                    current_proposal_review.published_at = Some(DateTime::new(get_date_time()?)?);
+                }
             }
 
             current_proposal_review.status = status.into();
@@ -243,9 +290,10 @@ impl<
         calling_principal: Principal,
         request: ListProposalReviewsRequest,
     ) -> Result<ListProposalReviewsResponse, ApiError> {
-        let calling_user = self
+        let calling_user_id = self
             .user_profile_repository
-            .get_user_profile_by_principal(&calling_principal);
+            .get_user_id_by_principal(&calling_principal);
+        let can_moderate = self.can_moderate(&calling_principal);
 
         // match filters
         let proposal_reviews = match (request.proposal_id, request.user_id) {
@@ -273,13 +321,10 @@ impl<
         let proposal_reviews = proposal_reviews
             .iter()
             .filter_map(|(proposal_review_id, proposal_review)| {
-                // if the proposal review is in draft, only allow the owner and admins to see it
+                // if the proposal review is in draft, only allow the owner and moderators to see it
                 if proposal_review.is_draft()
-                    && !calling_user
-                        .as_ref()
-                        .is_some_and(|(user_id, user_profile)| {
-                            user_id == &proposal_review.user_id || user_profile.is_admin()
-                        })
+                    && calling_user_id != Some(proposal_review.user_id)
+                    && !can_moderate
                 {
                     return None;
                 }
@@ -297,9 +342,9 @@ impl<
         calling_principal: Principal,
         request: GetProposalReviewRequest,
     ) -> Result<GetProposalReviewResponse, ApiError> {
-        let calling_user = self
+        let calling_user_id = self
             .user_profile_repository
-            .get_user_profile_by_principal(&calling_principal);
+            .get_user_id_by_principal(&calling_principal);
 
         let proposal_review_id = ProposalReviewId::try_from(request.proposal_review_id.as_str())?;
 
@@ -313,11 +358,10 @@ impl<
                 ))
             })?;
 
-        // if the proposal review is in draft, only allow the owner and admins to see it
+        // if the proposal review is in draft, only allow the owner and moderators to see it
         if proposal_review.is_draft()
-            && !calling_user.is_some_and(|(user_id, user_profile)| {
-                user_id == proposal_review.user_id || user_profile.is_admin()
-            })
+            && calling_user_id != Some(proposal_review.user_id)
+            && !self.can_moderate(&calling_principal)
         {
             return Err(ApiError::permission_denied("Not authorized"));
         }
@@ -419,6 +463,119 @@ impl<
         Ok(())
     }
 
+    fn create_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: CreateProposalReviewAttachmentRequest,
+    ) -> Result<CreateProposalReviewAttachmentResponse, ApiError> {
+        request.validate_fields()?;
+
+        let (id, mut current_proposal_review, user_id) = self
+            .get_current_proposal_review_with_user_id(
+                request.proposal_id.clone(),
+                &calling_principal,
+                None,
+            )?;
+
+        if current_proposal_review.attachments_ids().len() >= MAX_PROPOSAL_REVIEW_ATTACHMENTS {
+            return Err(ApiError::conflict(&format!(
+                "Proposal review for proposal with Id {} already has {} attachments",
+                request.proposal_id, MAX_PROPOSAL_REVIEW_ATTACHMENTS
+            )));
+        }
+
+        let sub_path = PathBuf::from_str(PROPOSAL_REVIEW_ATTACHMENTS_SUB_PATH).unwrap();
+
+        let date_time = get_date_time()?;
+
+        let content_type = request.content_type();
+        let content_encoding = AttachmentEncoding::for_content_type(&content_type);
+        let attachment = Image {
+            created_at: DateTime::new(date_time)?,
+            user_id,
+            content_type,
+            sub_path: Some(sub_path),
+            content_bytes: content_encoding.encode(&request.content_bytes()),
+            attachment: Some(ImageAttachment {
+                file_name: request.file_name(),
+                content_encoding,
+            }),
+        };
+        let attachment_http_response = create_attachment_http_response(attachment.clone())?;
+
+        let attachment_id = self.image_repository.create_image(attachment.clone());
+        let attachment_path = attachment.path(&attachment_id);
+
+        current_proposal_review
+            .attachments_ids
+            .get_or_insert_with(Vec::new)
+            .push(attachment_id);
+
+        // the attachment is only certified once the review references it,
+        // otherwise it is removed so that it is not left orphaned
+        if let Err(err) = self.save_proposal_review(id, current_proposal_review) {
+            self.image_repository.delete_image(&attachment_id)?;
+            return Err(err);
+        }
+
+        self.certification_repository
+            .certify_http_response(&attachment_path, &attachment_http_response);
+
+        Ok(CreateProposalReviewAttachmentResponse {
+            path: attachment_path,
+        })
+    }
+
+    fn delete_proposal_review_attachment(
+        &self,
+        calling_principal: Principal,
+        request: DeleteProposalReviewAttachmentRequest,
+    ) -> Result<(), ApiError> {
+        let (id, mut current_proposal_review, _) = self.get_current_proposal_review_with_user_id(
+            request.proposal_id.clone(),
+            &calling_principal,
+            None,
+        )?;
+
+        let attachment_id_to_delete = PathBuf::from(request.attachment_path.clone())
+            .iter()
+            .last()
+            .ok_or_else(|| ApiError::invalid_argument("Invalid attachment path"))?
+            .to_string_lossy()
+            .to_string();
+
+        let attachments_ids = current_proposal_review
+            .attachments_ids
+            .get_or_insert_with(Vec::new);
+
+        if let Some(existing_attachment_id_idx) = attachments_ids
+            .iter()
+            .position(|attachment_id| attachment_id.to_string() == attachment_id_to_delete)
+        {
+            let existing_attachment_id = attachments_ids.remove(existing_attachment_id_idx);
+            let deleted_attachment = self
+                .image_repository
+                .delete_image(&existing_attachment_id)?;
+
+            let attachment_http_request_path = deleted_attachment.path(&existing_attachment_id);
+            let attachment_http_response = create_attachment_http_response(deleted_attachment)?;
+            self.certification_repository
+                .remove_http_response_certificate(
+                    &attachment_http_request_path,
+                    &attachment_http_response,
+                );
+        } else {
+            return Err(ApiError::not_found(&format!(
+                "Attachment with path {} not found in proposal review for proposal with Id {}",
+                request.attachment_path, request.proposal_id
+            )));
+        }
+
+        self.save_proposal_review(id, current_proposal_review)?;
+
+        Ok(())
+    }
+
     fn get_my_proposal_review(
         &self,
         calling_principal: Principal,
@@ -447,12 +604,16 @@ impl<
             )?;
         let (proposal_review_commits, images_paths) = self
             .get_proposal_review_commits_and_images_paths(proposal_review_id, &proposal_review)?;
+        let attachments_links = self.get_attachments_links(&proposal_review);
+        let endorsements = self.get_endorsements(proposal_review_id, &proposal_review_commits)?;
 
         let summary_markdown = proposal_review_summary_markdown(
             &proposal,
             &proposal_review,
             &proposal_review_commits,
             &images_paths,
+            &attachments_links,
+            &endorsements,
         );
 
         Ok(GetMyProposalReviewSummaryResponse { summary_markdown })
@@ -474,8 +635,11 @@ impl<
         PRC: ProposalReviewCommitRepository,
         I: ImageRepository,
         C: CertificationRepository,
-    > ProposalReviewServiceImpl<PR, U, P, PRC, I, C>
+        E: EndorsementRepository,
+        AC: AccessControlService,
+    > ProposalReviewServiceImpl<PR, U, P, PRC, I, C, E, AC>
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         proposal_review_repository: PR,
         user_profile_repository: U,
@@ -483,6 +647,8 @@ impl<
         proposal_review_commit_repository: PRC,
         image_repository: I,
         certification_repository: C,
+        endorsement_repository: E,
+        access_control_service: AC,
     ) -> Self {
         Self {
             proposal_review_repository,
@@ -491,9 +657,17 @@ impl<
             proposal_review_commit_repository,
             image_repository,
             certification_repository,
+            endorsement_repository,
+            access_control_service,
         }
     }
 
+    fn can_moderate(&self, calling_principal: &Principal) -> bool {
+        self.access_control_service
+            .assert_permission(calling_principal, Permission::ModerateReviews)
+            .is_ok()
+    }
+
     fn validate_fields(&self, summary: Option<&String>) -> Result<(), ApiError> {
         if let Some(summary) = summary {
             if summary.is_empty() {
@@ -564,6 +738,23 @@ impl<
         Ok((proposal_review_commits, images_paths))
     }
 
+    /// Returns the `(file name, path)` pairs of the proposal review attachments.
+    fn get_attachments_links(&self, proposal_review: &ProposalReview) -> Vec<(String, String)> {
+        proposal_review
+            .attachments_ids()
+            .iter()
+            .filter_map(|attachment_id| {
+                // the None case should never happen
+                let image = self.image_repository.get_image_by_id(attachment_id)?;
+                let path = image.path(attachment_id);
+
+                image
+                    .attachment
+                    .map(|attachment| (attachment.file_name, path))
+            })
+            .collect()
+    }
+
     fn map_proposal_review(
         &self,
         id: ProposalReviewId,
@@ -571,15 +762,54 @@ impl<
     ) -> Result<backend_api::ProposalReviewWithId, ApiError> {
         let (proposal_review_commits, images_paths) =
             self.get_proposal_review_commits_and_images_paths(id, &proposal_review)?;
+        // computed from the ids directly, to avoid loading the attachments content
+        let attachments_paths = proposal_review
+            .attachments_ids()
+            .iter()
+            .map(|attachment_id| {
+                image_path(
+                    Some(Path::new(PROPOSAL_REVIEW_ATTACHMENTS_SUB_PATH)),
+                    attachment_id,
+                )
+            })
+            .collect();
+        let endorsements = self.get_endorsements(id, &proposal_review_commits)?;
 
         Ok(map_proposal_review(
             id,
             proposal_review,
             proposal_review_commits,
             images_paths,
+            attachments_paths,
+            endorsements,
         ))
     }
 
+    /// Returns the endorsements of the proposal review,
+    /// skipping the ones of commits that have been removed from the review.
+    fn get_endorsements(
+        &self,
+        id: ProposalReviewId,
+        proposal_review_commits: &[(ProposalReviewCommitId, ProposalReviewCommit)],
+    ) -> Result<Vec<(EndorsementId, Endorsement)>, ApiError> {
+        let endorsements = self
+            .endorsement_repository
+            .get_endorsements_by_proposal_review_id(id)?
+            .into_iter()
+            .filter(|(_, endorsement)| {
+                endorsement
+                    .proposal_review_commit_id
+                    .is_none_or(|proposal_review_commit_id| {
+                        proposal_review_commits
+                            .iter()
+                            .any(|(id, _)| *id == proposal_review_commit_id)
+                    })
+            })
+            .collect();
+
+        Ok(endorsements)
+    }
+
     fn save_proposal_review(
         &self,
         id: ProposalReviewId,
@@ -668,16 +898,22 @@ impl<
 ///
 /// Hashes match: [true or false]
 /// All reviewed commits match their descriptions: [true or false]
+/// Endorsements: [number of reviewers that endorsed the whole review]
 ///
 /// [proposal review images if any]
 ///
 /// Summary:
 /// [proposal review summary if any]
 ///
+/// Build logs:
+/// - [[attachment file name]]([attachment path])
+/// ...
+///
 /// Commits review:
 /// - **[commit sha truncated to 9 characters]**:
 ///   Matches description: [true or false]
 ///   Comment: [commit comment]
+///   Endorsements: [number of reviewers that endorsed the commit review, if any]
 /// - **[commit sha truncated to 9 characters]**:
 ///   ...
 /// ...
@@ -687,6 +923,8 @@ fn proposal_review_summary_markdown(
     proposal_review: &ProposalReview,
     proposal_review_commits: &[(ProposalReviewCommitId, ProposalReviewCommit)],
     images_paths: &[String],
+    attachments_links: &[(String, String)],
+    endorsements: &[(EndorsementId, Endorsement)],
 ) -> String {
     let mut md_content = String::new();
     let reviewed_commits: Vec<&(ProposalReviewCommitId, ProposalReviewCommit)> =
@@ -737,6 +975,13 @@ fn proposal_review_summary_markdown(
                 }
             },
         ));
+        md_content.push_str(&format!(
+            "Endorsements: {}\n",
+            endorsements
+                .iter()
+                .filter(|(_, endorsement)| endorsement.is_for_proposal_review())
+                .count()
+        ));
     }
     // images
     {
@@ -750,6 +995,16 @@ fn proposal_review_summary_markdown(
             md_content.push_str(&format!("\nSummary:\n{}\n", summary));
         }
     }
+    // attachments
+    {
+        if !attachments_links.is_empty() {
+            md_content.push_str("\nBuild logs:\n");
+
+            for (file_name, path) in attachments_links {
+                md_content.push_str(&format!("- [{}]({})\n", file_name, path));
+            }
+        }
+    }
     // commits
     {
         if !reviewed_commits.is_empty() {
@@ -758,7 +1013,7 @@ fn proposal_review_summary_markdown(
             const INDENT: &str = "  ";
 
             let mut commits_list = String::new();
-            for (_, commit) in reviewed_commits {
+            for (commit_id, commit) in reviewed_commits {
                 if let Some(state) = commit.reviewed_state() {
                     let mut commit_sha = commit.commit_sha.to_string();
                     commit_sha.truncate(9);
@@ -771,6 +1026,19 @@ fn proposal_review_summary_markdown(
                             .collect::<Vec<&str>>()
                             .join(&format!("\n{INDENT}"))
                     ));
+
+                    let commit_endorsements_count = endorsements
+                        .iter()
+                        .filter(|(_, endorsement)| {
+                            endorsement.proposal_review_commit_id == Some(*commit_id)
+                        })
+                        .count();
+                    if commit_endorsements_count > 0 {
+                        commits_list.push_str(&format!(
+                            "{INDENT}Endorsements: {}\n",
+                            commit_endorsements_count
+                        ));
+                    }
                 }
             }
             md_content.push_str(&commits_list);
@@ -786,15 +1054,17 @@ mod tests {
     use crate::{
         fixtures,
         repositories::{
-            ImageId, MockCertificationRepository, MockImageRepository, MockProposalRepository,
-            MockProposalReviewCommitRepository, MockProposalReviewRepository,
-            MockUserProfileRepository, NervousSystem, ProposalReviewId, ReviewCommitState,
-            ReviewedCommitState, IMAGES_BASE_PATH,
+            ImageId, MockCertificationRepository, MockEndorsementRepository, MockImageRepository,
+            MockProposalRepository, MockProposalReviewCommitRepository,
+            MockProposalReviewRepository, MockUserProfileRepository, NervousSystem,
+            ProposalReviewId, ReviewCommitState, ReviewedCommitState, IMAGES_BASE_PATH,
         },
+        services::MockAccessControlService,
     };
     use backend_api::{
+        CreateProposalReviewAttachmentRequest, CreateProposalReviewAttachmentResponse,
         CreateProposalReviewImageRequest, CreateProposalReviewImageResponse,
-        DeleteProposalReviewImageRequest,
+        DeleteProposalReviewAttachmentRequest, DeleteProposalReviewImageRequest,
     };
     use mockall::predicate::*;
     use rstest::*;
@@ -845,6 +1115,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -887,6 +1159,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -925,6 +1199,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -975,6 +1251,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1027,6 +1305,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1082,6 +1362,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1173,6 +1455,7 @@ mod tests {
     #[rstest]
     #[case::update(proposal_review_update())]
     #[case::publish(proposal_review_update_publish())]
+    #[case::republish(proposal_review_update_republish())]
     fn update_proposal_review(
         #[case] fixture: (
             ProposalReviewId,
@@ -1221,6 +1504,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         service
@@ -1257,6 +1542,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1304,6 +1591,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1358,6 +1647,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1422,6 +1713,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1480,6 +1773,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         service
@@ -1514,6 +1809,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1571,6 +1868,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1687,6 +1986,43 @@ mod tests {
                 summary: Some(summary),
                 build_reproduced: Some(build_reproduced),
                 last_updated_at: Some(DateTime::new(date_time).unwrap()),
+                published_at: Some(DateTime::new(date_time).unwrap()),
+                ..original_proposal_review
+            },
+        )
+    }
+
+    #[fixture]
+    fn proposal_review_update_republish() -> (
+        ProposalReviewId,
+        ProposalReview,
+        UpdateProposalReviewRequest,
+        ProposalReview,
+    ) {
+        let id = fixtures::proposal_review_id();
+        let date_time = get_date_time().unwrap();
+        let published_at = fixtures::date_time_a();
+        let original_proposal_review = ProposalReview {
+            user_id: fixtures::uuid_a(),
+            published_at: Some(published_at),
+            ..fixtures::proposal_review_draft()
+        };
+        let status = ProposalReviewStatus::Published;
+
+        (
+            id,
+            original_proposal_review.clone(),
+            UpdateProposalReviewRequest {
+                proposal_id: original_proposal_review.proposal_id.to_string(),
+                status: Some(status.clone().into()),
+                summary: None,
+                build_reproduced: None,
+                vote: None,
+            },
+            ProposalReview {
+                status,
+                last_updated_at: Some(DateTime::new(date_time).unwrap()),
+                published_at: Some(published_at),
                 ..original_proposal_review
             },
         )
@@ -1851,6 +2187,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1908,6 +2246,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -1989,6 +2329,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         service
@@ -2043,6 +2385,8 @@ mod tests {
             prc_repository_mock,
             image_repository_mock,
             certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
         );
 
         let result = service
@@ -2058,64 +2402,418 @@ mod tests {
         );
     }
 
-    #[fixture]
-    fn proposal_review_create_image() -> (
-        ProposalReviewId,
-        ProposalReview,
-        ImageId,
-        Image,
-        CreateProposalReviewImageRequest,
-        ProposalReview,
-        CreateProposalReviewImageResponse,
-    ) {
-        let proposal_review_id = fixtures::proposal_review_id();
-        let date_time = get_date_time().unwrap();
+    #[rstest]
+    fn create_proposal_review_attachment() {
+        let calling_principal = fixtures::principal_a();
         let user_id = fixtures::uuid_a();
-        let original_proposal_review = ProposalReview {
-            user_id,
-            images_ids: vec![],
-            ..fixtures::proposal_review_draft()
-        };
-        let image = Image {
-            created_at: DateTime::new(date_time).unwrap(),
-            sub_path: Some(PathBuf::from_str(PROPOSAL_REVIEW_IMAGES_SUB_PATH).unwrap()),
-            user_id,
-            ..fixtures::image_without_subpath()
-        };
-        let image_id = fixtures::uuid_b();
+        let (
+            id,
+            original_proposal_review,
+            attachment_id,
+            attachment,
+            request,
+            updated_proposal_review,
+            expected_response,
+        ) = proposal_review_create_attachment();
 
-        (
-            proposal_review_id,
-            original_proposal_review.clone(),
-            image_id,
-            image.clone(),
-            CreateProposalReviewImageRequest {
-                proposal_id: original_proposal_review.proposal_id.to_string(),
-                content_type: image.content_type.clone(),
-                content_bytes: image.content_bytes.clone(),
-            },
-            ProposalReview {
-                last_updated_at: Some(DateTime::new(date_time).unwrap()),
-                images_ids: vec![image_id],
-                ..original_proposal_review
-            },
-            CreateProposalReviewImageResponse {
-                path: image.path(&image_id),
-            },
-        )
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id), eq(user_id))
+            .return_const(Some((id, original_proposal_review.clone())));
+        pr_repository_mock
+            .expect_update_proposal_review()
+            .once()
+            .with(eq(id), eq(updated_proposal_review.clone()))
+            .return_const(Ok(()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let prc_repository_mock = MockProposalReviewCommitRepository::new();
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock
+            .expect_create_image()
+            .once()
+            .with(eq(attachment.clone()))
+            .return_const(attachment_id);
+        image_repository_mock.expect_delete_image().never();
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_certify_http_response()
+            .with(
+                eq(attachment.path(&attachment_id)),
+                eq(create_attachment_http_response(attachment).unwrap()),
+            )
+            .once()
+            .return_const(());
+
+        let service = ProposalReviewServiceImpl::new(
+            pr_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            prc_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
+        );
+
+        let result = service
+            .create_proposal_review_attachment(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result, expected_response);
     }
 
-    #[fixture]
-    fn proposal_review_update_image_existing() -> (
-        ProposalReviewId,
-        ProposalReview,
-        ImageId,
-        Image,
-        CreateProposalReviewImageRequest,
-        ProposalReview,
-        CreateProposalReviewImageResponse,
-    ) {
-        let proposal_review_id = fixtures::proposal_review_id();
+    #[rstest]
+    fn create_proposal_review_attachment_limit_reached() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_a();
+        let (id, original_proposal_review, _, _, request, _, _) =
+            proposal_review_create_attachment();
+
+        let proposal_review_with_attachments = ProposalReview {
+            attachments_ids: Some(
+                (0..MAX_PROPOSAL_REVIEW_ATTACHMENTS)
+                    .map(|_| fixtures::uuid())
+                    .collect(),
+            ),
+            ..original_proposal_review
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(
+                eq(proposal_review_with_attachments.proposal_id),
+                eq(user_id),
+            )
+            .return_const(Some((id, proposal_review_with_attachments.clone())));
+        pr_repository_mock.expect_update_proposal_review().never();
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_review_with_attachments.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let prc_repository_mock = MockProposalReviewCommitRepository::new();
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_create_image().never();
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_certify_http_response()
+            .never();
+
+        let service = ProposalReviewServiceImpl::new(
+            pr_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            prc_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
+        );
+
+        let result = service
+            .create_proposal_review_attachment(calling_principal, request.clone())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal review for proposal with Id {} already has {} attachments",
+                request.proposal_id, MAX_PROPOSAL_REVIEW_ATTACHMENTS
+            )),
+        );
+    }
+
+    #[rstest]
+    fn create_proposal_review_attachment_save_error() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_a();
+        let (
+            id,
+            original_proposal_review,
+            attachment_id,
+            attachment,
+            request,
+            updated_proposal_review,
+            _,
+        ) = proposal_review_create_attachment();
+        let save_error = ApiError::internal("Failed to save proposal review");
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id), eq(user_id))
+            .return_const(Some((id, original_proposal_review.clone())));
+        pr_repository_mock
+            .expect_update_proposal_review()
+            .once()
+            .with(eq(id), eq(updated_proposal_review))
+            .return_const(Err(save_error.clone()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let prc_repository_mock = MockProposalReviewCommitRepository::new();
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock
+            .expect_create_image()
+            .once()
+            .with(eq(attachment.clone()))
+            .return_const(attachment_id);
+        image_repository_mock
+            .expect_delete_image()
+            .once()
+            .with(eq(attachment_id))
+            .return_const(Ok(attachment));
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_certify_http_response()
+            .never();
+
+        let service = ProposalReviewServiceImpl::new(
+            pr_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            prc_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
+        );
+
+        let result = service
+            .create_proposal_review_attachment(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, save_error);
+    }
+
+    #[rstest]
+    fn delete_proposal_review_attachment() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_a();
+        let (id, original_proposal_review, request, updated_proposal_review) =
+            proposal_review_update_attachment_delete();
+
+        let attachment_to_delete = fixtures::attachment_with_subpath();
+        let attachment_id_to_delete = original_proposal_review.attachments_ids()[0];
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id), eq(user_id))
+            .return_const(Some((id, original_proposal_review.clone())));
+        pr_repository_mock
+            .expect_update_proposal_review()
+            .once()
+            .with(eq(id), eq(updated_proposal_review.clone()))
+            .return_const(Ok(()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let prc_repository_mock = MockProposalReviewCommitRepository::new();
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock
+            .expect_delete_image()
+            .once()
+            .with(eq(attachment_id_to_delete))
+            .return_const(Ok(attachment_to_delete.clone()));
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_remove_http_response_certificate()
+            .with(
+                eq(attachment_to_delete.path(&attachment_id_to_delete)),
+                eq(create_attachment_http_response(attachment_to_delete).unwrap()),
+            )
+            .once()
+            .return_const(());
+
+        let service = ProposalReviewServiceImpl::new(
+            pr_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            prc_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
+        );
+
+        service
+            .delete_proposal_review_attachment(calling_principal, request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_proposal_review_attachment_not_found() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_a();
+        let (id, original_proposal_review, request, _) = proposal_review_update_attachment_delete();
+
+        let original_proposal_review = ProposalReview {
+            attachments_ids: None,
+            ..original_proposal_review
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id), eq(user_id))
+            .return_const(Some((id, original_proposal_review.clone())));
+        pr_repository_mock.expect_update_proposal_review().never();
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(original_proposal_review.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let prc_repository_mock = MockProposalReviewCommitRepository::new();
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_delete_image().never();
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_remove_http_response_certificate()
+            .never();
+
+        let service = ProposalReviewServiceImpl::new(
+            pr_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            prc_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+            MockEndorsementRepository::new(),
+            MockAccessControlService::new(),
+        );
+
+        let result = service
+            .delete_proposal_review_attachment(calling_principal, request.clone())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Attachment with path {} not found in proposal review for proposal with Id {}",
+                request.attachment_path, request.proposal_id
+            )),
+        );
+    }
+
+    #[fixture]
+    fn proposal_review_create_image() -> (
+        ProposalReviewId,
+        ProposalReview,
+        ImageId,
+        Image,
+        CreateProposalReviewImageRequest,
+        ProposalReview,
+        CreateProposalReviewImageResponse,
+    ) {
+        let proposal_review_id = fixtures::proposal_review_id();
+        let date_time = get_date_time().unwrap();
+        let user_id = fixtures::uuid_a();
+        let original_proposal_review = ProposalReview {
+            user_id,
+            images_ids: vec![],
+            ..fixtures::proposal_review_draft()
+        };
+        let image = Image {
+            created_at: DateTime::new(date_time).unwrap(),
+            sub_path: Some(PathBuf::from_str(PROPOSAL_REVIEW_IMAGES_SUB_PATH).unwrap()),
+            user_id,
+            ..fixtures::image_without_subpath()
+        };
+        let image_id = fixtures::uuid_b();
+
+        (
+            proposal_review_id,
+            original_proposal_review.clone(),
+            image_id,
+            image.clone(),
+            CreateProposalReviewImageRequest {
+                proposal_id: original_proposal_review.proposal_id.to_string(),
+                content_type: image.content_type.clone(),
+                content_bytes: image.content_bytes.clone(),
+            },
+            ProposalReview {
+                last_updated_at: Some(DateTime::new(date_time).unwrap()),
+                images_ids: vec![image_id],
+                ..original_proposal_review
+            },
+            CreateProposalReviewImageResponse {
+                path: image.path(&image_id),
+            },
+        )
+    }
+
+    #[fixture]
+    fn proposal_review_update_image_existing() -> (
+        ProposalReviewId,
+        ProposalReview,
+        ImageId,
+        Image,
+        CreateProposalReviewImageRequest,
+        ProposalReview,
+        CreateProposalReviewImageResponse,
+    ) {
+        let proposal_review_id = fixtures::proposal_review_id();
         let date_time = get_date_time().unwrap();
         let user_id = fixtures::uuid_a();
         let original_proposal_review = ProposalReview {
@@ -2185,6 +2883,88 @@ mod tests {
             },
         )
     }
+    #[fixture]
+    fn proposal_review_create_attachment() -> (
+        ProposalReviewId,
+        ProposalReview,
+        ImageId,
+        Image,
+        CreateProposalReviewAttachmentRequest,
+        ProposalReview,
+        CreateProposalReviewAttachmentResponse,
+    ) {
+        let proposal_review_id = fixtures::proposal_review_id();
+        let date_time = get_date_time().unwrap();
+        let user_id = fixtures::uuid_a();
+        let original_proposal_review = ProposalReview {
+            user_id,
+            attachments_ids: None,
+            ..fixtures::proposal_review_draft()
+        };
+        let attachment = Image {
+            created_at: DateTime::new(date_time).unwrap(),
+            user_id,
+            sub_path: Some(PathBuf::from_str(PROPOSAL_REVIEW_ATTACHMENTS_SUB_PATH).unwrap()),
+            ..fixtures::attachment_with_subpath()
+        };
+        let attachment_id = fixtures::uuid_b();
+
+        (
+            proposal_review_id,
+            original_proposal_review.clone(),
+            attachment_id,
+            attachment.clone(),
+            CreateProposalReviewAttachmentRequest {
+                proposal_id: original_proposal_review.proposal_id.to_string(),
+                file_name: attachment.attachment.clone().unwrap().file_name,
+                content_type: attachment.content_type.clone(),
+                content_bytes: attachment.decoded_content_bytes().unwrap(),
+            },
+            ProposalReview {
+                last_updated_at: Some(DateTime::new(date_time).unwrap()),
+                attachments_ids: Some(vec![attachment_id]),
+                ..original_proposal_review
+            },
+            CreateProposalReviewAttachmentResponse {
+                path: attachment.path(&attachment_id),
+            },
+        )
+    }
+
+    #[fixture]
+    fn proposal_review_update_attachment_delete() -> (
+        ProposalReviewId,
+        ProposalReview,
+        DeleteProposalReviewAttachmentRequest,
+        ProposalReview,
+    ) {
+        let proposal_review_id = fixtures::proposal_review_id();
+        let date_time = get_date_time().unwrap();
+        let attachment_id = fixtures::uuid_b();
+        let original_proposal_review = ProposalReview {
+            user_id: fixtures::uuid_a(),
+            attachments_ids: Some(vec![attachment_id]),
+            ..fixtures::proposal_review_draft()
+        };
+
+        (
+            proposal_review_id,
+            original_proposal_review.clone(),
+            DeleteProposalReviewAttachmentRequest {
+                proposal_id: original_proposal_review.proposal_id.to_string(),
+                attachment_path: format!(
+                    "{IMAGES_BASE_PATH}{PROPOSAL_REVIEW_ATTACHMENTS_SUB_PATH}/{}",
+                    attachment_id
+                ),
+            },
+            ProposalReview {
+                last_updated_at: Some(DateTime::new(date_time).unwrap()),
+                attachments_ids: Some(vec![]),
+                ..original_proposal_review
+            },
+        )
+    }
+
     #[fixture]
     fn proposal_review_update_publish_no_summary() -> (
         ProposalReviewId,
@@ -2385,17 +3165,28 @@ mod tests {
         ]
     }
 
+    #[fixture]
+    fn attachments_links() -> Vec<(String, String)> {
+        vec![(
+            "build.log".to_string(),
+            "/images/reviews/attachments/0b2b3d4e-1f3c-4a3e-9f7e-2b6b0c9c5d1a".to_string(),
+        )]
+    }
+
     #[rstest]
     fn test_proposal_review_summary_markdown() {
         let basic_proposal = basic_proposal();
         let mut basic_review = basic_review();
         let mut review_commits = all_reviewed_commits_match();
         let images_paths = images_paths();
+        let attachments_links = attachments_links();
         let markdown = proposal_review_summary_markdown(
             &basic_proposal,
             &basic_review,
             &review_commits,
             &images_paths,
+            &attachments_links,
+            &[],
         );
 
         fn expected_markdown(
@@ -2409,6 +3200,7 @@ mod tests {
 Vote: ADOPTED
 Hashes match: {hashes_match}
 All reviewed commits match their descriptions: {all_reviewed_commits_match}
+Endorsements: 0
 
 ![](/images/13449503-1b2f-4b92-8346-8e843253e842.png)
 
@@ -2417,6 +3209,9 @@ All reviewed commits match their descriptions: {all_reviewed_commits_match}
 Summary:
 Test summary
 
+Build logs:
+- [build.log](/images/reviews/attachments/0b2b3d4e-1f3c-4a3e-9f7e-2b6b0c9c5d1a)
+
 Commits review:
 - **28111ed23**:
   Matches description: {}
@@ -2440,6 +3235,8 @@ Commits review:
             &basic_review,
             &review_commits,
             &images_paths,
+            &attachments_links,
+            &[],
         );
         assert_eq!(
             markdown,
@@ -2452,6 +3249,8 @@ Commits review:
             &basic_review,
             &review_commits,
             &images_paths,
+            &attachments_links,
+            &[],
         );
         assert_eq!(
             markdown,
@@ -2464,6 +3263,8 @@ Commits review:
             &basic_review,
             &review_commits,
             &images_paths,
+            &attachments_links,
+            &[],
         );
         assert_eq!(
             markdown,
@@ -2480,10 +3281,64 @@ Commits review:
             &basic_review,
             &review_commits,
             &images_paths,
+            &attachments_links,
+            &[],
         );
         assert_eq!(
             markdown,
             expected_markdown("Unanswered", "Unanswered", ("Unanswered", "Unanswered"))
         );
     }
+
+    #[rstest]
+    fn test_proposal_review_summary_markdown_endorsements() {
+        let review_commits = all_reviewed_commits_match()
+            .into_iter()
+            .zip([fixtures::uuid_a(), fixtures::uuid_b(), fixtures::uuid()])
+            .map(|((_, commit), id)| (id, commit))
+            .collect::<Vec<_>>();
+        let endorsements = vec![
+            (fixtures::uuid(), fixtures::endorsement_proposal_review()),
+            (fixtures::uuid(), fixtures::endorsement_proposal_review()),
+            (
+                fixtures::uuid(),
+                Endorsement {
+                    proposal_review_commit_id: Some(fixtures::uuid_b()),
+                    ..fixtures::endorsement_proposal_review_commit()
+                },
+            ),
+        ];
+
+        let markdown = proposal_review_summary_markdown(
+            &basic_proposal(),
+            &basic_review(),
+            &review_commits,
+            &[],
+            &[],
+            &endorsements,
+        );
+
+        assert_eq!(
+            markdown,
+            r#"# Proposal 123
+
+Vote: ADOPTED
+Hashes match: true
+All reviewed commits match their descriptions: false
+Endorsements: 2
+
+Summary:
+Test summary
+
+Commits review:
+- **28111ed23**:
+  Matches description: true
+  Comment: Good commit
+- **47d98477c**:
+  Matches description: false
+  Comment: Issues found
+  Endorsements: 1
+"#
+        );
+    }
 }
@@ -16,7 +16,7 @@ use ic_nns_governance::pb::v1::{ListProposalInfo, ProposalInfo, ProposalStatus,
 
 use super::{LogService, LogServiceImpl};
 
-const NNS_GOVERNANCE_CANISTER_ID: &str = "rrkah-fqaaa-aaaaa-aaaaq-cai";
+pub(crate) const NNS_GOVERNANCE_CANISTER_ID: &str = "rrkah-fqaaa-aaaaa-aaaaq-cai";
 
 async fn fetch_open_nns_proposals(
     before_proposal: Option<NnsProposalId>,
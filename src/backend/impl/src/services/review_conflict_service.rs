@@ -0,0 +1,763 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    mappings::map_review_conflicts,
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, CommitSha, DateTime, ProposalId,
+        ProposalReviewCommitRepository, ProposalReviewCommitRepositoryImpl, ProposalReviewId,
+        ProposalReviewRepository, ProposalReviewRepositoryImpl, ProposalVote, ReviewConflict,
+        ReviewConflictId, ReviewConflictKind, ReviewConflictRepository,
+        ReviewConflictRepositoryImpl, ReviewConflictStatus, UserProfileRepository,
+        UserProfileRepositoryImpl,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ListOpenReviewConflictsRequest, ListOpenReviewConflictsResponse,
+    ResolveReviewConflictRequest,
+};
+use candid::Principal;
+
+const MAX_REVIEW_CONFLICT_NOTE_CHARS: usize = 1000;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewConflictService {
+    /// Compares the published reviews of the proposal and records a conflict
+    /// for every field on which they disagree.
+    fn detect_review_conflicts(&self, proposal_id: ProposalId) -> Result<(), ApiError>;
+
+    fn list_open_review_conflicts(
+        &self,
+        request: ListOpenReviewConflictsRequest,
+    ) -> Result<ListOpenReviewConflictsResponse, ApiError>;
+
+    fn resolve_review_conflict(
+        &self,
+        calling_principal: Principal,
+        request: ResolveReviewConflictRequest,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewConflictServiceImpl<
+    RC: ReviewConflictRepository,
+    PR: ProposalReviewRepository,
+    PRC: ProposalReviewCommitRepository,
+    U: UserProfileRepository,
+    AL: AuditLogService,
+> {
+    review_conflict_repository: RC,
+    proposal_review_repository: PR,
+    proposal_review_commit_repository: PRC,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for ReviewConflictServiceImpl<
+        ReviewConflictRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        ProposalReviewCommitRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            ReviewConflictRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            ProposalReviewCommitRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        RC: ReviewConflictRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > ReviewConflictService for ReviewConflictServiceImpl<RC, PR, PRC, U, AL>
+{
+    fn detect_review_conflicts(&self, proposal_id: ProposalId) -> Result<(), ApiError> {
+        let published_reviews = self
+            .proposal_review_repository
+            .get_proposal_reviews_by_proposal_id(proposal_id)?
+            .into_iter()
+            .filter(|(_, proposal_review)| proposal_review.is_published())
+            .collect::<Vec<_>>();
+
+        let mut detected_conflicts = vec![];
+
+        let votes = published_reviews
+            .iter()
+            .filter_map(|(id, proposal_review)| match proposal_review.vote {
+                ProposalVote::Yes => Some((*id, true)),
+                ProposalVote::No => Some((*id, false)),
+                ProposalVote::Unspecified => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(ids) = conflicting_proposal_review_ids(&votes) {
+            detected_conflicts.push((ReviewConflictKind::Vote, ids));
+        }
+
+        let builds_reproduced = published_reviews
+            .iter()
+            .filter_map(|(id, proposal_review)| {
+                proposal_review
+                    .build_reproduced
+                    .map(|build_reproduced| (*id, build_reproduced))
+            })
+            .collect::<Vec<_>>();
+        if let Some(ids) = conflicting_proposal_review_ids(&builds_reproduced) {
+            detected_conflicts.push((ReviewConflictKind::BuildReproduced, ids));
+        }
+
+        let mut commits_matches_description =
+            BTreeMap::<CommitSha, Vec<(ProposalReviewId, bool)>>::new();
+        for (id, _) in published_reviews.iter() {
+            for (_, proposal_review_commit) in self
+                .proposal_review_commit_repository
+                .get_proposal_review_commits_by_proposal_review_id(*id)?
+            {
+                if let Some(matches_description) = proposal_review_commit
+                    .reviewed_state()
+                    .and_then(|state| state.matches_description)
+                {
+                    commits_matches_description
+                        .entry(proposal_review_commit.commit_sha)
+                        .or_default()
+                        .push((*id, matches_description));
+                }
+            }
+        }
+        for (commit_sha, matches_description) in commits_matches_description {
+            if let Some(ids) = conflicting_proposal_review_ids(&matches_description) {
+                detected_conflicts.push((
+                    ReviewConflictKind::CommitMatchesDescription { commit_sha },
+                    ids,
+                ));
+            }
+        }
+
+        if detected_conflicts.is_empty() {
+            return Ok(());
+        }
+
+        let current_time = DateTime::new(get_date_time()?)?;
+        let existing_conflicts = self
+            .review_conflict_repository
+            .get_review_conflicts_by_proposal_id(proposal_id)?;
+
+        for (kind, proposal_review_ids) in detected_conflicts {
+            let mut same_kind_conflicts = existing_conflicts
+                .iter()
+                .filter(|(_, review_conflict)| review_conflict.kind == kind);
+
+            if let Some((id, open_conflict)) = same_kind_conflicts
+                .clone()
+                .find(|(_, review_conflict)| review_conflict.is_open())
+            {
+                if open_conflict.proposal_review_ids != proposal_review_ids {
+                    self.review_conflict_repository.update_review_conflict(
+                        *id,
+                        ReviewConflict {
+                            proposal_review_ids,
+                            last_updated_at: Some(current_time),
+                            ..open_conflict.clone()
+                        },
+                    )?;
+                }
+            } else if !same_kind_conflicts.any(|(_, review_conflict)| {
+                review_conflict.proposal_review_ids == proposal_review_ids
+            }) {
+                // a resolved conflict is only reopened if other reviews joined the disagreement
+                self.review_conflict_repository
+                    .create_review_conflict(ReviewConflict {
+                        proposal_id,
+                        kind,
+                        proposal_review_ids,
+                        detected_at: current_time,
+                        last_updated_at: None,
+                        status: ReviewConflictStatus::Open,
+                    })?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn list_open_review_conflicts(
+        &self,
+        request: ListOpenReviewConflictsRequest,
+    ) -> Result<ListOpenReviewConflictsResponse, ApiError> {
+        let review_conflicts = match request.proposal_id {
+            Some(proposal_id) => {
+                let proposal_id = ProposalId::try_from(proposal_id.as_str())?;
+
+                self.review_conflict_repository
+                    .get_review_conflicts_by_proposal_id(proposal_id)?
+                    .into_iter()
+                    .filter(|(_, review_conflict)| review_conflict.is_open())
+                    .collect()
+            }
+            None => self.review_conflict_repository.get_open_review_conflicts(),
+        };
+
+        Ok(ListOpenReviewConflictsResponse {
+            review_conflicts: map_review_conflicts(review_conflicts),
+        })
+    }
+
+    fn resolve_review_conflict(
+        &self,
+        calling_principal: Principal,
+        request: ResolveReviewConflictRequest,
+    ) -> Result<(), ApiError> {
+        self.validate_note(&request.note)?;
+
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        let id = ReviewConflictId::try_from(request.id.as_str())?;
+        let review_conflict = self
+            .review_conflict_repository
+            .get_review_conflict_by_id(&id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!("Review conflict with id {} not found", id))
+            })?;
+
+        if !review_conflict.is_open() {
+            return Err(ApiError::conflict(&format!(
+                "Review conflict with id {} is already resolved",
+                id
+            )));
+        }
+
+        let current_time = DateTime::new(get_date_time()?)?;
+        let resolved_review_conflict = ReviewConflict {
+            last_updated_at: Some(current_time),
+            status: ReviewConflictStatus::Resolved {
+                resolved_by: user_id,
+                resolved_at: current_time,
+                note: request.note,
+            },
+            ..review_conflict.clone()
+        };
+        self.review_conflict_repository
+            .update_review_conflict(id, resolved_review_conflict.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ResolveReviewConflict,
+            vec![id.to_string()],
+            Some(audit_snapshot(&review_conflict)),
+            Some(audit_snapshot(&resolved_review_conflict)),
+        )
+    }
+}
+
+impl<
+        RC: ReviewConflictRepository,
+        PR: ProposalReviewRepository,
+        PRC: ProposalReviewCommitRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > ReviewConflictServiceImpl<RC, PR, PRC, U, AL>
+{
+    fn new(
+        review_conflict_repository: RC,
+        proposal_review_repository: PR,
+        proposal_review_commit_repository: PRC,
+        user_profile_repository: U,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            review_conflict_repository,
+            proposal_review_repository,
+            proposal_review_commit_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+
+    fn validate_note(&self, note: &str) -> Result<(), ApiError> {
+        if note.trim().is_empty() {
+            return Err(ApiError::invalid_argument("Note cannot be empty"));
+        }
+        if note.chars().count() > MAX_REVIEW_CONFLICT_NOTE_CHARS {
+            return Err(ApiError::invalid_argument(&format!(
+                "Note must be less than {} characters",
+                MAX_REVIEW_CONFLICT_NOTE_CHARS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the ids of all the reviews that answered,
+/// if at least one answered `true` and another one answered `false`.
+fn conflicting_proposal_review_ids(
+    answers: &[(ProposalReviewId, bool)],
+) -> Option<Vec<ProposalReviewId>> {
+    let has_true = answers.iter().any(|(_, answer)| *answer);
+    let has_false = answers.iter().any(|(_, answer)| !*answer);
+
+    if has_true && has_false {
+        Some(answers.iter().map(|(id, _)| *id).collect())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, commit_sha_a, commit_sha_b, uuid_a, uuid_b},
+        repositories::{
+            MockProposalReviewCommitRepository, MockProposalReviewRepository,
+            MockReviewConflictRepository, MockUserProfileRepository, ProposalReview,
+            ProposalReviewCommit, ReviewCommitState, ReviewedCommitState,
+        },
+        services::MockAuditLogService,
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn detect_review_conflicts() {
+        let proposal_id = fixtures::proposal_id();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (
+                    uuid_a(),
+                    ProposalReview {
+                        vote: ProposalVote::Yes,
+                        build_reproduced: Some(true),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    uuid_b(),
+                    ProposalReview {
+                        vote: ProposalVote::No,
+                        build_reproduced: Some(true),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    fixtures::uuid(),
+                    ProposalReview {
+                        vote: ProposalVote::No,
+                        build_reproduced: Some(false),
+                        ..fixtures::proposal_review_draft()
+                    },
+                ),
+            ]));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .once()
+            .with(eq(uuid_a()))
+            .return_const(Ok(vec![
+                (
+                    fixtures::uuid(),
+                    proposal_review_commit(commit_sha_a(), Some(true)),
+                ),
+                (
+                    fixtures::uuid(),
+                    proposal_review_commit(commit_sha_b(), Some(true)),
+                ),
+            ]));
+        prc_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .once()
+            .with(eq(uuid_b()))
+            .return_const(Ok(vec![
+                (
+                    fixtures::uuid(),
+                    proposal_review_commit(commit_sha_a(), Some(false)),
+                ),
+                (
+                    fixtures::uuid(),
+                    proposal_review_commit(commit_sha_b(), None),
+                ),
+            ]));
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflicts_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![]));
+        for kind in [
+            ReviewConflictKind::Vote,
+            ReviewConflictKind::CommitMatchesDescription {
+                commit_sha: commit_sha_a(),
+            },
+        ] {
+            rc_repository_mock
+                .expect_create_review_conflict()
+                .once()
+                .with(eq(ReviewConflict {
+                    proposal_id,
+                    kind,
+                    proposal_review_ids: vec![uuid_a(), uuid_b()],
+                    detected_at: current_time,
+                    last_updated_at: None,
+                    status: ReviewConflictStatus::Open,
+                }))
+                .return_const(Ok(fixtures::uuid()));
+        }
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            pr_repository_mock,
+            prc_repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        service.detect_review_conflicts(proposal_id).unwrap();
+    }
+
+    #[rstest]
+    fn detect_review_conflicts_existing_conflicts() {
+        let proposal_id = fixtures::proposal_id();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+        let open_conflict_id = fixtures::uuid();
+        let open_conflict = ReviewConflict {
+            kind: ReviewConflictKind::Vote,
+            proposal_review_ids: vec![uuid_a()],
+            ..fixtures::review_conflict_open()
+        };
+        let resolved_conflict = ReviewConflict {
+            kind: ReviewConflictKind::BuildReproduced,
+            proposal_review_ids: vec![uuid_a(), uuid_b()],
+            ..fixtures::review_conflict_resolved()
+        };
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (
+                    uuid_a(),
+                    ProposalReview {
+                        vote: ProposalVote::Yes,
+                        build_reproduced: Some(true),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    uuid_b(),
+                    ProposalReview {
+                        vote: ProposalVote::No,
+                        build_reproduced: Some(false),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+            ]));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .times(2)
+            .return_const(Ok(vec![]));
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflicts_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (open_conflict_id, open_conflict.clone()),
+                (fixtures::uuid(), resolved_conflict),
+            ]));
+        rc_repository_mock
+            .expect_update_review_conflict()
+            .once()
+            .with(
+                eq(open_conflict_id),
+                eq(ReviewConflict {
+                    proposal_review_ids: vec![uuid_a(), uuid_b()],
+                    last_updated_at: Some(current_time),
+                    ..open_conflict
+                }),
+            )
+            .return_const(Ok(()));
+        rc_repository_mock.expect_create_review_conflict().never();
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            pr_repository_mock,
+            prc_repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        service.detect_review_conflicts(proposal_id).unwrap();
+    }
+
+    #[rstest]
+    fn detect_review_conflicts_no_conflicts() {
+        let proposal_id = fixtures::proposal_id();
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (uuid_a(), fixtures::proposal_review_published()),
+                (uuid_b(), fixtures::proposal_review_published()),
+            ]));
+
+        let mut prc_repository_mock = MockProposalReviewCommitRepository::new();
+        prc_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .times(2)
+            .return_const(Ok(vec![(
+                fixtures::uuid(),
+                fixtures::proposal_review_commit_reviewed(),
+            )]));
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflicts_by_proposal_id()
+            .never();
+        rc_repository_mock.expect_create_review_conflict().never();
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            pr_repository_mock,
+            prc_repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        service.detect_review_conflicts(proposal_id).unwrap();
+    }
+
+    #[rstest]
+    fn list_open_review_conflicts_by_proposal_id() {
+        let proposal_id = fixtures::proposal_id();
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflicts_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (uuid_a(), fixtures::review_conflict_open()),
+                (uuid_b(), fixtures::review_conflict_resolved()),
+            ]));
+        rc_repository_mock
+            .expect_get_open_review_conflicts()
+            .never();
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_open_review_conflicts(ListOpenReviewConflictsRequest {
+                proposal_id: Some(proposal_id.to_string()),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListOpenReviewConflictsResponse {
+                review_conflicts: map_review_conflicts(vec![(
+                    uuid_a(),
+                    fixtures::review_conflict_open()
+                )]),
+            }
+        );
+    }
+
+    #[rstest]
+    fn resolve_review_conflict() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let id = fixtures::uuid();
+        let current_time = DateTime::new(get_date_time().unwrap()).unwrap();
+        let note = "Both answers are defensible".to_string();
+        let resolved_review_conflict = ReviewConflict {
+            last_updated_at: Some(current_time),
+            status: ReviewConflictStatus::Resolved {
+                resolved_by: user_id,
+                resolved_at: current_time,
+                note: note.clone(),
+            },
+            ..fixtures::review_conflict_open()
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflict_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::review_conflict_open()));
+        rc_repository_mock
+            .expect_update_review_conflict()
+            .once()
+            .with(eq(id), eq(resolved_review_conflict.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::ResolveReviewConflict),
+                eq(vec![id.to_string()]),
+                eq(Some(audit_snapshot(&fixtures::review_conflict_open()))),
+                eq(Some(audit_snapshot(&resolved_review_conflict))),
+            )
+            .return_const(Ok(()));
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .resolve_review_conflict(
+                calling_principal,
+                ResolveReviewConflictRequest {
+                    id: id.to_string(),
+                    note,
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn resolve_review_conflict_already_resolved() {
+        let calling_principal = fixtures::principal_a();
+        let id = fixtures::uuid();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::user_id()));
+
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock
+            .expect_get_review_conflict_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::review_conflict_resolved()));
+        rc_repository_mock.expect_update_review_conflict().never();
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            u_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .resolve_review_conflict(
+                calling_principal,
+                ResolveReviewConflictRequest {
+                    id: id.to_string(),
+                    note: "note".to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Review conflict with id {} is already resolved",
+                id
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::empty("  ".to_string(), ApiError::invalid_argument("Note cannot be empty"))]
+    #[case::too_long(
+        "a".repeat(MAX_REVIEW_CONFLICT_NOTE_CHARS + 1),
+        ApiError::invalid_argument("Note must be less than 1000 characters")
+    )]
+    fn resolve_review_conflict_invalid_note(#[case] note: String, #[case] error: ApiError) {
+        let mut rc_repository_mock = MockReviewConflictRepository::new();
+        rc_repository_mock.expect_update_review_conflict().never();
+
+        let service = ReviewConflictServiceImpl::new(
+            rc_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .resolve_review_conflict(
+                fixtures::principal_a(),
+                ResolveReviewConflictRequest {
+                    id: fixtures::uuid().to_string(),
+                    note,
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    fn proposal_review_commit(
+        commit_sha: CommitSha,
+        matches_description: Option<bool>,
+    ) -> ProposalReviewCommit {
+        ProposalReviewCommit {
+            commit_sha,
+            state: ReviewCommitState::Reviewed(ReviewedCommitState {
+                matches_description,
+                comment: None,
+            }),
+            ..fixtures::proposal_review_commit_reviewed()
+        }
+    }
+}
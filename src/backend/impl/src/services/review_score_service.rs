@@ -0,0 +1,543 @@
+use crate::{
+    mappings::{map_review_score, map_review_scores},
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, ProposalRepository,
+        ProposalRepositoryImpl, ProposalReviewId, ProposalReviewRepository,
+        ProposalReviewRepositoryImpl, ReviewScore, ReviewScoreRepository,
+        ReviewScoreRepositoryImpl, ReviewerReputation, UserId, UserProfileRepository,
+        UserProfileRepositoryImpl, MAX_REVIEW_SCORE, MIN_REVIEW_SCORE, REPUTATION_WINDOW_SIZE,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ListReviewScoresRequest, ListReviewScoresResponse, ScoreProposalReviewRequest,
+    ScoreProposalReviewResponse,
+};
+use candid::Principal;
+
+const MAX_REVIEW_SCORE_COMMENT_CHARS: usize = 1000;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewScoreService {
+    fn score_proposal_review(
+        &self,
+        calling_principal: Principal,
+        request: ScoreProposalReviewRequest,
+    ) -> Result<ScoreProposalReviewResponse, ApiError>;
+
+    fn list_review_scores(
+        &self,
+        request: ListReviewScoresRequest,
+    ) -> Result<ListReviewScoresResponse, ApiError>;
+}
+
+pub struct ReviewScoreServiceImpl<
+    RS: ReviewScoreRepository,
+    PR: ProposalReviewRepository,
+    P: ProposalRepository,
+    U: UserProfileRepository,
+    AL: AuditLogService,
+> {
+    review_score_repository: RS,
+    proposal_review_repository: PR,
+    proposal_repository: P,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for ReviewScoreServiceImpl<
+        ReviewScoreRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        ProposalRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            ReviewScoreRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        RS: ReviewScoreRepository,
+        PR: ProposalReviewRepository,
+        P: ProposalRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > ReviewScoreService for ReviewScoreServiceImpl<RS, PR, P, U, AL>
+{
+    fn score_proposal_review(
+        &self,
+        calling_principal: Principal,
+        request: ScoreProposalReviewRequest,
+    ) -> Result<ScoreProposalReviewResponse, ApiError> {
+        self.validate_score_proposal_review_request(&request)?;
+
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+        let proposal_review_id = ProposalReviewId::try_from(request.proposal_review_id.as_str())?;
+
+        let proposal_review = self
+            .proposal_review_repository
+            .get_proposal_review_by_id(&proposal_review_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Proposal review with Id {} not found",
+                    proposal_review_id
+                ))
+            })?;
+
+        if !proposal_review.is_published() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal review with Id {} is not published",
+                proposal_review_id
+            )));
+        }
+
+        let proposal = self
+            .proposal_repository
+            .get_proposal_by_id(&proposal_review.proposal_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Proposal with Id {} not found",
+                    proposal_review.proposal_id
+                ))
+            })?;
+
+        // timeliness can only be judged once the review period is over
+        if !proposal.is_completed() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal with Id {} is not completed yet",
+                proposal_review.proposal_id
+            )));
+        }
+
+        let now = DateTime::new(get_date_time()?)?;
+        let existing_review_score = self
+            .review_score_repository
+            .get_review_score_by_proposal_review_id(&proposal_review_id);
+        let (created_at, last_updated_at) = match &existing_review_score {
+            Some(existing_review_score) => (existing_review_score.created_at, Some(now)),
+            None => (now, None),
+        };
+
+        let review_score = ReviewScore {
+            proposal_id: proposal_review.proposal_id,
+            reviewer_user_id: proposal_review.user_id,
+            scored_by: user_id,
+            depth: request.depth,
+            accuracy: request.accuracy,
+            timeliness: request.timeliness,
+            comment: request.comment,
+            created_at,
+            last_updated_at,
+        };
+
+        self.review_score_repository
+            .upsert_review_score(proposal_review_id, review_score.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ScoreProposalReview,
+            vec![proposal_review_id.to_string()],
+            existing_review_score.as_ref().map(audit_snapshot),
+            Some(audit_snapshot(&review_score)),
+        )?;
+
+        Ok(map_review_score(proposal_review_id, review_score))
+    }
+
+    fn list_review_scores(
+        &self,
+        request: ListReviewScoresRequest,
+    ) -> Result<ListReviewScoresResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+
+        let review_scores = self
+            .review_score_repository
+            .get_review_scores_by_user_id(user_id)?;
+        let latest_review_scores = self
+            .review_score_repository
+            .get_latest_review_scores_by_user_id(user_id, REPUTATION_WINDOW_SIZE)?;
+
+        Ok(ListReviewScoresResponse {
+            review_scores: map_review_scores(review_scores),
+            reputation: ReviewerReputation::from_review_scores(&latest_review_scores)
+                .map(Into::into),
+        })
+    }
+}
+
+impl<
+        RS: ReviewScoreRepository,
+        PR: ProposalReviewRepository,
+        P: ProposalRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > ReviewScoreServiceImpl<RS, PR, P, U, AL>
+{
+    fn new(
+        review_score_repository: RS,
+        proposal_review_repository: PR,
+        proposal_repository: P,
+        user_profile_repository: U,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            review_score_repository,
+            proposal_review_repository,
+            proposal_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+
+    fn validate_score_proposal_review_request(
+        &self,
+        request: &ScoreProposalReviewRequest,
+    ) -> Result<(), ApiError> {
+        for (name, score) in [
+            ("Depth", request.depth),
+            ("Accuracy", request.accuracy),
+            ("Timeliness", request.timeliness),
+        ] {
+            if !(MIN_REVIEW_SCORE..=MAX_REVIEW_SCORE).contains(&score) {
+                return Err(ApiError::invalid_argument(&format!(
+                    "{} score must be between {} and {}",
+                    name, MIN_REVIEW_SCORE, MAX_REVIEW_SCORE
+                )));
+            }
+        }
+
+        if request.comment.trim().is_empty() {
+            return Err(ApiError::invalid_argument("Comment cannot be empty"));
+        }
+
+        if request.comment.chars().count() > MAX_REVIEW_SCORE_COMMENT_CHARS {
+            return Err(ApiError::invalid_argument(&format!(
+                "Comment must be less than {} characters",
+                MAX_REVIEW_SCORE_COMMENT_CHARS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_b},
+        repositories::{
+            MockProposalRepository, MockProposalReviewRepository, MockReviewScoreRepository,
+            MockUserProfileRepository,
+        },
+        services::MockAuditLogService,
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn score_proposal_review_request() -> ScoreProposalReviewRequest {
+        let review_score = fixtures::review_score();
+
+        ScoreProposalReviewRequest {
+            proposal_review_id: fixtures::proposal_review_id().to_string(),
+            depth: review_score.depth,
+            accuracy: review_score.accuracy,
+            timeliness: review_score.timeliness,
+            comment: review_score.comment,
+        }
+    }
+
+    #[rstest]
+    #[case::new(None)]
+    #[case::existing(Some(fixtures::review_score()))]
+    fn score_proposal_review(#[case] existing_review_score: Option<ReviewScore>) {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_id = fixtures::proposal_review_id();
+        let proposal_review = fixtures::proposal_review_published();
+        let now = DateTime::new(get_date_time().unwrap()).unwrap();
+        let review_score = ReviewScore {
+            proposal_id: proposal_review.proposal_id,
+            reviewer_user_id: proposal_review.user_id,
+            scored_by: uuid_b(),
+            created_at: existing_review_score
+                .as_ref()
+                .map(|review_score| review_score.created_at)
+                .unwrap_or(now),
+            last_updated_at: existing_review_score.as_ref().map(|_| now),
+            ..fixtures::review_score()
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(proposal_review.clone()));
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_review.proposal_id))
+            .return_const(Some(
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            ));
+
+        let mut rs_repository_mock = MockReviewScoreRepository::new();
+        rs_repository_mock
+            .expect_get_review_score_by_proposal_review_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(existing_review_score.clone());
+        rs_repository_mock
+            .expect_upsert_review_score()
+            .once()
+            .with(eq(proposal_review_id), eq(review_score.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::ScoreProposalReview),
+                eq(vec![proposal_review_id.to_string()]),
+                eq(existing_review_score.as_ref().map(audit_snapshot)),
+                eq(Some(audit_snapshot(&review_score))),
+            )
+            .return_const(Ok(()));
+
+        let service = ReviewScoreServiceImpl::new(
+            rs_repository_mock,
+            pr_repository_mock,
+            p_repository_mock,
+            u_repository_mock,
+            audit_log_service_mock,
+        );
+
+        let result = service
+            .score_proposal_review(calling_principal, score_proposal_review_request())
+            .unwrap();
+
+        assert_eq!(result, map_review_score(proposal_review_id, review_score));
+    }
+
+    #[rstest]
+    fn score_proposal_review_proposal_not_completed() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_id = fixtures::proposal_review_id();
+        let proposal_review = fixtures::proposal_review_published();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(proposal_review.clone()));
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_review.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+
+        let mut rs_repository_mock = MockReviewScoreRepository::new();
+        rs_repository_mock.expect_upsert_review_score().never();
+
+        let service = ReviewScoreServiceImpl::new(
+            rs_repository_mock,
+            pr_repository_mock,
+            p_repository_mock,
+            u_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .score_proposal_review(calling_principal, score_proposal_review_request())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal with Id {} is not completed yet",
+                proposal_review.proposal_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn score_proposal_review_draft() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_review_id = fixtures::proposal_review_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_id()
+            .once()
+            .with(eq(proposal_review_id))
+            .return_const(Some(fixtures::proposal_review_draft()));
+
+        let mut rs_repository_mock = MockReviewScoreRepository::new();
+        rs_repository_mock.expect_upsert_review_score().never();
+
+        let service = ReviewScoreServiceImpl::new(
+            rs_repository_mock,
+            pr_repository_mock,
+            MockProposalRepository::new(),
+            u_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .score_proposal_review(calling_principal, score_proposal_review_request())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal review with Id {} is not published",
+                proposal_review_id
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case::depth_too_low(
+        ScoreProposalReviewRequest {
+            depth: 0,
+            ..score_proposal_review_request()
+        },
+        ApiError::invalid_argument("Depth score must be between 1 and 5")
+    )]
+    #[case::timeliness_too_high(
+        ScoreProposalReviewRequest {
+            timeliness: 6,
+            ..score_proposal_review_request()
+        },
+        ApiError::invalid_argument("Timeliness score must be between 1 and 5")
+    )]
+    #[case::empty_comment(
+        ScoreProposalReviewRequest {
+            comment: " ".to_string(),
+            ..score_proposal_review_request()
+        },
+        ApiError::invalid_argument("Comment cannot be empty")
+    )]
+    #[case::comment_too_long(
+        ScoreProposalReviewRequest {
+            comment: "a".repeat(MAX_REVIEW_SCORE_COMMENT_CHARS + 1),
+            ..score_proposal_review_request()
+        },
+        ApiError::invalid_argument(&format!(
+            "Comment must be less than {} characters",
+            MAX_REVIEW_SCORE_COMMENT_CHARS
+        ))
+    )]
+    fn score_proposal_review_invalid_request(
+        #[case] request: ScoreProposalReviewRequest,
+        #[case] error: ApiError,
+    ) {
+        let mut rs_repository_mock = MockReviewScoreRepository::new();
+        rs_repository_mock.expect_upsert_review_score().never();
+
+        let service = ReviewScoreServiceImpl::new(
+            rs_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .score_proposal_review(fixtures::principal_a(), request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn list_review_scores() {
+        let user_id = fixtures::user_id();
+        let review_scores = vec![(fixtures::proposal_review_id(), fixtures::review_score())];
+
+        let mut rs_repository_mock = MockReviewScoreRepository::new();
+        rs_repository_mock
+            .expect_get_review_scores_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(review_scores.clone()));
+        rs_repository_mock
+            .expect_get_latest_review_scores_by_user_id()
+            .once()
+            .with(eq(user_id), eq(REPUTATION_WINDOW_SIZE))
+            .return_const(Ok(vec![fixtures::review_score()]));
+
+        let service = ReviewScoreServiceImpl::new(
+            rs_repository_mock,
+            MockProposalReviewRepository::new(),
+            MockProposalRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_review_scores(ListReviewScoresRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListReviewScoresResponse {
+                review_scores: map_review_scores(review_scores),
+                reputation: Some(backend_api::ReviewerReputation {
+                    score: 75,
+                    scored_reviews_count: 1,
+                }),
+            }
+        );
+    }
+}
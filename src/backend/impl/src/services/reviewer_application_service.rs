@@ -0,0 +1,797 @@
+use crate::{
+    mappings::map_reviewer_application,
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, ReviewerApplication,
+        ReviewerApplicationId, ReviewerApplicationRepository, ReviewerApplicationRepositoryImpl,
+        ReviewerApplicationStatus, SocialLink, UserConfig, UserId, UserProfile,
+        UserProfileRepository, UserProfileRepositoryImpl, WalletAddress,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, ApproveReviewerApplicationRequest, ListReviewerApplicationsRequest,
+    ListReviewerApplicationsResponse, RejectReviewerApplicationRequest,
+    SubmitReviewerApplicationRequest, SubmitReviewerApplicationResponse,
+};
+use candid::Principal;
+
+const MAX_REJECTION_REASON_CHARS: usize = 1000;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerApplicationService {
+    fn submit_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: SubmitReviewerApplicationRequest,
+    ) -> Result<SubmitReviewerApplicationResponse, ApiError>;
+
+    fn list_reviewer_applications(
+        &self,
+        request: ListReviewerApplicationsRequest,
+    ) -> Result<ListReviewerApplicationsResponse, ApiError>;
+
+    /// Promotes the applicant to reviewer and marks the application as approved.
+    fn approve_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: ApproveReviewerApplicationRequest,
+    ) -> Result<(), ApiError>;
+
+    fn reject_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: RejectReviewerApplicationRequest,
+    ) -> Result<(), ApiError>;
+}
+
+pub struct ReviewerApplicationServiceImpl<
+    RA: ReviewerApplicationRepository,
+    U: UserProfileRepository,
+    AL: AuditLogService,
+> {
+    reviewer_application_repository: RA,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for ReviewerApplicationServiceImpl<
+        ReviewerApplicationRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            ReviewerApplicationRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<RA: ReviewerApplicationRepository, U: UserProfileRepository, AL: AuditLogService>
+    ReviewerApplicationService for ReviewerApplicationServiceImpl<RA, U, AL>
+{
+    fn submit_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: SubmitReviewerApplicationRequest,
+    ) -> Result<SubmitReviewerApplicationResponse, ApiError> {
+        if request.bio.trim().is_empty() {
+            return Err(ApiError::invalid_argument("Bio cannot be empty"));
+        }
+        let wallet_address = WalletAddress::try_from(request.wallet_address.as_str())?;
+
+        let (user_id, user_profile) = self
+            .user_profile_repository
+            .get_user_profile_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+        self.assert_user_is_anonymous(user_id, &user_profile)?;
+
+        let has_pending_application = self
+            .reviewer_application_repository
+            .get_reviewer_applications_by_user_id(&user_id)
+            .iter()
+            .any(|(_, reviewer_application)| reviewer_application.is_pending());
+        if has_pending_application {
+            return Err(ApiError::conflict(&format!(
+                "User with id {} already has a pending reviewer application",
+                user_id
+            )));
+        }
+
+        let reviewer_application = ReviewerApplication {
+            user_id,
+            bio: request.bio,
+            neuron_id: request.neuron_id,
+            wallet_address,
+            social_links: request
+                .social_links
+                .into_iter()
+                .map(SocialLink::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            created_at: DateTime::new(get_date_time()?)?,
+            status: ReviewerApplicationStatus::Pending,
+        };
+        let id = self
+            .reviewer_application_repository
+            .create_reviewer_application(reviewer_application.clone())?;
+
+        Ok(map_reviewer_application(id, reviewer_application))
+    }
+
+    fn list_reviewer_applications(
+        &self,
+        request: ListReviewerApplicationsRequest,
+    ) -> Result<ListReviewerApplicationsResponse, ApiError> {
+        Ok(ListReviewerApplicationsResponse {
+            reviewer_applications: self
+                .reviewer_application_repository
+                .get_reviewer_applications()
+                .into_iter()
+                .filter(|(_, reviewer_application)| {
+                    !request.pending_only || reviewer_application.is_pending()
+                })
+                .map(|(id, reviewer_application)| {
+                    map_reviewer_application(id, reviewer_application)
+                })
+                .collect(),
+        })
+    }
+
+    fn approve_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: ApproveReviewerApplicationRequest,
+    ) -> Result<(), ApiError> {
+        let reviewer_user_id = self.get_user_id(&calling_principal)?;
+        let (id, reviewer_application) = self.get_pending_reviewer_application(&request.id)?;
+
+        let mut user_profile = self
+            .user_profile_repository
+            .get_user_profile_by_user_id(&reviewer_application.user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for user with id {} not found",
+                    reviewer_application.user_id
+                ))
+            })?;
+        self.assert_user_is_anonymous(reviewer_application.user_id, &user_profile)?;
+
+        user_profile.config = UserConfig::Reviewer {
+            bio: reviewer_application.bio.clone(),
+            neuron_id: reviewer_application.neuron_id,
+            wallet_address: Some(reviewer_application.wallet_address.clone()),
+            social_links: reviewer_application.social_links.clone(),
+        };
+
+        let current_time = DateTime::new(get_date_time()?)?;
+
+        // everything is validated at this point, so that the application
+        // can't be left pending once the profile has been promoted
+        self.user_profile_repository.update_user_profile(
+            calling_principal,
+            reviewer_application.user_id,
+            user_profile,
+        )?;
+
+        let approved_reviewer_application = ReviewerApplication {
+            status: ReviewerApplicationStatus::Approved {
+                reviewed_by: reviewer_user_id,
+                reviewed_at: current_time,
+            },
+            ..reviewer_application.clone()
+        };
+        self.reviewer_application_repository
+            .update_reviewer_application(id, approved_reviewer_application.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ApproveReviewerApplication,
+            vec![id.to_string(), reviewer_application.user_id.to_string()],
+            Some(audit_snapshot(&reviewer_application)),
+            Some(audit_snapshot(&approved_reviewer_application)),
+        )
+    }
+
+    fn reject_reviewer_application(
+        &self,
+        calling_principal: Principal,
+        request: RejectReviewerApplicationRequest,
+    ) -> Result<(), ApiError> {
+        self.validate_reason(&request.reason)?;
+
+        let reviewer_user_id = self.get_user_id(&calling_principal)?;
+        let (id, reviewer_application) = self.get_pending_reviewer_application(&request.id)?;
+
+        let rejected_reviewer_application = ReviewerApplication {
+            status: ReviewerApplicationStatus::Rejected {
+                reviewed_by: reviewer_user_id,
+                reviewed_at: DateTime::new(get_date_time()?)?,
+                reason: request.reason,
+            },
+            ..reviewer_application.clone()
+        };
+        self.reviewer_application_repository
+            .update_reviewer_application(id, rejected_reviewer_application.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::RejectReviewerApplication,
+            vec![id.to_string(), reviewer_application.user_id.to_string()],
+            Some(audit_snapshot(&reviewer_application)),
+            Some(audit_snapshot(&rejected_reviewer_application)),
+        )
+    }
+}
+
+impl<RA: ReviewerApplicationRepository, U: UserProfileRepository, AL: AuditLogService>
+    ReviewerApplicationServiceImpl<RA, U, AL>
+{
+    fn new(
+        reviewer_application_repository: RA,
+        user_profile_repository: U,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            reviewer_application_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+
+    fn get_user_id(&self, calling_principal: &Principal) -> Result<UserId, ApiError> {
+        self.user_profile_repository
+            .get_user_id_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    fn get_pending_reviewer_application(
+        &self,
+        id: &str,
+    ) -> Result<(ReviewerApplicationId, ReviewerApplication), ApiError> {
+        let id = ReviewerApplicationId::try_from(id)?;
+        let reviewer_application = self
+            .reviewer_application_repository
+            .get_reviewer_application_by_id(&id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!("Reviewer application with id {} not found", id))
+            })?;
+
+        if !reviewer_application.is_pending() {
+            return Err(ApiError::conflict(&format!(
+                "Reviewer application with id {} was already reviewed",
+                id
+            )));
+        }
+
+        Ok((id, reviewer_application))
+    }
+
+    fn assert_user_is_anonymous(
+        &self,
+        user_id: UserId,
+        user_profile: &UserProfile,
+    ) -> Result<(), ApiError> {
+        if user_profile.config != UserConfig::Anonymous {
+            return Err(ApiError::conflict(&format!(
+                "User with id {} is already a reviewer or an admin",
+                user_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn validate_reason(&self, reason: &str) -> Result<(), ApiError> {
+        if reason.trim().is_empty() {
+            return Err(ApiError::invalid_argument("Reason cannot be empty"));
+        }
+        if reason.chars().count() > MAX_REJECTION_REASON_CHARS {
+            return Err(ApiError::invalid_argument(&format!(
+                "Reason must be less than {} characters",
+                MAX_REJECTION_REASON_CHARS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{MockReviewerApplicationRepository, MockUserProfileRepository},
+        services::MockAuditLogService,
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn submit_request() -> SubmitReviewerApplicationRequest {
+        let reviewer_application = fixtures::reviewer_application_pending();
+
+        SubmitReviewerApplicationRequest {
+            bio: reviewer_application.bio,
+            neuron_id: reviewer_application.neuron_id,
+            wallet_address: reviewer_application.wallet_address.to_string(),
+            social_links: reviewer_application
+                .social_links
+                .into_iter()
+                .map(|link| link.into())
+                .collect(),
+        }
+    }
+
+    #[rstest]
+    fn submit_reviewer_application() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::anonymous_user_profile())));
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_applications_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(vec![(
+                fixtures::uuid_a(),
+                fixtures::reviewer_application_rejected(),
+            )]);
+        ra_repository_mock
+            .expect_create_reviewer_application()
+            .once()
+            .withf(move |reviewer_application| {
+                reviewer_application.user_id == user_id
+                    && reviewer_application.is_pending()
+                    && reviewer_application.wallet_address == fixtures::wallet_address()
+            })
+            .return_const(Ok(fixtures::uuid_b()));
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .submit_reviewer_application(calling_principal, submit_request())
+            .unwrap();
+
+        assert_eq!(result.id, fixtures::uuid_b().to_string());
+        assert_eq!(
+            result.reviewer_application.status,
+            backend_api::ReviewerApplicationStatus::Pending
+        );
+    }
+
+    #[rstest]
+    fn submit_reviewer_application_not_anonymous() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_create_reviewer_application()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .submit_reviewer_application(calling_principal, submit_request())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} is already a reviewer or an admin",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn submit_reviewer_application_already_pending() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .return_const(Some((user_id, fixtures::anonymous_user_profile())));
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_applications_by_user_id()
+            .once()
+            .return_const(vec![(
+                fixtures::uuid_a(),
+                fixtures::reviewer_application_pending(),
+            )]);
+        ra_repository_mock
+            .expect_create_reviewer_application()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .submit_reviewer_application(calling_principal, submit_request())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} already has a pending reviewer application",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn submit_reviewer_application_invalid_wallet_address() {
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            MockReviewerApplicationRepository::new(),
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .submit_reviewer_application(
+                fixtures::principal_a(),
+                SubmitReviewerApplicationRequest {
+                    wallet_address: "my wallet".to_string(),
+                    ..submit_request()
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(
+                "Wallet address my wallet must be an ICP account identifier or an ICRC-1 account"
+            )
+        );
+    }
+
+    #[rstest]
+    #[case::all(false, 2)]
+    #[case::pending_only(true, 1)]
+    fn list_reviewer_applications(#[case] pending_only: bool, #[case] expected_len: usize) {
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_applications()
+            .once()
+            .return_const(vec![
+                (fixtures::uuid_a(), fixtures::reviewer_application_pending()),
+                (
+                    fixtures::uuid_b(),
+                    fixtures::reviewer_application_rejected(),
+                ),
+            ]);
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_reviewer_applications(ListReviewerApplicationsRequest { pending_only })
+            .unwrap();
+
+        assert_eq!(result.reviewer_applications.len(), expected_len);
+        assert_eq!(
+            result.reviewer_applications[0],
+            map_reviewer_application(fixtures::uuid_a(), fixtures::reviewer_application_pending())
+        );
+    }
+
+    #[rstest]
+    fn approve_reviewer_application() {
+        let calling_principal = fixtures::principal_b();
+        let admin_user_id = fixtures::uuid_a();
+        let id = fixtures::uuid_b();
+        let reviewer_application = fixtures::reviewer_application_pending();
+        let applicant_user_id = reviewer_application.user_id;
+        let expected_config = UserConfig::Reviewer {
+            bio: reviewer_application.bio.clone(),
+            neuron_id: reviewer_application.neuron_id,
+            wallet_address: Some(reviewer_application.wallet_address.clone()),
+            social_links: reviewer_application.social_links.clone(),
+        };
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(admin_user_id));
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(applicant_user_id))
+            .return_const(Some(fixtures::anonymous_user_profile()));
+        up_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .withf(move |principal, user_id, user_profile| {
+                *principal == calling_principal
+                    && *user_id == applicant_user_id
+                    && user_profile.username == fixtures::anonymous_user_profile().username
+                    && user_profile.config == expected_config
+            })
+            .return_const(Ok(()));
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_application_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(reviewer_application.clone()));
+        ra_repository_mock
+            .expect_update_reviewer_application()
+            .once()
+            .withf(move |reviewer_application_id, reviewer_application| {
+                *reviewer_application_id == id
+                    && matches!(
+                        reviewer_application.status,
+                        ReviewerApplicationStatus::Approved { reviewed_by, .. }
+                            if reviewed_by == admin_user_id
+                    )
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::ApproveReviewerApplication),
+                eq(vec![id.to_string(), applicant_user_id.to_string()]),
+                eq(Some(audit_snapshot(&reviewer_application))),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .approve_reviewer_application(
+                calling_principal,
+                ApproveReviewerApplicationRequest { id: id.to_string() },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn approve_reviewer_application_already_reviewed() {
+        let calling_principal = fixtures::principal_b();
+        let id = fixtures::uuid_b();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .return_const(Some(fixtures::uuid_a()));
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_application_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::reviewer_application_rejected()));
+        ra_repository_mock
+            .expect_update_reviewer_application()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .approve_reviewer_application(
+                calling_principal,
+                ApproveReviewerApplicationRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reviewer application with id {} was already reviewed",
+                id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn approve_reviewer_application_applicant_not_anonymous() {
+        let id = fixtures::uuid_b();
+        let applicant_user_id = fixtures::reviewer_application_pending().user_id;
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .return_const(Some(fixtures::uuid_a()));
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .return_const(Some(fixtures::admin_user_profile()));
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_application_by_id()
+            .once()
+            .return_const(Some(fixtures::reviewer_application_pending()));
+        ra_repository_mock
+            .expect_update_reviewer_application()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .approve_reviewer_application(
+                fixtures::principal_b(),
+                ApproveReviewerApplicationRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} is already a reviewer or an admin",
+                applicant_user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn reject_reviewer_application() {
+        let calling_principal = fixtures::principal_b();
+        let admin_user_id = fixtures::uuid_a();
+        let id = fixtures::uuid_b();
+        let reason = "The neuron has no voting history yet.".to_string();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(admin_user_id));
+        up_repository_mock.expect_update_user_profile().never();
+
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_application_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::reviewer_application_pending()));
+        let expected_reason = reason.clone();
+        ra_repository_mock
+            .expect_update_reviewer_application()
+            .once()
+            .withf(move |reviewer_application_id, reviewer_application| {
+                *reviewer_application_id == id
+                    && matches!(
+                        &reviewer_application.status,
+                        ReviewerApplicationStatus::Rejected { reviewed_by, reason, .. }
+                            if *reviewed_by == admin_user_id && *reason == expected_reason
+                    )
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::RejectReviewerApplication),
+                eq(vec![
+                    id.to_string(),
+                    fixtures::reviewer_application_pending().user_id.to_string(),
+                ]),
+                eq(Some(audit_snapshot(
+                    &fixtures::reviewer_application_pending(),
+                ))),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            up_repository_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .reject_reviewer_application(
+                calling_principal,
+                RejectReviewerApplicationRequest {
+                    id: id.to_string(),
+                    reason,
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn reject_reviewer_application_empty_reason() {
+        let mut ra_repository_mock = MockReviewerApplicationRepository::new();
+        ra_repository_mock
+            .expect_update_reviewer_application()
+            .never();
+
+        let service = ReviewerApplicationServiceImpl::new(
+            ra_repository_mock,
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .reject_reviewer_application(
+                fixtures::principal_b(),
+                RejectReviewerApplicationRequest {
+                    id: fixtures::uuid_b().to_string(),
+                    reason: " ".to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::invalid_argument("Reason cannot be empty"));
+    }
+}
@@ -0,0 +1,1515 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    mappings::{
+        map_reviewer_assignment, map_reviewer_assignments, reviewer_assignment_status_matches_key,
+    },
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, ProposalId,
+        ProposalRepository, ProposalRepositoryImpl, ProposalReviewRepository,
+        ProposalReviewRepositoryImpl, ReviewPeriodStateKey, ReviewerAssignment,
+        ReviewerAssignmentConfig, ReviewerAssignmentId, ReviewerAssignmentPolicy,
+        ReviewerAssignmentRepository, ReviewerAssignmentRepositoryImpl, ReviewerAssignmentStatus,
+        UserId, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    AcceptReviewerAssignmentRequest, ApiError, AssignReviewerRequest, AssignReviewerResponse,
+    DeclineReviewerAssignmentRequest, GetReviewerAssignmentConfigResponse,
+    ListMyReviewerAssignmentsRequest, ListMyReviewerAssignmentsResponse,
+    ListProposalReviewerAssignmentsRequest, ListProposalReviewerAssignmentsResponse,
+    ListUnfulfilledReviewerAssignmentsRequest, ListUnfulfilledReviewerAssignmentsResponse,
+    UpdateReviewerAssignmentConfigRequest,
+};
+use candid::Principal;
+
+const MAX_REVIEWERS_PER_PROPOSAL: u8 = 10;
+
+const MAX_DECLINE_REASON_CHARS: usize = 500;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerAssignmentService {
+    fn assign_reviewer(
+        &self,
+        calling_principal: Principal,
+        request: AssignReviewerRequest,
+    ) -> Result<AssignReviewerResponse, ApiError>;
+
+    fn accept_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: AcceptReviewerAssignmentRequest,
+    ) -> Result<(), ApiError>;
+
+    fn decline_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: DeclineReviewerAssignmentRequest,
+    ) -> Result<(), ApiError>;
+
+    fn list_my_reviewer_assignments(
+        &self,
+        calling_principal: Principal,
+        request: ListMyReviewerAssignmentsRequest,
+    ) -> Result<ListMyReviewerAssignmentsResponse, ApiError>;
+
+    fn list_proposal_reviewer_assignments(
+        &self,
+        request: ListProposalReviewerAssignmentsRequest,
+    ) -> Result<ListProposalReviewerAssignmentsResponse, ApiError>;
+
+    fn list_unfulfilled_reviewer_assignments(
+        &self,
+        request: ListUnfulfilledReviewerAssignmentsRequest,
+    ) -> Result<ListUnfulfilledReviewerAssignmentsResponse, ApiError>;
+
+    fn get_reviewer_assignment_config(&self) -> GetReviewerAssignmentConfigResponse;
+
+    fn update_reviewer_assignment_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateReviewerAssignmentConfigRequest,
+    ) -> Result<(), ApiError>;
+
+    /// Assigns reviewers to the proposals that are in progress, according to the
+    /// configured [ReviewerAssignmentPolicy]. Returns the number of created assignments.
+    fn assign_reviewers(&self) -> Result<usize, ApiError>;
+
+    /// Closes the open assignments of completed proposals, marking them as fulfilled
+    /// if the reviewer published a review. Returns the number of closed assignments.
+    fn close_reviewer_assignments(&self) -> Result<usize, ApiError>;
+}
+
+pub struct ReviewerAssignmentServiceImpl<
+    RA: ReviewerAssignmentRepository,
+    U: UserProfileRepository,
+    P: ProposalRepository,
+    PR: ProposalReviewRepository,
+    AL: AuditLogService,
+> {
+    reviewer_assignment_repository: RA,
+    user_profile_repository: U,
+    proposal_repository: P,
+    proposal_review_repository: PR,
+    audit_log_service: AL,
+}
+
+impl Default
+    for ReviewerAssignmentServiceImpl<
+        ReviewerAssignmentRepositoryImpl,
+        UserProfileRepositoryImpl,
+        ProposalRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            ReviewerAssignmentRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        RA: ReviewerAssignmentRepository,
+        U: UserProfileRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        AL: AuditLogService,
+    > ReviewerAssignmentService for ReviewerAssignmentServiceImpl<RA, U, P, PR, AL>
+{
+    fn assign_reviewer(
+        &self,
+        calling_principal: Principal,
+        request: AssignReviewerRequest,
+    ) -> Result<AssignReviewerResponse, ApiError> {
+        let admin_user_id = self.get_user_id(&calling_principal)?;
+
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+        self.assert_proposal_in_progress(proposal_id)?;
+
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let user_profile = self
+            .user_profile_repository
+            .get_user_profile_by_user_id(&user_id)
+            .ok_or_else(|| ApiError::not_found(&format!("User with Id {} not found", user_id)))?;
+        if !user_profile.is_reviewer() {
+            return Err(ApiError::invalid_argument(&format!(
+                "User with Id {} is not a reviewer",
+                user_id
+            )));
+        }
+
+        if self
+            .reviewer_assignment_repository
+            .get_reviewer_assignment_by_proposal_id_and_user_id(proposal_id, user_id)
+            .is_some()
+        {
+            return Err(ApiError::conflict(&format!(
+                "User with Id {} has already been assigned to proposal with Id {}",
+                user_id, proposal_id
+            )));
+        }
+
+        let reviewer_assignment = ReviewerAssignment {
+            proposal_id,
+            user_id,
+            assigned_by: Some(admin_user_id),
+            created_at: DateTime::new(get_date_time()?)?,
+            last_updated_at: None,
+            status: ReviewerAssignmentStatus::Pending,
+        };
+
+        let id = self
+            .reviewer_assignment_repository
+            .create_reviewer_assignment(reviewer_assignment.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::AssignReviewer,
+            vec![id.to_string(), proposal_id.to_string(), user_id.to_string()],
+            None,
+            Some(audit_snapshot(&reviewer_assignment)),
+        )?;
+
+        Ok(map_reviewer_assignment(id, reviewer_assignment))
+    }
+
+    fn accept_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: AcceptReviewerAssignmentRequest,
+    ) -> Result<(), ApiError> {
+        let (id, mut reviewer_assignment) =
+            self.get_my_reviewer_assignment(&calling_principal, request.id.as_str())?;
+
+        if reviewer_assignment.status != ReviewerAssignmentStatus::Pending {
+            return Err(ApiError::conflict(&format!(
+                "Reviewer assignment with Id {} is not pending",
+                id
+            )));
+        }
+        self.assert_proposal_in_progress(reviewer_assignment.proposal_id)?;
+
+        reviewer_assignment.status = ReviewerAssignmentStatus::Accepted;
+        reviewer_assignment.last_updated_at = Some(DateTime::new(get_date_time()?)?);
+
+        self.reviewer_assignment_repository
+            .update_reviewer_assignment(id, reviewer_assignment)
+    }
+
+    fn decline_reviewer_assignment(
+        &self,
+        calling_principal: Principal,
+        request: DeclineReviewerAssignmentRequest,
+    ) -> Result<(), ApiError> {
+        if let Some(reason) = &request.reason {
+            if reason.chars().count() > MAX_DECLINE_REASON_CHARS {
+                return Err(ApiError::invalid_argument(&format!(
+                    "Decline reason must be less than {} characters",
+                    MAX_DECLINE_REASON_CHARS
+                )));
+            }
+        }
+
+        let (id, mut reviewer_assignment) =
+            self.get_my_reviewer_assignment(&calling_principal, request.id.as_str())?;
+
+        if !reviewer_assignment.is_open() {
+            return Err(ApiError::conflict(&format!(
+                "Reviewer assignment with Id {} is already closed",
+                id
+            )));
+        }
+        self.assert_proposal_in_progress(reviewer_assignment.proposal_id)?;
+
+        reviewer_assignment.status = ReviewerAssignmentStatus::Declined {
+            reason: request.reason,
+        };
+        reviewer_assignment.last_updated_at = Some(DateTime::new(get_date_time()?)?);
+
+        self.reviewer_assignment_repository
+            .update_reviewer_assignment(id, reviewer_assignment)
+    }
+
+    fn list_my_reviewer_assignments(
+        &self,
+        calling_principal: Principal,
+        request: ListMyReviewerAssignmentsRequest,
+    ) -> Result<ListMyReviewerAssignmentsResponse, ApiError> {
+        let user_id = self.get_user_id(&calling_principal)?;
+
+        let reviewer_assignments = self
+            .reviewer_assignment_repository
+            .get_reviewer_assignments_by_user_id(user_id)?
+            .into_iter()
+            .filter(|(_, reviewer_assignment)| {
+                request.status.as_ref().is_none_or(|status| {
+                    reviewer_assignment_status_matches_key(&reviewer_assignment.status, status)
+                })
+            })
+            .collect();
+
+        Ok(ListMyReviewerAssignmentsResponse {
+            reviewer_assignments: map_reviewer_assignments(reviewer_assignments),
+        })
+    }
+
+    fn list_proposal_reviewer_assignments(
+        &self,
+        request: ListProposalReviewerAssignmentsRequest,
+    ) -> Result<ListProposalReviewerAssignmentsResponse, ApiError> {
+        let proposal_id = ProposalId::try_from(request.proposal_id.as_str())?;
+
+        let reviewer_assignments = self
+            .reviewer_assignment_repository
+            .get_reviewer_assignments_by_proposal_id(proposal_id)?;
+
+        Ok(ListProposalReviewerAssignmentsResponse {
+            reviewer_assignments: map_reviewer_assignments(reviewer_assignments),
+        })
+    }
+
+    fn list_unfulfilled_reviewer_assignments(
+        &self,
+        request: ListUnfulfilledReviewerAssignmentsRequest,
+    ) -> Result<ListUnfulfilledReviewerAssignmentsResponse, ApiError> {
+        let reviewer_assignments = match request.user_id {
+            Some(user_id) => {
+                let user_id = UserId::try_from(user_id.as_str())?;
+
+                self.reviewer_assignment_repository
+                    .get_reviewer_assignments_by_user_id(user_id)?
+                    .into_iter()
+                    .filter(|(_, reviewer_assignment)| {
+                        reviewer_assignment.status == ReviewerAssignmentStatus::Unfulfilled
+                    })
+                    .collect()
+            }
+            None => self
+                .reviewer_assignment_repository
+                .get_unfulfilled_reviewer_assignments(),
+        };
+
+        Ok(ListUnfulfilledReviewerAssignmentsResponse {
+            reviewer_assignments: map_reviewer_assignments(reviewer_assignments),
+        })
+    }
+
+    fn get_reviewer_assignment_config(&self) -> GetReviewerAssignmentConfigResponse {
+        self.reviewer_assignment_repository
+            .get_reviewer_assignment_config()
+            .into()
+    }
+
+    fn update_reviewer_assignment_config(
+        &self,
+        calling_principal: Principal,
+        request: UpdateReviewerAssignmentConfigRequest,
+    ) -> Result<(), ApiError> {
+        let before = self
+            .reviewer_assignment_repository
+            .get_reviewer_assignment_config();
+        let mut config = before.clone();
+
+        if let Some(reviewers_per_proposal) = request.reviewers_per_proposal {
+            if reviewers_per_proposal == 0 || reviewers_per_proposal > MAX_REVIEWERS_PER_PROPOSAL {
+                return Err(ApiError::invalid_argument(&format!(
+                    "Reviewers per proposal must be between 1 and {}",
+                    MAX_REVIEWERS_PER_PROPOSAL
+                )));
+            }
+
+            config.reviewers_per_proposal = reviewers_per_proposal;
+        }
+        if let Some(policy) = request.policy {
+            config.policy = policy.into();
+        }
+
+        self.reviewer_assignment_repository
+            .set_reviewer_assignment_config(config.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::UpdateReviewerAssignmentConfig,
+            vec![],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&config)),
+        )
+    }
+
+    fn assign_reviewers(&self) -> Result<usize, ApiError> {
+        let mut config = self
+            .reviewer_assignment_repository
+            .get_reviewer_assignment_config();
+
+        if config.policy == ReviewerAssignmentPolicy::Manual {
+            return Ok(0);
+        }
+
+        let mut reviewers = self
+            .user_profile_repository
+            .get_all_reviewer_profiles()
+            .into_iter()
+            .map(|(user_id, _)| user_id)
+            .collect::<Vec<_>>();
+        if reviewers.is_empty() {
+            return Ok(0);
+        }
+        reviewers.sort();
+
+        let mut open_assignments_count = BTreeMap::<UserId, usize>::new();
+        for (_, reviewer_assignment) in self
+            .reviewer_assignment_repository
+            .get_open_reviewer_assignments()
+        {
+            *open_assignments_count
+                .entry(reviewer_assignment.user_id)
+                .or_default() += 1;
+        }
+
+        let proposals = self
+            .proposal_repository
+            .get_proposals(Some(ReviewPeriodStateKey::InProgress))?;
+        let created_at = DateTime::new(get_date_time()?)?;
+        let mut assigned_count = 0;
+
+        for (proposal_id, _) in proposals {
+            let existing_assignments = self
+                .reviewer_assignment_repository
+                .get_reviewer_assignments_by_proposal_id(proposal_id)?;
+
+            // declined assignments don't count towards the quota,
+            // but the reviewers that declined are not assigned again
+            let active_count = existing_assignments
+                .iter()
+                .filter(|(_, reviewer_assignment)| !reviewer_assignment.is_declined())
+                .count();
+            let needed_count =
+                (config.reviewers_per_proposal as usize).saturating_sub(active_count);
+            if needed_count == 0 {
+                continue;
+            }
+
+            let assigned_user_ids = existing_assignments
+                .iter()
+                .map(|(_, reviewer_assignment)| reviewer_assignment.user_id)
+                .collect::<BTreeSet<_>>();
+            let candidates = reviewers
+                .iter()
+                .filter(|user_id| !assigned_user_ids.contains(user_id))
+                .copied()
+                .collect::<Vec<_>>();
+
+            let selected_user_ids =
+                select_reviewers(&config, candidates, &open_assignments_count, needed_count);
+
+            for user_id in selected_user_ids {
+                self.reviewer_assignment_repository
+                    .create_reviewer_assignment(ReviewerAssignment {
+                        proposal_id,
+                        user_id,
+                        assigned_by: None,
+                        created_at,
+                        last_updated_at: None,
+                        status: ReviewerAssignmentStatus::Pending,
+                    })?;
+
+                *open_assignments_count.entry(user_id).or_default() += 1;
+                if config.policy == ReviewerAssignmentPolicy::RoundRobin {
+                    config.round_robin_cursor = Some(user_id);
+                }
+                assigned_count += 1;
+            }
+        }
+
+        if config.policy == ReviewerAssignmentPolicy::RoundRobin && assigned_count > 0 {
+            self.reviewer_assignment_repository
+                .set_reviewer_assignment_config(config)?;
+        }
+
+        Ok(assigned_count)
+    }
+
+    fn close_reviewer_assignments(&self) -> Result<usize, ApiError> {
+        let closed_at = DateTime::new(get_date_time()?)?;
+        let mut closed_count = 0;
+
+        for (id, mut reviewer_assignment) in self
+            .reviewer_assignment_repository
+            .get_open_reviewer_assignments()
+        {
+            let is_proposal_completed = self
+                .proposal_repository
+                .get_proposal_by_id(&reviewer_assignment.proposal_id)
+                .is_some_and(|proposal| proposal.is_completed());
+            if !is_proposal_completed {
+                continue;
+            }
+
+            let has_published_review = self
+                .proposal_review_repository
+                .get_proposal_review_by_proposal_id_and_user_id(
+                    reviewer_assignment.proposal_id,
+                    reviewer_assignment.user_id,
+                )
+                .is_some_and(|(_, proposal_review)| proposal_review.is_published());
+
+            reviewer_assignment.status = if has_published_review {
+                ReviewerAssignmentStatus::Fulfilled
+            } else {
+                ReviewerAssignmentStatus::Unfulfilled
+            };
+            reviewer_assignment.last_updated_at = Some(closed_at);
+
+            self.reviewer_assignment_repository
+                .update_reviewer_assignment(id, reviewer_assignment)?;
+            closed_count += 1;
+        }
+
+        Ok(closed_count)
+    }
+}
+
+impl<
+        RA: ReviewerAssignmentRepository,
+        U: UserProfileRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        AL: AuditLogService,
+    > ReviewerAssignmentServiceImpl<RA, U, P, PR, AL>
+{
+    fn new(
+        reviewer_assignment_repository: RA,
+        user_profile_repository: U,
+        proposal_repository: P,
+        proposal_review_repository: PR,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            reviewer_assignment_repository,
+            user_profile_repository,
+            proposal_repository,
+            proposal_review_repository,
+            audit_log_service,
+        }
+    }
+
+    fn get_user_id(&self, calling_principal: &Principal) -> Result<UserId, ApiError> {
+        self.user_profile_repository
+            .get_user_id_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    fn get_my_reviewer_assignment(
+        &self,
+        calling_principal: &Principal,
+        id: &str,
+    ) -> Result<(ReviewerAssignmentId, ReviewerAssignment), ApiError> {
+        let user_id = self.get_user_id(calling_principal)?;
+        let id = ReviewerAssignmentId::try_from(id)?;
+
+        self.reviewer_assignment_repository
+            .get_reviewer_assignment_by_id(&id)
+            // only the assigned reviewer can see their assignment here
+            .filter(|reviewer_assignment| reviewer_assignment.user_id == user_id)
+            .map(|reviewer_assignment| (id, reviewer_assignment))
+            .ok_or_else(|| {
+                ApiError::not_found(&format!("Reviewer assignment with Id {} not found", id))
+            })
+    }
+
+    fn assert_proposal_in_progress(&self, proposal_id: ProposalId) -> Result<(), ApiError> {
+        let proposal = self
+            .proposal_repository
+            .get_proposal_by_id(&proposal_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!("Proposal with Id {} not found", proposal_id))
+            })?;
+
+        if proposal.is_completed() {
+            return Err(ApiError::conflict(&format!(
+                "Proposal with Id {} is already completed",
+                proposal_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Picks up to `count` reviewers among the `candidates` (sorted by user id)
+/// according to the configured policy.
+fn select_reviewers(
+    config: &ReviewerAssignmentConfig,
+    mut candidates: Vec<UserId>,
+    open_assignments_count: &BTreeMap<UserId, usize>,
+    count: usize,
+) -> Vec<UserId> {
+    let count = count.min(candidates.len());
+
+    match config.policy {
+        ReviewerAssignmentPolicy::Manual => vec![],
+        ReviewerAssignmentPolicy::RoundRobin => {
+            // start from the first reviewer after the last assigned one, wrapping around
+            let start = config
+                .round_robin_cursor
+                .map_or(0, |cursor| candidates.partition_point(|id| id <= &cursor));
+
+            candidates
+                .iter()
+                .cycle()
+                .skip(start)
+                .take(count)
+                .copied()
+                .collect()
+        }
+        ReviewerAssignmentPolicy::LeastLoaded => {
+            candidates.sort_by_key(|user_id| {
+                (
+                    open_assignments_count.get(user_id).copied().unwrap_or(0),
+                    *user_id,
+                )
+            });
+            candidates.truncate(count);
+
+            candidates
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b},
+        repositories::{
+            MockProposalRepository, MockProposalReviewRepository, MockReviewerAssignmentRepository,
+            MockUserProfileRepository, ProposalReview, UserProfile,
+        },
+        services::MockAuditLogService,
+    };
+    use backend_api::ReviewerAssignmentStatusKey;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn assign_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let admin_user_id = uuid_b();
+        let proposal_id = fixtures::proposal_id();
+        let user_id = uuid_a();
+        let id = fixtures::uuid();
+        let reviewer_assignment = ReviewerAssignment {
+            proposal_id,
+            user_id,
+            assigned_by: Some(admin_user_id),
+            created_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            last_updated_at: None,
+            status: ReviewerAssignmentStatus::Pending,
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(admin_user_id));
+        u_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(proposal_id), eq(user_id))
+            .return_const(None);
+        ra_repository_mock
+            .expect_create_reviewer_assignment()
+            .once()
+            .with(eq(reviewer_assignment.clone()))
+            .return_const(Ok(id));
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::AssignReviewer),
+                eq(vec![
+                    id.to_string(),
+                    proposal_id.to_string(),
+                    user_id.to_string(),
+                ]),
+                eq(None::<String>),
+                eq(Some(audit_snapshot(&reviewer_assignment))),
+            )
+            .return_const(Ok(()));
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            audit_log_service_mock,
+        );
+
+        let result = service
+            .assign_reviewer(
+                calling_principal,
+                AssignReviewerRequest {
+                    proposal_id: proposal_id.to_string(),
+                    user_id: user_id.to_string(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(result, map_reviewer_assignment(id, reviewer_assignment));
+    }
+
+    #[rstest]
+    fn assign_reviewer_not_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let user_id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+        u_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::admin_user_profile()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_create_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .assign_reviewer(
+                calling_principal,
+                AssignReviewerRequest {
+                    proposal_id: proposal_id.to_string(),
+                    user_id: user_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(&format!("User with Id {} is not a reviewer", user_id))
+        );
+    }
+
+    #[rstest]
+    fn assign_reviewer_already_assigned() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+        let user_id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+        u_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(proposal_id), eq(user_id))
+            .return_const(Some((
+                fixtures::uuid(),
+                fixtures::reviewer_assignment_declined(),
+            )));
+        ra_repository_mock
+            .expect_create_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .assign_reviewer(
+                calling_principal,
+                AssignReviewerRequest {
+                    proposal_id: proposal_id.to_string(),
+                    user_id: user_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with Id {} has already been assigned to proposal with Id {}",
+                user_id, proposal_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn assign_reviewer_proposal_completed() {
+        let calling_principal = fixtures::principal_a();
+        let proposal_id = fixtures::proposal_id();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Some(
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            ));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_create_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .assign_reviewer(
+                calling_principal,
+                AssignReviewerRequest {
+                    proposal_id: proposal_id.to_string(),
+                    user_id: uuid_a().to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Proposal with Id {} is already completed",
+                proposal_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn accept_reviewer_assignment() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let id = uuid_a();
+        let reviewer_assignment = fixtures::reviewer_assignment_pending();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(reviewer_assignment.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(reviewer_assignment.clone()));
+        ra_repository_mock
+            .expect_update_reviewer_assignment()
+            .once()
+            .with(
+                eq(id),
+                eq(ReviewerAssignment {
+                    status: ReviewerAssignmentStatus::Accepted,
+                    last_updated_at: Some(DateTime::new(get_date_time().unwrap()).unwrap()),
+                    ..reviewer_assignment
+                }),
+            )
+            .return_const(Ok(()));
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        service
+            .accept_reviewer_assignment(
+                calling_principal,
+                AcceptReviewerAssignmentRequest { id: id.to_string() },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn accept_reviewer_assignment_not_pending() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::reviewer_assignment_declined()));
+        ra_repository_mock
+            .expect_update_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .accept_reviewer_assignment(
+                calling_principal,
+                AcceptReviewerAssignmentRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reviewer assignment with Id {} is not pending",
+                id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn accept_reviewer_assignment_other_user() {
+        let calling_principal = fixtures::principal_a();
+        let id = uuid_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(fixtures::reviewer_assignment_pending()));
+        ra_repository_mock
+            .expect_update_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .accept_reviewer_assignment(
+                calling_principal,
+                AcceptReviewerAssignmentRequest { id: id.to_string() },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!("Reviewer assignment with Id {} not found", id))
+        );
+    }
+
+    #[rstest]
+    #[case::pending(fixtures::reviewer_assignment_pending())]
+    #[case::accepted(fixtures::reviewer_assignment_accepted())]
+    fn decline_reviewer_assignment(#[case] reviewer_assignment: ReviewerAssignment) {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let id = uuid_a();
+        let reason = Some("Conflict of interest".to_string());
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(reviewer_assignment.proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_by_id()
+            .once()
+            .with(eq(id))
+            .return_const(Some(reviewer_assignment.clone()));
+        ra_repository_mock
+            .expect_update_reviewer_assignment()
+            .once()
+            .with(
+                eq(id),
+                eq(ReviewerAssignment {
+                    status: ReviewerAssignmentStatus::Declined {
+                        reason: reason.clone(),
+                    },
+                    last_updated_at: Some(DateTime::new(get_date_time().unwrap()).unwrap()),
+                    ..reviewer_assignment
+                }),
+            )
+            .return_const(Ok(()));
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        service
+            .decline_reviewer_assignment(
+                calling_principal,
+                DeclineReviewerAssignmentRequest {
+                    id: id.to_string(),
+                    reason,
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn decline_reviewer_assignment_reason_too_long() {
+        let calling_principal = fixtures::principal_a();
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let ra_repository_mock = MockReviewerAssignmentRepository::new();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .decline_reviewer_assignment(
+                calling_principal,
+                DeclineReviewerAssignmentRequest {
+                    id: uuid_a().to_string(),
+                    reason: Some("a".repeat(MAX_DECLINE_REASON_CHARS + 1)),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("Decline reason must be less than 500 characters")
+        );
+    }
+
+    #[rstest]
+    fn list_my_reviewer_assignments() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let reviewer_assignments = vec![
+            (uuid_a(), fixtures::reviewer_assignment_pending()),
+            (uuid_b(), fixtures::reviewer_assignment_declined()),
+        ];
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignments_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(reviewer_assignments));
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_my_reviewer_assignments(
+                calling_principal,
+                ListMyReviewerAssignmentsRequest {
+                    status: Some(ReviewerAssignmentStatusKey::Pending),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListMyReviewerAssignmentsResponse {
+                reviewer_assignments: vec![map_reviewer_assignment(
+                    uuid_a(),
+                    fixtures::reviewer_assignment_pending()
+                )],
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_unfulfilled_reviewer_assignments_by_user_id() {
+        let user_id = fixtures::user_id();
+        let unfulfilled_reviewer_assignment = ReviewerAssignment {
+            status: ReviewerAssignmentStatus::Unfulfilled,
+            ..fixtures::reviewer_assignment_accepted()
+        };
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignments_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![
+                (uuid_a(), unfulfilled_reviewer_assignment.clone()),
+                (uuid_b(), fixtures::reviewer_assignment_accepted()),
+            ]));
+        ra_repository_mock
+            .expect_get_unfulfilled_reviewer_assignments()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .list_unfulfilled_reviewer_assignments(ListUnfulfilledReviewerAssignmentsRequest {
+                user_id: Some(user_id.to_string()),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ListUnfulfilledReviewerAssignmentsResponse {
+                reviewer_assignments: vec![map_reviewer_assignment(
+                    uuid_a(),
+                    unfulfilled_reviewer_assignment
+                )],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::zero(0)]
+    #[case::too_many(MAX_REVIEWERS_PER_PROPOSAL + 1)]
+    fn update_reviewer_assignment_config_invalid(#[case] reviewers_per_proposal: u8) {
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_config()
+            .once()
+            .return_const(ReviewerAssignmentConfig::default());
+        ra_repository_mock
+            .expect_set_reviewer_assignment_config()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .update_reviewer_assignment_config(
+                fixtures::principal_a(),
+                UpdateReviewerAssignmentConfigRequest {
+                    policy: None,
+                    reviewers_per_proposal: Some(reviewers_per_proposal),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("Reviewers per proposal must be between 1 and 10")
+        );
+    }
+
+    #[rstest]
+    fn assign_reviewers_manual_policy() {
+        let u_repository_mock = MockUserProfileRepository::new();
+        let p_repository_mock = MockProposalRepository::new();
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_config()
+            .once()
+            .return_const(ReviewerAssignmentConfig::default());
+        ra_repository_mock
+            .expect_create_reviewer_assignment()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        assert_eq!(service.assign_reviewers().unwrap(), 0);
+    }
+
+    #[rstest]
+    fn assign_reviewers_round_robin() {
+        let proposal_id = fixtures::proposal_id();
+        let reviewer_ids = reviewer_ids();
+        let config = ReviewerAssignmentConfig {
+            policy: ReviewerAssignmentPolicy::RoundRobin,
+            reviewers_per_proposal: 2,
+            round_robin_cursor: Some(reviewer_ids[2]),
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_all_reviewer_profiles()
+            .once()
+            .return_const(reviewer_profiles(&reviewer_ids));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .with(eq(Some(ReviewPeriodStateKey::InProgress)))
+            .return_const(Ok(vec![(
+                proposal_id,
+                fixtures::nns_replica_version_management_proposal(None, None),
+            )]));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_config()
+            .once()
+            .return_const(config.clone());
+        ra_repository_mock
+            .expect_get_open_reviewer_assignments()
+            .once()
+            .return_const(vec![]);
+        ra_repository_mock
+            .expect_get_reviewer_assignments_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![]));
+        // the cursor points to the last reviewer, so the assignment wraps around
+        for user_id in [reviewer_ids[3], reviewer_ids[0]] {
+            ra_repository_mock
+                .expect_create_reviewer_assignment()
+                .once()
+                .withf(move |r_a| r_a.user_id == user_id && r_a.assigned_by.is_none())
+                .return_const(Ok(fixtures::uuid()));
+        }
+        ra_repository_mock
+            .expect_set_reviewer_assignment_config()
+            .once()
+            .with(eq(ReviewerAssignmentConfig {
+                round_robin_cursor: Some(reviewer_ids[0]),
+                ..config
+            }))
+            .return_const(Ok(()));
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        assert_eq!(service.assign_reviewers().unwrap(), 2);
+    }
+
+    #[rstest]
+    fn assign_reviewers_least_loaded() {
+        let proposal_id = fixtures::proposal_id();
+        let reviewer_ids = reviewer_ids();
+        let config = ReviewerAssignmentConfig {
+            policy: ReviewerAssignmentPolicy::LeastLoaded,
+            reviewers_per_proposal: 2,
+            round_robin_cursor: None,
+        };
+        let other_proposal_assignment = |user_id| ReviewerAssignment {
+            proposal_id: uuid_b(),
+            user_id,
+            ..fixtures::reviewer_assignment_pending()
+        };
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_all_reviewer_profiles()
+            .once()
+            .return_const(reviewer_profiles(&reviewer_ids));
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .with(eq(Some(ReviewPeriodStateKey::InProgress)))
+            .return_const(Ok(vec![(
+                proposal_id,
+                fixtures::nns_replica_version_management_proposal(None, None),
+            )]));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_reviewer_assignment_config()
+            .once()
+            .return_const(config);
+        ra_repository_mock
+            .expect_get_open_reviewer_assignments()
+            .once()
+            .return_const(vec![
+                (uuid_a(), other_proposal_assignment(reviewer_ids[0])),
+                (uuid_a(), other_proposal_assignment(reviewer_ids[1])),
+                (uuid_a(), other_proposal_assignment(reviewer_ids[3])),
+            ]);
+        // the third reviewer already declined this proposal and is not assigned again
+        ra_repository_mock
+            .expect_get_reviewer_assignments_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![(
+                uuid_a(),
+                ReviewerAssignment {
+                    proposal_id,
+                    user_id: reviewer_ids[2],
+                    ..fixtures::reviewer_assignment_declined()
+                },
+            )]));
+        for user_id in [reviewer_ids[0], reviewer_ids[1]] {
+            ra_repository_mock
+                .expect_create_reviewer_assignment()
+                .once()
+                .withf(move |r_a| r_a.user_id == user_id && r_a.proposal_id == proposal_id)
+                .return_const(Ok(fixtures::uuid()));
+        }
+        ra_repository_mock
+            .expect_set_reviewer_assignment_config()
+            .never();
+        let pr_repository_mock = MockProposalReviewRepository::new();
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        assert_eq!(service.assign_reviewers().unwrap(), 2);
+    }
+
+    #[rstest]
+    #[case::published(
+        fixtures::proposal_review_published(),
+        ReviewerAssignmentStatus::Fulfilled
+    )]
+    #[case::draft(
+        fixtures::proposal_review_draft(),
+        ReviewerAssignmentStatus::Unfulfilled
+    )]
+    fn close_reviewer_assignments(
+        #[case] proposal_review: ProposalReview,
+        #[case] expected_status: ReviewerAssignmentStatus,
+    ) {
+        let completed_proposal_id = uuid_a();
+        let in_progress_proposal_id = uuid_b();
+        let completed_assignment = ReviewerAssignment {
+            proposal_id: completed_proposal_id,
+            ..fixtures::reviewer_assignment_accepted()
+        };
+        let in_progress_assignment = ReviewerAssignment {
+            proposal_id: in_progress_proposal_id,
+            ..fixtures::reviewer_assignment_pending()
+        };
+
+        let u_repository_mock = MockUserProfileRepository::new();
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(completed_proposal_id))
+            .return_const(Some(
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            ));
+        p_repository_mock
+            .expect_get_proposal_by_id()
+            .once()
+            .with(eq(in_progress_proposal_id))
+            .return_const(Some(fixtures::nns_replica_version_management_proposal(
+                None, None,
+            )));
+        let mut ra_repository_mock = MockReviewerAssignmentRepository::new();
+        ra_repository_mock
+            .expect_get_open_reviewer_assignments()
+            .once()
+            .return_const(vec![
+                (uuid_a(), completed_assignment.clone()),
+                (uuid_b(), in_progress_assignment),
+            ]);
+        ra_repository_mock
+            .expect_update_reviewer_assignment()
+            .once()
+            .with(
+                eq(uuid_a()),
+                eq(ReviewerAssignment {
+                    status: expected_status,
+                    last_updated_at: Some(DateTime::new(get_date_time().unwrap()).unwrap()),
+                    ..completed_assignment.clone()
+                }),
+            )
+            .return_const(Ok(()));
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_review_by_proposal_id_and_user_id()
+            .once()
+            .with(eq(completed_proposal_id), eq(completed_assignment.user_id))
+            .return_const(Some((fixtures::proposal_review_id(), proposal_review)));
+
+        let service = ReviewerAssignmentServiceImpl::new(
+            ra_repository_mock,
+            u_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        assert_eq!(service.close_reviewer_assignments().unwrap(), 1);
+    }
+
+    fn reviewer_ids() -> Vec<UserId> {
+        let mut reviewer_ids = (1..=4)
+            .map(|i| UserId::from_random_bytes([i; 16]))
+            .collect::<Vec<_>>();
+        reviewer_ids.sort();
+        reviewer_ids
+    }
+
+    fn reviewer_profiles(reviewer_ids: &[UserId]) -> Vec<(UserId, UserProfile)> {
+        reviewer_ids
+            .iter()
+            .map(|user_id| (*user_id, fixtures::reviewer_user_profile()))
+            .collect()
+    }
+}
@@ -0,0 +1,539 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    mappings::map_user_profile,
+    repositories::{
+        DateTime, NervousSystem, Proposal, ProposalRepository, ProposalRepositoryImpl,
+        ProposalReview, ProposalReviewCommitRepository, ProposalReviewCommitRepositoryImpl,
+        ProposalReviewId, ProposalReviewRepository, ProposalReviewRepositoryImpl,
+        ReviewScoreRepository, ReviewScoreRepositoryImpl, ReviewerReputation, UserId,
+        UserProfileRepository, UserProfileRepositoryImpl, REPUTATION_WINDOW_SIZE,
+    },
+};
+use backend_api::{
+    ApiError, GetReviewerProfileRequest, GetReviewerProfileResponse, ReviewerProfileStatistics,
+    ReviewerRecentReview, ReviewerTopicStatistics,
+};
+
+const MAX_RECENT_REVIEWS: usize = 5;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait ReviewerProfileService {
+    /// Returns the public profile of an active reviewer,
+    /// with statistics computed from the reviewer's published reviews.
+    fn get_reviewer_profile(
+        &self,
+        request: GetReviewerProfileRequest,
+    ) -> Result<GetReviewerProfileResponse, ApiError>;
+}
+
+pub struct ReviewerProfileServiceImpl<
+    U: UserProfileRepository,
+    RS: ReviewScoreRepository,
+    PR: ProposalReviewRepository,
+    P: ProposalRepository,
+    PRC: ProposalReviewCommitRepository,
+> {
+    user_profile_repository: U,
+    review_score_repository: RS,
+    proposal_review_repository: PR,
+    proposal_repository: P,
+    proposal_review_commit_repository: PRC,
+}
+
+impl Default
+    for ReviewerProfileServiceImpl<
+        UserProfileRepositoryImpl,
+        ReviewScoreRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        ProposalRepositoryImpl,
+        ProposalReviewCommitRepositoryImpl,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            UserProfileRepositoryImpl::default(),
+            ReviewScoreRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            ProposalReviewCommitRepositoryImpl::default(),
+        )
+    }
+}
+
+impl<
+        U: UserProfileRepository,
+        RS: ReviewScoreRepository,
+        PR: ProposalReviewRepository,
+        P: ProposalRepository,
+        PRC: ProposalReviewCommitRepository,
+    > ReviewerProfileService for ReviewerProfileServiceImpl<U, RS, PR, P, PRC>
+{
+    fn get_reviewer_profile(
+        &self,
+        request: GetReviewerProfileRequest,
+    ) -> Result<GetReviewerProfileResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+
+        // suspended and deleted reviewers don't have a public profile
+        let user_profile = self
+            .user_profile_repository
+            .get_user_profile_by_user_id(&user_id)
+            .filter(|user_profile| user_profile.is_reviewer())
+            .filter(|_| {
+                self.user_profile_repository
+                    .get_user_status_by_user_id(&user_id)
+                    .is_none()
+            })
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Reviewer profile for user with id {} not found",
+                    user_id
+                ))
+            })?;
+
+        let review_scores = self
+            .review_score_repository
+            .get_latest_review_scores_by_user_id(user_id, REPUTATION_WINDOW_SIZE)?;
+        let reputation = ReviewerReputation::from_review_scores(&review_scores);
+
+        let mut published_reviews = self
+            .proposal_review_repository
+            .get_proposal_reviews_by_user_id(user_id)?
+            .into_iter()
+            .filter(|(_, proposal_review)| proposal_review.is_published())
+            .filter_map(|(id, proposal_review)| {
+                // the None case should never happen
+                self.proposal_repository
+                    .get_proposal_by_id(&proposal_review.proposal_id)
+                    .map(|proposal| PublishedReview {
+                        id,
+                        published_at: proposal_review.first_published_at(),
+                        proposal_review,
+                        proposal,
+                    })
+            })
+            .collect::<Vec<_>>();
+        published_reviews.sort_by(|a, b| b.published_at.cmp(&a.published_at));
+
+        let statistics = self.get_statistics(&published_reviews)?;
+
+        let recent_reviews = published_reviews
+            .into_iter()
+            .take(MAX_RECENT_REVIEWS)
+            .map(|review| ReviewerRecentReview {
+                proposal_review_id: review.id.to_string(),
+                proposal_id: review.proposal_review.proposal_id.to_string(),
+                nervous_system_proposal_id: review.proposal.nervous_system.proposal_id(),
+                published_at: review.published_at.to_string(),
+                vote: review.proposal_review.vote.into(),
+                build_reproduced: review.proposal_review.build_reproduced,
+            })
+            .collect();
+
+        Ok(GetReviewerProfileResponse {
+            profile: map_user_profile(user_id, user_profile, reputation),
+            statistics,
+            recent_reviews,
+        })
+    }
+}
+
+struct PublishedReview {
+    id: ProposalReviewId,
+    proposal_review: ProposalReview,
+    proposal: Proposal,
+    published_at: DateTime,
+}
+
+impl<
+        U: UserProfileRepository,
+        RS: ReviewScoreRepository,
+        PR: ProposalReviewRepository,
+        P: ProposalRepository,
+        PRC: ProposalReviewCommitRepository,
+    > ReviewerProfileServiceImpl<U, RS, PR, P, PRC>
+{
+    fn new(
+        user_profile_repository: U,
+        review_score_repository: RS,
+        proposal_review_repository: PR,
+        proposal_repository: P,
+        proposal_review_commit_repository: PRC,
+    ) -> Self {
+        Self {
+            user_profile_repository,
+            review_score_repository,
+            proposal_review_repository,
+            proposal_repository,
+            proposal_review_commit_repository,
+        }
+    }
+
+    fn get_statistics(
+        &self,
+        published_reviews: &[PublishedReview],
+    ) -> Result<ReviewerProfileStatistics, ApiError> {
+        let published_reviews_count = published_reviews.len() as u64;
+
+        let mut proposals_count_by_topic = BTreeMap::<i32, u64>::new();
+        let mut reviewed_commits_count = 0;
+        let mut build_reproduced_count = 0;
+        let mut total_publication_delay_seconds = 0;
+
+        for review in published_reviews {
+            *proposals_count_by_topic
+                .entry(proposal_topic(&review.proposal))
+                .or_default() += 1;
+
+            reviewed_commits_count += self
+                .proposal_review_commit_repository
+                .get_proposal_review_commits_by_proposal_review_id(review.id)?
+                .iter()
+                .filter(|(_, proposal_review_commit)| proposal_review_commit.is_reviewed())
+                .count() as u64;
+
+            if review.proposal_review.build_reproduced == Some(true) {
+                build_reproduced_count += 1;
+            }
+
+            // the proposal timestamp comes from the nervous system, so don't trust it to be earlier
+            total_publication_delay_seconds += review
+                .published_at
+                .timestamp_seconds()
+                .saturating_sub(review.proposal.proposed_at()?.timestamp_seconds());
+        }
+
+        let (build_reproduced_rate, average_publication_delay_seconds) =
+            if published_reviews_count == 0 {
+                (None, None)
+            } else {
+                (
+                    Some((build_reproduced_count * 100 / published_reviews_count) as u8),
+                    Some(total_publication_delay_seconds / published_reviews_count),
+                )
+            };
+
+        Ok(ReviewerProfileStatistics {
+            published_reviews_count,
+            proposals_reviewed_by_topic: proposals_count_by_topic
+                .into_iter()
+                .map(|(topic, proposals_count)| ReviewerTopicStatistics {
+                    topic,
+                    proposals_count,
+                })
+                .collect(),
+            reviewed_commits_count,
+            build_reproduced_rate,
+            average_publication_delay_seconds,
+        })
+    }
+}
+
+fn proposal_topic(proposal: &Proposal) -> i32 {
+    match &proposal.nervous_system {
+        NervousSystem::Network { proposal_info, .. } => proposal_info.topic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{
+            MockProposalRepository, MockProposalReviewCommitRepository,
+            MockProposalReviewRepository, MockReviewScoreRepository, MockUserProfileRepository,
+            ProposalId, UserProfile, UserStatus,
+        },
+    };
+    use chrono::Duration;
+    use ic_nns_governance::pb::v1::{ProposalInfo, Topic};
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn get_reviewer_profile() {
+        let user_id = fixtures::user_id();
+        let user_profile = fixtures::reviewer_user_profile();
+        let proposal_id_a = fixtures::uuid_a();
+        let proposal_id_b = fixtures::uuid_b();
+        let proposal_review_id_a = fixtures::uuid_a();
+        let proposal_review_id_b = fixtures::uuid_b();
+        let proposal_review_id_c = fixtures::uuid_c();
+        let proposed_at = fixtures::date_time_a();
+
+        let proposal_review_a = ProposalReview {
+            proposal_id: proposal_id_a,
+            published_at: Some(proposed_at.add(Duration::hours(2))),
+            build_reproduced: Some(true),
+            ..fixtures::proposal_review_published()
+        };
+        let proposal_review_b = ProposalReview {
+            proposal_id: proposal_id_b,
+            published_at: Some(proposed_at.add(Duration::hours(4))),
+            build_reproduced: Some(false),
+            ..fixtures::proposal_review_published()
+        };
+        let proposal_a = proposal(127094, Topic::IcOsVersionElection);
+        let proposal_b = proposal(127095, Topic::NetworkCanisterManagement);
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(user_profile.clone()));
+        user_profile_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+
+        let mut review_score_repository_mock = MockReviewScoreRepository::new();
+        review_score_repository_mock
+            .expect_get_latest_review_scores_by_user_id()
+            .once()
+            .with(eq(user_id), eq(REPUTATION_WINDOW_SIZE))
+            .return_const(Ok(vec![]));
+
+        let mut proposal_review_repository_mock = MockProposalReviewRepository::new();
+        proposal_review_repository_mock
+            .expect_get_proposal_reviews_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![
+                (proposal_review_id_a, proposal_review_a.clone()),
+                (proposal_review_id_b, proposal_review_b.clone()),
+                (proposal_review_id_c, fixtures::proposal_review_draft()),
+            ]));
+
+        let mut proposal_repository_mock = MockProposalRepository::new();
+        proposal_repository_mock
+            .expect_get_proposal_by_id()
+            .times(2)
+            .returning(move |proposal_id: &ProposalId| {
+                if *proposal_id == proposal_id_a {
+                    Some(proposal_a.clone())
+                } else {
+                    Some(proposal_b.clone())
+                }
+            });
+
+        let mut proposal_review_commit_repository_mock = MockProposalReviewCommitRepository::new();
+        proposal_review_commit_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .once()
+            .with(eq(proposal_review_id_a))
+            .return_const(Ok(vec![
+                (
+                    fixtures::uuid_a(),
+                    fixtures::proposal_review_commit_reviewed(),
+                ),
+                (
+                    fixtures::uuid_b(),
+                    fixtures::proposal_review_commit_not_reviewed(),
+                ),
+            ]));
+        proposal_review_commit_repository_mock
+            .expect_get_proposal_review_commits_by_proposal_review_id()
+            .once()
+            .with(eq(proposal_review_id_b))
+            .return_const(Ok(vec![(
+                fixtures::uuid_c(),
+                fixtures::proposal_review_commit_reviewed(),
+            )]));
+
+        let service = ReviewerProfileServiceImpl::new(
+            user_profile_repository_mock,
+            review_score_repository_mock,
+            proposal_review_repository_mock,
+            proposal_repository_mock,
+            proposal_review_commit_repository_mock,
+        );
+
+        let result = service
+            .get_reviewer_profile(GetReviewerProfileRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            GetReviewerProfileResponse {
+                profile: map_user_profile(user_id, user_profile, None),
+                statistics: ReviewerProfileStatistics {
+                    published_reviews_count: 2,
+                    proposals_reviewed_by_topic: vec![
+                        ReviewerTopicStatistics {
+                            topic: Topic::NetworkCanisterManagement as i32,
+                            proposals_count: 1,
+                        },
+                        ReviewerTopicStatistics {
+                            topic: Topic::IcOsVersionElection as i32,
+                            proposals_count: 1,
+                        },
+                    ],
+                    reviewed_commits_count: 2,
+                    build_reproduced_rate: Some(50),
+                    average_publication_delay_seconds: Some(3 * 3600),
+                },
+                recent_reviews: vec![
+                    ReviewerRecentReview {
+                        proposal_review_id: proposal_review_id_b.to_string(),
+                        proposal_id: proposal_id_b.to_string(),
+                        nervous_system_proposal_id: 127095,
+                        published_at: proposed_at.add(Duration::hours(4)).to_string(),
+                        vote: backend_api::ProposalVote::Yes,
+                        build_reproduced: Some(false),
+                    },
+                    ReviewerRecentReview {
+                        proposal_review_id: proposal_review_id_a.to_string(),
+                        proposal_id: proposal_id_a.to_string(),
+                        nervous_system_proposal_id: 127094,
+                        published_at: proposed_at.add(Duration::hours(2)).to_string(),
+                        vote: backend_api::ProposalVote::Yes,
+                        build_reproduced: Some(true),
+                    },
+                ],
+            }
+        );
+    }
+
+    #[rstest]
+    fn get_reviewer_profile_without_published_reviews() {
+        let user_id = fixtures::user_id();
+        let user_profile = fixtures::reviewer_user_profile();
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(user_profile.clone()));
+        user_profile_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+
+        let mut review_score_repository_mock = MockReviewScoreRepository::new();
+        review_score_repository_mock
+            .expect_get_latest_review_scores_by_user_id()
+            .once()
+            .with(eq(user_id), eq(REPUTATION_WINDOW_SIZE))
+            .return_const(Ok(vec![]));
+
+        let mut proposal_review_repository_mock = MockProposalReviewRepository::new();
+        proposal_review_repository_mock
+            .expect_get_proposal_reviews_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(vec![(
+                fixtures::uuid_a(),
+                fixtures::proposal_review_draft(),
+            )]));
+
+        let mut proposal_repository_mock = MockProposalRepository::new();
+        proposal_repository_mock.expect_get_proposal_by_id().never();
+
+        let service = ReviewerProfileServiceImpl::new(
+            user_profile_repository_mock,
+            review_score_repository_mock,
+            proposal_review_repository_mock,
+            proposal_repository_mock,
+            MockProposalReviewCommitRepository::new(),
+        );
+
+        let result = service
+            .get_reviewer_profile(GetReviewerProfileRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            GetReviewerProfileResponse {
+                profile: map_user_profile(user_id, user_profile, None),
+                statistics: ReviewerProfileStatistics {
+                    published_reviews_count: 0,
+                    proposals_reviewed_by_topic: vec![],
+                    reviewed_commits_count: 0,
+                    build_reproduced_rate: None,
+                    average_publication_delay_seconds: None,
+                },
+                recent_reviews: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::not_found(None, None)]
+    #[case::not_reviewer(Some(fixtures::admin_user_profile()), None)]
+    #[case::suspended(
+        Some(fixtures::reviewer_user_profile()),
+        Some(fixtures::suspended_user_status())
+    )]
+    #[case::deleted(
+        Some(fixtures::reviewer_user_profile()),
+        Some(fixtures::deleted_user_status())
+    )]
+    fn get_reviewer_profile_not_found(
+        #[case] user_profile: Option<UserProfile>,
+        #[case] user_status: Option<UserStatus>,
+    ) {
+        let user_id = fixtures::user_id();
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(user_profile);
+        user_profile_repository_mock
+            .expect_get_user_status_by_user_id()
+            .with(eq(user_id))
+            .return_const(user_status);
+
+        let mut proposal_review_repository_mock = MockProposalReviewRepository::new();
+        proposal_review_repository_mock
+            .expect_get_proposal_reviews_by_user_id()
+            .never();
+
+        let service = ReviewerProfileServiceImpl::new(
+            user_profile_repository_mock,
+            MockReviewScoreRepository::new(),
+            proposal_review_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewCommitRepository::new(),
+        );
+
+        let result = service
+            .get_reviewer_profile(GetReviewerProfileRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "Reviewer profile for user with id {} not found",
+                user_id
+            ))
+        );
+    }
+
+    fn proposal(nervous_system_proposal_id: u64, topic: Topic) -> Proposal {
+        Proposal {
+            nervous_system: NervousSystem::Network {
+                proposal_id: nervous_system_proposal_id,
+                proposal_info: ProposalInfo {
+                    topic: topic as i32,
+                    proposal_timestamp_seconds: fixtures::date_time_a().timestamp_seconds(),
+                    ..ProposalInfo::default()
+                },
+            },
+            ..fixtures::nns_replica_version_management_proposal_completed(None, None)
+        }
+    }
+}
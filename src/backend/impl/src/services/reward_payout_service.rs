@@ -0,0 +1,717 @@
+use std::{cell::RefCell, collections::BTreeSet, str::FromStr};
+
+use crate::repositories::{
+    audit_snapshot, reward_payout_memo, AuditAction, AuditLogRepositoryImpl, DateTime,
+    RewardPayout, RewardPayoutStatus, RewardPeriodId, RewardRepository, RewardRepositoryImpl,
+    RewardStatement, WalletAddress,
+};
+use crate::services::{AuditLogService, AuditLogServiceImpl};
+use crate::system_api::get_date_time;
+use backend_api::{
+    ApiError, ExecuteRewardPayoutsRequest, ExecuteRewardPayoutsResponse, ListRewardPayoutsRequest,
+    ListRewardPayoutsResponse,
+};
+use candid::Principal;
+use external_canisters::{
+    icrc1::{Account, Icrc1Ledger, Icrc1LedgerCanisterService, TransferError},
+    payout::{Payout, PayoutError, PayoutExecutor},
+};
+
+const ICP_LEDGER_CANISTER_ID: &str = "ryjl3-tyaaa-aaaaa-aaaba-cai";
+
+thread_local! {
+    static PAYOUTS_IN_PROGRESS: RefCell<BTreeSet<RewardPeriodId>> = const { RefCell::new(BTreeSet::new()) };
+}
+
+/// Prevents payouts of the same reward period from being executed concurrently.
+/// The lock is released when the guard is dropped, which also happens
+/// when the call traps after awaiting the ledger.
+struct PayoutsLockGuard(RewardPeriodId);
+
+impl PayoutsLockGuard {
+    fn acquire(reward_period_id: RewardPeriodId) -> Result<Self, ApiError> {
+        PAYOUTS_IN_PROGRESS.with_borrow_mut(|in_progress| {
+            if !in_progress.insert(reward_period_id) {
+                return Err(ApiError::conflict(&format!(
+                    "Payouts for reward period with Id {} are already being executed",
+                    reward_period_id
+                )));
+            }
+
+            Ok(Self(reward_period_id))
+        })
+    }
+}
+
+impl Drop for PayoutsLockGuard {
+    fn drop(&mut self) {
+        PAYOUTS_IN_PROGRESS.with_borrow_mut(|in_progress| {
+            in_progress.remove(&self.0);
+        });
+    }
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RewardPayoutService {
+    async fn execute_reward_payouts(
+        &self,
+        calling_principal: Principal,
+        request: ExecuteRewardPayoutsRequest,
+    ) -> Result<ExecuteRewardPayoutsResponse, ApiError>;
+
+    fn list_reward_payouts(
+        &self,
+        request: ListRewardPayoutsRequest,
+    ) -> Result<ListRewardPayoutsResponse, ApiError>;
+}
+
+pub struct RewardPayoutServiceImpl<RW: RewardRepository, L: Icrc1Ledger, AL: AuditLogService> {
+    reward_repository: RW,
+    payout_executor: PayoutExecutor<L>,
+    audit_log_service: AL,
+}
+
+impl Default
+    for RewardPayoutServiceImpl<
+        RewardRepositoryImpl,
+        Icrc1LedgerCanisterService,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            RewardRepositoryImpl::default(),
+            Icrc1LedgerCanisterService(Principal::from_str(ICP_LEDGER_CANISTER_ID).unwrap()),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<RW: RewardRepository, L: Icrc1Ledger, AL: AuditLogService> RewardPayoutService
+    for RewardPayoutServiceImpl<RW, L, AL>
+{
+    async fn execute_reward_payouts(
+        &self,
+        calling_principal: Principal,
+        request: ExecuteRewardPayoutsRequest,
+    ) -> Result<ExecuteRewardPayoutsResponse, ApiError> {
+        let reward_period_id = RewardPeriodId::try_from(request.reward_period_id.as_str())?;
+        self.assert_reward_period_is_closed(reward_period_id)?;
+
+        let _lock = PayoutsLockGuard::acquire(reward_period_id)?;
+
+        let reward_statements = self
+            .reward_repository
+            .get_reward_statements_by_reward_period_id(reward_period_id)?;
+
+        let mut payouts = vec![];
+        for reward_statement in reward_statements {
+            if reward_statement.total_e8s == 0 {
+                continue;
+            }
+
+            let reward_payout = self
+                .execute_reward_payout(reward_period_id, reward_statement)
+                .await?;
+            payouts.push(reward_payout);
+        }
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::ExecuteRewardPayouts,
+            vec![reward_period_id.to_string()],
+            None,
+            Some(audit_snapshot(&payouts)),
+        )?;
+
+        Ok(ExecuteRewardPayoutsResponse {
+            payouts: payouts.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    fn list_reward_payouts(
+        &self,
+        request: ListRewardPayoutsRequest,
+    ) -> Result<ListRewardPayoutsResponse, ApiError> {
+        let reward_period_id = RewardPeriodId::try_from(request.reward_period_id.as_str())?;
+        self.assert_reward_period_is_closed(reward_period_id)?;
+
+        let payouts = self
+            .reward_repository
+            .get_reward_payouts_by_reward_period_id(reward_period_id)?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(ListRewardPayoutsResponse { payouts })
+    }
+}
+
+impl<RW: RewardRepository, L: Icrc1Ledger, AL: AuditLogService> RewardPayoutServiceImpl<RW, L, AL> {
+    fn new(reward_repository: RW, ledger: L, audit_log_service: AL) -> Self {
+        Self {
+            reward_repository,
+            payout_executor: PayoutExecutor::new(ledger),
+            audit_log_service,
+        }
+    }
+
+    fn assert_reward_period_is_closed(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<(), ApiError> {
+        let reward_period = self
+            .reward_repository
+            .get_reward_period_by_id(&reward_period_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Reward period with Id {} not found",
+                    reward_period_id
+                ))
+            })?;
+
+        if !reward_period.is_closed() {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} is not closed yet",
+                reward_period_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn execute_reward_payout(
+        &self,
+        reward_period_id: RewardPeriodId,
+        reward_statement: RewardStatement,
+    ) -> Result<RewardPayout, ApiError> {
+        let now = DateTime::new(get_date_time()?)?;
+
+        let mut reward_payout = match self
+            .reward_repository
+            .get_reward_payout(reward_period_id, reward_statement.user_id)?
+        {
+            Some(reward_payout) if reward_payout.is_completed() => return Ok(reward_payout),
+            // the outcome of the previous attempts is unknown, so the same transfer
+            // must be sent again for the ledger to deduplicate it
+            Some(
+                reward_payout @ RewardPayout {
+                    status: RewardPayoutStatus::Pending { .. },
+                    ..
+                },
+            ) => reward_payout,
+            // nothing was transferred yet, so a new transfer can be created
+            _ => RewardPayout {
+                user_id: reward_statement.user_id,
+                wallet_address: reward_statement.wallet_address,
+                amount_e8s: reward_statement.total_e8s,
+                memo: reward_payout_memo(reward_period_id, reward_statement.user_id),
+                created_at_time: now.timestamp_micros() * 1_000,
+                status: RewardPayoutStatus::Pending {
+                    attempts: 0,
+                    last_error: None,
+                },
+            },
+        };
+
+        let to = match parse_wallet_account(&reward_payout.wallet_address) {
+            Ok(to) => to,
+            Err(err) => {
+                reward_payout.status = RewardPayoutStatus::Failed {
+                    error: err.message().to_string(),
+                    failed_at: now,
+                };
+                self.reward_repository
+                    .upsert_reward_payout(reward_period_id, reward_payout.clone())?;
+
+                return Ok(reward_payout);
+            }
+        };
+
+        let previous_attempts = match reward_payout.status {
+            RewardPayoutStatus::Pending { attempts, .. } => attempts,
+            _ => 0,
+        };
+        // persist the attempt before calling the ledger, so that the memo and
+        // created_at_time are kept even if the call does not return
+        reward_payout.status = RewardPayoutStatus::Pending {
+            attempts: previous_attempts + 1,
+            last_error: None,
+        };
+        self.reward_repository
+            .upsert_reward_payout(reward_period_id, reward_payout.clone())?;
+
+        let result = self
+            .payout_executor
+            .execute(&Payout {
+                to,
+                amount_e8s: reward_payout.amount_e8s,
+                memo: reward_payout.memo.clone(),
+                created_at_time: reward_payout.created_at_time,
+            })
+            .await;
+
+        reward_payout.status = match result {
+            Ok(block_index) => RewardPayoutStatus::Completed {
+                block_index,
+                completed_at: now,
+            },
+            // a previous attempt may have gone through, so the payout stays pending
+            // and is retried with the same memo and created_at_time, for the ledger
+            // to answer with the original block if it did
+            Err(PayoutError::Rejected(err)) if previous_attempts > 0 => {
+                RewardPayoutStatus::Pending {
+                    attempts: previous_attempts + 1,
+                    last_error: Some(match err {
+                        TransferError::TooOld => "Transfer is too old to be deduplicated by the ledger and must be reconciled manually".to_string(),
+                        err => format!("Ledger rejected the transfer: {:?}", err),
+                    }),
+                }
+            }
+            Err(PayoutError::Rejected(err)) => RewardPayoutStatus::Failed {
+                error: format!("Ledger rejected the transfer: {:?}", err),
+                failed_at: now,
+            },
+            Err(PayoutError::Unknown(err)) => RewardPayoutStatus::Pending {
+                attempts: previous_attempts + 1,
+                last_error: Some(err),
+            },
+        };
+        self.reward_repository
+            .upsert_reward_payout(reward_period_id, reward_payout.clone())?;
+
+        Ok(reward_payout)
+    }
+}
+
+/// Only ICRC-1 accounts can be paid through the ledger's ICRC-1 interface.
+fn parse_wallet_account(wallet_address: &str) -> Result<Account, ApiError> {
+    match WalletAddress::try_from(wallet_address)? {
+        WalletAddress::Icrc1Account { owner, subaccount } => Ok(Account { owner, subaccount }),
+        WalletAddress::AccountIdentifier { .. } => Err(ApiError::invalid_argument(&format!(
+            "Wallet address {} is an ICP account identifier, which cannot be paid through the ICRC-1 interface",
+            wallet_address
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures, repositories::MockRewardRepository, services::MockAuditLogService};
+    use external_canisters::icrc1::testing::InMemoryIcrc1Ledger;
+    use ic_cdk::api::call::RejectionCode;
+    use mockall::predicate::*;
+    use rstest::*;
+    use std::sync::{Arc, Mutex};
+
+    fn reward_statement() -> RewardStatement {
+        RewardStatement {
+            wallet_address: fixtures::principal_b().to_text(),
+            ..fixtures::reward_statement()
+        }
+    }
+
+    fn wallet_account() -> Account {
+        Account {
+            owner: fixtures::principal_b(),
+            subaccount: None,
+        }
+    }
+
+    fn reward_repository_mock(
+        reward_period_id: RewardPeriodId,
+        existing_reward_payout: Option<RewardPayout>,
+        upserts_count: usize,
+    ) -> MockRewardRepository {
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_closed()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Ok(vec![
+                reward_statement(),
+                RewardStatement {
+                    user_id: fixtures::uuid_b(),
+                    items: vec![],
+                    total_e8s: 0,
+                    ..reward_statement()
+                },
+            ]));
+        rw_repository_mock
+            .expect_get_reward_payout()
+            .once()
+            .with(eq(reward_period_id), eq(fixtures::user_id()))
+            .return_const(Ok(existing_reward_payout));
+        rw_repository_mock
+            .expect_upsert_reward_payout()
+            .times(upserts_count)
+            .returning(|_, _| Ok(()));
+
+        rw_repository_mock
+    }
+
+    fn audit_log_service_mock(reward_period_id: RewardPeriodId) -> MockAuditLogService {
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(fixtures::principal_a()),
+                eq(AuditAction::ExecuteRewardPayouts),
+                eq(vec![reward_period_id.to_string()]),
+                eq(None::<String>),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        audit_log_service_mock
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts() {
+        let reward_period_id = fixtures::uuid();
+
+        let service = RewardPayoutServiceImpl::new(
+            reward_repository_mock(reward_period_id, None, 2),
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            audit_log_service_mock(reward_period_id),
+        );
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.payouts.len(), 1);
+        assert!(matches!(
+            result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Completed { block_index: 0, .. }
+        ));
+        assert_eq!(
+            service.payout_executor.ledger().received(&wallet_account()),
+            150
+        );
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_completed() {
+        let reward_period_id = fixtures::uuid();
+
+        let service = RewardPayoutServiceImpl::new(
+            reward_repository_mock(
+                reward_period_id,
+                Some(fixtures::reward_payout_completed()),
+                0,
+            ),
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            audit_log_service_mock(reward_period_id),
+        );
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.payouts,
+            vec![fixtures::reward_payout_completed().into()]
+        );
+        assert_eq!(service.payout_executor.ledger().blocks_count(), 0);
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_pending_already_transferred() {
+        let reward_period_id = fixtures::uuid();
+        let reward_payout = RewardPayout {
+            wallet_address: fixtures::principal_b().to_text(),
+            status: RewardPayoutStatus::Pending {
+                attempts: 1,
+                last_error: Some("Call timed out".to_string()),
+            },
+            ..fixtures::reward_payout_pending()
+        };
+
+        let service = RewardPayoutServiceImpl::new(
+            reward_repository_mock(reward_period_id, Some(reward_payout.clone()), 2),
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            audit_log_service_mock(reward_period_id),
+        );
+        // the previous attempt reached the ledger, but its outcome was never recorded
+        service
+            .payout_executor
+            .execute(&Payout {
+                to: wallet_account(),
+                amount_e8s: reward_payout.amount_e8s,
+                memo: reward_payout.memo.clone(),
+                created_at_time: reward_payout.created_at_time,
+            })
+            .await
+            .unwrap();
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Completed { block_index: 0, .. }
+        ));
+        assert_eq!(service.payout_executor.ledger().blocks_count(), 1);
+        assert_eq!(
+            service.payout_executor.ledger().received(&wallet_account()),
+            150
+        );
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_rejected() {
+        let reward_period_id = fixtures::uuid();
+
+        let service = RewardPayoutServiceImpl::new(
+            reward_repository_mock(reward_period_id, None, 2),
+            InMemoryIcrc1Ledger::with_balance(100),
+            audit_log_service_mock(reward_period_id),
+        );
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            &result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Failed { error, .. }
+                if error.contains("InsufficientFunds")
+        ));
+        assert_eq!(service.payout_executor.ledger().balance(), 100);
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_rejected_after_unknown_outcome() {
+        let reward_period_id = fixtures::uuid();
+        let stored_reward_payout = Arc::new(Mutex::new(None::<RewardPayout>));
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .times(3)
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_closed()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .times(3)
+            .with(eq(reward_period_id))
+            .return_const(Ok(vec![reward_statement()]));
+        rw_repository_mock
+            .expect_get_reward_payout()
+            .times(3)
+            .returning({
+                let stored_reward_payout = stored_reward_payout.clone();
+                move |_, _| Ok(stored_reward_payout.lock().unwrap().clone())
+            });
+        rw_repository_mock
+            .expect_upsert_reward_payout()
+            .times(6)
+            .returning({
+                let stored_reward_payout = stored_reward_payout.clone();
+                move |_, reward_payout| {
+                    *stored_reward_payout.lock().unwrap() = Some(reward_payout);
+                    Ok(())
+                }
+            });
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .times(3)
+            .return_const(Ok(()));
+
+        let service = RewardPayoutServiceImpl::new(
+            rw_repository_mock,
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            audit_log_service_mock,
+        );
+        let request = ExecuteRewardPayoutsRequest {
+            reward_period_id: reward_period_id.to_string(),
+        };
+
+        // the outcome of the first attempt is unknown
+        service
+            .payout_executor
+            .ledger()
+            .reject_next_call(RejectionCode::SysUnknown);
+        service
+            .execute_reward_payouts(fixtures::principal_a(), request.clone())
+            .await
+            .unwrap();
+        let first_attempt = stored_reward_payout.lock().unwrap().clone().unwrap();
+
+        // the retry is rejected, but the first attempt may still have gone through
+        for _ in 0..3 {
+            service
+                .payout_executor
+                .ledger()
+                .fail_next_call(TransferError::TemporarilyUnavailable);
+        }
+        let result = service
+            .execute_reward_payouts(fixtures::principal_a(), request.clone())
+            .await
+            .unwrap();
+        assert!(matches!(
+            result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Pending { attempts: 2, .. }
+        ));
+
+        let result = service
+            .execute_reward_payouts(fixtures::principal_a(), request)
+            .await
+            .unwrap();
+        let last_attempt = stored_reward_payout.lock().unwrap().clone().unwrap();
+
+        assert!(matches!(
+            result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Completed { block_index: 0, .. }
+        ));
+        assert_eq!(last_attempt.created_at_time, first_attempt.created_at_time);
+        assert_eq!(last_attempt.memo, first_attempt.memo);
+        assert_eq!(service.payout_executor.ledger().blocks_count(), 1);
+        assert_eq!(
+            service.payout_executor.ledger().received(&wallet_account()),
+            150
+        );
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_invalid_wallet_address() {
+        let reward_period_id = fixtures::uuid();
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_closed()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Ok(vec![fixtures::reward_statement()]));
+        rw_repository_mock
+            .expect_get_reward_payout()
+            .once()
+            .return_const(Ok(None));
+        rw_repository_mock
+            .expect_upsert_reward_payout()
+            .once()
+            .withf(|_, reward_payout| {
+                matches!(reward_payout.status, RewardPayoutStatus::Failed { .. })
+            })
+            .returning(|_, _| Ok(()));
+
+        let service = RewardPayoutServiceImpl::new(
+            rw_repository_mock,
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            audit_log_service_mock(reward_period_id),
+        );
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            result.payouts[0].status,
+            backend_api::RewardPayoutStatus::Failed { .. }
+        ));
+        assert_eq!(service.payout_executor.ledger().blocks_count(), 0);
+    }
+
+    #[rstest]
+    async fn execute_reward_payouts_open_period() {
+        let reward_period_id = fixtures::uuid();
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_open()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .never();
+
+        let service = RewardPayoutServiceImpl::new(
+            rw_repository_mock,
+            InMemoryIcrc1Ledger::with_balance(1_000),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .execute_reward_payouts(
+                fixtures::principal_a(),
+                ExecuteRewardPayoutsRequest {
+                    reward_period_id: reward_period_id.to_string(),
+                },
+            )
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} is not closed yet",
+                reward_period_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn payouts_lock_guard() {
+        let reward_period_id = fixtures::uuid_a();
+
+        let lock = PayoutsLockGuard::acquire(reward_period_id).unwrap();
+        let result = PayoutsLockGuard::acquire(reward_period_id).map(|_| ());
+        drop(lock);
+
+        assert_eq!(
+            result,
+            Err(ApiError::conflict(&format!(
+                "Payouts for reward period with Id {} are already being executed",
+                reward_period_id
+            )))
+        );
+        assert!(PayoutsLockGuard::acquire(reward_period_id).is_ok());
+    }
+}
@@ -0,0 +1,1023 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    mappings::{
+        map_export_reward_statements_response, map_list_reward_periods_response, map_reward_period,
+    },
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, ProposalRepository,
+        ProposalRepositoryImpl, ProposalReviewRepository, ProposalReviewRepositoryImpl,
+        ReviewPeriodState, ReviewPeriodStateKey, RewardPeriod, RewardPeriodId, RewardPeriodStatus,
+        RewardRepository, RewardRepositoryImpl, RewardStatement, RewardStatementItem, UserConfig,
+        UserId, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, CloseRewardPeriodRequest, CreateRewardPeriodRequest, CreateRewardPeriodResponse,
+    ExportRewardStatementsRequest, ExportRewardStatementsResponse, ListRewardPeriodsResponse,
+    UpdateRewardPeriodRequest,
+};
+use candid::Principal;
+
+const MAX_REWARD_PERIOD_NAME_CHARS: usize = 100;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RewardService {
+    fn create_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CreateRewardPeriodRequest,
+    ) -> Result<CreateRewardPeriodResponse, ApiError>;
+
+    fn update_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: UpdateRewardPeriodRequest,
+    ) -> Result<(), ApiError>;
+
+    fn close_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CloseRewardPeriodRequest,
+    ) -> Result<(), ApiError>;
+
+    fn list_reward_periods(&self) -> Result<ListRewardPeriodsResponse, ApiError>;
+
+    fn export_reward_statements(
+        &self,
+        request: ExportRewardStatementsRequest,
+    ) -> Result<ExportRewardStatementsResponse, ApiError>;
+}
+
+pub struct RewardServiceImpl<
+    RW: RewardRepository,
+    P: ProposalRepository,
+    PR: ProposalReviewRepository,
+    U: UserProfileRepository,
+    AL: AuditLogService,
+> {
+    reward_repository: RW,
+    proposal_repository: P,
+    proposal_review_repository: PR,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for RewardServiceImpl<
+        RewardRepositoryImpl,
+        ProposalRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            RewardRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<
+        RW: RewardRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > RewardService for RewardServiceImpl<RW, P, PR, U, AL>
+{
+    fn create_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CreateRewardPeriodRequest,
+    ) -> Result<CreateRewardPeriodResponse, ApiError> {
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        let reward_period = RewardPeriod {
+            name: request.name,
+            starts_at: date_time_from_timestamp_ms(request.start_timestamp_ms)?,
+            ends_at: date_time_from_timestamp_ms(request.end_timestamp_ms)?,
+            rules: request.rules.into(),
+            status: RewardPeriodStatus::Open,
+            created_by: user_id,
+            created_at: DateTime::new(get_date_time()?)?,
+            last_updated_at: None,
+        };
+        self.validate_reward_period(None, &reward_period)?;
+
+        let reward_period_id = self
+            .reward_repository
+            .create_reward_period(reward_period.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::CreateRewardPeriod,
+            vec![reward_period_id.to_string()],
+            None,
+            Some(audit_snapshot(&reward_period)),
+        )?;
+
+        Ok(map_reward_period(reward_period_id, reward_period))
+    }
+
+    fn update_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: UpdateRewardPeriodRequest,
+    ) -> Result<(), ApiError> {
+        let reward_period_id = RewardPeriodId::try_from(request.id.as_str())?;
+        let before = self.get_open_reward_period(reward_period_id)?;
+        let mut reward_period = before.clone();
+
+        if let Some(name) = request.name {
+            reward_period.name = name;
+        }
+        if let Some(start_timestamp_ms) = request.start_timestamp_ms {
+            reward_period.starts_at = date_time_from_timestamp_ms(start_timestamp_ms)?;
+        }
+        if let Some(end_timestamp_ms) = request.end_timestamp_ms {
+            reward_period.ends_at = date_time_from_timestamp_ms(end_timestamp_ms)?;
+        }
+        if let Some(rules) = request.rules {
+            reward_period.rules = rules.into();
+        }
+        self.validate_reward_period(Some(reward_period_id), &reward_period)?;
+
+        reward_period.last_updated_at = Some(DateTime::new(get_date_time()?)?);
+
+        self.reward_repository
+            .update_reward_period(reward_period_id, reward_period.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::UpdateRewardPeriod,
+            vec![reward_period_id.to_string()],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&reward_period)),
+        )
+    }
+
+    fn close_reward_period(
+        &self,
+        calling_principal: Principal,
+        request: CloseRewardPeriodRequest,
+    ) -> Result<(), ApiError> {
+        let reward_period_id = RewardPeriodId::try_from(request.id.as_str())?;
+        let reward_period = self.get_open_reward_period(reward_period_id)?;
+
+        let now = DateTime::new(get_date_time()?)?;
+        if now < reward_period.ends_at {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} cannot be closed before it ends",
+                reward_period_id
+            )));
+        }
+
+        let reward_statements = self.compute_reward_statements(&reward_period)?;
+
+        self.reward_repository
+            .close_reward_period(reward_period_id, now, reward_statements)?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::CloseRewardPeriod,
+            vec![reward_period_id.to_string()],
+            Some(audit_snapshot(&reward_period)),
+            Some(audit_snapshot(&RewardPeriod {
+                status: RewardPeriodStatus::Closed { closed_at: now },
+                ..reward_period
+            })),
+        )
+    }
+
+    fn list_reward_periods(&self) -> Result<ListRewardPeriodsResponse, ApiError> {
+        let reward_periods = self.reward_repository.get_reward_periods();
+
+        Ok(map_list_reward_periods_response(reward_periods))
+    }
+
+    fn export_reward_statements(
+        &self,
+        request: ExportRewardStatementsRequest,
+    ) -> Result<ExportRewardStatementsResponse, ApiError> {
+        let reward_period_id = RewardPeriodId::try_from(request.reward_period_id.as_str())?;
+        let reward_period = self.get_reward_period(reward_period_id)?;
+
+        if !reward_period.is_closed() {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} is not closed yet",
+                reward_period_id
+            )));
+        }
+
+        let reward_statements = self
+            .reward_repository
+            .get_reward_statements_by_reward_period_id(reward_period_id)?;
+
+        Ok(map_export_reward_statements_response(
+            reward_period_id,
+            reward_period,
+            reward_statements,
+        ))
+    }
+}
+
+impl<
+        RW: RewardRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        U: UserProfileRepository,
+        AL: AuditLogService,
+    > RewardServiceImpl<RW, P, PR, U, AL>
+{
+    fn new(
+        reward_repository: RW,
+        proposal_repository: P,
+        proposal_review_repository: PR,
+        user_profile_repository: U,
+        audit_log_service: AL,
+    ) -> Self {
+        Self {
+            reward_repository,
+            proposal_repository,
+            proposal_review_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+
+    fn get_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<RewardPeriod, ApiError> {
+        self.reward_repository
+            .get_reward_period_by_id(&reward_period_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "Reward period with Id {} not found",
+                    reward_period_id
+                ))
+            })
+    }
+
+    fn get_open_reward_period(
+        &self,
+        reward_period_id: RewardPeriodId,
+    ) -> Result<RewardPeriod, ApiError> {
+        let reward_period = self.get_reward_period(reward_period_id)?;
+
+        if reward_period.is_closed() {
+            return Err(ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            )));
+        }
+
+        Ok(reward_period)
+    }
+
+    fn validate_reward_period(
+        &self,
+        reward_period_id: Option<RewardPeriodId>,
+        reward_period: &RewardPeriod,
+    ) -> Result<(), ApiError> {
+        if reward_period.name.trim().is_empty() {
+            return Err(ApiError::invalid_argument("Name cannot be empty"));
+        }
+
+        if reward_period.name.chars().count() > MAX_REWARD_PERIOD_NAME_CHARS {
+            return Err(ApiError::invalid_argument(&format!(
+                "Name must be less than {} characters",
+                MAX_REWARD_PERIOD_NAME_CHARS
+            )));
+        }
+
+        if reward_period.starts_at >= reward_period.ends_at {
+            return Err(ApiError::invalid_argument(
+                "Reward period must end after it starts",
+            ));
+        }
+
+        // overlapping periods would reward the same reviews twice
+        let overlapping_reward_period = self
+            .reward_repository
+            .get_reward_periods()
+            .into_iter()
+            .find(|(id, other_reward_period)| {
+                Some(*id) != reward_period_id && reward_period.overlaps(other_reward_period)
+            });
+        if let Some((id, _)) = overlapping_reward_period {
+            return Err(ApiError::conflict(&format!(
+                "Reward period overlaps with reward period with Id {}",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Computes one statement per reviewer, covering the published reviews
+    /// of the proposals that completed during the reward period.
+    fn compute_reward_statements(
+        &self,
+        reward_period: &RewardPeriod,
+    ) -> Result<Vec<RewardStatement>, ApiError> {
+        let rules = &reward_period.rules;
+        let wallet_addresses: BTreeMap<UserId, String> = self
+            .user_profile_repository
+            .get_all_reviewer_profiles()
+            .into_iter()
+            .filter_map(|(user_id, user_profile)| match user_profile.config {
+                // reviewers without a wallet address are still accounted for,
+                // their payouts fail until the statement is settled manually
+                UserConfig::Reviewer { wallet_address, .. } => Some((
+                    user_id,
+                    wallet_address
+                        .map(|wallet_address| wallet_address.to_string())
+                        .unwrap_or_default(),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let mut reward_statements: BTreeMap<UserId, RewardStatement> = BTreeMap::new();
+        let proposals = self
+            .proposal_repository
+            .get_proposals(Some(ReviewPeriodStateKey::Completed))?;
+
+        for (proposal_id, proposal) in proposals {
+            let ReviewPeriodState::Completed { completed_at } = proposal.state else {
+                continue;
+            };
+            if !reward_period.contains(&completed_at) {
+                continue;
+            }
+
+            let proposed_at = proposal.proposed_at()?;
+            let proposal_reviews = self
+                .proposal_review_repository
+                .get_proposal_reviews_by_proposal_id(proposal_id)?;
+
+            for (proposal_review_id, proposal_review) in proposal_reviews {
+                if !proposal_review.is_published() {
+                    continue;
+                }
+
+                // users that are no longer reviewers have no wallet address to be paid to
+                let Some(wallet_address) = wallet_addresses.get(&proposal_review.user_id) else {
+                    continue;
+                };
+
+                let published_at = proposal_review.first_published_at();
+                let verified_build = proposal_review.build_reproduced == Some(true);
+                let late = rules.is_late(&proposed_at, &published_at);
+                let amount_e8s = rules.reward_for_review(verified_build, late);
+
+                let reward_statement = reward_statements
+                    .entry(proposal_review.user_id)
+                    .or_insert_with(|| RewardStatement {
+                        user_id: proposal_review.user_id,
+                        wallet_address: wallet_address.clone(),
+                        items: vec![],
+                        total_e8s: 0,
+                    });
+                reward_statement.items.push(RewardStatementItem {
+                    proposal_id,
+                    proposal_review_id,
+                    verified_build,
+                    late,
+                    amount_e8s,
+                });
+                reward_statement.total_e8s = reward_statement.total_e8s.saturating_add(amount_e8s);
+            }
+        }
+
+        Ok(reward_statements.into_values().collect())
+    }
+}
+
+fn date_time_from_timestamp_ms(timestamp_ms: u64) -> Result<DateTime, ApiError> {
+    let timestamp_micros = timestamp_ms.checked_mul(1_000).ok_or_else(|| {
+        ApiError::invalid_argument(&format!("Invalid timestamp {}", timestamp_ms))
+    })?;
+
+    DateTime::from_timestamp_micros(timestamp_micros)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::{self, uuid_a, uuid_b, uuid_c},
+        repositories::{
+            MockProposalRepository, MockProposalReviewRepository, MockRewardRepository,
+            MockUserProfileRepository, Proposal, ProposalReview,
+        },
+        services::MockAuditLogService,
+    };
+    use chrono::Duration;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    fn create_reward_period_request() -> CreateRewardPeriodRequest {
+        let reward_period = fixtures::reward_period_open();
+
+        CreateRewardPeriodRequest {
+            name: reward_period.name,
+            start_timestamp_ms: reward_period.starts_at.timestamp_micros() / 1_000,
+            end_timestamp_ms: reward_period.ends_at.timestamp_micros() / 1_000,
+            rules: reward_period.rules.into(),
+        }
+    }
+
+    #[rstest]
+    fn create_reward_period() {
+        let calling_principal = fixtures::principal_a();
+        let request = create_reward_period_request();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_periods()
+            .once()
+            .return_const(vec![(
+                uuid_a(),
+                RewardPeriod {
+                    starts_at: fixtures::date_time_a(),
+                    ends_at: fixtures::date_time_future(),
+                    ..fixtures::reward_period_closed()
+                },
+            )]);
+        rw_repository_mock
+            .expect_create_reward_period()
+            .once()
+            .withf(|reward_period| {
+                reward_period
+                    == &RewardPeriod {
+                        created_at: reward_period.created_at,
+                        ..fixtures::reward_period_open()
+                    }
+            })
+            .return_const(Ok(fixtures::uuid()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::CreateRewardPeriod),
+                eq(vec![fixtures::uuid().to_string()]),
+                eq(None::<String>),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            u_repository_mock,
+            audit_log_service_mock,
+        );
+
+        let result = service
+            .create_reward_period(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(result.id, fixtures::uuid().to_string());
+        assert_eq!(
+            result.reward_period.rules,
+            backend_api::RewardRules::from(fixtures::reward_rules())
+        );
+        assert_eq!(
+            result.reward_period.status,
+            backend_api::RewardPeriodStatus::Open
+        );
+    }
+
+    #[rstest]
+    #[case::empty_name(
+        CreateRewardPeriodRequest {
+            name: "".to_string(),
+            ..create_reward_period_request()
+        },
+        ApiError::invalid_argument("Name cannot be empty")
+    )]
+    #[case::name_too_long(
+        CreateRewardPeriodRequest {
+            name: "a".repeat(MAX_REWARD_PERIOD_NAME_CHARS + 1),
+            ..create_reward_period_request()
+        },
+        ApiError::invalid_argument(&format!(
+            "Name must be less than {} characters",
+            MAX_REWARD_PERIOD_NAME_CHARS
+        ))
+    )]
+    #[case::ends_before_start(
+        CreateRewardPeriodRequest {
+            start_timestamp_ms: create_reward_period_request().end_timestamp_ms,
+            end_timestamp_ms: create_reward_period_request().start_timestamp_ms,
+            ..create_reward_period_request()
+        },
+        ApiError::invalid_argument("Reward period must end after it starts")
+    )]
+    fn create_reward_period_invalid(
+        #[case] request: CreateRewardPeriodRequest,
+        #[case] error: ApiError,
+    ) {
+        let calling_principal = fixtures::principal_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock.expect_create_reward_period().never();
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            u_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_reward_period(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, error);
+    }
+
+    #[rstest]
+    fn create_reward_period_overlapping() {
+        let calling_principal = fixtures::principal_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(uuid_b()));
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_periods()
+            .once()
+            .return_const(vec![(uuid_a(), fixtures::reward_period_closed())]);
+        rw_repository_mock.expect_create_reward_period().never();
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            u_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .create_reward_period(calling_principal, create_reward_period_request())
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period overlaps with reward period with Id {}",
+                uuid_a()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn update_reward_period() {
+        let reward_period_id = fixtures::uuid();
+        let request = UpdateRewardPeriodRequest {
+            id: reward_period_id.to_string(),
+            name: Some("Updated reward period".to_string()),
+            start_timestamp_ms: None,
+            end_timestamp_ms: None,
+            rules: None,
+        };
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_open()));
+        rw_repository_mock
+            .expect_get_reward_periods()
+            .once()
+            .return_const(vec![(reward_period_id, fixtures::reward_period_open())]);
+        rw_repository_mock
+            .expect_update_reward_period()
+            .once()
+            .withf(move |id, reward_period| {
+                *id == reward_period_id
+                    && reward_period
+                        == &RewardPeriod {
+                            name: "Updated reward period".to_string(),
+                            last_updated_at: reward_period.last_updated_at,
+                            ..fixtures::reward_period_open()
+                        }
+                    && reward_period.last_updated_at.is_some()
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(fixtures::principal_a()),
+                eq(AuditAction::UpdateRewardPeriod),
+                eq(vec![fixtures::uuid().to_string()]),
+                eq(Some(audit_snapshot(&fixtures::reward_period_open()))),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            audit_log_service_mock,
+        );
+
+        service
+            .update_reward_period(fixtures::principal_a(), request)
+            .unwrap();
+    }
+
+    #[rstest]
+    fn update_reward_period_closed() {
+        let reward_period_id = fixtures::uuid();
+        let request = UpdateRewardPeriodRequest {
+            id: reward_period_id.to_string(),
+            name: None,
+            start_timestamp_ms: None,
+            end_timestamp_ms: None,
+            rules: Some(fixtures::reward_rules().into()),
+        };
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_closed()));
+        rw_repository_mock.expect_update_reward_period().never();
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .update_reward_period(fixtures::principal_a(), request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} is closed",
+                reward_period_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn close_reward_period() {
+        let reward_period_id = fixtures::uuid();
+        // the completed proposal fixture is proposed and completed at date_time_a
+        let reward_period = RewardPeriod {
+            starts_at: fixtures::date_time_b(),
+            ends_at: fixtures::date_time_a().add(Duration::days(1)),
+            ..fixtures::reward_period_open()
+        };
+        let proposal_id = fixtures::proposal_id();
+        let other_proposal_id = uuid_c();
+        let proposed_at = fixtures::date_time_a();
+
+        let mut u_repository_mock = MockUserProfileRepository::new();
+        u_repository_mock
+            .expect_get_all_reviewer_profiles()
+            .once()
+            .return_const(vec![
+                (uuid_a(), fixtures::reviewer_user_profile()),
+                (uuid_b(), fixtures::reviewer_user_profile()),
+            ]);
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .with(eq(Some(ReviewPeriodStateKey::Completed)))
+            .return_const(Ok(vec![
+                (
+                    proposal_id,
+                    fixtures::nns_replica_version_management_proposal_completed(None, None),
+                ),
+                (
+                    other_proposal_id,
+                    Proposal {
+                        state: ReviewPeriodState::Completed {
+                            completed_at: fixtures::date_time_future(),
+                        },
+                        ..fixtures::nns_replica_version_management_proposal_completed(None, None)
+                    },
+                ),
+            ]));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (
+                    uuid_a(),
+                    ProposalReview {
+                        user_id: uuid_a(),
+                        // set back to draft and published again after the deadline
+                        published_at: Some(proposed_at.add(Duration::hours(2))),
+                        last_updated_at: Some(proposed_at.add(Duration::hours(72))),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    uuid_b(),
+                    ProposalReview {
+                        user_id: uuid_b(),
+                        build_reproduced: Some(false),
+                        published_at: Some(proposed_at.add(Duration::hours(49))),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    uuid_c(),
+                    ProposalReview {
+                        user_id: uuid_a(),
+                        ..fixtures::proposal_review_draft()
+                    },
+                ),
+                (
+                    fixtures::uuid(),
+                    ProposalReview {
+                        user_id: uuid_c(),
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+            ]));
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(reward_period.clone()));
+        rw_repository_mock
+            .expect_close_reward_period()
+            .once()
+            .withf(move |id, _, reward_statements| {
+                *id == reward_period_id
+                    && reward_statements
+                        == [
+                            RewardStatement {
+                                user_id: uuid_a(),
+                                wallet_address: fixtures::wallet_address().to_string(),
+                                items: vec![RewardStatementItem {
+                                    proposal_id,
+                                    proposal_review_id: uuid_a(),
+                                    verified_build: true,
+                                    late: false,
+                                    amount_e8s: 150,
+                                }],
+                                total_e8s: 150,
+                            },
+                            RewardStatement {
+                                user_id: uuid_b(),
+                                wallet_address: fixtures::wallet_address().to_string(),
+                                items: vec![RewardStatementItem {
+                                    proposal_id,
+                                    proposal_review_id: uuid_b(),
+                                    verified_build: false,
+                                    late: true,
+                                    amount_e8s: 70,
+                                }],
+                                total_e8s: 70,
+                            },
+                        ]
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(fixtures::principal_a()),
+                eq(AuditAction::CloseRewardPeriod),
+                eq(vec![fixtures::uuid().to_string()]),
+                eq(Some(audit_snapshot(&reward_period))),
+                always(),
+            )
+            .return_const(Ok(()));
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            u_repository_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .close_reward_period(
+                fixtures::principal_a(),
+                CloseRewardPeriodRequest {
+                    id: reward_period_id.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn close_reward_period_not_ended() {
+        let reward_period_id = fixtures::uuid();
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(RewardPeriod {
+                ends_at: fixtures::date_time_future(),
+                ..fixtures::reward_period_open()
+            }));
+        rw_repository_mock.expect_close_reward_period().never();
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .close_reward_period(
+                fixtures::principal_a(),
+                CloseRewardPeriodRequest {
+                    id: reward_period_id.to_string(),
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} cannot be closed before it ends",
+                reward_period_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn list_reward_periods() {
+        let reward_periods = vec![(fixtures::uuid(), fixtures::reward_period_open())];
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_periods()
+            .once()
+            .return_const(reward_periods.clone());
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service.list_reward_periods().unwrap();
+
+        assert_eq!(result, map_list_reward_periods_response(reward_periods));
+    }
+
+    #[rstest]
+    fn export_reward_statements() {
+        let reward_period_id = fixtures::uuid();
+        let reward_statements = vec![
+            fixtures::reward_statement(),
+            RewardStatement {
+                user_id: uuid_b(),
+                total_e8s: 70,
+                ..fixtures::reward_statement()
+            },
+        ];
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_closed()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Ok(reward_statements.clone()));
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .export_reward_statements(ExportRewardStatementsRequest {
+                reward_period_id: reward_period_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(result.total_e8s, 220);
+        assert_eq!(
+            result,
+            map_export_reward_statements_response(
+                reward_period_id,
+                fixtures::reward_period_closed(),
+                reward_statements
+            )
+        );
+    }
+
+    #[rstest]
+    fn export_reward_statements_open() {
+        let reward_period_id = fixtures::uuid();
+
+        let mut rw_repository_mock = MockRewardRepository::new();
+        rw_repository_mock
+            .expect_get_reward_period_by_id()
+            .once()
+            .with(eq(reward_period_id))
+            .return_const(Some(fixtures::reward_period_open()));
+        rw_repository_mock
+            .expect_get_reward_statements_by_reward_period_id()
+            .never();
+
+        let service = RewardServiceImpl::new(
+            rw_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .export_reward_statements(ExportRewardStatementsRequest {
+                reward_period_id: reward_period_id.to_string(),
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "Reward period with Id {} is not closed yet",
+                reward_period_id
+            ))
+        );
+    }
+}
@@ -0,0 +1,399 @@
+use crate::{
+    repositories::{
+        audit_snapshot, AuditAction, AuditLogRepositoryImpl, DateTime, Permission, Role,
+        RoleRepository, RoleRepositoryImpl, UserId, UserProfile, UserProfileRepository,
+        UserProfileRepositoryImpl, UserRoles,
+    },
+    services::{AuditLogService, AuditLogServiceImpl},
+    system_api::get_date_time,
+};
+use backend_api::{
+    ApiError, GetMyPermissionsResponse, GetUserRolesRequest, GetUserRolesResponse,
+    SetUserRolesRequest,
+};
+use candid::Principal;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RoleService {
+    /// Replaces the roles of the user, an empty list removes all of them.
+    fn set_user_roles(
+        &self,
+        calling_principal: Principal,
+        request: SetUserRolesRequest,
+    ) -> Result<(), ApiError>;
+
+    fn get_user_roles(
+        &self,
+        request: GetUserRolesRequest,
+    ) -> Result<GetUserRolesResponse, ApiError>;
+
+    fn get_my_permissions(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPermissionsResponse, ApiError>;
+}
+
+pub struct RoleServiceImpl<R: RoleRepository, U: UserProfileRepository, AL: AuditLogService> {
+    role_repository: R,
+    user_profile_repository: U,
+    audit_log_service: AL,
+}
+
+impl Default
+    for RoleServiceImpl<
+        RoleRepositoryImpl,
+        UserProfileRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            RoleRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+        )
+    }
+}
+
+impl<R: RoleRepository, U: UserProfileRepository, AL: AuditLogService> RoleService
+    for RoleServiceImpl<R, U, AL>
+{
+    fn set_user_roles(
+        &self,
+        calling_principal: Principal,
+        request: SetUserRolesRequest,
+    ) -> Result<(), ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        self.get_user_profile(&user_id)?;
+
+        let updated_by = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        let mut roles: Vec<Role> = request.roles.into_iter().map(|role| role.into()).collect();
+        roles.sort();
+        roles.dedup();
+
+        let before = self.role_repository.get_user_roles_by_user_id(&user_id);
+        let user_roles = UserRoles {
+            roles,
+            updated_by,
+            updated_at: DateTime::new(get_date_time()?)?,
+        };
+        self.role_repository
+            .upsert_user_roles(user_id, user_roles.clone())?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::SetUserRoles,
+            vec![user_id.to_string()],
+            before.as_ref().map(audit_snapshot),
+            Some(audit_snapshot(&user_roles)),
+        )
+    }
+
+    fn get_user_roles(
+        &self,
+        request: GetUserRolesRequest,
+    ) -> Result<GetUserRolesResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let user_profile = self.get_user_profile(&user_id)?;
+        let user_roles = self.role_repository.get_user_roles_by_user_id(&user_id);
+
+        Ok(GetUserRolesResponse {
+            user_id: user_id.to_string(),
+            permissions: effective_permissions(&user_profile, user_roles.as_ref()),
+            roles: map_roles(user_roles.as_ref()),
+            updated_by: user_roles
+                .as_ref()
+                .map(|user_roles| user_roles.updated_by.to_string()),
+            updated_at: user_roles.map(|user_roles| user_roles.updated_at.to_string()),
+        })
+    }
+
+    fn get_my_permissions(
+        &self,
+        calling_principal: Principal,
+    ) -> Result<GetMyPermissionsResponse, ApiError> {
+        let (user_id, user_profile) = self
+            .user_profile_repository
+            .get_user_profile_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+        let user_roles = self.role_repository.get_user_roles_by_user_id(&user_id);
+
+        Ok(GetMyPermissionsResponse {
+            permissions: effective_permissions(&user_profile, user_roles.as_ref()),
+            roles: map_roles(user_roles.as_ref()),
+        })
+    }
+}
+
+impl<R: RoleRepository, U: UserProfileRepository, AL: AuditLogService> RoleServiceImpl<R, U, AL> {
+    fn new(role_repository: R, user_profile_repository: U, audit_log_service: AL) -> Self {
+        Self {
+            role_repository,
+            user_profile_repository,
+            audit_log_service,
+        }
+    }
+
+    fn get_user_profile(&self, user_id: &UserId) -> Result<UserProfile, ApiError> {
+        self.user_profile_repository
+            .get_user_profile_by_user_id(user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for user with id {} not found",
+                    user_id
+                ))
+            })
+    }
+}
+
+fn map_roles(user_roles: Option<&UserRoles>) -> Vec<backend_api::Role> {
+    user_roles
+        .map(|user_roles| user_roles.roles.iter().map(|role| (*role).into()).collect())
+        .unwrap_or_default()
+}
+
+fn effective_permissions(
+    user_profile: &UserProfile,
+    user_roles: Option<&UserRoles>,
+) -> Vec<backend_api::Permission> {
+    let mut permissions: Vec<Permission> = user_profile.config.default_permissions().to_vec();
+    if let Some(user_roles) = user_roles {
+        permissions.extend(
+            user_roles
+                .roles
+                .iter()
+                .flat_map(|role| role.permissions().iter().copied()),
+        );
+    }
+    permissions.sort();
+    permissions.dedup();
+
+    permissions
+        .into_iter()
+        .map(|permission| permission.into())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{MockRoleRepository, MockUserProfileRepository},
+        services::MockAuditLogService,
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn set_user_roles() {
+        let calling_principal = fixtures::principal_a();
+        let admin_id = fixtures::uuid_a();
+        let user_id = fixtures::uuid_b();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(admin_id));
+
+        let user_roles = UserRoles {
+            roles: vec![Role::Moderator, Role::SyncOperator],
+            updated_by: admin_id,
+            updated_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+        };
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::user_roles()));
+        role_repository_mock
+            .expect_upsert_user_roles()
+            .once()
+            .with(eq(user_id), eq(user_roles.clone()))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SetUserRoles),
+                eq(vec![user_id.to_string()]),
+                eq(Some(audit_snapshot(&fixtures::user_roles()))),
+                eq(Some(audit_snapshot(&user_roles))),
+            )
+            .return_const(Ok(()));
+
+        let service = RoleServiceImpl::new(
+            role_repository_mock,
+            up_repository_mock,
+            audit_log_service_mock,
+        );
+
+        service
+            .set_user_roles(
+                calling_principal,
+                SetUserRolesRequest {
+                    user_id: user_id.to_string(),
+                    roles: vec![
+                        backend_api::Role::SyncOperator,
+                        backend_api::Role::Moderator,
+                        backend_api::Role::SyncOperator,
+                    ],
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn set_user_roles_user_not_found() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_b();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock.expect_upsert_user_roles().never();
+
+        let service = RoleServiceImpl::new(
+            role_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .set_user_roles(
+                calling_principal,
+                SetUserRolesRequest {
+                    user_id: user_id.to_string(),
+                    roles: vec![backend_api::Role::Moderator],
+                },
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for user with id {} not found",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_user_roles() {
+        let user_id = fixtures::uuid_b();
+        let user_roles = fixtures::user_roles();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(user_roles.clone()));
+
+        let service = RoleServiceImpl::new(
+            role_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service
+            .get_user_roles(GetUserRolesRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            GetUserRolesResponse {
+                user_id: user_id.to_string(),
+                roles: vec![
+                    backend_api::Role::RewardsManager,
+                    backend_api::Role::SyncOperator
+                ],
+                permissions: vec![
+                    backend_api::Permission::ReadLogs,
+                    backend_api::Permission::SyncProposals,
+                    backend_api::Permission::ManageRewards,
+                    backend_api::Permission::ReviewProposals,
+                    backend_api::Permission::Comment,
+                ],
+                updated_by: Some(user_roles.updated_by.to_string()),
+                updated_at: Some(user_roles.updated_at.to_string()),
+            }
+        );
+    }
+
+    #[rstest]
+    fn get_my_permissions_without_roles() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::anonymous_user_profile())));
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+
+        let service = RoleServiceImpl::new(
+            role_repository_mock,
+            up_repository_mock,
+            MockAuditLogService::new(),
+        );
+
+        let result = service.get_my_permissions(calling_principal).unwrap();
+
+        assert_eq!(
+            result,
+            GetMyPermissionsResponse {
+                roles: vec![],
+                permissions: vec![],
+            }
+        );
+    }
+}
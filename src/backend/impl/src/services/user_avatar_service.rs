@@ -0,0 +1,549 @@
+use crate::{
+    helpers::create_image_http_response,
+    repositories::{
+        CertificationRepository, CertificationRepositoryImpl, CreateImageRequest, DateTime, Image,
+        ImageId, ImageRepository, ImageRepositoryImpl, UserId, UserProfile, UserProfileRepository,
+        UserProfileRepositoryImpl, USER_AVATARS_SUB_PATH,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{ApiError, CreateMyUserAvatarRequest, CreateMyUserAvatarResponse};
+use candid::Principal;
+use std::{path::PathBuf, str::FromStr};
+
+const MAX_USER_AVATAR_BYTES: usize = 512 * 1024;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait UserAvatarService {
+    /// Replaces the avatar of the caller's profile, if it already has one.
+    fn create_my_user_avatar(
+        &self,
+        calling_principal: Principal,
+        request: CreateMyUserAvatarRequest,
+    ) -> Result<CreateMyUserAvatarResponse, ApiError>;
+
+    fn delete_my_user_avatar(&self, calling_principal: Principal) -> Result<(), ApiError>;
+}
+
+pub struct UserAvatarServiceImpl<
+    U: UserProfileRepository,
+    I: ImageRepository,
+    C: CertificationRepository,
+> {
+    user_profile_repository: U,
+    image_repository: I,
+    certification_repository: C,
+}
+
+impl Default
+    for UserAvatarServiceImpl<
+        UserProfileRepositoryImpl,
+        ImageRepositoryImpl,
+        CertificationRepositoryImpl,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            UserProfileRepositoryImpl::default(),
+            ImageRepositoryImpl::default(),
+            CertificationRepositoryImpl::default(),
+        )
+    }
+}
+
+impl<U: UserProfileRepository, I: ImageRepository, C: CertificationRepository> UserAvatarService
+    for UserAvatarServiceImpl<U, I, C>
+{
+    fn create_my_user_avatar(
+        &self,
+        calling_principal: Principal,
+        request: CreateMyUserAvatarRequest,
+    ) -> Result<CreateMyUserAvatarResponse, ApiError> {
+        request.validate_fields()?;
+
+        if request.content_bytes.len() > MAX_USER_AVATAR_BYTES {
+            return Err(ApiError::invalid_argument(&format!(
+                "Avatar cannot be larger than {} bytes",
+                MAX_USER_AVATAR_BYTES
+            )));
+        }
+
+        if !has_image_signature(&request.content_type, &request.content_bytes) {
+            return Err(ApiError::invalid_argument(&format!(
+                "Avatar content is not a valid {} image",
+                request.content_type
+            )));
+        }
+
+        let (user_id, mut user_profile) = self.get_my_user_profile(&calling_principal)?;
+
+        // the previous avatar is replaced, so it must not be served anymore
+        if let Some(previous_avatar_id) = user_profile.avatar_id.take() {
+            self.delete_avatar_image(&previous_avatar_id)?;
+        }
+
+        let date_time = get_date_time()?;
+
+        let image = Image {
+            created_at: DateTime::new(date_time)?,
+            user_id,
+            content_type: request.content_type,
+            sub_path: Some(PathBuf::from_str(USER_AVATARS_SUB_PATH).unwrap()),
+            content_bytes: request.content_bytes,
+            attachment: None,
+        };
+
+        let image_id = self.image_repository.create_image(image.clone());
+
+        // certify the image response
+        let image_path = image.path(&image_id);
+        let image_http_response = create_image_http_response(image);
+        self.certification_repository
+            .certify_http_response(&image_path, &image_http_response);
+
+        user_profile.avatar_id = Some(image_id);
+
+        self.user_profile_repository.update_user_profile(
+            calling_principal,
+            user_id,
+            user_profile,
+        )?;
+
+        Ok(CreateMyUserAvatarResponse { path: image_path })
+    }
+
+    fn delete_my_user_avatar(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        let (user_id, mut user_profile) = self.get_my_user_profile(&calling_principal)?;
+
+        let avatar_id = user_profile.avatar_id.take().ok_or_else(|| {
+            ApiError::not_found(&format!(
+                "User profile for principal {} has no avatar",
+                calling_principal.to_text()
+            ))
+        })?;
+
+        self.delete_avatar_image(&avatar_id)?;
+
+        self.user_profile_repository
+            .update_user_profile(calling_principal, user_id, user_profile)
+    }
+}
+
+impl<U: UserProfileRepository, I: ImageRepository, C: CertificationRepository>
+    UserAvatarServiceImpl<U, I, C>
+{
+    fn new(user_profile_repository: U, image_repository: I, certification_repository: C) -> Self {
+        Self {
+            user_profile_repository,
+            image_repository,
+            certification_repository,
+        }
+    }
+
+    fn get_my_user_profile(
+        &self,
+        calling_principal: &Principal,
+    ) -> Result<(UserId, UserProfile), ApiError> {
+        self.user_profile_repository
+            .get_user_profile_by_principal(calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })
+    }
+
+    /// Deletes the image and its certification, so that it is no longer served.
+    fn delete_avatar_image(&self, avatar_id: &ImageId) -> Result<(), ApiError> {
+        let deleted_image = self.image_repository.delete_image(avatar_id)?;
+
+        let image_http_request_path = deleted_image.path(avatar_id);
+        let image_http_response = create_image_http_response(deleted_image);
+        self.certification_repository
+            .remove_http_response_certificate(&image_http_request_path, &image_http_response);
+
+        Ok(())
+    }
+}
+
+/// Checks the leading bytes of the content against the format of the declared content type.
+fn has_image_signature(content_type: &str, content_bytes: &[u8]) -> bool {
+    match content_type {
+        "image/png" => content_bytes.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/jpeg" => content_bytes.starts_with(&[0xff, 0xd8, 0xff]),
+        "image/gif" => content_bytes.starts_with(b"GIF87a") || content_bytes.starts_with(b"GIF89a"),
+        "image/webp" => {
+            content_bytes.len() >= 12
+                && content_bytes.starts_with(b"RIFF")
+                && content_bytes[8..12] == *b"WEBP"
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{
+            MockCertificationRepository, MockImageRepository, MockUserProfileRepository,
+        },
+    };
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[rstest]
+    fn create_my_user_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let image_id = fixtures::uuid_b();
+        let request = create_my_user_avatar_request();
+        let image = avatar_image(user_id);
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+        user_profile_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(user_id),
+                eq(UserProfile {
+                    avatar_id: Some(image_id),
+                    ..fixtures::reviewer_user_profile()
+                }),
+            )
+            .return_const(Ok(()));
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_delete_image().never();
+        image_repository_mock
+            .expect_create_image()
+            .once()
+            .with(eq(image.clone()))
+            .return_const(image_id);
+
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_certify_http_response()
+            .once()
+            .with(
+                eq(image.path(&image_id)),
+                eq(create_image_http_response(image)),
+            )
+            .return_const(());
+        certification_repository_mock
+            .expect_remove_http_response_certificate()
+            .never();
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+        );
+
+        let result = service
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(
+            result,
+            CreateMyUserAvatarResponse {
+                path: format!("/images/avatars/{}", image_id),
+            }
+        );
+    }
+
+    #[rstest]
+    fn create_my_user_avatar_replaces_previous_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let previous_image_id = fixtures::uuid_a();
+        let image_id = fixtures::uuid_b();
+        let request = create_my_user_avatar_request();
+        let previous_image = avatar_image(user_id);
+        let image = avatar_image(user_id);
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((
+                user_id,
+                UserProfile {
+                    avatar_id: Some(previous_image_id),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )));
+        user_profile_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(user_id),
+                eq(UserProfile {
+                    avatar_id: Some(image_id),
+                    ..fixtures::reviewer_user_profile()
+                }),
+            )
+            .return_const(Ok(()));
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock
+            .expect_delete_image()
+            .once()
+            .with(eq(previous_image_id))
+            .return_const(Ok(previous_image.clone()));
+        image_repository_mock
+            .expect_create_image()
+            .once()
+            .with(eq(image.clone()))
+            .return_const(image_id);
+
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_remove_http_response_certificate()
+            .once()
+            .with(
+                eq(previous_image.path(&previous_image_id)),
+                eq(create_image_http_response(previous_image)),
+            )
+            .return_const(());
+        certification_repository_mock
+            .expect_certify_http_response()
+            .once()
+            .with(
+                eq(image.path(&image_id)),
+                eq(create_image_http_response(image)),
+            )
+            .return_const(());
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+        );
+
+        let result = service
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap();
+
+        assert_eq!(
+            result,
+            CreateMyUserAvatarResponse {
+                path: format!("/images/avatars/{}", image_id),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case::content_type_not_allowed(
+        CreateMyUserAvatarRequest {
+            content_type: "image/svg+xml".to_string(),
+            ..create_my_user_avatar_request()
+        },
+        ApiError::invalid_argument("Content type image/svg+xml not allowed")
+    )]
+    #[case::content_empty(
+        CreateMyUserAvatarRequest {
+            content_bytes: vec![],
+            ..create_my_user_avatar_request()
+        },
+        ApiError::invalid_argument("Image content cannot be empty")
+    )]
+    #[case::content_too_large(
+        CreateMyUserAvatarRequest {
+            content_bytes: vec![0; MAX_USER_AVATAR_BYTES + 1],
+            ..create_my_user_avatar_request()
+        },
+        ApiError::invalid_argument(&format!(
+            "Avatar cannot be larger than {} bytes",
+            MAX_USER_AVATAR_BYTES
+        ))
+    )]
+    #[case::content_type_mismatch(
+        CreateMyUserAvatarRequest {
+            content_type: "image/jpeg".to_string(),
+            ..create_my_user_avatar_request()
+        },
+        ApiError::invalid_argument("Avatar content is not a valid image/jpeg image")
+    )]
+    fn create_my_user_avatar_invalid(
+        #[case] request: CreateMyUserAvatarRequest,
+        #[case] expected_error: ApiError,
+    ) {
+        let calling_principal = fixtures::principal_a();
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .never();
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_create_image().never();
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            MockCertificationRepository::new(),
+        );
+
+        let result = service
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, expected_error);
+    }
+
+    #[rstest]
+    fn create_my_user_avatar_no_profile() {
+        let calling_principal = fixtures::principal_a();
+        let request = create_my_user_avatar_request();
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(None);
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_create_image().never();
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            MockCertificationRepository::new(),
+        );
+
+        let result = service
+            .create_my_user_avatar(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for principal {} not found",
+                calling_principal.to_text()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn delete_my_user_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let image_id = fixtures::uuid_a();
+        let image = avatar_image(user_id);
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((
+                user_id,
+                UserProfile {
+                    avatar_id: Some(image_id),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )));
+        user_profile_repository_mock
+            .expect_update_user_profile()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(user_id),
+                eq(fixtures::reviewer_user_profile()),
+            )
+            .return_const(Ok(()));
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock
+            .expect_delete_image()
+            .once()
+            .with(eq(image_id))
+            .return_const(Ok(image.clone()));
+
+        let mut certification_repository_mock = MockCertificationRepository::new();
+        certification_repository_mock
+            .expect_remove_http_response_certificate()
+            .once()
+            .with(
+                eq(image.path(&image_id)),
+                eq(create_image_http_response(image)),
+            )
+            .return_const(());
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            certification_repository_mock,
+        );
+
+        service.delete_my_user_avatar(calling_principal).unwrap();
+    }
+
+    #[rstest]
+    fn delete_my_user_avatar_no_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut user_profile_repository_mock = MockUserProfileRepository::new();
+        user_profile_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+        user_profile_repository_mock
+            .expect_update_user_profile()
+            .never();
+
+        let mut image_repository_mock = MockImageRepository::new();
+        image_repository_mock.expect_delete_image().never();
+
+        let service = UserAvatarServiceImpl::new(
+            user_profile_repository_mock,
+            image_repository_mock,
+            MockCertificationRepository::new(),
+        );
+
+        let result = service
+            .delete_my_user_avatar(calling_principal)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for principal {} has no avatar",
+                calling_principal.to_text()
+            ))
+        );
+    }
+
+    #[fixture]
+    fn create_my_user_avatar_request() -> CreateMyUserAvatarRequest {
+        let image = fixtures::image_without_subpath();
+
+        CreateMyUserAvatarRequest {
+            content_type: image.content_type,
+            content_bytes: image.content_bytes,
+        }
+    }
+
+    fn avatar_image(user_id: UserId) -> Image {
+        Image {
+            created_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+            user_id,
+            sub_path: Some(PathBuf::from(USER_AVATARS_SUB_PATH)),
+            ..fixtures::image_without_subpath()
+        }
+    }
+}
@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    mappings::{map_user_config, map_user_status},
+    repositories::{
+        is_reserved_username, normalize_username, validate_username, Role, RoleRepository,
+        RoleRepositoryImpl, UserId, UserProfile, UserProfileRepository, UserProfileRepositoryImpl,
+    },
+};
+use backend_api::{
+    ApiError, GetPrincipalsByUserIdRequest, GetPrincipalsByUserIdResponse,
+    GetUserIdByPrincipalRequest, GetUserIdByPrincipalResponse, GetUsernameReportResponse,
+    InvalidUsername, ListUsersRequest, ListUsersResponse, UserConfigType, UserDirectoryEntry,
+    UsernameCollision,
+};
+
+const DEFAULT_USERS_LIMIT: u64 = 50;
+const MAX_USERS_LIMIT: u64 = 100;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait UserDirectoryService {
+    fn list_users(&self, request: ListUsersRequest) -> Result<ListUsersResponse, ApiError>;
+
+    fn get_user_id_by_principal(
+        &self,
+        request: GetUserIdByPrincipalRequest,
+    ) -> Result<GetUserIdByPrincipalResponse, ApiError>;
+
+    fn get_principals_by_user_id(
+        &self,
+        request: GetPrincipalsByUserIdRequest,
+    ) -> Result<GetPrincipalsByUserIdResponse, ApiError>;
+
+    /// Lists the existing usernames that collide with each other or break the username rules.
+    fn get_username_report(&self) -> Result<GetUsernameReportResponse, ApiError>;
+}
+
+pub struct UserDirectoryServiceImpl<U: UserProfileRepository, R: RoleRepository> {
+    user_profile_repository: U,
+    role_repository: R,
+}
+
+impl Default for UserDirectoryServiceImpl<UserProfileRepositoryImpl, RoleRepositoryImpl> {
+    fn default() -> Self {
+        Self::new(
+            UserProfileRepositoryImpl::default(),
+            RoleRepositoryImpl::default(),
+        )
+    }
+}
+
+impl<U: UserProfileRepository, R: RoleRepository> UserDirectoryService
+    for UserDirectoryServiceImpl<U, R>
+{
+    fn list_users(&self, request: ListUsersRequest) -> Result<ListUsersResponse, ApiError> {
+        let offset = request.offset.unwrap_or(0);
+        let limit = request.limit.unwrap_or(DEFAULT_USERS_LIMIT);
+
+        if limit > MAX_USERS_LIMIT {
+            return Err(ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_USERS_LIMIT
+            )));
+        }
+
+        let profiles = match request.principal {
+            Some(principal) => self
+                .user_profile_repository
+                .get_user_profile_by_principal(&principal)
+                .into_iter()
+                .collect(),
+            None => self.user_profile_repository.get_all_user_profiles(),
+        };
+
+        let username = request.username.map(|username| username.to_lowercase());
+        let role = request.role.map(Role::from);
+        let users: Vec<(UserId, UserProfile, Vec<Role>)> = profiles
+            .into_iter()
+            .filter(|(_, profile)| {
+                request
+                    .config_type
+                    .is_none_or(|config_type| UserConfigType::from(&profile.config) == config_type)
+            })
+            .filter(|(_, profile)| {
+                username.as_ref().is_none_or(|username| {
+                    profile.username.to_lowercase().contains(username.as_str())
+                })
+            })
+            .map(|(user_id, profile)| {
+                let roles = self
+                    .role_repository
+                    .get_user_roles_by_user_id(&user_id)
+                    .map(|user_roles| user_roles.roles)
+                    .unwrap_or_default();
+
+                (user_id, profile, roles)
+            })
+            .filter(|(_, _, roles)| role.is_none_or(|role| roles.contains(&role)))
+            .collect();
+
+        let total = users.len() as u64;
+        // principals and statuses are only looked up for the returned page,
+        // each through the user id keyed index
+        let users = users
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(user_id, profile, roles)| {
+                Ok(UserDirectoryEntry {
+                    id: user_id.to_string(),
+                    config: map_user_config(&profile),
+                    username: profile.username,
+                    roles: roles.into_iter().map(|role| role.into()).collect(),
+                    principals: self
+                        .user_profile_repository
+                        .get_principals_by_user_id(&user_id)?,
+                    status: map_user_status(
+                        self.user_profile_repository
+                            .get_user_status_by_user_id(&user_id),
+                    ),
+                })
+            })
+            .collect::<Result<Vec<_>, ApiError>>()?;
+
+        Ok(ListUsersResponse { users, total })
+    }
+
+    fn get_user_id_by_principal(
+        &self,
+        request: GetUserIdByPrincipalRequest,
+    ) -> Result<GetUserIdByPrincipalResponse, ApiError> {
+        let user_id = self
+            .user_profile_repository
+            .get_user_id_by_principal(&request.principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    request.principal.to_text()
+                ))
+            })?;
+
+        Ok(GetUserIdByPrincipalResponse {
+            user_id: user_id.to_string(),
+        })
+    }
+
+    fn get_principals_by_user_id(
+        &self,
+        request: GetPrincipalsByUserIdRequest,
+    ) -> Result<GetPrincipalsByUserIdResponse, ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        self.user_profile_repository
+            .get_user_profile_by_user_id(&user_id)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for user with id {} not found",
+                    user_id
+                ))
+            })?;
+
+        Ok(GetPrincipalsByUserIdResponse {
+            principals: self
+                .user_profile_repository
+                .get_principals_by_user_id(&user_id)?,
+        })
+    }
+
+    fn get_username_report(&self) -> Result<GetUsernameReportResponse, ApiError> {
+        let default_usernames = [
+            UserProfile::new_anonymous().username,
+            UserProfile::new_admin().username,
+        ];
+
+        let mut user_ids_by_username: BTreeMap<String, Vec<UserId>> = BTreeMap::new();
+        let mut invalid_usernames = vec![];
+        for (user_id, profile) in self.user_profile_repository.get_all_user_profiles() {
+            // the defaults are assigned by the canister and are allowed to be shared
+            if default_usernames.contains(&profile.username) {
+                continue;
+            }
+
+            if let Err(err) = validate_username(&profile.username) {
+                invalid_usernames.push(InvalidUsername {
+                    user_id: user_id.to_string(),
+                    username: profile.username.clone(),
+                    reason: err.message().to_string(),
+                });
+            }
+
+            if !is_reserved_username(&profile.username) {
+                user_ids_by_username
+                    .entry(normalize_username(&profile.username))
+                    .or_default()
+                    .push(user_id);
+            }
+        }
+
+        let collisions = user_ids_by_username
+            .into_iter()
+            .filter(|(_, user_ids)| user_ids.len() > 1)
+            .map(|(username, user_ids)| UsernameCollision {
+                owner_id: self
+                    .user_profile_repository
+                    .get_user_id_by_username(&username)
+                    .map(|owner_id| owner_id.to_string()),
+                username,
+                user_ids: user_ids
+                    .into_iter()
+                    .map(|user_id| user_id.to_string())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(GetUsernameReportResponse {
+            collisions,
+            invalid_usernames,
+        })
+    }
+}
+
+impl<U: UserProfileRepository, R: RoleRepository> UserDirectoryServiceImpl<U, R> {
+    fn new(user_profile_repository: U, role_repository: R) -> Self {
+        Self {
+            user_profile_repository,
+            role_repository,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{MockRoleRepository, MockUserProfileRepository, UserRoles},
+    };
+    use candid::Principal;
+    use mockall::predicate::*;
+    use rstest::*;
+
+    #[fixture]
+    fn list_users_request() -> ListUsersRequest {
+        ListUsersRequest {
+            config_type: None,
+            role: None,
+            username: None,
+            principal: None,
+            offset: None,
+            limit: None,
+        }
+    }
+
+    #[fixture]
+    fn directory_profiles() -> Vec<(UserId, UserProfile)> {
+        vec![
+            (fixtures::uuid_a(), fixtures::admin_user_profile()),
+            (fixtures::uuid_b(), fixtures::reviewer_user_profile()),
+            (
+                fixtures::uuid_c(),
+                UserProfile {
+                    username: "GenevaGazer".to_string(),
+                    ..fixtures::anonymous_user_profile()
+                },
+            ),
+        ]
+    }
+
+    fn repository_mocks(
+        profiles: Vec<(UserId, UserProfile)>,
+    ) -> (MockUserProfileRepository, MockRoleRepository) {
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_all_user_profiles()
+            .once()
+            .return_const(profiles);
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .returning(|_| Ok(vec![fixtures::principal_a()]));
+        up_repository_mock
+            .expect_get_user_status_by_user_id()
+            .return_const(None);
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .returning(|user_id| {
+                (*user_id == fixtures::uuid_b()).then(|| UserRoles {
+                    roles: vec![Role::Moderator],
+                    ..fixtures::user_roles()
+                })
+            });
+
+        (up_repository_mock, role_repository_mock)
+    }
+
+    #[rstest]
+    #[case::no_filters(list_users_request(), vec![fixtures::uuid_a(), fixtures::uuid_b(), fixtures::uuid_c()], 3)]
+    #[case::config_type(
+        ListUsersRequest {
+            config_type: Some(UserConfigType::Reviewer),
+            ..list_users_request()
+        },
+        vec![fixtures::uuid_b()],
+        1
+    )]
+    #[case::role(
+        ListUsersRequest {
+            role: Some(backend_api::Role::Moderator),
+            ..list_users_request()
+        },
+        vec![fixtures::uuid_b()],
+        1
+    )]
+    #[case::username(
+        ListUsersRequest {
+            username: Some("lausanne".to_string()),
+            ..list_users_request()
+        },
+        vec![fixtures::uuid_a()],
+        1
+    )]
+    #[case::pagination(
+        ListUsersRequest {
+            offset: Some(1),
+            limit: Some(1),
+            ..list_users_request()
+        },
+        vec![fixtures::uuid_b()],
+        3
+    )]
+    fn list_users(
+        #[case] request: ListUsersRequest,
+        #[case] expected_ids: Vec<UserId>,
+        #[case] expected_total: u64,
+    ) {
+        let (up_repository_mock, role_repository_mock) = repository_mocks(directory_profiles());
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, role_repository_mock);
+
+        let result = service.list_users(request).unwrap();
+
+        assert_eq!(result.total, expected_total);
+        assert_eq!(
+            result
+                .users
+                .iter()
+                .map(|user| user.id.clone())
+                .collect::<Vec<_>>(),
+            expected_ids
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn list_users_page_principals() {
+        let profiles = directory_profiles();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_all_user_profiles()
+            .once()
+            .return_const(profiles);
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(Ok(vec![fixtures::principal_b()]));
+        up_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(None);
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .times(3)
+            .return_const(None);
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, role_repository_mock);
+
+        let result = service
+            .list_users(ListUsersRequest {
+                offset: Some(1),
+                limit: Some(1),
+                ..list_users_request()
+            })
+            .unwrap();
+
+        assert_eq!(result.total, 3);
+        assert_eq!(result.users.len(), 1);
+        assert_eq!(result.users[0].principals, vec![fixtures::principal_b()]);
+    }
+
+    #[rstest]
+    fn list_users_entry() {
+        let (up_repository_mock, role_repository_mock) = repository_mocks(vec![(
+            fixtures::uuid_b(),
+            fixtures::reviewer_user_profile(),
+        )]);
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, role_repository_mock);
+
+        let result = service.list_users(list_users_request()).unwrap();
+
+        assert_eq!(
+            result,
+            ListUsersResponse {
+                users: vec![UserDirectoryEntry {
+                    id: fixtures::uuid_b().to_string(),
+                    username: fixtures::reviewer_user_profile().username,
+                    config: fixtures::reviewer_user_profile().config.into(),
+                    roles: vec![backend_api::Role::Moderator],
+                    principals: vec![fixtures::principal_a()],
+                    status: backend_api::UserStatus::Active,
+                }],
+                total: 1,
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_users_by_principal() {
+        let principal = fixtures::principal_b();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock.expect_get_all_user_profiles().never();
+        up_repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(principal))
+            .return_const(Some((
+                fixtures::uuid_b(),
+                fixtures::reviewer_user_profile(),
+            )));
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(Ok(vec![principal]));
+        up_repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(fixtures::uuid_b()))
+            .return_const(Some(fixtures::deleted_user_status()));
+
+        let mut role_repository_mock = MockRoleRepository::new();
+        role_repository_mock
+            .expect_get_user_roles_by_user_id()
+            .once()
+            .return_const(None);
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, role_repository_mock);
+
+        let result = service
+            .list_users(ListUsersRequest {
+                principal: Some(principal),
+                ..list_users_request()
+            })
+            .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.users[0].principals, vec![principal]);
+        assert_eq!(
+            result.users[0].status,
+            backend_api::UserStatus::Deleted {
+                deleted_at: fixtures::date_time_a().to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn list_users_limit_too_high() {
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock.expect_get_all_user_profiles().never();
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service
+            .list_users(ListUsersRequest {
+                limit: Some(MAX_USERS_LIMIT + 1),
+                ..list_users_request()
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(&format!(
+                "Limit cannot be greater than {}",
+                MAX_USERS_LIMIT
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_user_id_by_principal() {
+        let principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(principal))
+            .return_const(Some(user_id));
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service
+            .get_user_id_by_principal(GetUserIdByPrincipalRequest { principal })
+            .unwrap();
+
+        assert_eq!(
+            result,
+            GetUserIdByPrincipalResponse {
+                user_id: user_id.to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    fn get_user_id_by_principal_not_found() {
+        let principal = Principal::anonymous();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(principal))
+            .return_const(None);
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service
+            .get_user_id_by_principal(GetUserIdByPrincipalRequest { principal })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User id for principal {} not found",
+                principal.to_text()
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_principals_by_user_id() {
+        let user_id = fixtures::user_id();
+        let principals = vec![fixtures::principal_a(), fixtures::principal_b()];
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Ok(principals.clone()));
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service
+            .get_principals_by_user_id(GetPrincipalsByUserIdRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap();
+
+        assert_eq!(result, GetPrincipalsByUserIdResponse { principals });
+    }
+
+    #[rstest]
+    fn get_principals_by_user_id_not_found() {
+        let user_id = fixtures::user_id();
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+        up_repository_mock
+            .expect_get_principals_by_user_id()
+            .never();
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service
+            .get_principals_by_user_id(GetPrincipalsByUserIdRequest {
+                user_id: user_id.to_string(),
+            })
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for user with id {} not found",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn get_username_report() {
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_all_user_profiles()
+            .once()
+            .return_const(vec![
+                (fixtures::uuid_a(), fixtures::reviewer_user_profile()),
+                (
+                    fixtures::uuid_b(),
+                    UserProfile {
+                        username: "zurichexplorer2023".to_string(),
+                        ..fixtures::anonymous_user_profile()
+                    },
+                ),
+                (fixtures::uuid_c(), UserProfile::new_anonymous()),
+                (
+                    fixtures::uuid(),
+                    UserProfile {
+                        username: "admin".to_string(),
+                        ..fixtures::anonymous_user_profile()
+                    },
+                ),
+            ]);
+        up_repository_mock
+            .expect_get_user_id_by_username()
+            .once()
+            .withf(|username| username == "zurichexplorer2023")
+            .return_const(Some(fixtures::uuid_a()));
+
+        let service = UserDirectoryServiceImpl::new(up_repository_mock, MockRoleRepository::new());
+
+        let result = service.get_username_report().unwrap();
+
+        assert_eq!(
+            result,
+            GetUsernameReportResponse {
+                collisions: vec![UsernameCollision {
+                    username: "zurichexplorer2023".to_string(),
+                    user_ids: vec![
+                        fixtures::uuid_a().to_string(),
+                        fixtures::uuid_b().to_string()
+                    ],
+                    owner_id: Some(fixtures::uuid_a().to_string()),
+                }],
+                invalid_usernames: vec![InvalidUsername {
+                    user_id: fixtures::uuid().to_string(),
+                    username: "admin".to_string(),
+                    reason: "Username admin is reserved".to_string(),
+                }],
+            }
+        );
+    }
+}
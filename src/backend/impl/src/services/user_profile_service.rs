@@ -4,13 +4,19 @@ use crate::{
         map_get_my_user_profile_response, map_list_reviewer_profiles_response,
     },
     repositories::{
-        UserConfig, UserId, UserProfile, UserProfileRepository, UserProfileRepositoryImpl,
+        audit_snapshot, validate_username, AuditAction, AuditLogRepositoryImpl,
+        CertificationRepositoryImpl, DateTime, ImageRepositoryImpl, ReviewScoreRepository,
+        ReviewScoreRepositoryImpl, ReviewerReputation, SocialLink, UserConfig, UserId, UserProfile,
+        UserProfileRepository, UserProfileRepositoryImpl, UserStatus, WalletAddress,
+        REPUTATION_WINDOW_SIZE,
     },
+    services::{AuditLogService, AuditLogServiceImpl, UserAvatarService, UserAvatarServiceImpl},
+    system_api::get_date_time,
 };
 use backend_api::{
     ApiError, CreateMyUserProfileResponse, GetMyUserProfileHistoryResponse,
-    GetMyUserProfileResponse, ListReviewerProfilesResponse, UpdateMyUserProfileRequest,
-    UpdateUserProfileRequest,
+    GetMyUserProfileResponse, ListReviewerProfilesResponse, RestoreUserRequest, SuspendUserRequest,
+    UpdateMyUserProfileRequest, UpdateUserProfileRequest,
 };
 use candid::Principal;
 
@@ -44,21 +50,77 @@ pub trait UserProfileService {
         calling_principal: Principal,
         request: UpdateUserProfileRequest,
     ) -> Result<(), ApiError>;
+
+    fn suspend_user(
+        &self,
+        calling_principal: Principal,
+        request: SuspendUserRequest,
+    ) -> Result<(), ApiError>;
+
+    /// Reactivates a suspended or deleted profile with the config it had before.
+    fn restore_user(
+        &self,
+        calling_principal: Principal,
+        request: RestoreUserRequest,
+    ) -> Result<(), ApiError>;
+
+    /// Soft deletes the caller's profile, only an admin can restore it.
+    /// The avatar is deleted for good, so that it is no longer served.
+    fn delete_my_user_profile(&self, calling_principal: Principal) -> Result<(), ApiError>;
 }
 
-pub struct UserProfileServiceImpl<T: UserProfileRepository> {
+pub struct UserProfileServiceImpl<
+    T: UserProfileRepository,
+    R: ReviewScoreRepository,
+    AL: AuditLogService,
+    UA: UserAvatarService,
+> {
     user_profile_repository: T,
+    review_score_repository: R,
+    audit_log_service: AL,
+    user_avatar_service: UA,
 }
 
-impl Default for UserProfileServiceImpl<UserProfileRepositoryImpl> {
+impl Default
+    for UserProfileServiceImpl<
+        UserProfileRepositoryImpl,
+        ReviewScoreRepositoryImpl,
+        AuditLogServiceImpl<AuditLogRepositoryImpl>,
+        UserAvatarServiceImpl<
+            UserProfileRepositoryImpl,
+            ImageRepositoryImpl,
+            CertificationRepositoryImpl,
+        >,
+    >
+{
     fn default() -> Self {
-        Self::new(UserProfileRepositoryImpl::default())
+        Self::new(
+            UserProfileRepositoryImpl::default(),
+            ReviewScoreRepositoryImpl::default(),
+            AuditLogServiceImpl::default(),
+            UserAvatarServiceImpl::default(),
+        )
     }
 }
 
-impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T> {
+impl<
+        T: UserProfileRepository,
+        R: ReviewScoreRepository,
+        AL: AuditLogService,
+        UA: UserAvatarService,
+    > UserProfileService for UserProfileServiceImpl<T, R, AL, UA>
+{
     fn list_reviewer_profiles(&self) -> Result<ListReviewerProfilesResponse, ApiError> {
-        let profiles = self.user_profile_repository.get_all_reviewer_profiles();
+        let profiles = self
+            .user_profile_repository
+            .get_all_reviewer_profiles()
+            .into_iter()
+            .map(|(user_id, profile)| {
+                let reputation = self.get_reviewer_reputation(user_id)?;
+
+                Ok((user_id, profile, reputation))
+            })
+            .collect::<Result<Vec<_>, ApiError>>()?;
 
         Ok(map_list_reviewer_profiles_response(profiles))
     }
@@ -77,7 +139,13 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
                 ))
             })?;
 
-        Ok(map_get_my_user_profile_response(id, profile))
+        let reputation = if profile.is_reviewer() {
+            self.get_reviewer_reputation(id)?
+        } else {
+            None
+        };
+
+        Ok(map_get_my_user_profile_response(id, profile, reputation))
     }
 
     fn get_my_user_profile_history(
@@ -146,6 +214,7 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
             })?;
 
         if let Some(username) = request.username {
+            validate_username(&username)?;
             current_user_profile.username = username;
         }
 
@@ -171,12 +240,15 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
             ) => UserConfig::Reviewer {
                 bio: bio_update.unwrap_or(bio),
                 neuron_id,
-                wallet_address: wallet_address_update.unwrap_or(wallet_address),
+                wallet_address: match wallet_address_update {
+                    Some(wallet_address) => Some(WalletAddress::try_from(wallet_address.as_str())?),
+                    None => wallet_address,
+                },
                 social_links: match social_links_update {
                     Some(links) => links
                         .into_iter()
-                        .map(|link| link.into())
-                        .collect::<Vec<_>>(),
+                        .map(SocialLink::try_from)
+                        .collect::<Result<Vec<_>, _>>()?,
                     None => social_links,
                 },
             },
@@ -190,6 +262,7 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
                 ))
             }
         };
+        current_user_profile.clear_replaced_legacy_wallet_address();
 
         self.user_profile_repository.update_user_profile(
             calling_principal,
@@ -215,8 +288,10 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
                     user_id
                 ))
             })?;
+        let before = current_user_profile.clone();
 
         if let Some(username) = request.username {
+            validate_username(&username)?;
             current_user_profile.username = username;
         }
 
@@ -248,19 +323,21 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
                         _ => 0,
                     });
 
-                    let wallet_address =
-                        wallet_address.unwrap_or_else(|| {
-                            match current_user_profile.clone().config {
-                                UserConfig::Reviewer { wallet_address, .. } => wallet_address,
-                                _ => "".to_string(),
-                            }
-                        });
+                    let wallet_address = match wallet_address {
+                        Some(wallet_address) => {
+                            Some(WalletAddress::try_from(wallet_address.as_str())?)
+                        }
+                        None => match current_user_profile.clone().config {
+                            UserConfig::Reviewer { wallet_address, .. } => wallet_address,
+                            _ => None,
+                        },
+                    };
 
                     let social_links = match social_links {
                         Some(links) => links
                             .into_iter()
-                            .map(|link| link.into())
-                            .collect::<Vec<_>>(),
+                            .map(SocialLink::try_from)
+                            .collect::<Result<Vec<_>, _>>()?,
                         None => match current_user_profile.config {
                             UserConfig::Reviewer { social_links, .. } => social_links,
                             _ => vec![],
@@ -278,24 +355,155 @@ impl<T: UserProfileRepository> UserProfileService for UserProfileServiceImpl<T>
                     current_user_profile.config = UserConfig::Anonymous;
                 }
             }
+            current_user_profile.clear_replaced_legacy_wallet_address();
         }
 
         self.user_profile_repository.update_user_profile(
             calling_principal,
             user_id,
-            current_user_profile,
+            current_user_profile.clone(),
         )?;
 
-        Ok(())
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::UpdateUserProfile,
+            vec![user_id.to_string()],
+            Some(audit_snapshot(&before)),
+            Some(audit_snapshot(&current_user_profile)),
+        )
+    }
+
+    fn suspend_user(
+        &self,
+        calling_principal: Principal,
+        request: SuspendUserRequest,
+    ) -> Result<(), ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let suspended_by = self
+            .user_profile_repository
+            .get_user_id_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User id for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+        if suspended_by == user_id {
+            return Err(ApiError::invalid_argument(
+                "The calling principal cannot suspend its own user profile",
+            ));
+        }
+
+        if self
+            .user_profile_repository
+            .get_user_status_by_user_id(&user_id)
+            .is_some()
+        {
+            return Err(ApiError::conflict(&format!(
+                "User with id {} is already suspended or deleted",
+                user_id
+            )));
+        }
+
+        let user_status = UserStatus::Suspended {
+            reason: request.reason,
+            suspended_by,
+            suspended_at: DateTime::new(get_date_time()?)?,
+        };
+        self.user_profile_repository.set_user_status(
+            calling_principal,
+            user_id,
+            user_status.clone(),
+        )?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::SuspendUser,
+            vec![user_id.to_string()],
+            None,
+            Some(audit_snapshot(&user_status)),
+        )
+    }
+
+    fn restore_user(
+        &self,
+        calling_principal: Principal,
+        request: RestoreUserRequest,
+    ) -> Result<(), ApiError> {
+        let user_id = UserId::try_from(request.user_id.as_str())?;
+        let before = self
+            .user_profile_repository
+            .get_user_status_by_user_id(&user_id);
+
+        self.user_profile_repository
+            .remove_user_status(calling_principal, user_id)?;
+
+        self.audit_log_service.record_action(
+            calling_principal,
+            AuditAction::RestoreUser,
+            vec![user_id.to_string()],
+            before.as_ref().map(audit_snapshot),
+            None,
+        )
+    }
+
+    fn delete_my_user_profile(&self, calling_principal: Principal) -> Result<(), ApiError> {
+        let (user_id, user_profile) = self
+            .user_profile_repository
+            .get_user_profile_by_principal(&calling_principal)
+            .ok_or_else(|| {
+                ApiError::not_found(&format!(
+                    "User profile for principal {} not found",
+                    calling_principal.to_text()
+                ))
+            })?;
+
+        if user_profile.avatar_id.is_some() {
+            self.user_avatar_service
+                .delete_my_user_avatar(calling_principal)?;
+        }
+
+        self.user_profile_repository.set_user_status(
+            calling_principal,
+            user_id,
+            UserStatus::Deleted {
+                deleted_at: DateTime::new(get_date_time()?)?,
+            },
+        )
     }
 }
 
-impl<T: UserProfileRepository> UserProfileServiceImpl<T> {
-    fn new(user_profile_repository: T) -> Self {
+impl<
+        T: UserProfileRepository,
+        R: ReviewScoreRepository,
+        AL: AuditLogService,
+        UA: UserAvatarService,
+    > UserProfileServiceImpl<T, R, AL, UA>
+{
+    fn new(
+        user_profile_repository: T,
+        review_score_repository: R,
+        audit_log_service: AL,
+        user_avatar_service: UA,
+    ) -> Self {
         Self {
             user_profile_repository,
+            review_score_repository,
+            audit_log_service,
+            user_avatar_service,
         }
     }
+
+    fn get_reviewer_reputation(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<ReviewerReputation>, ApiError> {
+        let review_scores = self
+            .review_score_repository
+            .get_latest_review_scores_by_user_id(user_id, REPUTATION_WINDOW_SIZE)?;
+
+        Ok(ReviewerReputation::from_review_scores(&review_scores))
+    }
 }
 
 #[cfg(test)]
@@ -303,7 +511,8 @@ mod tests {
     use super::*;
     use crate::{
         fixtures::{self},
-        repositories::MockUserProfileRepository,
+        repositories::{MockReviewScoreRepository, MockUserProfileRepository},
+        services::{MockAuditLogService, MockUserAvatarService},
     };
     use backend_api::{
         HistoryAction, HistoryEntry, MyUserConfigUpdate, UserConfigUpdate, UserProfileHistoryEntry,
@@ -313,19 +522,40 @@ mod tests {
 
     #[rstest]
     fn list_reviewer_profiles() {
-        let profiles = vec![(fixtures::user_id(), fixtures::reviewer_user_profile())];
+        let user_id = fixtures::user_id();
+        let profile = fixtures::reviewer_user_profile();
+        let review_scores = vec![fixtures::review_score()];
 
         let mut repository_mock = MockUserProfileRepository::new();
         repository_mock
             .expect_get_all_reviewer_profiles()
             .once()
-            .return_const(profiles.clone());
+            .return_const(vec![(user_id, profile.clone())]);
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let mut review_score_repository_mock = MockReviewScoreRepository::new();
+        review_score_repository_mock
+            .expect_get_latest_review_scores_by_user_id()
+            .once()
+            .with(eq(user_id), eq(REPUTATION_WINDOW_SIZE))
+            .return_const(Ok(review_scores.clone()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            review_score_repository_mock,
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service.list_reviewer_profiles().unwrap();
 
-        assert_eq!(result, map_list_reviewer_profiles_response(profiles))
+        assert_eq!(
+            result,
+            map_list_reviewer_profiles_response(vec![(
+                user_id,
+                profile,
+                ReviewerReputation::from_review_scores(&review_scores),
+            )])
+        )
     }
 
     #[rstest]
@@ -341,7 +571,12 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Some((fixtures::user_id(), profile.clone())));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service.get_my_user_profile(calling_principal).unwrap();
 
@@ -351,10 +586,52 @@ mod tests {
                 id: id.to_string(),
                 username: profile.username,
                 config: profile.config.into(),
+                reputation: None,
+                avatar_path: None,
             }
         )
     }
 
+    #[rstest]
+    fn get_my_user_profile_reviewer() {
+        let calling_principal = fixtures::principal_a();
+        let id = fixtures::user_id();
+        let profile = fixtures::reviewer_user_profile();
+        let review_scores = vec![fixtures::review_score()];
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((id, profile.clone())));
+
+        let mut review_score_repository_mock = MockReviewScoreRepository::new();
+        review_score_repository_mock
+            .expect_get_latest_review_scores_by_user_id()
+            .once()
+            .with(eq(id), eq(REPUTATION_WINDOW_SIZE))
+            .return_const(Ok(review_scores.clone()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            review_score_repository_mock,
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service.get_my_user_profile(calling_principal).unwrap();
+
+        assert_eq!(
+            result,
+            map_get_my_user_profile_response(
+                id,
+                profile,
+                ReviewerReputation::from_review_scores(&review_scores)
+            )
+        )
+    }
+
     #[rstest]
     fn get_my_user_profile_no_profile() {
         let calling_principal = fixtures::principal_a();
@@ -366,7 +643,12 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(None);
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service.get_my_user_profile(calling_principal).unwrap_err();
 
@@ -391,7 +673,12 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Ok(Some(history.clone())));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .get_my_user_profile_history(calling_principal)
@@ -424,7 +711,12 @@ mod tests {
             .with(eq(calling_principal))
             .return_const(Ok(None));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .get_my_user_profile_history(calling_principal)
@@ -457,7 +749,12 @@ mod tests {
             .with(eq(calling_principal), eq(profile.clone()))
             .return_const(Ok(id));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service.create_my_user_profile(calling_principal).unwrap();
 
@@ -484,7 +781,12 @@ mod tests {
             .return_const(Some(id));
         repository_mock.expect_create_user_profile().never();
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .create_my_user_profile(calling_principal)
@@ -503,6 +805,7 @@ mod tests {
     #[case(my_anonymous_update())]
     #[case(my_admin_update())]
     #[case(my_reviewer_update())]
+    #[case(my_legacy_wallet_address_update())]
     fn update_my_user_profile(
         #[case] fixture: (UserProfile, UpdateMyUserProfileRequest, UserProfile),
     ) {
@@ -527,7 +830,12 @@ mod tests {
             .with(eq(calling_principal), eq(user_id), eq(updated_profile))
             .return_const(Ok(()));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         service
             .update_my_user_profile(calling_principal, profile_update_request)
@@ -597,7 +905,7 @@ mod tests {
                 config: UserConfig::Reviewer {
                     bio,
                     neuron_id,
-                    wallet_address,
+                    wallet_address: Some(wallet_address),
                     social_links,
                 },
                 ..original_profile
@@ -605,6 +913,43 @@ mod tests {
         )
     }
 
+    #[fixture]
+    fn my_legacy_wallet_address_update() -> (UserProfile, UpdateMyUserProfileRequest, UserProfile) {
+        let original_profile = UserProfile {
+            config: UserConfig::Reviewer {
+                bio: "Bio".to_string(),
+                neuron_id: fixtures::neuron_id(),
+                wallet_address: None,
+                social_links: vec![],
+            },
+            legacy_wallet_address: Some("my wallet".to_string()),
+            ..fixtures::reviewer_user_profile()
+        };
+        let wallet_address = fixtures::wallet_address();
+
+        (
+            original_profile.clone(),
+            UpdateMyUserProfileRequest {
+                username: None,
+                config: Some(MyUserConfigUpdate::Reviewer {
+                    bio: None,
+                    wallet_address: Some(wallet_address.to_string()),
+                    social_links: None,
+                }),
+            },
+            UserProfile {
+                config: UserConfig::Reviewer {
+                    bio: "Bio".to_string(),
+                    neuron_id: fixtures::neuron_id(),
+                    wallet_address: Some(wallet_address),
+                    social_links: vec![],
+                },
+                legacy_wallet_address: None,
+                ..original_profile
+            },
+        )
+    }
+
     #[rstest]
     fn update_my_user_profile_no_user_id() {
         let calling_principal = fixtures::principal_a();
@@ -622,7 +967,12 @@ mod tests {
         repository_mock.expect_get_user_profile_by_user_id().never();
         repository_mock.expect_update_user_profile().never();
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .update_my_user_profile(calling_principal, request)
@@ -659,7 +1009,12 @@ mod tests {
             .return_const(None);
         repository_mock.expect_update_user_profile().never();
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .update_my_user_profile(calling_principal, request)
@@ -674,6 +1029,92 @@ mod tests {
         )
     }
 
+    #[rstest]
+    fn update_my_user_profile_invalid_wallet_address() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let request = UpdateMyUserProfileRequest {
+            username: None,
+            config: Some(MyUserConfigUpdate::Reviewer {
+                bio: None,
+                wallet_address: Some("my wallet".to_string()),
+                social_links: None,
+            }),
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::reviewer_user_profile()));
+        repository_mock.expect_update_user_profile().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .update_my_user_profile(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(
+                "Wallet address my wallet must be an ICP account identifier or an ICRC-1 account"
+            )
+        )
+    }
+
+    #[rstest]
+    #[case::empty("", "Username must be between 3 and 32 characters long")]
+    #[case::reserved("Admin", "Username Admin is reserved")]
+    fn update_my_user_profile_invalid_username(
+        #[case] username: &str,
+        #[case] expected_message: &str,
+    ) {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let request = UpdateMyUserProfileRequest {
+            username: Some(username.to_string()),
+            config: None,
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::anonymous_user_profile()));
+        repository_mock.expect_update_user_profile().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .update_my_user_profile(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(result, ApiError::invalid_argument(expected_message));
+    }
+
     #[rstest]
     #[case(anonymous_username_update())]
     #[case(anonymous_admin_update())]
@@ -690,14 +1131,36 @@ mod tests {
             .expect_get_user_profile_by_user_id()
             .once()
             .with(eq(user_id))
-            .return_const(Some(original_profile));
+            .return_const(Some(original_profile.clone()));
         repository_mock
             .expect_update_user_profile()
             .once()
-            .with(eq(calling_principal), eq(user_id), eq(updated_profile))
+            .with(
+                eq(calling_principal),
+                eq(user_id),
+                eq(updated_profile.clone()),
+            )
             .return_const(Ok(()));
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::UpdateUserProfile),
+                eq(vec![user_id.to_string()]),
+                eq(Some(audit_snapshot(&original_profile))),
+                eq(Some(audit_snapshot(&updated_profile))),
+            )
+            .return_const(Ok(()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            audit_log_service_mock,
+            MockUserAvatarService::new(),
+        );
 
         service
             .update_user_profile(calling_principal, profile_update_request)
@@ -760,7 +1223,7 @@ mod tests {
                 config: Some(UserConfigUpdate::Reviewer {
                     bio: Some(bio.clone()),
                     neuron_id: Some(neuron_id),
-                    wallet_address: Some(wallet_address.clone()),
+                    wallet_address: Some(wallet_address.to_string()),
                     social_links: Some(
                         social_links
                             .clone()
@@ -774,7 +1237,7 @@ mod tests {
                 config: UserConfig::Reviewer {
                     bio,
                     neuron_id,
-                    wallet_address,
+                    wallet_address: Some(wallet_address),
                     social_links,
                 },
                 ..fixtures::anonymous_user_profile()
@@ -827,7 +1290,7 @@ mod tests {
                 config: Some(UserConfigUpdate::Reviewer {
                     bio: None,
                     neuron_id: Some(neuron_id),
-                    wallet_address: Some(wallet_address.clone()),
+                    wallet_address: Some(wallet_address.to_string()),
                     social_links: Some(
                         social_links
                             .clone()
@@ -841,7 +1304,7 @@ mod tests {
                 config: UserConfig::Reviewer {
                     bio: original_bio,
                     neuron_id,
-                    wallet_address,
+                    wallet_address: Some(wallet_address),
                     social_links,
                 },
                 ..original_profile
@@ -867,7 +1330,12 @@ mod tests {
             .return_const(None);
         repository_mock.expect_update_user_profile().never();
 
-        let service = UserProfileServiceImpl::new(repository_mock);
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
 
         let result = service
             .update_user_profile(calling_principal, request)
@@ -881,4 +1349,345 @@ mod tests {
             ))
         )
     }
+
+    #[rstest]
+    fn update_user_profile_invalid_wallet_address() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let request = UpdateUserProfileRequest {
+            user_id: user_id.to_string(),
+            username: None,
+            config: Some(UserConfigUpdate::Reviewer {
+                bio: None,
+                neuron_id: Some(fixtures::neuron_id()),
+                wallet_address: Some(
+                    "da01eeae5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb".to_string(),
+                ),
+                social_links: None,
+            }),
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::anonymous_user_profile()));
+        repository_mock.expect_update_user_profile().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .update_user_profile(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument(
+                "Wallet address da01eeae5eb00bb853b9c42e1637433c81348a8856f4cff1bb917e2cd04df2cb has an invalid checksum"
+            )
+        )
+    }
+
+    #[rstest]
+    fn suspend_user() {
+        let calling_principal = fixtures::principal_a();
+        let admin_id = fixtures::uuid_a();
+        let user_id = fixtures::uuid_b();
+        let request = SuspendUserRequest {
+            user_id: user_id.to_string(),
+            reason: "Posted spam in review comments".to_string(),
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(admin_id));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(None);
+        repository_mock
+            .expect_set_user_status()
+            .once()
+            .withf(move |principal, id, user_status| {
+                *principal == calling_principal
+                    && *id == user_id
+                    && matches!(
+                        user_status,
+                        UserStatus::Suspended { reason, suspended_by, .. }
+                            if reason == "Posted spam in review comments" && *suspended_by == admin_id
+                    )
+            })
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::SuspendUser),
+                eq(vec![user_id.to_string()]),
+                eq(None::<String>),
+                eq(Some(audit_snapshot(&UserStatus::Suspended {
+                    reason: "Posted spam in review comments".to_string(),
+                    suspended_by: admin_id,
+                    suspended_at: DateTime::new(get_date_time().unwrap()).unwrap(),
+                }))),
+            )
+            .return_const(Ok(()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            audit_log_service_mock,
+            MockUserAvatarService::new(),
+        );
+
+        service.suspend_user(calling_principal, request).unwrap();
+    }
+
+    #[rstest]
+    fn suspend_user_self() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+        let request = SuspendUserRequest {
+            user_id: user_id.to_string(),
+            reason: "Testing".to_string(),
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(user_id));
+        repository_mock.expect_set_user_status().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .suspend_user(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::invalid_argument("The calling principal cannot suspend its own user profile")
+        );
+    }
+
+    #[rstest]
+    fn suspend_user_already_inactive() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::uuid_b();
+        let request = SuspendUserRequest {
+            user_id: user_id.to_string(),
+            reason: "Posted spam in review comments".to_string(),
+        };
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_id_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some(fixtures::uuid_a()));
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::deleted_user_status()));
+        repository_mock.expect_set_user_status().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .suspend_user(calling_principal, request)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::conflict(&format!(
+                "User with id {} is already suspended or deleted",
+                user_id
+            ))
+        );
+    }
+
+    #[rstest]
+    fn restore_user() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_status_by_user_id()
+            .once()
+            .with(eq(user_id))
+            .return_const(Some(fixtures::suspended_user_status()));
+        repository_mock
+            .expect_remove_user_status()
+            .once()
+            .with(eq(calling_principal), eq(user_id))
+            .return_const(Ok(()));
+
+        let mut audit_log_service_mock = MockAuditLogService::new();
+        audit_log_service_mock
+            .expect_record_action()
+            .once()
+            .with(
+                eq(calling_principal),
+                eq(AuditAction::RestoreUser),
+                eq(vec![user_id.to_string()]),
+                eq(Some(audit_snapshot(&fixtures::suspended_user_status()))),
+                eq(None::<String>),
+            )
+            .return_const(Ok(()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            audit_log_service_mock,
+            MockUserAvatarService::new(),
+        );
+
+        service
+            .restore_user(
+                calling_principal,
+                RestoreUserRequest {
+                    user_id: user_id.to_string(),
+                },
+            )
+            .unwrap();
+    }
+
+    #[rstest]
+    fn delete_my_user_profile() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((user_id, fixtures::reviewer_user_profile())));
+        repository_mock
+            .expect_set_user_status()
+            .once()
+            .withf(move |principal, id, user_status| {
+                *principal == calling_principal
+                    && *id == user_id
+                    && matches!(user_status, UserStatus::Deleted { .. })
+            })
+            .return_const(Ok(()));
+
+        let mut user_avatar_service_mock = MockUserAvatarService::new();
+        user_avatar_service_mock
+            .expect_delete_my_user_avatar()
+            .never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            user_avatar_service_mock,
+        );
+
+        service.delete_my_user_profile(calling_principal).unwrap();
+    }
+
+    #[rstest]
+    fn delete_my_user_profile_with_avatar() {
+        let calling_principal = fixtures::principal_a();
+        let user_id = fixtures::user_id();
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Some((
+                user_id,
+                UserProfile {
+                    avatar_id: Some(fixtures::uuid_b()),
+                    ..fixtures::reviewer_user_profile()
+                },
+            )));
+        repository_mock
+            .expect_set_user_status()
+            .once()
+            .withf(move |principal, id, user_status| {
+                *principal == calling_principal
+                    && *id == user_id
+                    && matches!(user_status, UserStatus::Deleted { .. })
+            })
+            .return_const(Ok(()));
+
+        let mut user_avatar_service_mock = MockUserAvatarService::new();
+        user_avatar_service_mock
+            .expect_delete_my_user_avatar()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(Ok(()));
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            user_avatar_service_mock,
+        );
+
+        service.delete_my_user_profile(calling_principal).unwrap();
+    }
+
+    #[rstest]
+    fn delete_my_user_profile_no_profile() {
+        let calling_principal = fixtures::principal_a();
+
+        let mut repository_mock = MockUserProfileRepository::new();
+        repository_mock
+            .expect_get_user_profile_by_principal()
+            .once()
+            .with(eq(calling_principal))
+            .return_const(None);
+        repository_mock.expect_set_user_status().never();
+
+        let service = UserProfileServiceImpl::new(
+            repository_mock,
+            MockReviewScoreRepository::new(),
+            MockAuditLogService::new(),
+            MockUserAvatarService::new(),
+        );
+
+        let result = service
+            .delete_my_user_profile(calling_principal)
+            .unwrap_err();
+
+        assert_eq!(
+            result,
+            ApiError::not_found(&format!(
+                "User profile for principal {} not found",
+                calling_principal.to_text()
+            ))
+        );
+    }
 }
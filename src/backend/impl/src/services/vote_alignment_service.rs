@@ -0,0 +1,786 @@
+use std::str::FromStr;
+
+use crate::{
+    mappings::map_vote_alignment,
+    repositories::{
+        DateTime, NervousSystem, NeuronId, Proposal, ProposalId, ProposalRepository,
+        ProposalRepositoryImpl, ProposalReview, ProposalReviewId, ProposalReviewRepository,
+        ProposalReviewRepositoryImpl, ProposalVote, ReviewPeriodStateKey, UserConfig, UserId,
+        UserProfileRepository, UserProfileRepositoryImpl, VoteAlignment, VoteAlignmentRepository,
+        VoteAlignmentRepositoryImpl, VoteAlignmentStatus,
+    },
+    system_api::get_date_time,
+};
+use backend_api::{ApiError, ListVoteAlignmentsRequest, ListVoteAlignmentsResponse};
+use candid::Principal;
+use chrono::Duration;
+use external_canisters::nns::{BallotReader, GovernanceCanisterService, NeuronBallot};
+
+use super::NNS_GOVERNANCE_CANISTER_ID;
+
+/// The governance canister only keeps the latest ballots of each neuron,
+/// so proposals whose voting closed longer ago than this are not checked.
+const BALLOTS_RETENTION_DAYS: i64 = 7;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait VoteAlignmentService {
+    fn list_vote_alignments(
+        &self,
+        request: ListVoteAlignmentsRequest,
+    ) -> Result<ListVoteAlignmentsResponse, ApiError>;
+
+    /// Compares the votes of the published reviews with the ballots of the reviewers' neurons,
+    /// for the completed proposals whose voting closed and that were not checked yet.
+    /// Reviews whose neuron ballot is not visible to the canister, because the neuron is private
+    /// or the ballot is no longer among its recent ones, are not checked.
+    /// Returns the number of checked proposals.
+    async fn check_vote_alignments(&self) -> Result<usize, ApiError>;
+}
+
+pub struct VoteAlignmentServiceImpl<
+    VA: VoteAlignmentRepository,
+    P: ProposalRepository,
+    PR: ProposalReviewRepository,
+    U: UserProfileRepository,
+    B: BallotReader,
+> {
+    vote_alignment_repository: VA,
+    proposal_repository: P,
+    proposal_review_repository: PR,
+    user_profile_repository: U,
+    ballot_reader: B,
+}
+
+impl Default
+    for VoteAlignmentServiceImpl<
+        VoteAlignmentRepositoryImpl,
+        ProposalRepositoryImpl,
+        ProposalReviewRepositoryImpl,
+        UserProfileRepositoryImpl,
+        GovernanceCanisterService,
+    >
+{
+    fn default() -> Self {
+        Self::new(
+            VoteAlignmentRepositoryImpl::default(),
+            ProposalRepositoryImpl::default(),
+            ProposalReviewRepositoryImpl::default(),
+            UserProfileRepositoryImpl::default(),
+            GovernanceCanisterService(Principal::from_str(NNS_GOVERNANCE_CANISTER_ID).unwrap()),
+        )
+    }
+}
+
+impl<
+        VA: VoteAlignmentRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        U: UserProfileRepository,
+        B: BallotReader,
+    > VoteAlignmentService for VoteAlignmentServiceImpl<VA, P, PR, U, B>
+{
+    fn list_vote_alignments(
+        &self,
+        request: ListVoteAlignmentsRequest,
+    ) -> Result<ListVoteAlignmentsResponse, ApiError> {
+        let proposal_id = request
+            .proposal_id
+            .map(|id| ProposalId::try_from(id.as_str()))
+            .transpose()?;
+        let user_id = request
+            .user_id
+            .map(|id| UserId::try_from(id.as_str()))
+            .transpose()?;
+        let status = request.status.map(VoteAlignmentStatus::from);
+
+        let vote_alignments = match proposal_id {
+            Some(proposal_id) => self
+                .vote_alignment_repository
+                .get_vote_alignments_by_proposal_id(proposal_id)?,
+            None => self.vote_alignment_repository.get_vote_alignments(),
+        };
+
+        Ok(ListVoteAlignmentsResponse {
+            vote_alignments: vote_alignments
+                .into_iter()
+                .filter(|vote_alignment| {
+                    user_id.is_none_or(|user_id| vote_alignment.user_id == user_id)
+                        && status
+                            .as_ref()
+                            .is_none_or(|status| vote_alignment.status == *status)
+                })
+                .map(map_vote_alignment)
+                .collect(),
+        })
+    }
+
+    async fn check_vote_alignments(&self) -> Result<usize, ApiError> {
+        let now = DateTime::new(get_date_time()?)?;
+        let mut checked_proposals_count = 0;
+
+        for (proposal_id, proposal) in self
+            .proposal_repository
+            .get_proposals(Some(ReviewPeriodStateKey::Completed))?
+        {
+            if is_past_ballots_retention(&proposal, &now) {
+                continue;
+            }
+
+            let is_already_checked = !self
+                .vote_alignment_repository
+                .get_vote_alignments_by_proposal_id(proposal_id)?
+                .is_empty();
+            if is_already_checked {
+                continue;
+            }
+
+            let reviews = self.get_published_reviews_with_neurons(proposal_id)?;
+            if reviews.is_empty() {
+                continue;
+            }
+
+            let nns_proposal_id = proposal.nervous_system.proposal_id();
+            let voting_period = self
+                .ballot_reader
+                .get_proposal_voting_period(nns_proposal_id)
+                .await
+                .map_err(|err| {
+                    ApiError::internal(&format!(
+                        "Failed to fetch proposal {}: {:?}",
+                        nns_proposal_id, err
+                    ))
+                })?;
+            let Some(voting_period) = voting_period else {
+                continue;
+            };
+            if !voting_period.is_voting_closed(now.timestamp_seconds()) {
+                continue;
+            }
+
+            let neuron_ids = reviews.iter().map(|(_, _, neuron_id)| *neuron_id).collect();
+            let neuron_ballots = self
+                .ballot_reader
+                .get_neuron_recent_ballots(neuron_ids)
+                .await
+                .map_err(|err| {
+                    ApiError::internal(&format!(
+                        "Failed to fetch ballots of proposal {}: {:?}",
+                        nns_proposal_id, err
+                    ))
+                })?;
+
+            for (proposal_review_id, proposal_review, neuron_id) in reviews {
+                let neuron_vote = match neuron_ballots
+                    .get(&neuron_id)
+                    .map(|ballots| ballots.ballot(nns_proposal_id))
+                {
+                    Some(NeuronBallot::Voted(vote)) => Some(ProposalVote::from(vote)),
+                    Some(NeuronBallot::NotVoted) => None,
+                    Some(NeuronBallot::Unknown) | None => continue,
+                };
+                let status = VoteAlignmentStatus::new(&proposal_review.vote, neuron_vote.as_ref());
+
+                self.vote_alignment_repository
+                    .upsert_vote_alignment(VoteAlignment {
+                        proposal_id,
+                        user_id: proposal_review.user_id,
+                        proposal_review_id,
+                        neuron_id,
+                        review_vote: proposal_review.vote,
+                        neuron_vote,
+                        status,
+                        checked_at: now,
+                    })?;
+            }
+
+            checked_proposals_count += 1;
+        }
+
+        Ok(checked_proposals_count)
+    }
+}
+
+impl<
+        VA: VoteAlignmentRepository,
+        P: ProposalRepository,
+        PR: ProposalReviewRepository,
+        U: UserProfileRepository,
+        B: BallotReader,
+    > VoteAlignmentServiceImpl<VA, P, PR, U, B>
+{
+    fn new(
+        vote_alignment_repository: VA,
+        proposal_repository: P,
+        proposal_review_repository: PR,
+        user_profile_repository: U,
+        ballot_reader: B,
+    ) -> Self {
+        Self {
+            vote_alignment_repository,
+            proposal_repository,
+            proposal_review_repository,
+            user_profile_repository,
+            ballot_reader,
+        }
+    }
+
+    /// Returns the published reviews of the proposal, along with the neuron of their reviewer.
+    /// Reviews of users that are not reviewers anymore are skipped.
+    fn get_published_reviews_with_neurons(
+        &self,
+        proposal_id: ProposalId,
+    ) -> Result<Vec<(ProposalReviewId, ProposalReview, NeuronId)>, ApiError> {
+        Ok(self
+            .proposal_review_repository
+            .get_proposal_reviews_by_proposal_id(proposal_id)?
+            .into_iter()
+            .filter(|(_, proposal_review)| proposal_review.is_published())
+            .filter_map(|(proposal_review_id, proposal_review)| {
+                match self
+                    .user_profile_repository
+                    .get_user_profile_by_user_id(&proposal_review.user_id)?
+                    .config
+                {
+                    UserConfig::Reviewer { neuron_id, .. } => {
+                        Some((proposal_review_id, proposal_review, neuron_id))
+                    }
+                    _ => None,
+                }
+            })
+            .collect())
+    }
+}
+
+fn is_past_ballots_retention(proposal: &Proposal, now: &DateTime) -> bool {
+    let NervousSystem::Network { proposal_info, .. } = &proposal.nervous_system;
+
+    proposal_info
+        .deadline_timestamp_seconds
+        .is_some_and(|deadline| {
+            now.sub(Duration::days(BALLOTS_RETENTION_DAYS))
+                .timestamp_seconds()
+                > deadline
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures,
+        repositories::{
+            MockProposalRepository, MockProposalReviewRepository, MockUserProfileRepository,
+            MockVoteAlignmentRepository, UserProfile,
+        },
+    };
+    use external_canisters::nns::{
+        BallotVote, CanisterResult, NeuronRecentBallots, ProposalVotingPeriod,
+        MAX_NEURON_RECENT_BALLOTS,
+    };
+    use ic_cdk::api::call::RejectionCode;
+    use ic_nns_governance::pb::v1::ProposalInfo;
+    use mockall::predicate::*;
+    use rstest::*;
+    use std::collections::BTreeMap;
+
+    /// Like the governance canister, only returns the recent ballots of public neurons.
+    struct FakeBallotReader {
+        voting_period: CanisterResult<Option<ProposalVotingPeriod>>,
+        public_neurons: BTreeMap<u64, NeuronRecentBallots>,
+    }
+
+    impl FakeBallotReader {
+        fn new(
+            voting_period: CanisterResult<Option<ProposalVotingPeriod>>,
+            public_neurons: Vec<(u64, Vec<(u64, BallotVote)>)>,
+        ) -> Self {
+            Self {
+                voting_period,
+                public_neurons: public_neurons
+                    .into_iter()
+                    .map(|(neuron_id, votes)| {
+                        (
+                            neuron_id,
+                            NeuronRecentBallots {
+                                votes: BTreeMap::from_iter(votes),
+                            },
+                        )
+                    })
+                    .collect(),
+            }
+        }
+    }
+
+    impl BallotReader for FakeBallotReader {
+        async fn get_proposal_voting_period(
+            &self,
+            _proposal_id: u64,
+        ) -> CanisterResult<Option<ProposalVotingPeriod>> {
+            self.voting_period.clone()
+        }
+
+        async fn get_neuron_recent_ballots(
+            &self,
+            neuron_ids: Vec<u64>,
+        ) -> CanisterResult<BTreeMap<u64, NeuronRecentBallots>> {
+            Ok(neuron_ids
+                .into_iter()
+                .filter_map(|neuron_id| {
+                    self.public_neurons
+                        .get(&neuron_id)
+                        .map(|ballots| (neuron_id, ballots.clone()))
+                })
+                .collect())
+        }
+    }
+
+    fn closed_voting_period() -> ProposalVotingPeriod {
+        let now = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        ProposalVotingPeriod {
+            deadline_timestamp_seconds: Some(now.timestamp_seconds()),
+            decided_timestamp_seconds: now.timestamp_seconds(),
+        }
+    }
+
+    fn reviewer_user_profile_with_neuron(neuron_id: NeuronId) -> UserProfile {
+        let mut user_profile = fixtures::reviewer_user_profile();
+        if let UserConfig::Reviewer {
+            neuron_id: ref mut reviewer_neuron_id,
+            ..
+        } = user_profile.config
+        {
+            *reviewer_neuron_id = neuron_id;
+        }
+
+        user_profile
+    }
+
+    #[rstest]
+    fn list_vote_alignments() {
+        let request = ListVoteAlignmentsRequest {
+            proposal_id: Some(fixtures::proposal_id().to_string()),
+            user_id: None,
+            status: Some(backend_api::VoteAlignmentStatus::NotVoted),
+        };
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .with(eq(fixtures::proposal_id()))
+            .return_const(Ok(vec![
+                fixtures::vote_alignment_matched(),
+                fixtures::vote_alignment_not_voted(),
+            ]));
+        va_repository_mock.expect_get_vote_alignments().never();
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            FakeBallotReader::new(Ok(None), vec![]),
+        );
+
+        let result = service.list_vote_alignments(request).unwrap();
+
+        assert_eq!(
+            result.vote_alignments,
+            vec![map_vote_alignment(fixtures::vote_alignment_not_voted())]
+        );
+    }
+
+    #[rstest]
+    fn list_vote_alignments_by_user_id() {
+        let user_id = fixtures::uuid_a();
+        let request = ListVoteAlignmentsRequest {
+            proposal_id: None,
+            user_id: Some(user_id.to_string()),
+            status: None,
+        };
+        let user_vote_alignment = VoteAlignment {
+            user_id,
+            ..fixtures::vote_alignment_matched()
+        };
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments()
+            .once()
+            .return_const(vec![
+                fixtures::vote_alignment_not_voted(),
+                user_vote_alignment.clone(),
+            ]);
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            MockProposalRepository::new(),
+            MockProposalReviewRepository::new(),
+            MockUserProfileRepository::new(),
+            FakeBallotReader::new(Ok(None), vec![]),
+        );
+
+        let result = service.list_vote_alignments(request).unwrap();
+
+        assert_eq!(
+            result.vote_alignments,
+            vec![map_vote_alignment(user_vote_alignment)]
+        );
+    }
+
+    #[rstest]
+    async fn check_vote_alignments() {
+        let proposal_id = fixtures::proposal_id();
+        let matched_user_id = fixtures::uuid_a();
+        let mismatched_user_id = fixtures::uuid_b();
+        let not_voted_user_id = fixtures::uuid_c();
+        let private_neuron_user_id = fixtures::user_id();
+        let matched_neuron_id = 1;
+        let mismatched_neuron_id = 2;
+        let not_voted_neuron_id = 3;
+        let private_neuron_id = 4;
+        let nns_proposal_id = 127093;
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .with(eq(Some(ReviewPeriodStateKey::Completed)))
+            .return_const(Ok(vec![(
+                proposal_id,
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            )]));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![
+                (
+                    fixtures::uuid_a(),
+                    ProposalReview {
+                        user_id: matched_user_id,
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    fixtures::uuid_b(),
+                    ProposalReview {
+                        user_id: mismatched_user_id,
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    fixtures::uuid_c(),
+                    ProposalReview {
+                        user_id: not_voted_user_id,
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (
+                    fixtures::proposal_review_id(),
+                    ProposalReview {
+                        user_id: private_neuron_user_id,
+                        ..fixtures::proposal_review_published()
+                    },
+                ),
+                (fixtures::uuid(), fixtures::proposal_review_draft()),
+            ]));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        for (user_id, neuron_id) in [
+            (matched_user_id, matched_neuron_id),
+            (mismatched_user_id, mismatched_neuron_id),
+            (not_voted_user_id, not_voted_neuron_id),
+            (private_neuron_user_id, private_neuron_id),
+        ] {
+            up_repository_mock
+                .expect_get_user_profile_by_user_id()
+                .once()
+                .with(eq(user_id))
+                .return_const(Some(reviewer_user_profile_with_neuron(neuron_id)));
+        }
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .with(eq(proposal_id))
+            .return_const(Ok(vec![]));
+        for (user_id, neuron_vote, status) in [
+            (
+                matched_user_id,
+                Some(ProposalVote::Yes),
+                VoteAlignmentStatus::Matched,
+            ),
+            (
+                mismatched_user_id,
+                Some(ProposalVote::No),
+                VoteAlignmentStatus::Mismatched,
+            ),
+            (not_voted_user_id, None, VoteAlignmentStatus::NotVoted),
+        ] {
+            va_repository_mock
+                .expect_upsert_vote_alignment()
+                .once()
+                .withf(move |vote_alignment| {
+                    vote_alignment.proposal_id == proposal_id
+                        && vote_alignment.user_id == user_id
+                        && vote_alignment.review_vote == ProposalVote::Yes
+                        && vote_alignment.neuron_vote == neuron_vote
+                        && vote_alignment.status == status
+                })
+                .return_const(Ok(()));
+        }
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            up_repository_mock,
+            // the private neuron voted too, but its ballots are not visible to the canister
+            FakeBallotReader::new(
+                Ok(Some(closed_voting_period())),
+                vec![
+                    (
+                        matched_neuron_id,
+                        vec![(nns_proposal_id, BallotVote::Yes), (127094, BallotVote::No)],
+                    ),
+                    (
+                        mismatched_neuron_id,
+                        vec![(nns_proposal_id, BallotVote::No)],
+                    ),
+                    (not_voted_neuron_id, vec![(127092, BallotVote::Yes)]),
+                ],
+            ),
+        );
+
+        let result = service.check_vote_alignments().await.unwrap();
+
+        assert_eq!(result, 1);
+    }
+
+    #[rstest]
+    async fn check_vote_alignments_skips_ballots_past_recent_ones() {
+        let proposal_id = fixtures::proposal_id();
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .return_const(Ok(vec![(
+                proposal_id,
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            )]));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![(
+                fixtures::uuid(),
+                fixtures::proposal_review_published(),
+            )]));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .return_const(Some(fixtures::reviewer_user_profile()));
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![]));
+        va_repository_mock.expect_upsert_vote_alignment().never();
+
+        // every kept ballot is on a newer proposal, so the vote on this one is unknown
+        let newer_ballots = (0..MAX_NEURON_RECENT_BALLOTS as u64)
+            .map(|offset| (127094 + offset, BallotVote::Yes))
+            .collect();
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            up_repository_mock,
+            FakeBallotReader::new(
+                Ok(Some(closed_voting_period())),
+                vec![(fixtures::neuron_id(), newer_ballots)],
+            ),
+        );
+
+        let result = service.check_vote_alignments().await.unwrap();
+
+        assert_eq!(result, 1);
+    }
+
+    #[rstest]
+    async fn check_vote_alignments_voting_open() {
+        let proposal_id = fixtures::proposal_id();
+        let now = DateTime::new(get_date_time().unwrap()).unwrap();
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .return_const(Ok(vec![(
+                proposal_id,
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            )]));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![(
+                fixtures::uuid(),
+                fixtures::proposal_review_published(),
+            )]));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .return_const(Some(fixtures::reviewer_user_profile()));
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![]));
+        va_repository_mock.expect_upsert_vote_alignment().never();
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            up_repository_mock,
+            FakeBallotReader::new(
+                Ok(Some(ProposalVotingPeriod {
+                    deadline_timestamp_seconds: Some(
+                        now.add(Duration::hours(1)).timestamp_seconds(),
+                    ),
+                    ..closed_voting_period()
+                })),
+                vec![(fixtures::neuron_id(), vec![(127093, BallotVote::Yes)])],
+            ),
+        );
+
+        let result = service.check_vote_alignments().await.unwrap();
+
+        assert_eq!(result, 0);
+    }
+
+    #[rstest]
+    async fn check_vote_alignments_skips_checked_and_old_proposals() {
+        let now = DateTime::new(get_date_time().unwrap()).unwrap();
+        let old_proposal = Proposal {
+            nervous_system: NervousSystem::Network {
+                proposal_id: 127092,
+                proposal_info: ProposalInfo {
+                    deadline_timestamp_seconds: Some(
+                        now.sub(Duration::days(BALLOTS_RETENTION_DAYS + 1))
+                            .timestamp_seconds(),
+                    ),
+                    ..ProposalInfo::default()
+                },
+            },
+            ..fixtures::nns_replica_version_management_proposal_completed(None, None)
+        };
+
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .return_const(Ok(vec![
+                (fixtures::uuid_a(), old_proposal),
+                (
+                    fixtures::proposal_id(),
+                    fixtures::nns_replica_version_management_proposal_completed(None, None),
+                ),
+            ]));
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .with(eq(fixtures::proposal_id()))
+            .return_const(Ok(vec![fixtures::vote_alignment_matched()]));
+        va_repository_mock.expect_upsert_vote_alignment().never();
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .never();
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            MockUserProfileRepository::new(),
+            FakeBallotReader::new(Ok(None), vec![]),
+        );
+
+        let result = service.check_vote_alignments().await.unwrap();
+
+        assert_eq!(result, 0);
+    }
+
+    #[rstest]
+    async fn check_vote_alignments_governance_error() {
+        let mut p_repository_mock = MockProposalRepository::new();
+        p_repository_mock
+            .expect_get_proposals()
+            .once()
+            .return_const(Ok(vec![(
+                fixtures::proposal_id(),
+                fixtures::nns_replica_version_management_proposal_completed(None, None),
+            )]));
+
+        let mut pr_repository_mock = MockProposalReviewRepository::new();
+        pr_repository_mock
+            .expect_get_proposal_reviews_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![(
+                fixtures::uuid(),
+                fixtures::proposal_review_published(),
+            )]));
+
+        let mut up_repository_mock = MockUserProfileRepository::new();
+        up_repository_mock
+            .expect_get_user_profile_by_user_id()
+            .once()
+            .return_const(Some(fixtures::reviewer_user_profile()));
+
+        let mut va_repository_mock = MockVoteAlignmentRepository::new();
+        va_repository_mock
+            .expect_get_vote_alignments_by_proposal_id()
+            .once()
+            .return_const(Ok(vec![]));
+        va_repository_mock.expect_upsert_vote_alignment().never();
+
+        let service = VoteAlignmentServiceImpl::new(
+            va_repository_mock,
+            p_repository_mock,
+            pr_repository_mock,
+            up_repository_mock,
+            FakeBallotReader::new(
+                Err((
+                    RejectionCode::SysTransient,
+                    "Canister is stopping".to_string(),
+                )),
+                vec![],
+            ),
+        );
+
+        let result = service.check_vote_alignments().await.unwrap_err();
+
+        assert!(result
+            .message()
+            .starts_with("Failed to fetch proposal 127093"));
+    }
+}
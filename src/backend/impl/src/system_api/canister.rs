@@ -0,0 +1,12 @@
+use candid::Principal;
+
+pub fn get_canister_id() -> Principal {
+    #[cfg(target_family = "wasm")]
+    let canister_id = ic_cdk::id();
+
+    // fallback id for non-wasm targets, e.g. unit tests
+    #[cfg(not(target_family = "wasm"))]
+    let canister_id = Principal::from_text("bkyz2-fmaaa-aaaaa-qaaaq-cai").unwrap();
+
+    canister_id
+}
@@ -1,5 +1,7 @@
+mod canister;
 mod rand;
 mod time;
 
+pub use canister::*;
 pub use rand::*;
 pub use time::*;
@@ -280,6 +280,7 @@ export function validateProposalReview(
       summary: expect.any(Array),
       build_reproduced: expect.any(Array),
       images_paths: expect.any(Array),
+      attachments_paths: expect.any(Array),
       vote: expected.vote,
       proposal_review_commits: expected.commits.commitSha.map(
         commitSha =>
@@ -83,7 +83,7 @@ describe('get my proposal review', () => {
 
     expect(resErr).toEqual({
       code: 403,
-      message: `Principal ${bob.getPrincipal()} must be a reviewer to call this endpoint`,
+      message: `Principal ${bob.getPrincipal()} must have the review_proposals permission to call this endpoint`,
     });
   });
 
@@ -97,7 +97,7 @@ describe('get my proposal review', () => {
 
     expect(resErr).toEqual({
       code: 403,
-      message: `Principal ${charlie.getPrincipal()} must be a reviewer to call this endpoint`,
+      message: `Principal ${charlie.getPrincipal()} must have the review_proposals permission to call this endpoint`,
     });
   });
 
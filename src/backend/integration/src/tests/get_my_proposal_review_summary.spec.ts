@@ -88,7 +88,7 @@ describe('get my proposal review summary', () => {
 
     expect(resErr).toEqual({
       code: 403,
-      message: `Principal ${bob.getPrincipal()} must be a reviewer to call this endpoint`,
+      message: `Principal ${bob.getPrincipal()} must have the review_proposals permission to call this endpoint`,
     });
   });
 
@@ -102,7 +102,7 @@ describe('get my proposal review summary', () => {
 
     expect(resErr).toEqual({
       code: 403,
-      message: `Principal ${charlie.getPrincipal()} must be a reviewer to call this endpoint`,
+      message: `Principal ${charlie.getPrincipal()} must have the review_proposals permission to call this endpoint`,
     });
   });
 
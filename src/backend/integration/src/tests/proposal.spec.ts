@@ -121,7 +121,7 @@ describe('Proposal', () => {
       const resAnonymousErr = extractErrResponse(resAnonymous);
       expect(resAnonymousErr).toEqual({
         code: 403,
-        message: `Principal ${anonymousIdentity.getPrincipal().toText()} must be an admin to call this endpoint`,
+        message: `Principal ${anonymousIdentity.getPrincipal().toText()} must have the sync_proposals permission to call this endpoint`,
       });
 
       const [reviewerIdentity] = await driver.users.createReviewer();
@@ -130,7 +130,7 @@ describe('Proposal', () => {
       const resReviewerErr = extractErrResponse(resReviewer);
       expect(resReviewerErr).toEqual({
         code: 403,
-        message: `Principal ${reviewerIdentity.getPrincipal().toText()} must be an admin to call this endpoint`,
+        message: `Principal ${reviewerIdentity.getPrincipal().toText()} must have the sync_proposals permission to call this endpoint`,
       });
     });
 
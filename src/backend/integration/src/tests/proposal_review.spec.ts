@@ -74,7 +74,7 @@ describe('Proposal Review', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -92,7 +92,7 @@ describe('Proposal Review', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
@@ -128,6 +128,7 @@ describe('Proposal Review', () => {
           summary: ['summary'],
           build_reproduced: [true],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { yes: null },
         } satisfies ProposalReview,
@@ -166,6 +167,7 @@ describe('Proposal Review', () => {
           summary: [],
           build_reproduced: [],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { unspecified: null },
         } satisfies ProposalReview,
@@ -337,7 +339,7 @@ describe('Proposal Review', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -356,7 +358,7 @@ describe('Proposal Review', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
@@ -86,7 +86,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${anonymousIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -108,7 +108,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
@@ -750,7 +750,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -771,7 +771,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
@@ -985,7 +985,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -1000,7 +1000,7 @@ describe('Proposal Review Commit', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
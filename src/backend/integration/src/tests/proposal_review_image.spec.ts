@@ -78,7 +78,7 @@ describe('Proposal Review Image', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
 
       // as admin
@@ -95,7 +95,7 @@ describe('Proposal Review Image', () => {
         code: 403,
         message: `Principal ${controllerIdentity
           .getPrincipal()
-          .toText()} must be a reviewer to call this endpoint`,
+          .toText()} must have the review_proposals permission to call this endpoint`,
       });
     });
 
@@ -582,8 +582,10 @@ describe('User Profile', () => {
       const resErr = extractErrResponse(res);
 
       expect(resErr).toEqual({
-        code: 401,
-        message: 'Anonymous principals are not allowed to call this endpoint',
+        code: 404,
+        message: `Principal ${anonymousIdentity
+          .getPrincipal()
+          .toText()} must have a profile to call this endpoint`,
       });
     });
 
@@ -623,7 +625,7 @@ describe('User Profile', () => {
         code: 403,
         message: `Principal ${alice
           .getPrincipal()
-          .toText()} must be an admin to call this endpoint`,
+          .toText()} must have the manage_users permission to call this endpoint`,
       });
     });
 
@@ -1,7 +1,7 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, ItemFn, Path, ReturnType};
+use syn::{parse_macro_input, parse_quote, Attribute, Expr, ItemFn, Path, ReturnType, Stmt};
 
 #[proc_macro_attribute]
 pub fn log_errors(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -42,3 +42,81 @@ pub fn log_errors(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     result.into()
 }
+
+/// Asserts that the calling principal has the given permission before the body runs.
+///
+/// Meant for controller methods, it relies on the `access_control_service` field
+/// and the `calling_principal` argument that every controller method follows.
+#[proc_macro_attribute]
+pub fn require_permission(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let permission = parse_macro_input!(attr as Expr);
+    let input = parse_macro_input!(item as ItemFn);
+
+    expand_access_policies(AccessPolicy::Permission(permission), input)
+}
+
+/// Asserts that the calling principal is not anonymous before the body runs.
+///
+/// See [macro@require_permission] for the requirements on the annotated method.
+#[proc_macro_attribute]
+pub fn require_authenticated(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return quote! {
+            compile_error!("The require_authenticated macro does not take any arguments.");
+        }
+        .into();
+    }
+    let input = parse_macro_input!(item as ItemFn);
+
+    expand_access_policies(AccessPolicy::Authenticated, input)
+}
+
+enum AccessPolicy {
+    Authenticated,
+    Permission(Expr),
+}
+
+impl AccessPolicy {
+    fn from_attribute(attr: &Attribute) -> Option<syn::Result<Self>> {
+        let name = attr.path().segments.last()?.ident.to_string();
+        match name.as_str() {
+            "require_authenticated" => Some(Ok(AccessPolicy::Authenticated)),
+            "require_permission" => Some(attr.parse_args::<Expr>().map(AccessPolicy::Permission)),
+            _ => None,
+        }
+    }
+
+    fn to_check(&self) -> Stmt {
+        match self {
+            AccessPolicy::Authenticated => parse_quote! {
+                self.access_control_service
+                    .assert_principal_not_anonymous(&calling_principal)?;
+            },
+            AccessPolicy::Permission(permission) => parse_quote! {
+                self.access_control_service
+                    .assert_permission(&calling_principal, #permission)?;
+            },
+        }
+    }
+}
+
+// The outermost policy attribute expands the remaining ones as well,
+// so that the checks run in the order they are declared in.
+fn expand_access_policies(first: AccessPolicy, mut input: ItemFn) -> TokenStream {
+    let mut policies = vec![first];
+    let mut attrs = vec![];
+    for attr in input.attrs.drain(..) {
+        match AccessPolicy::from_attribute(&attr) {
+            Some(Ok(policy)) => policies.push(policy),
+            Some(Err(err)) => return err.to_compile_error().into(),
+            None => attrs.push(attr),
+        }
+    }
+    input.attrs = attrs;
+
+    let mut stmts: Vec<Stmt> = policies.iter().map(AccessPolicy::to_check).collect();
+    stmts.append(&mut input.block.stmts);
+    input.block.stmts = stmts;
+
+    quote!(#input).into()
+}
@@ -165,6 +165,7 @@ function mapSocialLinksRequest(
   return links?.map(link => ({
     platform: mapSocialLinkPlatformRequest(link.type),
     username: link.username,
+    url: [],
   }));
 }
 
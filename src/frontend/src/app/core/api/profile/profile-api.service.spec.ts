@@ -63,17 +63,18 @@ describe('ProfileApiService', () => {
     },
   ];
   const commonApiSocialMediaLinks: ApiSocialLink[] = [
-    { platform: { dscvr: null }, username: 'dscvr_username' },
+    { platform: { dscvr: null }, username: 'dscvr_username', url: [] },
     {
       platform: { dfinityforum: null },
       username: 'forum_username',
+      url: [],
     },
-    { platform: { discord: null }, username: 'discord_username' },
-    { platform: { github: null }, username: 'github_username' },
-    { platform: { openchat: null }, username: 'oc_username' },
-    { platform: { taggr: null }, username: 'taggr_username' },
-    { platform: { website: null }, username: 'website_username' },
-    { platform: { x: null }, username: 'x_username' },
+    { platform: { discord: null }, username: 'discord_username', url: [] },
+    { platform: { github: null }, username: 'github_username', url: [] },
+    { platform: { openchat: null }, username: 'oc_username', url: [] },
+    { platform: { taggr: null }, username: 'taggr_username', url: [] },
+    { platform: { website: null }, username: 'website_username', url: [] },
+    { platform: { x: null }, username: 'x_username', url: [] },
   ];
 
   beforeEach(() => {
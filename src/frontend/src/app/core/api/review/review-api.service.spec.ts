@@ -77,6 +77,7 @@ describe('ReviewApiService', () => {
 
           build_reproduced: [],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { unspecified: null },
         },
@@ -197,6 +198,7 @@ describe('ReviewApiService', () => {
 
               build_reproduced: [],
               images_paths: [],
+              attachments_paths: [],
               proposal_review_commits: [],
               vote: { unspecified: null },
             },
@@ -215,6 +217,7 @@ describe('ReviewApiService', () => {
 
               build_reproduced: [],
               images_paths: [],
+              attachments_paths: [],
               proposal_review_commits: [],
               vote: { unspecified: null },
             },
@@ -299,6 +302,7 @@ describe('ReviewApiService', () => {
 
           build_reproduced: [],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { unspecified: null },
         },
@@ -368,6 +372,7 @@ describe('ReviewApiService', () => {
 
           build_reproduced: [],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { unspecified: null },
         },
@@ -443,6 +448,7 @@ describe('ReviewApiService', () => {
 
           build_reproduced: [],
           images_paths: [],
+          attachments_paths: [],
           proposal_review_commits: [],
           vote: { unspecified: null },
         },